@@ -0,0 +1,13 @@
+// 按特性和目标平台确定是否使用Rayon并行判断：本机构建看parallel特性，wasm构建看wasm-threads特性
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(rayon_backend)");
+    let wasm = std::env::var("CARGO_CFG_TARGET_ARCH").is_ok_and(|arch| arch == "wasm32");
+    let enabled = if wasm {
+        std::env::var_os("CARGO_FEATURE_WASM_THREADS").is_some()
+    } else {
+        std::env::var_os("CARGO_FEATURE_PARALLEL").is_some()
+    };
+    if enabled {
+        println!("cargo::rustc-cfg=rayon_backend");
+    }
+}
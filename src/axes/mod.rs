@@ -0,0 +1,76 @@
+// 主轴模块：求选中点的加权质心和主成分方向（PCA），选区的朝向标记可以直接在wasm中算出，无需把选中的点导出到js端
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 每个点的权重 类型Float32Array 与点一一对应，传空数组时每个点的权重为1
+//     3. 选择掩码 类型Uint32Array 与点一一对应（例如判断函数的结果），非0表示选中，传空数组时选中全部点
+// 输出(js端):
+//     1. 类型Float64Array [总权重, 质心x, 质心y, 主方差, 次方差, 主轴x, 主轴y, 次轴x, 次轴y]
+//        主方差不小于次方差，两轴为互相垂直的单位向量，主轴的x分量不为负（竖直时y分量为正）
+// 说明: 协方差按总权重归一化（总体协方差），特征值即沿两轴的加权方差，开平方即标准差，可直接作为椭圆标记的半轴长度；
+//     权重不是正有限数的点不参与计算；没有选中任何点（总权重为0）时质心、方差和轴都为NaN；
+//     两个方差相等（各向同性）时方向不确定，取x轴为主轴
+
+use alloc::{vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+pub mod test;
+
+// WebAssembly导出函数：选中点的加权质心和主轴
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn selection_axes(
+    points: &[f32],  // 输入点集，格式为[x1, y1, x2, y2, ...]
+    weights: &[f32], // 每个点的权重，空数组表示权重都为1
+    mask: &[u32],    // 选择掩码，空数组表示全部选中
+) -> Vec<f64> {
+    let point_count = points.len() / 2;
+    let selected = |i: usize| -> Option<f64> {
+        if !mask.is_empty() && mask.get(i).is_none_or(|&m| m == 0) {
+            return None;
+        }
+        let w = if weights.is_empty() { 1.0 } else { *weights.get(i)? as f64 };
+        (w.is_finite() && w > 0.0).then_some(w)
+    };
+
+    // 第一遍求质心，第二遍求相对质心的协方差，避免大坐标下相减抵消
+    let (mut total, mut sum_x, mut sum_y) = (0.0, 0.0, 0.0);
+    for i in 0..point_count {
+        if let Some(w) = selected(i) {
+            total += w;
+            sum_x += w * points[i * 2] as f64;
+            sum_y += w * points[i * 2 + 1] as f64;
+        }
+    }
+    if total == 0.0 {
+        let mut result = vec![f64::NAN; 9];
+        result[0] = 0.0;
+        return result;
+    }
+    let (cx, cy) = (sum_x / total, sum_y / total);
+
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+    for i in 0..point_count {
+        if let Some(w) = selected(i) {
+            let (dx, dy) = (points[i * 2] as f64 - cx, points[i * 2 + 1] as f64 - cy);
+            sxx += w * dx * dx;
+            sxy += w * dx * dy;
+            syy += w * dy * dy;
+        }
+    }
+    let (sxx, sxy, syy) = (sxx / total, sxy / total, syy / total);
+
+    // 2×2对称矩阵的特征分解
+    let half_trace = (sxx + syy) / 2.0;
+    let radius = ((sxx - syy) * (sxx - syy) / 4.0 + sxy * sxy).sqrt();
+    let (major, minor) = (half_trace + radius, (half_trace - radius).max(0.0));
+    let theta = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+    let (mut ux, mut uy) = (theta.cos(), theta.sin());
+    if ux < 0.0 || (ux == 0.0 && uy < 0.0) {
+        (ux, uy) = (-ux, -uy);
+    }
+
+    vec![total, cx, cy, major, minor, ux, uy, -uy, ux]
+}
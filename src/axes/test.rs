@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use crate::axes::selection_axes;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_diagonal_selection() {
+        // 沿y = x分布的4个点，外加一个未选中的离群点
+        let points = vec![0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 100.0, -100.0];
+        let mask = vec![1, 1, 1, 1, 0];
+        let r = 0.5f64.sqrt();
+        // 沿对角线的方差：偏移 ±1.5√2、±0.5√2 的平方均值为2.5
+        assert_close(&selection_axes(&points, &[], &mask), &[4.0, 1.5, 1.5, 2.5, 0.0, r, r, -r, r]);
+
+        // 权重集中在两端时方差变大
+        let weights = vec![3.0, 1.0, 1.0, 3.0, 1.0];
+        let axes = selection_axes(&points, &weights, &mask);
+        assert_close(&axes[..3], &[8.0, 1.5, 1.5]);
+        assert!((axes[3] - (6.0 * 4.5 + 2.0 * 0.5) / 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_axis_aligned_and_empty() {
+        // 竖直方向更分散：主轴为y轴，x分量为0时y分量取正
+        let points = vec![0.0, -2.0, 0.0, 2.0, -1.0, 0.0, 1.0, 0.0];
+        assert_close(&selection_axes(&points, &[], &[]), &[4.0, 0.0, 0.0, 2.0, 0.5, 0.0, 1.0, -1.0, 0.0]);
+
+        // 不合法的权重不参与计算，没有选中的点时结果为NaN
+        let axes = selection_axes(&points, &[f32::NAN, -1.0, 0.0, 0.0], &[]);
+        assert_eq!(axes[0], 0.0);
+        assert!(axes[1..].iter().all(|v| v.is_nan()));
+        assert_eq!(selection_axes(&[], &[], &[]).len(), 9);
+    }
+}
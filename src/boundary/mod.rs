@@ -0,0 +1,255 @@
+// 边界模块：沿多边形环的弧长参数化工具
+// 用于在边界上放置动画标记、按边界相对位置做标注等场景
+
+// 输入(js端):
+//     1. 多边形路径点 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形路径点的拆分 类型Uint32Array 含义与points_in_polygon模块一致
+//     3. 环索引 0表示外环, 1表示第一个洞, 以此类推
+//     4. 归一化弧长t 0表示环的起点, 1表示绕环一周回到起点
+// 输出(js端):
+//     1. boundary_point_at: 类型Float64Array [x, y, tx, ty] 其中(tx, ty)为该处的单位切线方向; 环不存在或长度为0时返回空数组
+//     2. boundary_arc_lengths: 类型Float64Array 与顶点一一对应, 为该顶点沿所在环距环起点的累计弧长
+//     3. boundary_ring_lengths: 类型Float64Array 每个环闭合后的总周长
+//     4. nearest_ring: 类型Float64Array 每个点占4个值 [环索引, 距离, 最近点x, 最近点y]，与点是否在多边形内无关；
+//        没有任何环时为空数组
+//     5. sample_boundary: 类型Float64Array 每个采样点占4个值 [x, y, 环索引, 边的起点顶点索引（全局）]，
+//        每个环从起点开始等间距采样，间距为不超过spacing的最大的"周长的整数分之一"，使采样点在环上均匀分布、首尾间距相同；
+//        长度为0的环不产生采样点；spacing不是正数时为空数组，采样点总数超过MAX_SAMPLES时报错
+
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::common::{ring_edges, ring_ranges, vertex_diagonal, RELATIVE_TOLERANCE};
+
+pub mod test;
+
+// sample_boundary 采样点总数的上限
+const MAX_SAMPLES: usize = 1 << 24;
+
+// 返回环上归一化弧长t处的坐标和单位切线
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn boundary_point_at(
+    polygon: &[f32],  // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],    // 多边形环的分割索引
+    ring_idx: usize,  // 目标环的索引
+    t: f64,           // 归一化弧长，超出[0, 1]时按周期回绕
+) -> Vec<f64> {
+    let ring = match ring_slice(polygon, rings, ring_idx) {
+        Some(ring) => ring,
+        None => return Vec::new(),
+    };
+
+    let lengths = cumulative_lengths(ring);
+    let total = lengths[lengths.len() - 1];
+    let tolerance = degenerate_length(polygon);
+    if total <= tolerance {
+        return Vec::new();
+    }
+
+    // 回绕到[0, 1)，便于动画时直接传入递增的t
+    let target = t.rem_euclid(1.0) * total;
+    let vertex_count = ring.len() / 2;
+
+    // 找到目标弧长所在的边（跳过退化边），恰好落在顶点上时取后一条边的切线
+    let mut edge_idx = None;
+    for i in 0..vertex_count {
+        if lengths[i + 1] - lengths[i] <= tolerance {
+            continue;
+        }
+        edge_idx = Some(i);
+        if target < lengths[i + 1] {
+            break;
+        }
+    }
+
+    let i = match edge_idx {
+        Some(i) => i,
+        None => return Vec::new(),
+    };
+    let seg_len = lengths[i + 1] - lengths[i];
+    let (x1, y1) = vertex(ring, i);
+    let (x2, y2) = vertex(ring, (i + 1) % vertex_count);
+    let local = ((target - lengths[i]) / seg_len).min(1.0);
+
+    vec![
+        x1 + local * (x2 - x1),
+        y1 + local * (y2 - y1),
+        (x2 - x1) / seg_len,
+        (y2 - y1) / seg_len,
+    ]
+}
+
+// 返回每个顶点在其所在环上的累计弧长，可用于把线性参考数据（如"沿围栏500米"）映射到边界坐标
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn boundary_arc_lengths(polygon: &[f32], rings: &[u32]) -> Vec<f64> {
+    let mut result = Vec::with_capacity(polygon.len() / 2);
+
+    for (start, end) in ring_ranges(polygon.len() / 2, rings) {
+        let lengths = cumulative_lengths(&polygon[start * 2..end * 2]);
+        // 去掉最后一项（闭合后的总周长），保证与顶点一一对应
+        result.extend_from_slice(&lengths[..end - start]);
+    }
+
+    result
+}
+
+// 返回每个环闭合后的总周长，与boundary_arc_lengths配合使用
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn boundary_ring_lengths(polygon: &[f32], rings: &[u32]) -> Vec<f64> {
+    ring_ranges(polygon.len() / 2, rings)
+        .into_iter()
+        .map(|(start, end)| {
+            let lengths = cumulative_lengths(&polygon[start * 2..end * 2]);
+            lengths[lengths.len() - 1]
+        })
+        .collect()
+}
+
+// 返回每个点最近的环、到该环的距离以及环上的最近点，用于"把选区吸附到最近的区域边界"等交互
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn nearest_ring(
+    points: &[f32],  // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32], // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],   // 多边形环的分割索引
+) -> Vec<f64> {
+    let ranges = ring_ranges(polygon.len() / 2, rings);
+    if ranges.iter().all(|&(start, end)| end == start) {
+        return Vec::new();
+    }
+    // 每个环的边界框，点到边界框的距离不小于当前最近距离时跳过该环
+    let boxes: Vec<[f64; 4]> = ranges
+        .iter()
+        .map(|&(start, end)| {
+            let mut b = [f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY];
+            for k in start..end {
+                let (x, y) = vertex(polygon, k);
+                b = [b[0].min(x), b[1].min(y), b[2].max(x), b[3].max(y)];
+            }
+            b
+        })
+        .collect();
+
+    let mut result = Vec::with_capacity(points.len() / 2 * 4);
+    for point in points.chunks_exact(2) {
+        let (px, py) = (point[0] as f64, point[1] as f64);
+        let mut best = (0usize, f64::INFINITY, px, py);
+        for (ring_idx, (&(start, end), b)) in ranges.iter().zip(&boxes).enumerate() {
+            let (dx, dy) = ((b[0] - px).max(px - b[2]).max(0.0), (b[1] - py).max(py - b[3]).max(0.0));
+            if dx.hypot(dy) >= best.1 {
+                continue;
+            }
+            for [ax, ay, bx, by] in ring_edges(polygon, start, end).map(|edge| edge.map(f64::from)) {
+                let (cx, cy) = closest_on_segment(px, py, ax, ay, bx, by);
+                let d = (px - cx).hypot(py - cy);
+                if d < best.1 {
+                    best = (ring_idx, d, cx, cy);
+                }
+            }
+        }
+        result.extend_from_slice(&[best.0 as f64, best.1, best.2, best.3]);
+    }
+    result
+}
+
+// 沿所有环等间距采样，用于生成可点击的边界控制点，或近似计算Hausdorff/Fréchet距离
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn sample_boundary(
+    polygon: &[f32], // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],   // 多边形环的分割索引
+    spacing: f64,    // 采样间距的上限
+) -> Result<Vec<f64>, ExportError> {
+    sample_boundary_values(polygon, rings, spacing).map_err(export_error)
+}
+
+// 边界采样的主流程，采样点过多时返回错误信息
+pub(crate) fn sample_boundary_values(polygon: &[f32], rings: &[u32], spacing: f64) -> Result<Vec<f64>, String> {
+    if !(spacing.is_finite() && spacing > 0.0) {
+        return Ok(Vec::new());
+    }
+    let ranges = ring_ranges(polygon.len() / 2, rings);
+    let tolerance = degenerate_length(polygon);
+    let ring_lengths: Vec<Vec<f64>> = ranges.iter().map(|&(start, end)| cumulative_lengths(&polygon[start * 2..end * 2])).collect();
+    let counts: Vec<usize> = ring_lengths
+        .iter()
+        .map(|lengths| {
+            let total = lengths[lengths.len() - 1];
+            if total <= tolerance { 0 } else { (total / spacing).ceil().min(MAX_SAMPLES as f64 + 1.0) as usize }
+        })
+        .collect();
+    let sample_count: usize = counts.iter().sum();
+    if sample_count > MAX_SAMPLES {
+        return Err(format!("sampling at spacing {} needs more than {} points", spacing, MAX_SAMPLES));
+    }
+
+    let mut result = Vec::with_capacity(sample_count * 4);
+    for (ring_idx, ((&(start, end), lengths), &count)) in ranges.iter().zip(&ring_lengths).zip(&counts).enumerate() {
+        let ring = &polygon[start * 2..end * 2];
+        let vertex_count = end - start;
+        let step = lengths[vertex_count] / count.max(1) as f64;
+        // 采样点的弧长递增，所在的边只向前移动，跳过退化边
+        let mut i = 0;
+        for k in 0..count {
+            let target = k as f64 * step;
+            while i + 1 < vertex_count && (target >= lengths[i + 1] || lengths[i + 1] - lengths[i] <= tolerance) {
+                i += 1;
+            }
+            let (x1, y1) = vertex(ring, i);
+            let (x2, y2) = vertex(ring, (i + 1) % vertex_count);
+            let seg_len = lengths[i + 1] - lengths[i];
+            let local = if seg_len <= tolerance { 0.0 } else { ((target - lengths[i]) / seg_len).clamp(0.0, 1.0) };
+            result.extend_from_slice(&[x1 + local * (x2 - x1), y1 + local * (y2 - y1), ring_idx as f64, (start + i) as f64]);
+        }
+    }
+    Ok(result)
+}
+
+// 长度不超过该值的边视为退化边：与多边形的默认容差相同，按全部顶点边界框的对角线长度缩放
+fn degenerate_length(polygon: &[f32]) -> f64 {
+    RELATIVE_TOLERANCE * vertex_diagonal(polygon)
+}
+
+// 线段(ax, ay)-(bx, by)上距离点(px, py)最近的点
+fn closest_on_segment(px: f64, py: f64, ax: f64, ay: f64, bx: f64, by: f64) -> (f64, f64) {
+    let (dx, dy) = (bx - ax, by - ay);
+    let len_sq = dx * dx + dy * dy;
+    if len_sq == 0.0 {
+        return (ax, ay);
+    }
+    let t = (((px - ax) * dx + (py - ay) * dy) / len_sq).clamp(0.0, 1.0);
+    (ax + t * dx, ay + t * dy)
+}
+
+// 取出指定环的顶点切片，至少需要2个顶点
+fn ring_slice<'a>(polygon: &'a [f32], rings: &[u32], ring_idx: usize) -> Option<&'a [f32]> {
+    let ranges = ring_ranges(polygon.len() / 2, rings);
+    let &(start, end) = ranges.get(ring_idx)?;
+    if end - start < 2 {
+        return None;
+    }
+    Some(&polygon[start * 2..end * 2])
+}
+
+#[inline]
+fn vertex(ring: &[f32], i: usize) -> (f64, f64) {
+    (ring[i * 2] as f64, ring[i * 2 + 1] as f64)
+}
+
+// 计算环上每个顶点的累计弧长，最后一项为闭合后的总周长（长度为顶点数+1）
+fn cumulative_lengths(ring: &[f32]) -> Vec<f64> {
+    let vertex_count = ring.len() / 2;
+    let mut lengths = Vec::with_capacity(vertex_count + 1);
+    let mut acc = 0.0;
+    lengths.push(acc);
+
+    for i in 0..vertex_count {
+        let (x1, y1) = vertex(ring, i);
+        let (x2, y2) = vertex(ring, (i + 1) % vertex_count);
+        acc += ((x2 - x1) * (x2 - x1) + (y2 - y1) * (y2 - y1)).sqrt();
+        lengths.push(acc);
+    }
+
+    lengths
+}
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use crate::boundary::{boundary_arc_lengths, boundary_point_at, boundary_ring_lengths, nearest_ring, sample_boundary_values};
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_point_at_on_square_with_hole() {
+        let polygon = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // Outer ring, perimeter 16
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole, perimeter 4
+        ];
+        let rings = vec![4];
+
+        // 外环起点、第一条边中点、第二条边上
+        assert_close(&boundary_point_at(&polygon, &rings, 0, 0.0), &[0.0, 0.0, 1.0, 0.0]);
+        assert_close(&boundary_point_at(&polygon, &rings, 0, 0.125), &[2.0, 0.0, 1.0, 0.0]);
+        assert_close(&boundary_point_at(&polygon, &rings, 0, 0.375), &[4.0, 2.0, 0.0, 1.0]);
+
+        // 闭合边（最后一个顶点回到起点）
+        assert_close(&boundary_point_at(&polygon, &rings, 0, 0.875), &[0.0, 2.0, 0.0, -1.0]);
+
+        // t超出范围时按周期回绕
+        assert_close(&boundary_point_at(&polygon, &rings, 0, 1.125), &[2.0, 0.0, 1.0, 0.0]);
+        assert_close(&boundary_point_at(&polygon, &rings, 0, -0.125), &[0.0, 2.0, 0.0, -1.0]);
+
+        // 洞（拆分点之后的剩余顶点）
+        assert_close(&boundary_point_at(&polygon, &rings, 1, 0.5), &[2.0, 2.0, -1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_point_at_invalid_ring() {
+        let polygon = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0];
+        assert!(boundary_point_at(&polygon, &[3], 1, 0.5).is_empty());
+        assert!(boundary_point_at(&[0.0, 0.0, 0.0, 0.0], &[2], 0, 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_arc_lengths_per_vertex() {
+        let polygon = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 3.0, // Outer triangle, perimeter 12
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole, perimeter 4
+        ];
+        let rings = vec![3];
+
+        assert_close(
+            &boundary_arc_lengths(&polygon, &rings),
+            &[0.0, 4.0, 7.0, 0.0, 1.0, 2.0, 3.0],
+        );
+        assert_close(&boundary_ring_lengths(&polygon, &rings), &[12.0, 4.0]);
+    }
+
+    #[test]
+    fn test_nearest_ring_and_snap_point() {
+        let polygon = vec![
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 外环
+            4.0, 4.0, 6.0, 4.0, 6.0, 6.0, 4.0, 6.0, // 洞
+        ];
+        let points = vec![
+            1.0, 5.0, // 内部，靠近外环左边
+            3.0, 5.0, // 内部，靠近洞的左边
+            5.0, 5.0, // 洞中
+            12.0, 13.0, // 外部，最近点是外环的右上角
+        ];
+        assert_close(
+            &nearest_ring(&points, &polygon, &[4]),
+            &[
+                0.0, 1.0, 0.0, 5.0,
+                1.0, 1.0, 4.0, 5.0,
+                1.0, 1.0, 5.0, 4.0, // 到洞的四条边距离相同，取第一条边（底边）上的最近点
+                0.0, 13f64.sqrt(), 10.0, 10.0,
+            ],
+        );
+        assert!(nearest_ring(&points, &[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_sample_boundary_even_spacing() {
+        // 外环周长16，洞周长4；间距1.5时外环取11个点（间距16/11），洞取3个点（间距4/3）
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 1.0];
+        let samples = sample_boundary_values(&polygon, &[4], 1.5).unwrap();
+        assert_eq!(samples.len(), (11 + 3) * 4);
+        assert_close(&samples[..4], &[0.0, 0.0, 0.0, 0.0]);
+        // 外环第3个点落在第一条边上，第4个点落在第二条边上
+        assert_close(&samples[8..12], &[32.0 / 11.0, 0.0, 0.0, 0.0]);
+        assert_close(&samples[12..16], &[4.0, 48.0 / 11.0 - 4.0, 0.0, 1.0]);
+        // 洞的采样点带环索引1和全局顶点索引
+        assert_close(&samples[44..48], &[1.0, 1.0, 1.0, 4.0]);
+        assert_close(&samples[48..52], &[1.0 + 1.0 / 3.0, 2.0, 1.0, 5.0]);
+
+        // 相邻采样点的间距相同
+        let outer: Vec<(f64, f64)> = samples[..44].chunks(4).map(|s| (s[0], s[1])).collect();
+        let along = |p: (f64, f64)| if p.1 == 0.0 { p.0 } else if p.0 == 4.0 { 4.0 + p.1 } else if p.1 == 4.0 { 12.0 - p.0 } else { 16.0 - p.1 };
+        for w in outer.windows(2) {
+            assert!((along(w[1]) - along(w[0]) - 16.0 / 11.0).abs() < 1e-9);
+        }
+
+        assert!(sample_boundary_values(&polygon, &[4], 0.0).unwrap().is_empty());
+        assert!(sample_boundary_values(&polygon, &[4], 1e-9).is_err());
+        assert_eq!(sample_boundary_values(&polygon, &[4], 100.0).unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_repeated_split_keeps_ring_indices() {
+        use crate::points_in_polygon::scanline::ScanlineIndex;
+
+        // 拆分[4, 4, 8]：环0为外环，环1为空环，环2、3为两个洞，与构建多边形时的环索引一致
+        let polygon = vec![
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 外环
+            2.0, 2.0, 4.0, 2.0, 4.0, 4.0, 2.0, 4.0, // 洞A
+            6.0, 6.0, 8.0, 6.0, 8.0, 8.0, 6.0, 8.0, // 洞B
+        ];
+        let rings = vec![4, 4, 8];
+        let mut touched = Vec::new();
+        ScanlineIndex::new(&polygon, &rings).boundary_rings(7.0, 6.0, &mut touched);
+        assert_eq!(touched, vec![3]);
+
+        assert_close(&nearest_ring(&[7.0, 5.5], &polygon, &rings), &[3.0, 0.5, 7.0, 6.0]);
+        assert_close(&boundary_ring_lengths(&polygon, &rings), &[40.0, 0.0, 8.0, 8.0]);
+        assert_close(&boundary_point_at(&polygon, &rings, 2, 0.0), &[2.0, 2.0, 1.0, 0.0]);
+        assert!(boundary_point_at(&polygon, &rings, 1, 0.0).is_empty());
+
+        let samples = sample_boundary_values(&polygon, &rings, 2.0).unwrap();
+        let sampled_rings: Vec<f64> = samples.chunks(4).map(|s| s[2]).collect();
+        assert_eq!(sampled_rings.iter().filter(|&&r| r == 2.0).count(), 4);
+        assert_eq!(sampled_rings.iter().filter(|&&r| r == 3.0).count(), 4);
+        assert!(!sampled_rings.contains(&1.0));
+    }
+
+    #[test]
+    fn test_tiny_ring_is_not_degenerate() {
+        // 周长远小于1e-9的环仍可取点和采样
+        let polygon = [0.0, 0.0, 1e-10, 0.0, 1e-10, 1e-10, 0.0, 1e-10];
+        let point = boundary_point_at(&polygon, &[4], 0, 0.125);
+        assert_eq!(point.len(), 4);
+        assert!((point[0] - 5e-11).abs() < 1e-15 && point[1] == 0.0);
+        assert_eq!(sample_boundary_values(&polygon, &[4], 1.5e-10).unwrap().len(), 3 * 4);
+    }
+}
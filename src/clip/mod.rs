@@ -0,0 +1,89 @@
+// 线段裁剪模块：一次调用把大量互不相关的线段裁剪到一组区域（多边形）内，返回每条线段落在各区域内的部分，
+// 用于大型线数据集只渲染区域内的部分；每个区域的索引只构建一次，线段先按区域边界框排除
+
+// 输入(js端):
+//     1. 线段 类型Float32Array 每条线段占4个值 [x0, y0, x1, y1, ...]
+//     2. 区域组 类型Float32Array 所有区域多边形的路径点依次拼接
+//     3. 区域拆分 类型Uint32Array 第2个及之后每个区域的起始顶点索引，含义与coverage模块相同
+//     4. 环拆分 类型Uint32Array 各区域内部洞的起始顶点索引（按拼接后的全局顶点索引）
+//     5. 边界上点是否考虑为内部 boolean 为true时沿边界延伸的部分也计入区域内
+// 输出(js端):
+//     1. 类型Float64Array 每段区域内的部分占6个值 [线段索引, 区域索引, x0, y0, x1, y1]，
+//        按线段索引、区域索引排列，同一线段在同一区域内的部分按从起点到终点的顺序排列；
+//        区域重叠时同一部分对每个区域各输出一次
+// 说明: 线段被区域边界切开处作为各部分的端点，相邻的区域内部分合并为一段；退化为点的线段不输出
+
+use alloc::vec::Vec;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::coverage::split_polygons;
+use crate::points_in_polygon::scanline::ScanlineIndex;
+use crate::trajectory::segment_breaks;
+
+pub mod test;
+
+// WebAssembly导出函数：把线段裁剪到区域组内
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn clip_segments(
+    segments: &[f32],         // 线段，格式为[x0, y0, x1, y1, ...]
+    regions: &[f32],          // 所有区域多边形顶点依次拼接
+    region_splits: &[u32],    // 第2个及之后每个区域的起始顶点索引
+    rings: &[u32],            // 洞的起始顶点索引（全局）
+    boundary_is_inside: bool, // 边界上的点是否视为在区域内部
+) -> Vec<f64> {
+    let indexes: Vec<(ScanlineIndex, Vec<[f64; 4]>)> = split_polygons(regions, region_splits, rings)
+        .iter()
+        .map(|(polygon, local_rings)| {
+            let index = ScanlineIndex::new(polygon, local_rings);
+            let edges = index.edges().collect();
+            (index, edges)
+        })
+        .collect();
+
+    let mut result = Vec::new();
+    for (i, segment) in segments.chunks_exact(4).enumerate() {
+        let [x0, y0, x1, y1] = [segment[0] as f64, segment[1] as f64, segment[2] as f64, segment[3] as f64];
+        if x0 == x1 && y0 == y1 {
+            continue;
+        }
+        let (dx, dy) = (x1 - x0, y1 - y0);
+
+        for (region, (index, edges)) in indexes.iter().enumerate() {
+            // 线段的边界框与区域的边界框不相交时整条线段都在区域外
+            let (min_x, min_y, max_x, max_y) = index.bounds();
+            if x0.max(x1) < min_x || x0.min(x1) > max_x || y0.max(y1) < min_y || y0.min(y1) > max_y {
+                continue;
+            }
+
+            // 区域边界把线段切成若干子线段，每个子线段整体位于同一侧，用中点判断
+            let mut open: Option<f64> = None; // 当前区域内部分的起点参数
+            let breaks = segment_breaks(edges, x0, y0, x1, y1, index.polygon().tolerance);
+            for pair in breaks.windows(2) {
+                let (t0, t1) = (pair[0], pair[1]);
+                let mid = (t0 + t1) / 2.0;
+                let inside = index.locate(x0 + dx * mid, y0 + dy * mid).to_u32(boundary_is_inside) == 1;
+                match (inside, open) {
+                    (true, None) => open = Some(t0),
+                    (false, Some(start)) => {
+                        push_piece(&mut result, i, region, x0, y0, dx, dy, start, t0);
+                        open = None;
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(start) = open {
+                push_piece(&mut result, i, region, x0, y0, dx, dy, start, 1.0);
+            }
+        }
+    }
+    result
+}
+
+// 输出线段参数 [t0, t1] 对应的部分，端点参数为0或1时直接使用原端点坐标
+#[allow(clippy::too_many_arguments)]
+fn push_piece(result: &mut Vec<f64>, segment: usize, region: usize, x0: f64, y0: f64, dx: f64, dy: f64, t0: f64, t1: f64) {
+    let point = |t: f64| if t == 1.0 { (x0 + dx, y0 + dy) } else { (x0 + dx * t, y0 + dy * t) };
+    let (ax, ay) = point(t0);
+    let (bx, by) = point(t1);
+    result.extend_from_slice(&[segment as f64, region as f64, ax, ay, bx, by]);
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use crate::clip::clip_segments;
+
+    // 区域0: [0,4]×[0,4] 带洞 [1,3]×[1,3]；区域1: [3,6]×[0,2] 与区域0重叠
+    const REGIONS: [f32; 24] = [
+        0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // 区域0外环
+        1.0, 1.0, 3.0, 1.0, 3.0, 3.0, 1.0, 3.0, // 区域0的洞
+        3.0, 0.0, 6.0, 0.0, 6.0, 2.0, 3.0, 2.0, // 区域1
+    ];
+
+    #[test]
+    fn test_clip_through_hole_and_overlap() {
+        let segments = vec![
+            -1.0, 2.0, 7.0, 2.0, // 穿过区域0和洞，沿区域1的顶边
+            -1.0, 0.5, 7.0, 0.5, // 穿过两个区域的重叠部分
+            8.0, 8.0, 9.0, 9.0, // 在所有区域外
+            2.0, 2.0, 2.0, 2.0, // 退化为点
+        ];
+        let result = clip_segments(&segments, &REGIONS, &[8], &[4], false);
+        assert_eq!(
+            result,
+            vec![
+                0.0, 0.0, 0.0, 2.0, 1.0, 2.0, // 线段0在区域0内洞左侧的部分
+                0.0, 0.0, 3.0, 2.0, 4.0, 2.0, // 线段0在区域0内洞右侧的部分
+                1.0, 0.0, 0.0, 0.5, 4.0, 0.5, // 线段1在区域0内
+                1.0, 1.0, 3.0, 0.5, 6.0, 0.5, // 线段1在区域1内
+            ]
+        );
+
+        // 边界视为内部时，沿区域1顶边的部分也计入区域1
+        let result = clip_segments(&segments[..4], &REGIONS, &[8], &[4], true);
+        assert_eq!(result, vec![0.0, 0.0, 0.0, 2.0, 1.0, 2.0, 0.0, 0.0, 3.0, 2.0, 4.0, 2.0, 0.0, 1.0, 3.0, 2.0, 6.0, 2.0]);
+    }
+
+    #[test]
+    fn test_segment_inside_keeps_endpoints() {
+        // 完全在区域内的线段原样输出，方向保持不变
+        let result = clip_segments(&[0.5, 3.5, 0.5, 0.5], &REGIONS, &[8], &[4], true);
+        assert_eq!(result, vec![0.0, 0.0, 0.5, 3.5, 0.5, 0.5]);
+    }
+}
@@ -0,0 +1,156 @@
+// 覆盖率模块：统计点云落在一组多边形（的并集）内的比例，可选给出每个多边形各自的覆盖率
+// 所有多边形共享一个粗网格索引，一次遍历点云即可得到全部结果，
+// 适用于"有多少传感器位于已测绘区域内"这类质检统计
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形组 类型Float32Array 所有多边形的路径点依次拼接
+//     3. 多边形拆分 类型Uint32Array 第2个及之后每个多边形的起始顶点索引，含义与环的拆分相同
+//     4. 环拆分 类型Uint32Array 各多边形内部洞的起始顶点索引（按拼接后的全局顶点索引）
+//     5. 是否输出每个多边形的覆盖率 boolean
+//     6. 边界上点是否考虑为内部 boolean
+// 输出(js端):
+//     1. 类型Float64Array [并集覆盖率, 多边形0覆盖率, 多边形1覆盖率, ...] 覆盖率为0~1的比例，
+//        不输出逐个多边形的覆盖率时只有第一个值；点云为空时覆盖率为0
+
+use alloc::{vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::points_in_polygon::scanline::ScanlineIndex;
+use crate::points_in_polygon::common::ring_ranges;
+
+pub mod test;
+
+// 共享网格每个方向的单元数
+const GRID_SIZE: usize = 64;
+
+// WebAssembly导出函数：点云被多边形组覆盖的比例
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn coverage(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygons: &[f32],         // 所有多边形顶点依次拼接
+    polygon_splits: &[u32],   // 第2个及之后每个多边形的起始顶点索引
+    rings: &[u32],            // 洞的起始顶点索引（全局）
+    per_region: bool,         // 是否输出每个多边形的覆盖率
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<f64> {
+    let indexes: Vec<ScanlineIndex> = split_polygons(polygons, polygon_splits, rings)
+        .iter()
+        .map(|(polygon, local_rings)| ScanlineIndex::new(polygon, local_rings))
+        .collect();
+    let grid = SharedGrid::new(&indexes);
+
+    let point_count = points.len() / 2;
+    let mut union_hits = 0usize;
+    let mut region_hits = vec![0usize; indexes.len()];
+
+    for i in 0..point_count {
+        let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+        let mut covered = false;
+        for &region in grid.candidates(x, y) {
+            if indexes[region].locate(x, y).to_u32(boundary_is_inside) == 1 {
+                covered = true;
+                if !per_region {
+                    break;
+                }
+                region_hits[region] += 1;
+            }
+        }
+        union_hits += covered as usize;
+    }
+
+    let fraction = |hits: usize| if point_count == 0 { 0.0 } else { hits as f64 / point_count as f64 };
+    let mut result = vec![fraction(union_hits)];
+    if per_region {
+        result.extend(region_hits.into_iter().map(fraction));
+    }
+    result
+}
+
+// 把拼接的多边形组拆成单个多边形：(顶点切片, 局部环拆分)
+// 没有洞的多边形补上末尾拆分，与单个多边形接口的约定一致；
+// 空的或重复的拆分得到没有顶点的多边形，仍占据多边形序号，输出中的序号始终与输入一致
+pub(crate) fn split_polygons<'a>(polygons: &'a [f32], polygon_splits: &[u32], rings: &[u32]) -> Vec<(&'a [f32], Vec<u32>)> {
+    ring_ranges(polygons.len() / 2, polygon_splits)
+        .into_iter()
+        .map(|(start, end)| {
+            let mut local: Vec<u32> = rings
+                .iter()
+                .map(|&r| r as usize)
+                .filter(|&r| r > start && r < end)
+                .map(|r| (r - start) as u32)
+                .collect();
+            if local.is_empty() {
+                local.push((end - start) as u32);
+            }
+            (&polygons[start * 2..end * 2], local)
+        })
+        .collect()
+}
+
+// 所有多边形共享的粗网格：每个单元记录边界框与之相交的多边形
+pub(crate) struct SharedGrid {
+    min_x: f64,
+    min_y: f64,
+    cell_w: f64,
+    cell_h: f64,
+    cells: Vec<Vec<usize>>,
+}
+
+impl SharedGrid {
+    pub(crate) fn new(indexes: &[ScanlineIndex]) -> SharedGrid {
+        let bounds: Vec<(f64, f64, f64, f64)> = indexes.iter().map(|index| index.bounds()).collect();
+        let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+        let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for &(x0, y0, x1, y1) in &bounds {
+            min_x = min_x.min(x0);
+            min_y = min_y.min(y0);
+            max_x = max_x.max(x1);
+            max_y = max_y.max(y1);
+        }
+
+        let mut grid = SharedGrid {
+            min_x,
+            min_y,
+            cell_w: ((max_x - min_x) / GRID_SIZE as f64).max(f64::MIN_POSITIVE),
+            cell_h: ((max_y - min_y) / GRID_SIZE as f64).max(f64::MIN_POSITIVE),
+            cells: vec![Vec::new(); GRID_SIZE * GRID_SIZE],
+        };
+        if bounds.is_empty() {
+            return grid;
+        }
+
+        for (region, &(x0, y0, x1, y1)) in bounds.iter().enumerate() {
+            // 没有边的多边形不进入任何单元
+            if x0 > x1 || y0 > y1 {
+                continue;
+            }
+            let (c0, r0) = grid.cell_of(x0, y0);
+            let (c1, r1) = grid.cell_of(x1, y1);
+            for r in r0..=r1 {
+                for c in c0..=c1 {
+                    grid.cells[r * GRID_SIZE + c].push(region);
+                }
+            }
+        }
+        grid
+    }
+
+    // 点所在单元的列、行，范围外的点夹到边缘单元
+    fn cell_of(&self, x: f64, y: f64) -> (usize, usize) {
+        let c = ((x - self.min_x) / self.cell_w).floor().clamp(0.0, (GRID_SIZE - 1) as f64) as usize;
+        let r = ((y - self.min_y) / self.cell_h).floor().clamp(0.0, (GRID_SIZE - 1) as f64) as usize;
+        (c, r)
+    }
+
+    // 可能包含该点的多边形
+    pub(crate) fn candidates(&self, x: f64, y: f64) -> &[usize] {
+        if !(self.min_x.is_finite() && x >= self.min_x && y >= self.min_y) {
+            return &[];
+        }
+        let (c, r) = self.cell_of(x, y);
+        &self.cells[r * GRID_SIZE + c]
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::coverage::coverage;
+
+    #[test]
+    fn test_union_and_per_region_coverage() {
+        // 多边形0: [0,4]×[0,4] 带洞 [1,2]×[1,2]；多边形1: [3,6]×[0,2] 与多边形0重叠
+        let polygons = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // 多边形0外环
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // 多边形0的洞
+            3.0, 0.0, 6.0, 0.0, 6.0, 2.0, 3.0, 2.0, // 多边形1
+        ];
+        let polygon_splits = vec![8];
+        let rings = vec![4];
+        let points = vec![
+            0.5, 0.5, // 只在多边形0内
+            1.5, 1.5, // 在洞内
+            3.5, 1.0, // 两者重叠处
+            5.0, 1.0, // 只在多边形1内
+            5.0, 3.0, // 外部
+            -1.0, -1.0, // 外部
+            4.0, 3.0, // 多边形0的边界上
+            3.0, 3.0, // 只在多边形0内
+        ];
+
+        assert_eq!(
+            coverage(&points, &polygons, &polygon_splits, &rings, true, true),
+            vec![5.0 / 8.0, 4.0 / 8.0, 2.0 / 8.0]
+        );
+        assert_eq!(coverage(&points, &polygons, &polygon_splits, &rings, false, false), vec![4.0 / 8.0]);
+        assert_eq!(coverage(&[], &polygons, &polygon_splits, &rings, true, true), vec![0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_empty_polygon_keeps_indices() {
+        // 重复的拆分得到空的多边形1，多边形2的覆盖率仍在序号2处
+        let polygons = vec![
+            0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0, // 多边形0
+            5.0, 0.0, 7.0, 0.0, 7.0, 2.0, 5.0, 2.0, // 多边形2
+        ];
+        let points = vec![1.0, 1.0, 6.0, 1.0, 6.0, 1.5, 9.0, 9.0];
+        assert_eq!(coverage(&points, &polygons, &[4, 4], &[], true, true), vec![0.75, 0.25, 0.0, 0.5]);
+    }
+}
@@ -0,0 +1,235 @@
+// 密度区域模块：把点云分箱统计密度，按阈值选出高密度的单元，再把这些单元的并集矢量化为多边形，
+// 用于"自动选中密集的点簇"，得到的多边形可以直接传回判断函数
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 单元边长 cell_size 按点的坐标单位，网格对齐到cell_size的整数倍
+//     3. 阈值 threshold 单元内的点数不少于该值时选中
+// 输出(js端):
+//     1. DensityRegion
+//        polygon() 路径点 类型Float32Array
+//        polygon_splits() 第2个及之后每个组成部分的起始顶点索引 类型Uint32Array
+//        rings() 洞的起始顶点索引（全局） 类型Uint32Array
+//        三者可直接传给point_in_multipolygon；part_count()为组成部分的个数，没有选中任何单元时为0
+// 说明: 多边形沿选中单元的边界描出，外环逆时针、洞顺时针，共线的顶点已合并；只在对角相接的单元属于不同的部分（4连通），
+//     完全被选中单元包围的未选中区域成为洞；单元总数超过MAX_CELLS、坐标与单元边长之比过大或参数不合法时报错
+
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use hashbrown::HashMap;
+use crate::points_in_polygon::index_u32;
+
+pub mod test;
+
+// 网格单元总数的上限
+const MAX_CELLS: usize = 1 << 24;
+// 单元索引绝对值的上限：超出时坐标与单元边长之比过大，单元索引之差可能溢出
+const MAX_CELL_INDEX: f64 = (1u64 << 52) as f64;
+
+// 矢量化后的密度区域
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct DensityRegion {
+    polygon: Vec<f32>,
+    polygon_splits: Vec<u32>,
+    rings: Vec<u32>,
+    part_count: usize,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl DensityRegion {
+    pub fn polygon(&self) -> Vec<f32> {
+        self.polygon.clone()
+    }
+
+    pub fn polygon_splits(&self) -> Vec<u32> {
+        self.polygon_splits.clone()
+    }
+
+    pub fn rings(&self) -> Vec<u32> {
+        self.rings.clone()
+    }
+
+    pub fn part_count(&self) -> usize {
+        self.part_count
+    }
+}
+
+// WebAssembly导出函数：高密度区域的多边形
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn density_region(
+    points: &[f32],  // 输入点集，格式为[x1, y1, x2, y2, ...]
+    cell_size: f64,  // 单元边长
+    threshold: f64,  // 选中单元的最少点数
+) -> Result<DensityRegion, ExportError> {
+    density_region_values(points, cell_size, threshold).map_err(export_error)
+}
+
+// 密度区域的主流程，参数不合法时返回错误信息
+pub(crate) fn density_region_values(points: &[f32], cell_size: f64, threshold: f64) -> Result<DensityRegion, String> {
+    if !(cell_size.is_finite() && cell_size > 0.0) {
+        return Err(format!("cell_size must be a positive number, got {}", cell_size));
+    }
+    let mut result = DensityRegion { polygon: Vec::new(), polygon_splits: Vec::new(), rings: Vec::new(), part_count: 0 };
+
+    // 分箱：网格原点对齐到cell_size的整数倍，非有限坐标的点被忽略
+    let cell_index = |v: f32| -> Result<i64, String> {
+        let index = (v as f64 / cell_size).floor();
+        if index.abs() > MAX_CELL_INDEX {
+            return Err(format!("coordinate {} is too large for cell_size {}, use a larger cell_size", v, cell_size));
+        }
+        Ok(index as i64)
+    };
+    let cells: Vec<(i64, i64)> = points
+        .chunks_exact(2)
+        .filter(|p| p[0].is_finite() && p[1].is_finite())
+        .map(|p| Ok((cell_index(p[0])?, cell_index(p[1])?)))
+        .collect::<Result<_, String>>()?;
+    let Some(&(first_c, first_r)) = cells.first() else {
+        return Ok(result);
+    };
+    let (mut c0, mut r0, mut c1, mut r1) = (first_c, first_r, first_c, first_r);
+    for &(c, r) in &cells {
+        (c0, r0, c1, r1) = (c0.min(c), r0.min(r), c1.max(c), r1.max(r));
+    }
+    // 单元索引不超过MAX_CELL_INDEX，索引之差不会溢出
+    let span = |lo: i64, hi: i64| usize::try_from(hi - lo).ok().and_then(|n| n.checked_add(1));
+    let too_large = || format!("density grid spans more than {} cells, use a larger cell_size", MAX_CELLS);
+    let (cols, rows) = (span(c0, c1).ok_or_else(too_large)?, span(r0, r1).ok_or_else(too_large)?);
+    let cell_count = cols.checked_mul(rows).filter(|&n| n <= MAX_CELLS).ok_or_else(|| {
+        format!("density grid of {}x{} cells exceeds {} cells, use a larger cell_size", cols, rows, MAX_CELLS)
+    })?;
+
+    let mut counts = vec![0u32; cell_count];
+    for &(c, r) in &cells {
+        counts[(r - r0) as usize * cols + (c - c0) as usize] += 1;
+    }
+    let selected = |c: i64, r: i64| -> bool {
+        c >= 0 && r >= 0 && (c as usize) < cols && (r as usize) < rows && counts[r as usize * cols + c as usize] as f64 >= threshold
+    };
+
+    // 描出选中单元的边界环（网格坐标），按面积的符号区分外环和洞
+    let loops = trace_loops(cols, rows, &selected);
+    let area = |ring: &[(i64, i64)]| -> i64 {
+        (0..ring.len()).map(|k| {
+            let (a, b) = (ring[k], ring[(k + 1) % ring.len()]);
+            a.0 * b.1 - b.0 * a.1
+        }).sum()
+    };
+    let (outers, holes): (Vec<_>, Vec<_>) = loops.into_iter().partition(|ring| area(ring) > 0);
+
+    // 洞归属于包含它的面积最小的外环：以洞的第一条边左侧（选中的一侧）单元的中心判断
+    let mut part_holes: Vec<Vec<&Vec<(i64, i64)>>> = vec![Vec::new(); outers.len()];
+    for hole in &holes {
+        let (a, b) = (hole[0], hole[1]);
+        let (dx, dy) = ((b.0 - a.0).signum(), (b.1 - a.1).signum());
+        // 第一个单元的中心：沿边前进半个单元再向左偏移半个单元，坐标放大2倍保持为整数
+        let (px, py) = (2 * a.0 + dx - dy, 2 * a.1 + dy + dx);
+        if let Some(owner) = (0..outers.len())
+            .filter(|&k| contains_doubled(&outers[k], px, py))
+            .min_by_key(|&k| area(&outers[k]))
+        {
+            part_holes[owner].push(hole);
+        }
+    }
+
+    let to_world = |(i, j): (i64, i64)| [((i + c0) as f64 * cell_size) as f32, ((j + r0) as f64 * cell_size) as f32];
+    for (outer, holes) in outers.iter().zip(&part_holes) {
+        if result.part_count > 0 {
+            result.polygon_splits.push(index_u32(result.polygon.len() / 2)?);
+        }
+        result.part_count += 1;
+        result.polygon.extend(outer.iter().flat_map(|&v| to_world(v)));
+        for hole in holes {
+            result.rings.push(index_u32(result.polygon.len() / 2)?);
+            result.polygon.extend(hole.iter().flat_map(|&v| to_world(v)));
+        }
+    }
+    Ok(result)
+}
+
+// 沿选中单元与未选中单元之间的边描出全部闭合环，顶点为网格坐标，选中的一侧始终在行进方向的左侧；
+// 一个顶点有两条出边（对角相接的单元）时优先左转，使对角相接的单元分属不同的环
+pub(crate) fn trace_loops(cols: usize, rows: usize, selected: &dyn Fn(i64, i64) -> bool) -> Vec<Vec<(i64, i64)>> {
+    let mut edges: Vec<((i64, i64), (i64, i64))> = Vec::new();
+    for r in 0..rows as i64 {
+        for c in 0..cols as i64 {
+            if !selected(c, r) {
+                continue;
+            }
+            if !selected(c, r - 1) {
+                edges.push(((c, r), (c + 1, r)));
+            }
+            if !selected(c + 1, r) {
+                edges.push(((c + 1, r), (c + 1, r + 1)));
+            }
+            if !selected(c, r + 1) {
+                edges.push(((c + 1, r + 1), (c, r + 1)));
+            }
+            if !selected(c - 1, r) {
+                edges.push(((c, r + 1), (c, r)));
+            }
+        }
+    }
+
+    let mut outgoing: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    for (k, &(from, _)) in edges.iter().enumerate() {
+        outgoing.entry(from).or_default().push(k);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut loops = Vec::new();
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+        let mut ring = Vec::new();
+        let mut current = start;
+        loop {
+            used[current] = true;
+            let (from, to) = edges[current];
+            let dir = (to.0 - from.0, to.1 - from.1);
+            ring.push((from, dir));
+
+            // 在终点处选择下一条边：左转优先，其次直行，最后右转
+            let next = outgoing[&to]
+                .iter()
+                .copied()
+                .filter(|&k| !used[k] || k == start)
+                .min_by_key(|&k| {
+                    let (a, b) = edges[k];
+                    let d = (b.0 - a.0, b.1 - a.1);
+                    if d == (-dir.1, dir.0) { 0 } else if d == dir { 1 } else { 2 }
+                });
+            match next {
+                Some(k) if k != start => current = k,
+                _ => break,
+            }
+        }
+
+        // 只保留方向改变处的顶点
+        let n = ring.len();
+        let corners: Vec<(i64, i64)> = (0..n).filter(|&k| ring[k].1 != ring[(k + n - 1) % n].1).map(|k| ring[k].0).collect();
+        loops.push(corners);
+    }
+    loops
+}
+
+// 点(px/2, py/2)是否在环内（坐标放大2倍，点不落在网格线上，奇偶规则）
+fn contains_doubled(ring: &[(i64, i64)], px: i64, py: i64) -> bool {
+    let mut inside = false;
+    for k in 0..ring.len() {
+        let (a, b) = (ring[k], ring[(k + 1) % ring.len()]);
+        let (ay, by) = (a.1 * 2, b.1 * 2);
+        if (ay > py) != (by > py) {
+            // 网格线都是水平或竖直的，穿过扫描线的边是竖直边
+            if a.0 * 2 > px {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::density::density_region_values;
+    use crate::points_in_polygon::multipolygon::point_in_multipolygon;
+
+    // 在单元[c, c+1]x[r, r+1]的中心附近放n个点
+    fn fill(points: &mut Vec<f32>, c: i32, r: i32, n: usize) {
+        for k in 0..n {
+            points.push(c as f32 + 0.25 + 0.5 * (k % 2) as f32);
+            points.push(r as f32 + 0.25 + 0.5 * ((k / 2) % 2) as f32);
+        }
+    }
+
+    #[test]
+    fn test_dense_ring_with_hole() {
+        // 3x3的密集单元，中心单元稀疏，另有一个远处的孤立点
+        let mut points = Vec::new();
+        for r in 0..3 {
+            for c in 0..3 {
+                fill(&mut points, c, r, if (c, r) == (1, 1) { 1 } else { 4 });
+            }
+        }
+        points.extend_from_slice(&[10.5, 10.5]);
+
+        let region = density_region_values(&points, 1.0, 3.0).unwrap();
+        assert_eq!(region.part_count(), 1);
+        assert!(region.polygon_splits().is_empty());
+        assert_eq!(region.polygon(), vec![0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, 2.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+        assert_eq!(region.rings(), vec![4]);
+
+        // 结果直接用于判断
+        let query = [0.5, 0.5, 1.5, 1.5, 2.5, 2.5, 10.5, 10.5];
+        let inside = point_in_multipolygon(&query, &region.polygon(), &region.polygon_splits(), &region.rings(), true);
+        assert_eq!(inside, vec![1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_diagonal_cells_are_separate_parts() {
+        let mut points = Vec::new();
+        fill(&mut points, 0, 0, 2);
+        fill(&mut points, 1, 1, 2);
+        fill(&mut points, 1, 0, 1);
+
+        let region = density_region_values(&points, 2.0, 2.0).unwrap();
+        assert_eq!(region.part_count(), 1);
+
+        let region = density_region_values(&points, 1.0, 2.0).unwrap();
+        assert_eq!(region.part_count(), 2);
+        assert_eq!(region.polygon_splits(), vec![4]);
+        assert!(region.rings().is_empty());
+
+        assert_eq!(density_region_values(&points, 1.0, 5.0).unwrap().part_count(), 0);
+        assert!(density_region_values(&points, 0.0, 1.0).is_err());
+        assert!(density_region_values(&[0.0, 0.0, 1e6, 1e6], 0.01, 1.0).is_err());
+        // 单元索引超出整数范围、索引之差溢出的情况返回错误而不是溢出
+        assert!(density_region_values(&[-3e38, 0.0, 3e38, 0.0], 1e-30, 1.0).is_err());
+        assert!(density_region_values(&[1e30, 0.0], 1e-10, 1.0).is_err());
+        assert!(density_region_values(&[0.0, 0.0, 1e9, 0.0], 1.0, 1.0).is_err());
+    }
+}
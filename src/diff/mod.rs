@@ -0,0 +1,319 @@
+// 多边形差异模块：比较编辑前后的两个多边形，给出新增和移除的区域以及改动过的顶点范围，
+// 用于撤销预览，以及增量选区中只对变化的区域重新判断
+
+// 输入(js端):
+//     1. 编辑前的多边形 a 类型Float32Array, 环拆分索引 rings_a 类型Uint32Array
+//     2. 编辑后的多边形 b 类型Float32Array, 环拆分索引 rings_b 类型Uint32Array
+// 输出(js端):
+//     1. PolygonDiff
+//        added() / added_rings() 只在b内的区域（b减a）的路径点和环拆分索引（每个环的结束顶点索引）
+//        removed() / removed_rings() 只在a内的区域（a减b）的路径点和环拆分索引
+//        两组结果的环可能包含多个互不相交的外环，需按填充规则判断：传给point_in_polygon_with_options，
+//        options.set_hole_detection(2)（全部环按奇偶规则合并）；默认的按顺序区分洞会把第一个环之后的外环当作洞
+//        changed_vertices() 改动过的顶点范围 [a_start, a_end, b_start, b_end]，a的[a_start, a_end)被替换为b的[b_start, b_end)；
+//            顶点完全相同时为空数组
+//        bounds() 新增和移除区域的边界框 [min_x, min_y, max_x, max_y]，没有变化时为空数组，只需对其中的点重新判断
+// 说明: 区域按奇偶规则理解（各环的交替覆盖），结果的环同样按奇偶规则组合；两个多边形共用的边不会出现在结果中；
+//     边两两求交，复杂度为两个多边形边数的乘积，适合交互编辑的规模
+
+use alloc::{string::String, vec, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use hashbrown::{HashMap, HashSet};
+use crate::points_in_polygon::common::build_polygon;
+use crate::points_in_polygon::index_u32;
+
+pub mod test;
+
+// 交点参数的相对误差，参数在端点附近时视为端点
+const PARAM_EPSILON: f64 = 1e-12;
+// 两侧探测点与子边的距离，按子边长度的比例
+const PROBE_OFFSET: f64 = 1e-4;
+
+// 两个多边形的差异
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct PolygonDiff {
+    added: Vec<f32>,
+    added_rings: Vec<u32>,
+    removed: Vec<f32>,
+    removed_rings: Vec<u32>,
+    changed_vertices: Vec<u32>,
+    bounds: Vec<f64>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl PolygonDiff {
+    pub fn added(&self) -> Vec<f32> {
+        self.added.clone()
+    }
+
+    pub fn added_rings(&self) -> Vec<u32> {
+        self.added_rings.clone()
+    }
+
+    pub fn removed(&self) -> Vec<f32> {
+        self.removed.clone()
+    }
+
+    pub fn removed_rings(&self) -> Vec<u32> {
+        self.removed_rings.clone()
+    }
+
+    pub fn changed_vertices(&self) -> Vec<u32> {
+        self.changed_vertices.clone()
+    }
+
+    pub fn bounds(&self) -> Vec<f64> {
+        self.bounds.clone()
+    }
+
+    // 两个多边形覆盖的区域是否相同
+    pub fn is_same_area(&self) -> bool {
+        self.added_rings.is_empty() && self.removed_rings.is_empty()
+    }
+}
+
+// WebAssembly导出函数：编辑前后两个多边形的差异
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn polygon_diff(
+    a: &[f32],        // 编辑前的多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings_a: &[u32],  // 编辑前的环拆分索引
+    b: &[f32],        // 编辑后的多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings_b: &[u32],  // 编辑后的环拆分索引
+) -> Result<PolygonDiff, ExportError> {
+    polygon_diff_values(a, rings_a, b, rings_b).map_err(export_error)
+}
+
+// 差异计算的实现，顶点索引超出u32范围时返回错误信息
+pub(crate) fn polygon_diff_values(a: &[f32], rings_a: &[u32], b: &[f32], rings_b: &[u32]) -> Result<PolygonDiff, String> {
+    let (segments_a, segments_b) = (segments(a, rings_a), segments(b, rings_b));
+    let (split_a, split_b) = split_at_crossings(&segments_a, &segments_b);
+
+    // 每条子边两侧各取一个探测点，新增（或移除）区域在两侧不同时，子边是该区域的边界，方向取区域在左侧
+    let mut added_edges = Vec::new();
+    let mut removed_edges = Vec::new();
+    let mut seen_added = HashSet::new();
+    let mut seen_removed = HashSet::new();
+    for &(p, q) in split_a.iter().chain(&split_b) {
+        let (dx, dy) = (q.0 - p.0, q.1 - p.1);
+        let (mx, my) = ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0);
+        let (nx, ny) = (-dy * PROBE_OFFSET, dx * PROBE_OFFSET);
+        let left = (mx + nx, my + ny);
+        let right = (mx - nx, my - ny);
+        let (in_a, in_b) = (|p: (f64, f64)| contains(&segments_a, p), |p: (f64, f64)| contains(&segments_b, p));
+        let (left_a, left_b, right_a, right_b) = (in_a(left), in_b(left), in_a(right), in_b(right));
+
+        for (left_in, right_in, edges, seen) in [
+            (left_b && !left_a, right_b && !right_a, &mut added_edges, &mut seen_added),
+            (left_a && !left_b, right_a && !right_b, &mut removed_edges, &mut seen_removed),
+        ] {
+            if left_in == right_in {
+                continue;
+            }
+            let edge = if left_in { (p, q) } else { (q, p) };
+            // 两个多边形共用的子边只记录一次
+            if seen.insert(edge_key(edge)) {
+                edges.push(edge);
+            }
+        }
+    }
+
+    let (added, added_rings) = chain_rings(&added_edges)?;
+    let (removed, removed_rings) = chain_rings(&removed_edges)?;
+    let bounds = added_edges
+        .iter()
+        .chain(&removed_edges)
+        .flat_map(|&(p, q)| [p, q])
+        .fold(None, |acc: Option<[f64; 4]>, (x, y)| {
+            Some(acc.map_or([x, y, x, y], |b| [b[0].min(x), b[1].min(y), b[2].max(x), b[3].max(y)]))
+        })
+        .map_or_else(Vec::new, |b| b.to_vec());
+
+    Ok(PolygonDiff { added, added_rings, removed, removed_rings, changed_vertices: changed_vertices(a, b)?, bounds })
+}
+
+type Point = (f64, f64);
+type Segment = (Point, Point);
+
+// 多边形中参与内外判断的边，环拆分索引为空时没有边
+fn segments(polygon: &[f32], rings: &[u32]) -> Vec<Segment> {
+    if polygon.is_empty() || rings.is_empty() {
+        return Vec::new();
+    }
+    let poly = build_polygon(polygon, rings);
+    poly.rings
+        .iter()
+        .filter(|ring| !ring.boundary_only)
+        .flat_map(|ring| &poly.edges[ring.start_idx..ring.start_idx + ring.edge_count])
+        .map(|e| ((e.x1, e.y1), (e.x2, e.y2)))
+        .collect()
+}
+
+// 在两组边的交点处（共线重叠时在对方的端点处）切分，返回两组子边；
+// 同一个交点在两组中使用完全相同的坐标，相交于端点时取该端点的原始坐标
+fn split_at_crossings(a: &[Segment], b: &[Segment]) -> (Vec<Segment>, Vec<Segment>) {
+    let mut cuts_a: Vec<Vec<(f64, Point)>> = vec![Vec::new(); a.len()];
+    let mut cuts_b: Vec<Vec<(f64, Point)>> = vec![Vec::new(); b.len()];
+    for (i, &(a1, a2)) in a.iter().enumerate() {
+        for (j, &(b1, b2)) in b.iter().enumerate() {
+            if a1.0.max(a2.0) < b1.0.min(b2.0)
+                || b1.0.max(b2.0) < a1.0.min(a2.0)
+                || a1.1.max(a2.1) < b1.1.min(b2.1)
+                || b1.1.max(b2.1) < a1.1.min(a2.1)
+            {
+                continue;
+            }
+            let da = (a2.0 - a1.0, a2.1 - a1.1);
+            let db = (b2.0 - b1.0, b2.1 - b1.1);
+            let offset = (b1.0 - a1.0, b1.1 - a1.1);
+            let denom = cross(da, db);
+            let scale = (da.0.abs() + da.1.abs()) * (db.0.abs() + db.1.abs());
+            if denom.abs() > PARAM_EPSILON * scale {
+                let t = snap(cross(offset, db) / denom);
+                let u = snap(cross(offset, da) / denom);
+                if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+                    continue;
+                }
+                let point = match (t, u) {
+                    (0.0, _) => a1,
+                    (1.0, _) => a2,
+                    (_, 0.0) => b1,
+                    (_, 1.0) => b2,
+                    _ => (a1.0 + t * da.0, a1.1 + t * da.1),
+                };
+                if t > 0.0 && t < 1.0 {
+                    cuts_a[i].push((t, point));
+                }
+                if u > 0.0 && u < 1.0 {
+                    cuts_b[j].push((u, point));
+                }
+            } else if cross(offset, da).abs() <= PARAM_EPSILON * (da.0.abs() + da.1.abs()) * (offset.0.abs() + offset.1.abs()) {
+                // 共线：对方落在本边内部的端点成为切分点
+                for (cuts, (p1, p2), others) in [(&mut cuts_a[i], (a1, a2), [b1, b2]), (&mut cuts_b[j], (b1, b2), [a1, a2])] {
+                    let d = (p2.0 - p1.0, p2.1 - p1.1);
+                    let length2 = d.0 * d.0 + d.1 * d.1;
+                    for other in others {
+                        let t = snap(((other.0 - p1.0) * d.0 + (other.1 - p1.1) * d.1) / length2);
+                        if t > 0.0 && t < 1.0 {
+                            cuts.push((t, other));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let split = |segments: &[Segment], cuts: &mut [Vec<(f64, Point)>]| -> Vec<Segment> {
+        let mut pieces = Vec::new();
+        for (&(p, q), cuts) in segments.iter().zip(cuts.iter_mut()) {
+            cuts.sort_by(|x, y| x.0.total_cmp(&y.0));
+            let mut start = p;
+            for &(_, point) in cuts.iter().chain([(1.0, q)].iter()) {
+                if point != start {
+                    pieces.push((start, point));
+                    start = point;
+                }
+            }
+        }
+        pieces
+    };
+    (split(a, &mut cuts_a), split(b, &mut cuts_b))
+}
+
+// 参数非常接近端点时取端点
+#[inline]
+fn snap(t: f64) -> f64 {
+    if t.abs() <= PARAM_EPSILON {
+        0.0
+    } else if (t - 1.0).abs() <= PARAM_EPSILON {
+        1.0
+    } else {
+        t
+    }
+}
+
+#[inline]
+fn cross(u: Point, v: Point) -> f64 {
+    u.0 * v.1 - u.1 * v.0
+}
+
+#[inline]
+fn edge_key((p, q): Segment) -> [u64; 4] {
+    [p.0.to_bits(), p.1.to_bits(), q.0.to_bits(), q.1.to_bits()]
+}
+
+// 奇偶规则：向右的水平射线穿过的边数为奇数时在内部
+fn contains(segments: &[Segment], (x, y): Point) -> bool {
+    let mut inside = false;
+    for &((x1, y1), (x2, y2)) in segments {
+        if (y1 > y) != (y2 > y) && x < x1 + (y - y1) / (y2 - y1) * (x2 - x1) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// 把有向边首尾相连成闭合的环，去掉方向不变处的顶点，返回路径点和每个环的结束顶点索引
+fn chain_rings(edges: &[Segment]) -> Result<(Vec<f32>, Vec<u32>), String> {
+    let point_key = |p: Point| (p.0.to_bits(), p.1.to_bits());
+    let mut outgoing: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (k, &(p, _)) in edges.iter().enumerate() {
+        outgoing.entry(point_key(p)).or_default().push(k);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let (mut coords, mut ends) = (Vec::new(), Vec::new());
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+        let mut ring = vec![edges[start].0];
+        used[start] = true;
+        let mut end = edges[start].1;
+        let mut closed = false;
+        while !closed {
+            if end == edges[start].0 {
+                closed = true;
+                continue;
+            }
+            let next = outgoing.get(&point_key(end)).and_then(|list| list.iter().copied().find(|&k| !used[k]));
+            let Some(next) = next else { break };
+            used[next] = true;
+            ring.push(end);
+            end = edges[next].1;
+        }
+        // 数值误差导致无法闭合的边链被丢弃
+        if !closed {
+            continue;
+        }
+
+        let n = ring.len();
+        let corners: Vec<Point> = (0..n)
+            .filter(|&k| {
+                let (prev, p, next) = (ring[(k + n - 1) % n], ring[k], ring[(k + 1) % n]);
+                let (u, v) = ((p.0 - prev.0, p.1 - prev.1), (next.0 - p.0, next.1 - p.1));
+                cross(u, v) != 0.0 || u.0 * v.0 + u.1 * v.1 < 0.0
+            })
+            .map(|k| ring[k])
+            .collect();
+        if corners.len() < 3 {
+            continue;
+        }
+        coords.extend(corners.iter().flat_map(|&(x, y)| [x as f32, y as f32]));
+        ends.push(index_u32(coords.len() / 2)?);
+    }
+    Ok((coords, ends))
+}
+
+// 去掉相同的开头和结尾后两边剩下的顶点范围
+fn changed_vertices(a: &[f32], b: &[f32]) -> Result<Vec<u32>, String> {
+    let (va, vb): (Vec<&[f32]>, Vec<&[f32]>) = (a.chunks_exact(2).collect(), b.chunks_exact(2).collect());
+    let prefix = va.iter().zip(&vb).take_while(|(p, q)| p == q).count();
+    if prefix == va.len() && prefix == vb.len() {
+        return Ok(Vec::new());
+    }
+    let max_suffix = va.len().min(vb.len()) - prefix;
+    let suffix = va.iter().rev().zip(vb.iter().rev()).take(max_suffix).take_while(|(p, q)| p == q).count();
+    [prefix, va.len() - suffix, prefix, vb.len() - suffix].into_iter().map(index_u32).collect()
+}
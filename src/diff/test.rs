@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::diff::polygon_diff_values;
+    use crate::points_in_polygon::options::{point_in_polygon_with_options, PipOptions};
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    // 按奇偶规则组合的各环的面积
+    fn area(coords: &[f32], ends: &[u32]) -> f64 {
+        let mut start = 0;
+        let mut total = 0.0;
+        for &end in ends {
+            let ring: Vec<(f64, f64)> = (start..end as usize).map(|k| (coords[k * 2] as f64, coords[k * 2 + 1] as f64)).collect();
+            let signed: f64 = (0..ring.len())
+                .map(|k| {
+                    let (p, q) = (ring[k], ring[(k + 1) % ring.len()]);
+                    p.0 * q.1 - q.0 * p.1
+                })
+                .sum();
+            total += signed / 2.0;
+            start = end as usize;
+        }
+        total
+    }
+
+    #[test]
+    fn test_moved_vertex() {
+        // 把右上角从(4, 4)移到(6, 4)，新增一个三角形
+        let a = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let b = vec![0.0, 0.0, 4.0, 0.0, 6.0, 4.0, 0.0, 4.0];
+        let diff = polygon_diff_values(&a, &[4], &b, &[4]).unwrap();
+        assert!(diff.removed_rings().is_empty());
+        assert_eq!(diff.added_rings(), vec![3]);
+        assert_eq!(area(&diff.added(), &diff.added_rings()), 4.0);
+        assert_eq!(diff.changed_vertices(), vec![2, 3, 2, 3]);
+        assert_eq!(diff.bounds(), vec![4.0, 0.0, 6.0, 4.0]);
+
+        // 结果可以直接传给判断函数
+        let points = vec![5.0, 3.0, 5.0, 1.0, 2.0, 2.0];
+        assert_eq!(point_in_polygon_scanline(&points, &diff.added(), &diff.added_rings(), false), vec![1, 0, 0]);
+    }
+
+    #[test]
+    fn test_shifted_square_with_shared_edges() {
+        // 共线重叠的上下边只在不重叠的部分出现在结果中
+        let a = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0];
+        let b = vec![1.0, 0.0, 3.0, 0.0, 3.0, 2.0, 1.0, 2.0];
+        let diff = polygon_diff_values(&a, &[4], &b, &[4]).unwrap();
+        assert_eq!(area(&diff.added(), &diff.added_rings()), 2.0);
+        assert_eq!(area(&diff.removed(), &diff.removed_rings()), 2.0);
+        assert_eq!(diff.added_rings(), vec![4]);
+        let points = vec![2.5, 1.0, 0.5, 1.0, 1.5, 1.0];
+        assert_eq!(point_in_polygon_scanline(&points, &diff.added(), &diff.added_rings(), false), vec![1, 0, 0]);
+        assert_eq!(point_in_polygon_scanline(&points, &diff.removed(), &diff.removed_rings(), false), vec![0, 1, 0]);
+        assert_eq!(diff.bounds(), vec![0.0, 0.0, 3.0, 2.0]);
+    }
+
+    #[test]
+    fn test_hole_and_identical_polygons() {
+        // 新增一个洞：洞的区域被移除
+        let a = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let b = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0];
+        let diff = polygon_diff_values(&a, &[4], &b, &[4]).unwrap();
+        assert!(diff.added_rings().is_empty());
+        assert_eq!(area(&diff.removed(), &diff.removed_rings()), 1.0);
+        assert_eq!(diff.changed_vertices(), vec![4, 4, 4, 8]);
+
+        let same = polygon_diff_values(&a, &[4], &a, &[4]).unwrap();
+        assert!(same.is_same_area());
+        assert!(same.changed_vertices().is_empty());
+        assert!(same.bounds().is_empty());
+
+        // 起点不同但区域相同
+        let rotated = vec![4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 0.0, 0.0];
+        assert!(polygon_diff_values(&a, &[4], &rotated, &[4]).unwrap().is_same_area());
+
+        // 与空多边形比较时整个多边形被新增
+        let created = polygon_diff_values(&[], &[], &a, &[4]).unwrap();
+        assert_eq!(area(&created.added(), &created.added_rings()), 16.0);
+        assert_eq!(created.changed_vertices(), vec![0, 0, 0, 4]);
+    }
+
+    #[test]
+    fn test_separate_regions_use_fill_rule() {
+        // 上边两端各凸出一块：新增区域是两个互不相交的外环
+        let a = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let b = vec![0.0, 0.0, 4.0, 0.0, 4.0, 5.0, 3.0, 5.0, 3.0, 4.0, 1.0, 4.0, 1.0, 5.0, 0.0, 5.0];
+        let diff = polygon_diff_values(&a, &[4], &b, &[8]).unwrap();
+        assert_eq!(diff.added_rings().len(), 2);
+        assert_eq!(area(&diff.added(), &diff.added_rings()).abs(), 2.0);
+
+        let points = vec![0.5, 4.5, 3.5, 4.5, 2.0, 4.5, 2.0, 2.0];
+        let mut options = PipOptions::new();
+        options.set_hole_detection(2);
+        assert_eq!(point_in_polygon_with_options(&points, &diff.added(), &diff.added_rings(), &options), vec![1, 1, 0, 0]);
+    }
+}
@@ -0,0 +1,22 @@
+// 导出函数的错误类型：wasm特性下为JsValue，导出函数把错误作为js异常抛出；
+// 关闭wasm特性作为普通Rust库使用时，同一批函数返回错误信息字符串
+
+// 说明: 内部流程返回Result<_, String>，导出函数用 .map_err(export_error) 转换
+
+#[cfg(feature = "wasm")]
+pub type ExportError = wasm_bindgen::JsValue;
+#[cfg(not(feature = "wasm"))]
+pub type ExportError = alloc::string::String;
+
+// 由错误信息构造导出函数的错误
+#[inline]
+pub(crate) fn export_error(message: alloc::string::String) -> ExportError {
+    #[cfg(feature = "wasm")]
+    {
+        wasm_bindgen::JsValue::from_str(&message)
+    }
+    #[cfg(not(feature = "wasm"))]
+    {
+        message
+    }
+}
@@ -0,0 +1,159 @@
+// 圆角模块：把多边形每个环的拐角替换为圆弧（倒圆角），圆弧按指定的段数折线化，
+// 用于程序生成的选区在判断和渲染之前得到圆角
+
+// 输入(js端):
+//     1. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array 含义与points_in_polygon模块一致
+//     2. 圆角半径 radius 按多边形的坐标单位
+//     3. 每个圆角的折线段数 segments 为0时按1处理（即倒直角）
+// 输出(js端):
+//     1. FilletedPolygon
+//        polygon() 圆角后的路径点 类型Float32Array
+//        rings() 圆角后的拆分索引 类型Uint32Array，环的顺序与输入一致，可直接传给points_in_polygon模块
+// 说明: 圆弧与拐角两侧的边相切。边长不足以容纳两端的圆角时，该拐角的半径缩小到切点恰好位于边的中点，
+//     相邻的圆角因此不会重叠；共线的顶点和不同顶点少于3个的环原样保留，末尾重复第一个顶点的环先去掉该重复顶点
+
+use alloc::{string::String, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::common::ring_ranges;
+use crate::points_in_polygon::index_u32;
+
+pub mod test;
+
+// 判断拐角是否共线、顶点是否重复的精度
+const EPSILON: f64 = 1e-12;
+
+// 圆角后的多边形
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct FilletedPolygon {
+    polygon: Vec<f32>,
+    rings: Vec<u32>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl FilletedPolygon {
+    pub fn polygon(&self) -> Vec<f32> {
+        self.polygon.clone()
+    }
+
+    pub fn rings(&self) -> Vec<u32> {
+        self.rings.clone()
+    }
+}
+
+// WebAssembly导出函数：倒圆角
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn fillet_polygon(
+    polygon: &[f32],  // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],    // 多边形环的分割索引
+    radius: f64,      // 圆角半径
+    segments: usize,  // 每个圆角的折线段数
+) -> Result<FilletedPolygon, ExportError> {
+    fillet_polygon_values(polygon, rings, radius, segments).map_err(export_error)
+}
+
+// 倒圆角的实现，顶点索引超出u32范围时返回错误信息
+pub(crate) fn fillet_polygon_values(polygon: &[f32], rings: &[u32], radius: f64, segments: usize) -> Result<FilletedPolygon, String> {
+    let mut result = FilletedPolygon { polygon: Vec::new(), rings: Vec::new() };
+    for (start, end) in ring_ranges(polygon.len() / 2, rings) {
+        if !result.polygon.is_empty() {
+            result.rings.push(index_u32(result.polygon.len() / 2)?);
+        }
+        let ring = ring_vertices(&polygon[start * 2..end * 2]);
+        for (x, y) in fillet_ring(&ring, radius, segments.max(1)) {
+            result.polygon.push(x as f32);
+            result.polygon.push(y as f32);
+        }
+    }
+    // 只有一个环时补上末尾拆分，与单个多边形接口的约定一致
+    if result.rings.is_empty() && !result.polygon.is_empty() {
+        result.rings.push(index_u32(result.polygon.len() / 2)?);
+    }
+    Ok(result)
+}
+
+// 环的顶点，去掉连续重复的顶点和末尾重复的第一个顶点
+fn ring_vertices(ring: &[f32]) -> Vec<(f64, f64)> {
+    let mut vertices: Vec<(f64, f64)> = Vec::with_capacity(ring.len() / 2);
+    for vertex in ring.chunks_exact(2) {
+        let point = (vertex[0] as f64, vertex[1] as f64);
+        if vertices.last() != Some(&point) {
+            vertices.push(point);
+        }
+    }
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    vertices
+}
+
+// 对一个环的每个拐角倒圆角
+pub(crate) fn fillet_ring(ring: &[(f64, f64)], radius: f64, segments: usize) -> Vec<(f64, f64)> {
+    let n = ring.len();
+    if n < 3 || radius.is_nan() || radius <= 0.0 {
+        return ring.to_vec();
+    }
+
+    let mut result = Vec::with_capacity(n * (segments + 1));
+    for i in 0..n {
+        let (px, py) = ring[i];
+        let (ax, ay) = ring[(i + n - 1) % n];
+        let (bx, by) = ring[(i + 1) % n];
+        let (la, lb) = ((ax - px).hypot(ay - py), (bx - px).hypot(by - py));
+        if la < EPSILON || lb < EPSILON {
+            result.push((px, py));
+            continue;
+        }
+
+        // 拐角两侧边的单位方向，以及两者的夹角theta
+        let (ux, uy) = ((ax - px) / la, (ay - py) / la);
+        let (vx, vy) = ((bx - px) / lb, (by - py) / lb);
+        let theta = (ux * vx + uy * vy).clamp(-1.0, 1.0).acos();
+        let half_tan = (theta / 2.0).tan();
+        if theta < EPSILON || core::f64::consts::PI - theta < EPSILON {
+            result.push((px, py)); // 共线或折返的顶点没有可倒的角
+            continue;
+        }
+
+        // 切点到顶点的距离，不超过两侧边长的一半
+        let d = (radius / half_tan).min(la / 2.0).min(lb / 2.0);
+        let r = d * half_tan;
+        let (t1x, t1y) = (px + ux * d, py + uy * d);
+        let (t2x, t2y) = (px + vx * d, py + vy * d);
+
+        // 圆心在角平分线上，到顶点的距离为 r / sin(theta/2)
+        let (bisect_x, bisect_y) = (ux + vx, uy + vy);
+        let bisect_len = bisect_x.hypot(bisect_y);
+        let center_dist = r / (theta / 2.0).sin();
+        let (cx, cy) = (px + bisect_x / bisect_len * center_dist, py + bisect_y / bisect_len * center_dist);
+
+        // 从第一个切点沿短弧扫到第二个切点
+        let a1 = (t1y - cy).atan2(t1x - cx);
+        let a2 = (t2y - cy).atan2(t2x - cx);
+        let mut sweep = a2 - a1;
+        if sweep > core::f64::consts::PI {
+            sweep -= core::f64::consts::TAU;
+        } else if sweep < -core::f64::consts::PI {
+            sweep += core::f64::consts::TAU;
+        }
+        result.push((t1x, t1y));
+        for k in 1..segments {
+            let angle = a1 + sweep * k as f64 / segments as f64;
+            result.push((cx + r * angle.cos(), cy + r * angle.sin()));
+        }
+        result.push((t2x, t2y));
+    }
+
+    // 切点恰好位于边的中点时，相邻圆角的切点重合，只保留一个
+    result.dedup_by(|a, b| (a.0 - b.0).abs() < EPSILON && (a.1 - b.1).abs() < EPSILON);
+    if result.len() > 1 {
+        let (first, last) = (result[0], result[result.len() - 1]);
+        if (first.0 - last.0).abs() < EPSILON && (first.1 - last.1).abs() < EPSILON {
+            result.pop();
+        }
+    }
+    result
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::fillet::fillet_polygon_values;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    #[test]
+    fn test_square_corners_become_arcs() {
+        let square = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+        let filleted = fillet_polygon_values(&square, &[4], 2.0, 4).unwrap();
+        let polygon = filleted.polygon();
+        assert_eq!(polygon.len() / 2, 20);
+        assert_eq!(filleted.rings(), vec![20]);
+
+        // 每个圆角上的点到对应圆心的距离都等于半径
+        for vertex in polygon.chunks(2) {
+            let (x, y) = (vertex[0] as f64, vertex[1] as f64);
+            let (cx, cy) = (x.clamp(2.0, 8.0), y.clamp(2.0, 8.0));
+            let d = (x - cx).hypot(y - cy);
+            assert!(d < 1e-5 || (d - 2.0).abs() < 1e-5, "({}, {})", x, y);
+        }
+
+        // 原来的拐角附近变为外部，边的中部不受影响
+        let points = vec![0.1, 0.1, 9.9, 9.9, 0.5, 5.0, 5.0, 5.0];
+        assert_eq!(point_in_polygon_scanline(&points, &polygon, &filleted.rings(), true), vec![0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn test_radius_clamped_to_half_edges() {
+        // 半径过大时切点落在边的中点，相邻切点重合，正方形变为近似的圆
+        let square = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, 0.0, 0.0];
+        let filleted = fillet_polygon_values(&square, &[5], 100.0, 8).unwrap();
+        let polygon = filleted.polygon();
+        assert_eq!(polygon.len() / 2, 32);
+        for vertex in polygon.chunks(2) {
+            let d = (vertex[0] as f64 - 5.0).hypot(vertex[1] as f64 - 5.0);
+            assert!((d - 5.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_holes_and_reflex_corners() {
+        // L形外环（含一个凹角）加一个三角形洞
+        let polygon = vec![
+            0.0, 0.0, 6.0, 0.0, 6.0, 2.0, 2.0, 2.0, 2.0, 6.0, 0.0, 6.0, // L形外环
+            0.5, 0.5, 1.5, 0.5, 0.5, 1.5, // 洞
+        ];
+        let filleted = fillet_polygon_values(&polygon, &[6], 0.5, 3).unwrap();
+        let rings = filleted.rings();
+        assert_eq!(rings, vec![24]);
+        // 洞的直角边长为1，两端的切点都落在直角边的中点而重合，洞只有10个顶点
+        assert_eq!(filleted.polygon().len() / 2, 24 + 10);
+
+        // 凹角处圆弧向外凸出，(2.1, 2.1) 原本在外部，倒圆角后在内部
+        let points = vec![2.1, 2.1, 3.0, 3.0, 4.0, 1.0];
+        assert_eq!(point_in_polygon_scanline(&points, &filleted.polygon(), &rings, true), vec![1, 0, 1]);
+
+        // 半径不大于0时原样保留
+        assert_eq!(fillet_polygon_values(&polygon, &[6], 0.0, 3).unwrap().polygon(), polygon);
+    }
+}
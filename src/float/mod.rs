@@ -0,0 +1,102 @@
+// 浮点函数模块：no_std构建中core没有sqrt、floor、sin等需要数学库的方法，这里用libm为f64补上同名方法，
+// 调用处的写法与std构建相同（x.sqrt()、a.atan2(b)）；std构建中使用f64自带的方法，本模块为空
+
+// 说明: 需要这些方法的模块加上 #[cfg(not(any(feature = "std", test)))] use crate::float::Float;
+//     libm的结果与std可能在最后一位上不同，只影响距离、角度等连续量，不影响基于比较的内外判断
+
+#[cfg(not(any(feature = "std", test)))]
+pub(crate) trait Float {
+    fn sqrt(self) -> f64;
+    fn floor(self) -> f64;
+    fn ceil(self) -> f64;
+    fn round(self) -> f64;
+    fn fract(self) -> f64;
+    fn rem_euclid(self, rhs: f64) -> f64;
+    fn powi(self, n: i32) -> f64;
+    fn mul_add(self, a: f64, b: f64) -> f64;
+    fn hypot(self, other: f64) -> f64;
+    fn ln(self) -> f64;
+    fn sin(self) -> f64;
+    fn cos(self) -> f64;
+    fn tan(self) -> f64;
+    fn sin_cos(self) -> (f64, f64);
+    fn asin(self) -> f64;
+    fn acos(self) -> f64;
+    fn atan2(self, other: f64) -> f64;
+}
+
+#[cfg(not(any(feature = "std", test)))]
+impl Float for f64 {
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn fract(self) -> f64 {
+        self - libm::trunc(self)
+    }
+
+    fn rem_euclid(self, rhs: f64) -> f64 {
+        let r = self % rhs;
+        if r < 0.0 {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    fn mul_add(self, a: f64, b: f64) -> f64 {
+        libm::fma(self, a, b)
+    }
+
+    fn hypot(self, other: f64) -> f64 {
+        libm::hypot(self, other)
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn tan(self) -> f64 {
+        libm::tan(self)
+    }
+
+    fn sin_cos(self) -> (f64, f64) {
+        libm::sincos(self)
+    }
+
+    fn asin(self) -> f64 {
+        libm::asin(self)
+    }
+
+    fn acos(self) -> f64 {
+        libm::acos(self)
+    }
+
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+}
@@ -0,0 +1,51 @@
+// geo-types互操作模块（geo特性）：本机Rust程序（例如服务端的数据处理流水线）直接用geo_types的几何类型调用同一套判断引擎，
+// 不需要先把多边形和点展平为Float32Array格式
+
+// 输入(Rust端):
+//     1. PreparedPolygon::from(&polygon) polygon为geo_types::Polygon<f64>，外环和内环（洞）保持f64精度
+//     2. prepared.contains_points(&coords) coords为geo_types::Coord<f64>的切片
+// 输出(Rust端):
+//     1. 每个点是否在多边形内部 类型Vec<bool>
+// 说明: contains_points与geo的Contains语义相同，边界上的点不算包含；需要把边界算作内部时用prepared.query。
+//     按默认选项构建（自动容差、偶奇填充），geo_types的环首尾重复的闭合顶点在构建时去掉；
+//     本模块不导出到js端，wasm构建中启用geo特性也不会改变js接口
+
+use alloc::vec::Vec;
+use geo_types::{Coord, Polygon};
+use crate::points_in_polygon::index_u32;
+use crate::points_in_polygon::options::PipOptions;
+use crate::points_in_polygon::prepared::PreparedPolygon;
+use crate::points_in_polygon::Location;
+
+pub mod test;
+
+impl From<&Polygon<f64>> for PreparedPolygon {
+    fn from(polygon: &Polygon<f64>) -> PreparedPolygon {
+        let mut coordinates = Vec::new();
+        let mut rings = Vec::new();
+        // 外环为空时没有区域，空的洞被跳过
+        if !polygon.exterior().0.is_empty() {
+            for (k, ring) in core::iter::once(polygon.exterior()).chain(polygon.interiors()).enumerate() {
+                if ring.0.is_empty() {
+                    continue;
+                }
+                if k > 0 {
+                    // From不能返回错误，超出u32范围的顶点索引与容量溢出一样panic
+                    rings.push(index_u32(coordinates.len() / 2).unwrap_or_else(|e| panic!("{}", e)));
+                }
+                for coord in ring.coords() {
+                    coordinates.push(coord.x);
+                    coordinates.push(coord.y);
+                }
+            }
+        }
+        PreparedPolygon::from_coordinates(&coordinates, &rings, &PipOptions::default())
+    }
+}
+
+impl PreparedPolygon {
+    // 判断每个点是否严格在多边形内部
+    pub fn contains_points(&self, points: &[Coord<f64>]) -> Vec<bool> {
+        points.iter().map(|point| self.locate(point.x, point.y) == Location::Inside).collect()
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use geo_types::{coord, polygon, Coord, LineString, Polygon};
+    use crate::points_in_polygon::prepared::PreparedPolygon;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    #[test]
+    fn test_polygon_with_hole() {
+        let polygon = polygon!(
+            exterior: [(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)],
+            interiors: [[(x: 2.0, y: 2.0), (x: 2.0, y: 8.0), (x: 8.0, y: 8.0), (x: 8.0, y: 2.0)]],
+        );
+        let prepared = PreparedPolygon::from(&polygon);
+        let points = [coord! { x: 1.0, y: 1.0 }, coord! { x: 5.0, y: 5.0 }, coord! { x: 12.0, y: 5.0 }, coord! { x: 10.0, y: 5.0 }];
+        // 边界上的点不算包含
+        assert_eq!(prepared.contains_points(&points), vec![true, false, false, false]);
+
+        // 与Float32Array接口的结果相同
+        let flat: Vec<f32> = points.iter().flat_map(|c| [c.x as f32, c.y as f32]).collect();
+        let expected = point_in_polygon_scanline(
+            &flat,
+            &[0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, 2.0, 2.0, 2.0, 8.0, 8.0, 8.0, 8.0, 2.0],
+            &[4],
+            true,
+        );
+        assert_eq!(prepared.query(&flat, true), expected);
+    }
+
+    #[test]
+    fn test_keeps_f64_precision() {
+        // 投影坐标中相距1厘米的点，f32无法区分
+        let (x0, y0) = (4_500_000.0, 5_500_000.0);
+        let square = Polygon::new(
+            LineString::from(vec![(x0, y0), (x0 + 1.0, y0), (x0 + 1.0, y0 + 1.0), (x0, y0 + 1.0)]),
+            vec![],
+        );
+        let prepared = PreparedPolygon::from(&square);
+        let points = [Coord { x: x0 + 0.01, y: y0 + 0.5 }, Coord { x: x0 - 0.01, y: y0 + 0.5 }];
+        assert_eq!(prepared.contains_points(&points), vec![true, false]);
+    }
+
+    #[test]
+    fn test_empty_polygon() {
+        let empty = Polygon::new(LineString::<f64>::new(vec![]), vec![]);
+        let prepared = PreparedPolygon::from(&empty);
+        assert_eq!(prepared.contains_points(&[coord! { x: 0.0, y: 0.0 }]), vec![false]);
+    }
+}
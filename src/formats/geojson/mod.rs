@@ -0,0 +1,371 @@
+// GeoJSON模块：解析GeoJSON（RFC 7946）中的面状几何，直接用于点在多边形内判断
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]（与GeoJSON相同，x为经度、y为纬度）
+//     2. GeoJSON文本 类型string，可以是 Polygon、MultiPolygon、GeometryCollection、Feature 或 FeatureCollection
+//     3. 判断选项 PipOptions（边界语义、填充规则、容差等，见options模块）
+// 输出(js端):
+//     1. point_in_polygon_geojson(points, geojson, options) 类型Uint32Array 1表示在某个多边形内部,0表示在外部
+//     2. parse_geojson(geojson) 解析结果ParsedGeometry（见formats模块），可反复用于其他接受MultiPolygon的函数
+// 说明: 每个Polygon（以及MultiPolygon的每个成员）的第一个环是外环，其余是洞。RFC 7946要求外环逆时针、洞顺时针，
+//     但同时要求解析方不因绕向拒绝输入，这里环的角色只按位置确定，按旧规范（2008）或其他工具以相反绕向写出的数据结果相同；
+//     第三个及之后的坐标（高程等）被忽略。点、线等非面状几何和geometry为null的要素不围成区域，直接跳过；
+//     GeoJSON不合法（JSON语法错误、缺少type或coordinates、坐标不是数字等）时抛出错误，错误信息给出出错的位置
+
+use alloc::{format, string::String, string::ToString, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::options::PipOptions;
+use super::{ParsedGeometry, Rings};
+
+pub mod test;
+
+// 嵌套层数的上限，防止恶意输入耗尽栈空间
+const MAX_DEPTH: usize = 256;
+
+// WebAssembly导出函数：判断点是否在GeoJSON描述的区域内
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_geojson(
+    points: &[f32],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    geojson: &str,        // GeoJSON文本
+    options: &PipOptions, // 判断选项
+) -> Result<Vec<u32>, ExportError> {
+    classify_geojson(points, geojson, options).map_err(export_error)
+}
+
+// WebAssembly导出函数：解析GeoJSON中的面状几何
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn parse_geojson(geojson: &str) -> Result<ParsedGeometry, ExportError> {
+    parse_geojson_values(geojson).map_err(export_error)
+}
+
+// 判断流程，GeoJSON不合法时返回错误信息
+pub(crate) fn classify_geojson(points: &[f32], geojson: &str, options: &PipOptions) -> Result<Vec<u32>, String> {
+    Ok(parse_geojson_values(geojson)?.classify(points, options))
+}
+
+// 解析流程，GeoJSON不合法时返回错误信息
+pub(crate) fn parse_geojson_values(geojson: &str) -> Result<ParsedGeometry, String> {
+    let value = Parser::new(geojson).parse_document()?;
+    let mut polygons = Vec::new();
+    collect_polygons(&value, &mut polygons, 0)?;
+    ParsedGeometry::from_polygons(polygons)
+}
+
+// 按GeoJSON对象的类型收集其中的多边形
+fn collect_polygons(value: &Json, polygons: &mut Vec<Rings>, depth: usize) -> Result<(), String> {
+    if depth > MAX_DEPTH {
+        return Err("GeoJSON objects are nested too deeply".to_string());
+    }
+    let kind = match value.get("type") {
+        Some(Json::String(kind)) => kind.as_str(),
+        Some(_) => return Err("GeoJSON \"type\" must be a string".to_string()),
+        None => return Err("GeoJSON object has no \"type\"".to_string()),
+    };
+    match kind {
+        "Polygon" => polygons.push(polygon_rings(coordinates(value)?)?),
+        "MultiPolygon" => {
+            for (k, member) in array(coordinates(value)?, "MultiPolygon coordinates")?.iter().enumerate() {
+                polygons.push(polygon_rings(member).map_err(|e| format!("MultiPolygon member {}: {}", k, e))?);
+            }
+        }
+        "GeometryCollection" => {
+            let geometries = value.get("geometries").ok_or("GeometryCollection has no \"geometries\"")?;
+            for geometry in array(geometries, "geometries")? {
+                collect_polygons(geometry, polygons, depth + 1)?;
+            }
+        }
+        "Feature" => match value.get("geometry") {
+            Some(Json::Null) | None => {}
+            Some(geometry) => collect_polygons(geometry, polygons, depth + 1)?,
+        },
+        "FeatureCollection" => {
+            let features = value.get("features").ok_or("FeatureCollection has no \"features\"")?;
+            for (k, feature) in array(features, "features")?.iter().enumerate() {
+                collect_polygons(feature, polygons, depth + 1).map_err(|e| format!("feature {}: {}", k, e))?;
+            }
+        }
+        // 非面状几何不围成区域
+        "Point" | "MultiPoint" | "LineString" | "MultiLineString" => {}
+        other => return Err(format!("unknown GeoJSON type \"{}\"", other)),
+    }
+    Ok(())
+}
+
+fn coordinates(value: &Json) -> Result<&Json, String> {
+    value.get("coordinates").ok_or_else(|| "geometry has no \"coordinates\"".to_string())
+}
+
+fn array<'a>(value: &'a Json, what: &str) -> Result<&'a [Json], String> {
+    match value {
+        Json::Array(items) => Ok(items),
+        _ => Err(format!("{} must be an array", what)),
+    }
+}
+
+// Polygon的坐标：环的数组，每个环是位置的数组
+fn polygon_rings(value: &Json) -> Result<Rings, String> {
+    array(value, "polygon coordinates")?
+        .iter()
+        .enumerate()
+        .map(|(r, ring)| {
+            array(ring, "ring")?
+                .iter()
+                .map(position)
+                .collect::<Result<Vec<_>, String>>()
+                .map_err(|e| format!("ring {}: {}", r, e))
+        })
+        .collect()
+}
+
+// 位置：至少两个数字 [x, y, ...]
+fn position(value: &Json) -> Result<(f64, f64), String> {
+    match value {
+        Json::Array(items) if items.len() >= 2 => match (&items[0], &items[1]) {
+            (Json::Number(x), Json::Number(y)) => Ok((*x, *y)),
+            _ => Err("position coordinates must be numbers".to_string()),
+        },
+        _ => Err("position must be an array of at least two numbers".to_string()),
+    }
+}
+
+// JSON值：只保留解析GeoJSON需要的信息
+#[derive(Debug, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    // 对象的成员，键重复时取第一个
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+// 递归下降的JSON解析器，按字节扫描，错误信息给出字节偏移
+pub(crate) struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(text: &'a str) -> Parser<'a> {
+        Parser { bytes: text.as_bytes(), pos: 0 }
+    }
+
+    // 整个文本是一个JSON值（前后可以有空白）
+    pub(crate) fn parse_document(&mut self) -> Result<Json, String> {
+        let value = self.parse_value(0)?;
+        self.skip_whitespace();
+        if self.pos != self.bytes.len() {
+            return Err(self.error("unexpected trailing characters"));
+        }
+        Ok(value)
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("invalid JSON at byte {}: {}", self.pos, message)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        if self.bytes.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", byte as char)))
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<Json, String> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        self.skip_whitespace();
+        match self.bytes.get(self.pos) {
+            Some(b'{') => self.parse_object(depth),
+            Some(b'[') => self.parse_array(depth),
+            Some(b'"') => self.parse_string().map(Json::String),
+            Some(b't') => self.parse_literal("true", Json::Bool(true)),
+            Some(b'f') => self.parse_literal("false", Json::Bool(false)),
+            Some(b'n') => self.parse_literal("null", Json::Null),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_literal(&mut self, word: &str, value: Json) -> Result<Json, String> {
+        if self.bytes[self.pos..].starts_with(word.as_bytes()) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(self.error("invalid literal"))
+        }
+    }
+
+    fn parse_object(&mut self, depth: usize) -> Result<Json, String> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            let value = self.parse_value(depth + 1)?;
+            members.push((key, value));
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self, depth: usize) -> Result<Json, String> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.parse_value(depth + 1)?);
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(items));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    // 数字按JSON的语法检查后交给标准库解析
+    fn parse_number(&mut self) -> Result<Json, String> {
+        let start = self.pos;
+        let digits = |parser: &mut Parser| {
+            let from = parser.pos;
+            while parser.bytes.get(parser.pos).is_some_and(u8::is_ascii_digit) {
+                parser.pos += 1;
+            }
+            parser.pos > from
+        };
+        if self.bytes.get(self.pos) == Some(&b'-') {
+            self.pos += 1;
+        }
+        if self.bytes.get(self.pos) == Some(&b'0') {
+            self.pos += 1;
+        } else if !digits(self) {
+            return Err(self.error("invalid number"));
+        }
+        if self.bytes.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            if !digits(self) {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if let Some(b'e' | b'E') = self.bytes.get(self.pos) {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.bytes.get(self.pos) {
+                self.pos += 1;
+            }
+            if !digits(self) {
+                return Err(self.error("invalid number"));
+            }
+        }
+        // 以上只接受ASCII字符，切片一定是合法的UTF-8
+        let text = core::str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+        text.parse::<f64>().map(Json::Number).map_err(|_| self.error("invalid number"))
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut out = Vec::new();
+        loop {
+            match self.bytes.get(self.pos) {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    let code = self.bytes.get(self.pos + 1).copied();
+                    self.pos += 2;
+                    let escaped = match code {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => self.parse_unicode_escape()?,
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    let mut buffer = [0; 4];
+                    out.extend_from_slice(escaped.encode_utf8(&mut buffer).as_bytes());
+                }
+                Some(&byte) if byte < 0x20 => return Err(self.error("control character in string")),
+                Some(&byte) => {
+                    out.push(byte);
+                    self.pos += 1;
+                }
+            }
+        }
+        // 输入是&str，未转义的字节原样复制，结果仍是合法的UTF-8
+        String::from_utf8(out).map_err(|_| self.error("invalid UTF-8 in string"))
+    }
+
+    // \uXXXX，包括代理对；调用时pos指向第一个十六进制数字，返回时pos指向转义之后
+    fn parse_unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.parse_hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or_else(|| self.error("invalid unicode escape"));
+        }
+        if !self.bytes[self.pos..].starts_with(b"\\u") {
+            return Err(self.error("unpaired surrogate"));
+        }
+        self.pos += 2;
+        let low = self.parse_hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(self.error("unpaired surrogate"));
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, String> {
+        let digits = self.bytes.get(self.pos..self.pos + 4).ok_or_else(|| self.error("invalid unicode escape"))?;
+        let text = core::str::from_utf8(digits).map_err(|_| self.error("invalid unicode escape"))?;
+        let value = u32::from_str_radix(text, 16).map_err(|_| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(value)
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use crate::formats::geojson::{classify_geojson, parse_geojson_values};
+    use crate::points_in_polygon::multipolygon::point_in_multipolygon;
+    use crate::points_in_polygon::options::PipOptions;
+
+    const POLYGON_WITH_HOLE: &str = r#"{
+        "type": "Polygon",
+        "coordinates": [
+            [[0, 0], [10, 0], [10, 10], [0, 10], [0, 0]],
+            [[2, 2], [2, 8], [8, 8], [8, 2], [2, 2]]
+        ]
+    }"#;
+
+    #[test]
+    fn test_polygon_with_hole() {
+        let points = vec![1.0, 1.0, 5.0, 5.0, 12.0, 5.0, 10.0, 5.0];
+        let options = PipOptions::new();
+        assert_eq!(classify_geojson(&points, POLYGON_WITH_HOLE, &options).unwrap(), vec![1, 0, 0, 1]);
+
+        let parsed = parse_geojson_values(POLYGON_WITH_HOLE).unwrap();
+        assert_eq!(parsed.part_count(), 1);
+        assert_eq!(parsed.rings(), vec![5]);
+        assert!(parsed.polygon_splits().is_empty());
+    }
+
+    #[test]
+    fn test_orientation_does_not_matter() {
+        // 外环顺时针、洞逆时针（与RFC 7946相反）的结果相同
+        let reversed = r#"{"type":"Polygon","coordinates":[
+            [[0,0],[0,10],[10,10],[10,0],[0,0]],
+            [[2,2],[8,2],[8,8],[2,8],[2,2]]
+        ]}"#;
+        let points = vec![1.0, 1.0, 5.0, 5.0, 12.0, 5.0, 9.0, 9.0];
+        let options = PipOptions::new();
+        assert_eq!(
+            classify_geojson(&points, reversed, &options).unwrap(),
+            classify_geojson(&points, POLYGON_WITH_HOLE, &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_multipolygon_matches_point_in_multipolygon() {
+        let geojson = r#"{"type":"MultiPolygon","coordinates":[
+            [[[0,0],[10,0],[10,10],[0,10],[0,0]],[[2,2],[8,2],[8,8],[2,8],[2,2]]],
+            [[[4,4],[6,4],[6,6],[4,6],[4,4]]],
+            [[[20,0],[30,0],[25,10],[20,0]]]
+        ]}"#;
+        let parsed = parse_geojson_values(geojson).unwrap();
+        assert_eq!(parsed.part_count(), 3);
+        let points = vec![1.0, 1.0, 3.0, 3.0, 5.0, 5.0, 25.0, 3.0, 15.0, 5.0, 6.0, 5.0];
+        let expected = point_in_multipolygon(&points, &parsed.polygon(), &parsed.polygon_splits(), &parsed.rings(), true);
+        assert_eq!(expected, vec![1, 0, 1, 1, 0, 1]);
+        assert_eq!(classify_geojson(&points, geojson, &PipOptions::new()).unwrap(), expected);
+
+        let mut options = PipOptions::new();
+        options.set_boundary_is_inside(false);
+        assert_eq!(classify_geojson(&points, geojson, &options).unwrap(), vec![1, 0, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_features() {
+        // 要素中的高程坐标、properties、非面状几何和null几何都不影响结果
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {"name": "a \"b\" é"},
+                 "geometry": {"type": "Polygon", "coordinates": [[[0,0,5],[4,0,5],[4,4,5],[0,4,5],[0,0,5]]]}},
+                {"type": "Feature", "properties": null, "geometry": null},
+                {"type": "Feature", "geometry": {"type": "Point", "coordinates": [50, 50]}},
+                {"type": "Feature", "geometry": {"type": "GeometryCollection", "geometries": [
+                    {"type": "LineString", "coordinates": [[0, 0], [1, 1]]},
+                    {"type": "Polygon", "coordinates": [[[10,0],[14,0],[14,4],[10,4],[10,0]]]}
+                ]}}
+            ]
+        }"#;
+        let points = vec![2.0, 2.0, 12.0, 2.0, 7.0, 2.0, 50.0, 50.0];
+        assert_eq!(classify_geojson(&points, geojson, &PipOptions::new()).unwrap(), vec![1, 1, 0, 0]);
+        assert_eq!(parse_geojson_values(geojson).unwrap().part_count(), 2);
+    }
+
+    #[test]
+    fn test_empty_geometry() {
+        let points = vec![1.0, 1.0];
+        let empty = r#"{"type":"FeatureCollection","features":[]}"#;
+        assert_eq!(classify_geojson(&points, empty, &PipOptions::new()).unwrap(), vec![0]);
+        let no_rings = r#"{"type":"Polygon","coordinates":[]}"#;
+        assert_eq!(classify_geojson(&points, no_rings, &PipOptions::new()).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let invalid = [
+            "",
+            "{\"type\":\"Polygon\",\"coordinates\":[[[0,0],[1,0],[1,1]]]",
+            "{\"type\":\"Polygon\"}",
+            "{\"coordinates\":[]}",
+            "{\"type\":\"Circle\",\"coordinates\":[]}",
+            "{\"type\":\"Polygon\",\"coordinates\":[[[0,0],[1],[1,1]]]}",
+            "{\"type\":\"Polygon\",\"coordinates\":[[[0,0],[\"1\",0],[1,1]]]}",
+            "{\"type\":\"Polygon\",\"coordinates\":[[[0,0],[01,0],[1,1]]]}",
+            "{\"type\":\"Polygon\",\"coordinates\":[[[0,0],[1,0],[1,1]]]} x",
+            "{\"type\":\"FeatureCollection\",\"features\":[{\"type\":\"Feature\",\"geometry\":{\"type\":\"Polygon\"}}]}",
+        ];
+        for text in invalid {
+            assert!(parse_geojson_values(text).is_err(), "{:?}", text);
+        }
+
+        // 嵌套过深被拒绝而不是栈溢出
+        let deep = "[".repeat(100_000);
+        assert!(parse_geojson_values(&deep).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+// 几何格式模块：把常见的几何交换格式解析为本库使用的平铺顶点和拆分索引，解析结果直接交给MultiPolygon的判断流程，
+// js端不必手动把嵌套的坐标数组展平为 polygon / polygon_splits / rings
+
+// 输出(js端):
+//     1. ParsedGeometry 各格式的parse_*函数返回的解析结果
+//        polygon() 路径点 类型Float32Array，polygon_f64() 同样的路径点 类型Float64Array（不损失投影坐标的精度）
+//        polygon_splits() 第2个及之后每个组成部分的起始顶点索引 类型Uint32Array
+//        rings() 洞的起始顶点索引（全局） 类型Uint32Array
+//        三者可直接传给point_in_multipolygon等接受MultiPolygon的函数；part_count()为组成部分（多边形）的个数
+// 说明: 每个多边形是一个组成部分，它的第一个环是外环，其余环是洞，环的角色只由位置决定，与绕向无关；
+//     多个多边形（MultiPolygon的成员、FeatureCollection中的各个要素）的并集为判断区域，
+//     洞只挖去所属多边形的外环，与point_in_multipolygon的语义相同。环末尾重复的闭合顶点在构建时去掉
+
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::points_in_polygon::common::build_multipolygon_with;
+use crate::points_in_polygon::index_u32;
+use crate::points_in_polygon::options::PipOptions;
+use crate::points_in_polygon::scanline::locate_points_in;
+
+#[cfg(feature = "geo")]
+pub mod geo;
+pub mod geojson;
+pub mod wkb;
+pub mod wkt;
+
+// 解析得到的一个多边形：若干环，第一个是外环，其余是洞，每个环是顶点 (x, y) 的列表
+pub(crate) type Rings = Vec<Vec<(f64, f64)>>;
+
+// 解析结果：所有多边形的顶点依次拼接
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct ParsedGeometry {
+    polygon: Vec<f64>,
+    polygon_splits: Vec<u32>,
+    rings: Vec<u32>,
+    part_count: usize,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ParsedGeometry {
+    pub fn polygon(&self) -> Vec<f32> {
+        self.polygon.iter().map(|&c| c as f32).collect()
+    }
+
+    pub fn polygon_f64(&self) -> Vec<f64> {
+        self.polygon.clone()
+    }
+
+    pub fn polygon_splits(&self) -> Vec<u32> {
+        self.polygon_splits.clone()
+    }
+
+    pub fn rings(&self) -> Vec<u32> {
+        self.rings.clone()
+    }
+
+    pub fn part_count(&self) -> usize {
+        self.part_count
+    }
+}
+
+impl ParsedGeometry {
+    // 把各多边形展平；没有顶点的环被跳过，没有外环的多边形不产生组成部分；顶点索引超出u32范围时返回错误信息
+    pub(crate) fn from_polygons(polygons: Vec<Rings>) -> Result<ParsedGeometry, String> {
+        let mut geometry = ParsedGeometry { polygon: Vec::new(), polygon_splits: Vec::new(), rings: Vec::new(), part_count: 0 };
+        for rings in polygons {
+            let mut rings = rings.into_iter().filter(|ring| !ring.is_empty());
+            let Some(outer) = rings.next() else { continue };
+            if geometry.part_count > 0 {
+                geometry.polygon_splits.push(index_u32(geometry.polygon.len() / 2)?);
+            }
+            geometry.push_ring(&outer);
+            for hole in rings {
+                geometry.rings.push(index_u32(geometry.polygon.len() / 2)?);
+                geometry.push_ring(&hole);
+            }
+            geometry.part_count += 1;
+        }
+        Ok(geometry)
+    }
+
+    fn push_ring(&mut self, ring: &[(f64, f64)]) {
+        for &(x, y) in ring {
+            self.polygon.push(x);
+            self.polygon.push(y);
+        }
+    }
+
+    // 按选项判断点是否在解析得到的区域内，没有任何多边形时所有点都在外部
+    pub(crate) fn classify(&self, points: &[f32], options: &PipOptions) -> Vec<u32> {
+        let point_count = points.len() / 2;
+        let poly = build_multipolygon_with(&self.polygon, &self.polygon_splits, &self.rings, options);
+        let point = |i: usize| options.axes(points[i * 2] as f64, points[i * 2 + 1] as f64);
+        locate_points_in(&poly, point_count, point)
+            .into_iter()
+            .map(|location| location.to_u32(options.boundary_is_inside))
+            .collect()
+    }
+}
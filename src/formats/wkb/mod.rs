@@ -0,0 +1,155 @@
+// WKB模块：解析WKB / EWKB（PostGIS）二进制几何中的Polygon和MultiPolygon，直接用于点在多边形内判断，省去JSON往返
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. WKB数据 类型Uint8Array，例如PostGIS ST_AsBinary / ST_AsEWKB 的结果
+//     3. 判断选项 PipOptions（边界语义、填充规则、容差等，见options模块）
+// 输出(js端):
+//     1. point_in_polygon_wkb(points, wkb, options) 类型Uint32Array 1表示在某个多边形内部,0表示在外部
+//     2. parse_wkb(wkb) 解析结果ParsedGeometry（见formats模块），可反复用于其他接受MultiPolygon的函数
+// 说明: 每个几何（包括MultiPolygon的每个成员）各自声明字节序，大端和小端都支持；
+//     类型码接受ISO WKB（1003 / 2003 / 3003 表示带Z、M、ZM）和EWKB的标志位（Z、M、SRID），SRID被忽略，多出的坐标被忽略。
+//     每个多边形的第一个环是外环，其余是洞，与绕向无关。其他几何类型、数据被截断或末尾有多余字节时抛出错误
+
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::options::PipOptions;
+use super::{ParsedGeometry, Rings};
+
+pub mod test;
+
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOLYGON: u32 = 6;
+
+// EWKB类型码的高位标志
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+// WebAssembly导出函数：判断点是否在WKB描述的区域内
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_wkb(
+    points: &[f32],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    wkb: &[u8],           // WKB / EWKB数据
+    options: &PipOptions, // 判断选项
+) -> Result<Vec<u32>, ExportError> {
+    classify_wkb(points, wkb, options).map_err(export_error)
+}
+
+// WebAssembly导出函数：解析WKB中的Polygon或MultiPolygon
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn parse_wkb(wkb: &[u8]) -> Result<ParsedGeometry, ExportError> {
+    parse_wkb_values(wkb).map_err(export_error)
+}
+
+// 判断流程，WKB不合法时返回错误信息
+pub(crate) fn classify_wkb(points: &[f32], wkb: &[u8], options: &PipOptions) -> Result<Vec<u32>, String> {
+    Ok(parse_wkb_values(wkb)?.classify(points, options))
+}
+
+// 解析流程，WKB不合法时返回错误信息
+pub(crate) fn parse_wkb_values(wkb: &[u8]) -> Result<ParsedGeometry, String> {
+    let mut reader = Reader { bytes: wkb, pos: 0, little_endian: true };
+    let (kind, dimensions) = reader.header()?;
+    let polygons = match kind {
+        WKB_POLYGON => vec![reader.polygon(dimensions)?],
+        WKB_MULTIPOLYGON => {
+            let count = reader.count(9)?;
+            let mut polygons = Vec::with_capacity(count);
+            for k in 0..count {
+                let (member, dimensions) = reader.header()?;
+                if member != WKB_POLYGON {
+                    return Err(format!("MultiPolygon member {} has geometry type {}, expected a Polygon", k, member));
+                }
+                polygons.push(reader.polygon(dimensions)?);
+            }
+            polygons
+        }
+        other => return Err(format!("unsupported WKB geometry type {}", other)),
+    };
+    if reader.pos != wkb.len() {
+        return Err(reader.error("unexpected trailing bytes"));
+    }
+    ParsedGeometry::from_polygons(polygons)
+}
+
+// 按当前几何的字节序读取，错误信息给出字节偏移
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    little_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn error(&self, message: &str) -> String {
+        format!("invalid WKB at byte {}: {}", self.pos, message)
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let bytes = self.bytes.get(self.pos..self.pos + N).ok_or_else(|| self.error("unexpected end of data"))?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let bytes = self.take::<4>()?;
+        Ok(if self.little_endian { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) })
+    }
+
+    fn f64(&mut self) -> Result<f64, String> {
+        let bytes = self.take::<8>()?;
+        Ok(if self.little_endian { f64::from_le_bytes(bytes) } else { f64::from_be_bytes(bytes) })
+    }
+
+    // 元素个数；每个元素至少占min_size字节，个数超过剩余数据能容纳的上限时直接报错，避免按伪造的个数分配内存
+    fn count(&mut self, min_size: usize) -> Result<usize, String> {
+        let count = self.u32()? as usize;
+        if count > (self.bytes.len() - self.pos) / min_size {
+            return Err(self.error("element count exceeds the remaining data"));
+        }
+        Ok(count)
+    }
+
+    // 几何头：字节序、类型码和可选的SRID，返回基本类型和每个位置的坐标个数
+    fn header(&mut self) -> Result<(u32, usize), String> {
+        self.little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            _ => return Err(self.error("byte order must be 0 or 1")),
+        };
+        let code = self.u32()?;
+        if code & EWKB_SRID != 0 {
+            self.u32()?;
+        }
+        let flags = 2 + (code & EWKB_Z != 0) as usize + (code & EWKB_M != 0) as usize;
+        let iso = code & !(EWKB_Z | EWKB_M | EWKB_SRID);
+        let dimensions = match (iso / 1000, flags) {
+            (0, flags) => flags,
+            (1 | 2, 2) => 3,
+            (3, 2) => 4,
+            _ => return Err(self.error(&format!("invalid geometry type code {:#x}", code))),
+        };
+        Ok((iso % 1000, dimensions))
+    }
+
+    fn polygon(&mut self, dimensions: usize) -> Result<Rings, String> {
+        let ring_count = self.count(4)?;
+        let mut rings = Vec::with_capacity(ring_count);
+        for _ in 0..ring_count {
+            let point_count = self.count(8 * dimensions)?;
+            let mut ring = Vec::with_capacity(point_count);
+            for _ in 0..point_count {
+                let x = self.f64()?;
+                let y = self.f64()?;
+                for _ in 2..dimensions {
+                    self.f64()?;
+                }
+                ring.push((x, y));
+            }
+            rings.push(ring);
+        }
+        Ok(rings)
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use crate::formats::wkb::{classify_wkb, parse_wkb_values};
+    use crate::formats::wkt::parse_wkt_values;
+    use crate::points_in_polygon::options::PipOptions;
+
+    // 按给定字节序写出WKB
+    struct Writer {
+        bytes: Vec<u8>,
+        little_endian: bool,
+    }
+
+    impl Writer {
+        fn u32(&mut self, value: u32) {
+            let bytes = if self.little_endian { value.to_le_bytes() } else { value.to_be_bytes() };
+            self.bytes.extend_from_slice(&bytes);
+        }
+
+        fn header(&mut self, code: u32, srid: Option<u32>) {
+            self.bytes.push(self.little_endian as u8);
+            self.u32(code);
+            if let Some(srid) = srid {
+                self.u32(srid);
+            }
+        }
+
+        // extra为每个位置附加的坐标个数
+        fn polygon(&mut self, rings: &[&[(f64, f64)]], extra: usize) {
+            self.u32(rings.len() as u32);
+            for ring in rings {
+                self.u32(ring.len() as u32);
+                for &(x, y) in ring.iter() {
+                    for c in [x, y].into_iter().chain(std::iter::repeat_n(7.0, extra)) {
+                        let bytes = if self.little_endian { c.to_le_bytes() } else { c.to_be_bytes() };
+                        self.bytes.extend_from_slice(&bytes);
+                    }
+                }
+            }
+        }
+    }
+
+    const OUTER: &[(f64, f64)] = &[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)];
+    const HOLE: &[(f64, f64)] = &[(2.0, 2.0), (2.0, 8.0), (8.0, 8.0), (8.0, 2.0), (2.0, 2.0)];
+    const TRIANGLE: &[(f64, f64)] = &[(20.0, 0.0), (30.0, 0.0), (25.0, 10.0), (20.0, 0.0)];
+
+    fn polygon_wkb(little_endian: bool, code: u32, srid: Option<u32>, extra: usize) -> Vec<u8> {
+        let mut writer = Writer { bytes: Vec::new(), little_endian };
+        writer.header(code, srid);
+        writer.polygon(&[OUTER, HOLE], extra);
+        writer.bytes
+    }
+
+    #[test]
+    fn test_polygon_byte_orders_and_dimensions() {
+        let points = vec![1.0, 1.0, 5.0, 5.0, 12.0, 5.0, 10.0, 5.0];
+        let options = PipOptions::new();
+        let cases = [
+            polygon_wkb(true, 3, None, 0),
+            polygon_wkb(false, 3, None, 0),
+            polygon_wkb(true, 1003, None, 1),
+            polygon_wkb(false, 3003, None, 2),
+            polygon_wkb(true, 0x2000_0003, Some(4326), 0),
+            polygon_wkb(false, 0xA000_0003, Some(3857), 1),
+            polygon_wkb(true, 0xC000_0003, None, 2),
+        ];
+        for wkb in cases {
+            assert_eq!(classify_wkb(&points, &wkb, &options).unwrap(), vec![1, 0, 0, 1]);
+        }
+    }
+
+    #[test]
+    fn test_multipolygon_matches_wkt() {
+        // 成员的字节序可以与外层不同
+        let mut writer = Writer { bytes: Vec::new(), little_endian: false };
+        writer.header(0x2000_0006, Some(4326));
+        writer.u32(2);
+        writer.little_endian = true;
+        writer.header(3, None);
+        writer.polygon(&[OUTER, HOLE], 0);
+        writer.little_endian = false;
+        writer.header(3, None);
+        writer.polygon(&[TRIANGLE], 0);
+
+        let from_wkb = parse_wkb_values(&writer.bytes).unwrap();
+        let from_wkt = parse_wkt_values(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,2 8,8 8,8 2,2 2)),((20 0,30 0,25 10,20 0)))",
+        )
+        .unwrap();
+        assert_eq!(from_wkb.part_count(), 2);
+        assert_eq!(from_wkb.polygon_f64(), from_wkt.polygon_f64());
+        assert_eq!(from_wkb.polygon_splits(), from_wkt.polygon_splits());
+        assert_eq!(from_wkb.rings(), from_wkt.rings());
+
+        // 空的MultiPolygon（PostGIS的MULTIPOLYGON EMPTY）
+        let empty = [1, 6, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(classify_wkb(&[1.0, 1.0], &empty, &PipOptions::new()).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let valid = polygon_wkb(true, 3, None, 0);
+        assert!(parse_wkb_values(&[]).is_err());
+        assert!(parse_wkb_values(&valid[..valid.len() - 1]).is_err());
+        let mut trailing = valid.clone();
+        trailing.push(0);
+        assert!(parse_wkb_values(&trailing).is_err());
+        let mut bad_order = valid.clone();
+        bad_order[0] = 2;
+        assert!(parse_wkb_values(&bad_order).is_err());
+
+        // 点、带Z标志又用ISO类型码、伪造的巨大环数
+        assert!(parse_wkb_values(&[1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        assert!(parse_wkb_values(&polygon_wkb(true, 0x8000_0000 | 1003, None, 2)).is_err());
+        assert!(parse_wkb_values(&[1, 3, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]).is_err());
+
+        // MultiPolygon的成员必须是Polygon
+        let mut writer = Writer { bytes: Vec::new(), little_endian: true };
+        writer.header(6, None);
+        writer.u32(1);
+        writer.header(6, None);
+        writer.u32(0);
+        assert!(parse_wkb_values(&writer.bytes).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+// WKT模块：解析WKT（Well-Known Text）中的POLYGON和MULTIPOLYGON，直接用于点在多边形内判断
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. WKT文本 类型string 例子"POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 8, 8 8, 8 2, 2 2))"
+//     3. 判断选项 PipOptions（边界语义、填充规则、容差等，见options模块）
+// 输出(js端):
+//     1. point_in_polygon_wkt(points, wkt, options) 类型Uint32Array 1表示在某个多边形内部,0表示在外部
+//     2. parse_wkt(wkt) 解析结果ParsedGeometry（见formats模块），可反复用于其他接受MultiPolygon的函数
+// 说明: 关键字不区分大小写；接受PostGIS的EWKT前缀"SRID=4326;"（SRID被忽略）、Z/M/ZM维度标记（多出的坐标被忽略）
+//     以及EMPTY（不围成区域）。每个多边形的第一个环是外环，其余是洞，与绕向无关。
+//     其他几何类型（POINT、LINESTRING、GEOMETRYCOLLECTION等）和不合法的文本抛出错误，错误信息给出出错的位置
+
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::options::PipOptions;
+use super::{ParsedGeometry, Rings};
+
+pub mod test;
+
+// WebAssembly导出函数：判断点是否在WKT描述的区域内
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_wkt(
+    points: &[f32],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    wkt: &str,            // WKT文本
+    options: &PipOptions, // 判断选项
+) -> Result<Vec<u32>, ExportError> {
+    classify_wkt(points, wkt, options).map_err(export_error)
+}
+
+// WebAssembly导出函数：解析WKT中的POLYGON或MULTIPOLYGON
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn parse_wkt(wkt: &str) -> Result<ParsedGeometry, ExportError> {
+    parse_wkt_values(wkt).map_err(export_error)
+}
+
+// 判断流程，WKT不合法时返回错误信息
+pub(crate) fn classify_wkt(points: &[f32], wkt: &str, options: &PipOptions) -> Result<Vec<u32>, String> {
+    Ok(parse_wkt_values(wkt)?.classify(points, options))
+}
+
+// 解析流程，WKT不合法时返回错误信息
+pub(crate) fn parse_wkt_values(wkt: &str) -> Result<ParsedGeometry, String> {
+    let mut reader = Reader { text: wkt, pos: 0 };
+    reader.skip_srid()?;
+    let kind = reader.word().to_ascii_uppercase();
+    let mut dimensions = reader.dimensions()?;
+    let polygons = match kind.as_str() {
+        "POLYGON" => reader.polygon(&mut dimensions)?.into_iter().collect(),
+        "MULTIPOLYGON" => reader.multipolygon(&mut dimensions)?,
+        "" => return Err(reader.error("expected a geometry type")),
+        other => return Err(format!("unsupported WKT geometry type {}", other)),
+    };
+    reader.skip_whitespace();
+    if reader.pos != reader.text.len() {
+        return Err(reader.error("unexpected trailing characters"));
+    }
+    ParsedGeometry::from_polygons(polygons)
+}
+
+// 按字符扫描WKT文本，错误信息给出字节偏移
+struct Reader<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn error(&self, message: &str) -> String {
+        format!("invalid WKT at byte {}: {}", self.pos, message)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    // 下一个字符是c时跳过它
+    fn accept(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.accept(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c)))
+        }
+    }
+
+    // 由字母组成的关键字
+    fn word(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    // EWKT的 "SRID=4326;" 前缀
+    fn skip_srid(&mut self) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.rest().len() >= 5 && self.rest()[..5].eq_ignore_ascii_case("SRID=") {
+            self.pos += self.rest().find(';').ok_or_else(|| self.error("expected ';' after SRID"))? + 1;
+        }
+        Ok(())
+    }
+
+    // 类型名之后可选的 Z / M / ZM 标记，返回每个位置的坐标个数；None表示由第一个位置决定
+    fn dimensions(&mut self) -> Result<Option<usize>, String> {
+        let start = self.pos;
+        match self.word().to_ascii_uppercase().as_str() {
+            "" => Ok(None),
+            "Z" | "M" => Ok(Some(3)),
+            "ZM" => Ok(Some(4)),
+            // EMPTY留给后续判断
+            "EMPTY" => {
+                self.pos = start;
+                Ok(None)
+            }
+            _ => Err(self.error("expected Z, M, ZM, EMPTY or '('")),
+        }
+    }
+
+    // 当前位置是EMPTY时跳过它
+    fn empty(&mut self) -> bool {
+        let start = self.pos;
+        if self.word().eq_ignore_ascii_case("EMPTY") {
+            true
+        } else {
+            self.pos = start;
+            false
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E')))
+            .unwrap_or(rest.len());
+        let value = rest[..len].parse::<f64>().map_err(|_| self.error("expected a number"))?;
+        if !value.is_finite() {
+            return Err(self.error("coordinate is not finite"));
+        }
+        self.pos += len;
+        Ok(value)
+    }
+
+    // 一个位置：以空白分隔的若干个数字，只保留前两个
+    fn position(&mut self, dimensions: &mut Option<usize>) -> Result<(f64, f64), String> {
+        let x = self.number()?;
+        let y = self.number()?;
+        let mut count = 2;
+        loop {
+            self.skip_whitespace();
+            match self.rest().chars().next() {
+                Some(',' | ')') | None => break,
+                _ => {
+                    self.number()?;
+                    count += 1;
+                }
+            }
+        }
+        match *dimensions {
+            None if count <= 4 => *dimensions = Some(count),
+            Some(expected) if expected == count => {}
+            _ => return Err(self.error("positions have inconsistent dimensions")),
+        }
+        Ok((x, y))
+    }
+
+    fn ring(&mut self, dimensions: &mut Option<usize>) -> Result<Vec<(f64, f64)>, String> {
+        if self.empty() {
+            return Ok(Vec::new());
+        }
+        self.expect('(')?;
+        let mut ring = vec![self.position(dimensions)?];
+        while self.accept(',') {
+            ring.push(self.position(dimensions)?);
+        }
+        self.expect(')')?;
+        Ok(ring)
+    }
+
+    // POLYGON EMPTY返回None
+    fn polygon(&mut self, dimensions: &mut Option<usize>) -> Result<Option<Rings>, String> {
+        if self.empty() {
+            return Ok(None);
+        }
+        self.expect('(')?;
+        let mut rings = vec![self.ring(dimensions)?];
+        while self.accept(',') {
+            rings.push(self.ring(dimensions)?);
+        }
+        self.expect(')')?;
+        Ok(Some(rings))
+    }
+
+    fn multipolygon(&mut self, dimensions: &mut Option<usize>) -> Result<Vec<Rings>, String> {
+        let mut polygons = Vec::new();
+        if self.empty() {
+            return Ok(polygons);
+        }
+        self.expect('(')?;
+        loop {
+            polygons.extend(self.polygon(dimensions)?);
+            if !self.accept(',') {
+                break;
+            }
+        }
+        self.expect(')')?;
+        Ok(polygons)
+    }
+}
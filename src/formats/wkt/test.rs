@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use crate::formats::geojson::parse_geojson_values;
+    use crate::formats::wkt::{classify_wkt, parse_wkt_values};
+    use crate::points_in_polygon::options::PipOptions;
+
+    #[test]
+    fn test_polygon_with_hole() {
+        let wkt = "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 8, 8 8, 8 2, 2 2))";
+        let points = vec![1.0, 1.0, 5.0, 5.0, 12.0, 5.0, 10.0, 5.0];
+        assert_eq!(classify_wkt(&points, wkt, &PipOptions::new()).unwrap(), vec![1, 0, 0, 1]);
+
+        let parsed = parse_wkt_values(wkt).unwrap();
+        assert_eq!(parsed.part_count(), 1);
+        assert_eq!(parsed.rings(), vec![5]);
+    }
+
+    #[test]
+    fn test_multipolygon_matches_geojson() {
+        let wkt = "multipolygon(((0 0,10 0,10 10,0 10,0 0),(2 2,8 2,8 8,2 8,2 2)),((4 4,6 4,6 6,4 6,4 4)),((20 0,30 0,25 10,20 0)))";
+        let geojson = r#"{"type":"MultiPolygon","coordinates":[
+            [[[0,0],[10,0],[10,10],[0,10],[0,0]],[[2,2],[8,2],[8,8],[2,8],[2,2]]],
+            [[[4,4],[6,4],[6,6],[4,6],[4,4]]],
+            [[[20,0],[30,0],[25,10],[20,0]]]
+        ]}"#;
+        let from_wkt = parse_wkt_values(wkt).unwrap();
+        let from_geojson = parse_geojson_values(geojson).unwrap();
+        assert_eq!(from_wkt.polygon_f64(), from_geojson.polygon_f64());
+        assert_eq!(from_wkt.polygon_splits(), from_geojson.polygon_splits());
+        assert_eq!(from_wkt.rings(), from_geojson.rings());
+
+        let points = vec![1.0, 1.0, 3.0, 3.0, 5.0, 5.0, 25.0, 3.0, 15.0, 5.0];
+        assert_eq!(classify_wkt(&points, wkt, &PipOptions::new()).unwrap(), vec![1, 0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_ewkt_dimensions_and_empty() {
+        let points = vec![2.0, 2.0, 7.0, 2.0];
+        let options = PipOptions::new();
+        for wkt in [
+            "SRID=4326;POLYGON((0 0,4 0,4 4,0 4,0 0))",
+            "POLYGON Z ((0 0 1,4 0 1,4 4 1,0 4 1,0 0 1))",
+            "POLYGON ZM ((0 0 1 2,4 0 1 2,4 4 1 2,0 4 1 2,0 0 1 2))",
+            "POLYGON ((0 0 1,4 0 1,4 4 1,0 4 1,0 0 1))",
+            "  Polygon ( ( 0 0 , 4 0 , 4 4 , 0 4 , 0 0 ) )  ",
+            "POLYGON ((0.0 0e0, 4.0 0, 4 4E0, -0 4, 0 0))",
+            "MULTIPOLYGON (EMPTY, ((0 0,4 0,4 4,0 4,0 0)))",
+        ] {
+            assert_eq!(classify_wkt(&points, wkt, &options).unwrap(), vec![1, 0], "{}", wkt);
+        }
+        for wkt in ["POLYGON EMPTY", "MULTIPOLYGON EMPTY", "SRID=3857;POLYGON Z EMPTY"] {
+            assert_eq!(classify_wkt(&points, wkt, &options).unwrap(), vec![0, 0], "{}", wkt);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let invalid = [
+            "",
+            "POINT (1 2)",
+            "POLYGON",
+            "POLYGON ((0 0, 4 0, 4 4, 0 0)",
+            "POLYGON ((0 0, 4 0, 4, 0 0))",
+            "POLYGON ((0 0, 4 x, 4 4, 0 0))",
+            "POLYGON ((0 0, 4 0 1, 4 4, 0 0))",
+            "POLYGON Z ((0 0, 4 0, 4 4, 0 0))",
+            "POLYGON ((0 0, 4 0, 4 4, 0 0)) POLYGON",
+            "POLYGON ((0 0, 1e999 0, 4 4, 0 0))",
+            "SRID=4326 POLYGON ((0 0, 4 0, 4 4, 0 0))",
+        ];
+        for wkt in invalid {
+            assert!(parse_wkt_values(wkt).is_err(), "{:?}", wkt);
+        }
+    }
+}
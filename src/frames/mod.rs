@@ -0,0 +1,161 @@
+// 逐帧判断模块：同一组点依次对一系列多边形（例如随时间移动的风暴单体）做判断，
+// 给出每个点第一次进入的帧和最后一次离开的帧
+// 点的空间索引只在开始时构建一次，每一帧只检查落在该帧多边形边界框内的点
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 各帧的多边形 类型Float32Array 按帧的顺序依次拼接
+//     3. 多边形拆分 类型Uint32Array 第2帧及之后每帧多边形的起始顶点索引，含义与coverage模块相同
+//     4. 环拆分 类型Uint32Array 各帧多边形内部洞的起始顶点索引（按拼接后的全局顶点索引）
+//     5. 边界上点是否考虑为内部 boolean
+// 输出(js端):
+//     1. 类型Uint32Array 每个点占2个值 [进入帧, 离开帧]：进入帧是点第一次在多边形内部的帧，
+//        离开帧是点最后一次在内部的帧的下一帧（点在最后一帧仍在内部时为帧数），即点在 [进入帧, 离开帧) 之间出现过；
+//        从未在内部的点两个值都是4294967295（u32最大值）
+// 说明: 不含顶点的帧仍占据帧序号，所有点在该帧都在外部，与coverage模块拆分多边形组的约定一致
+
+use alloc::{string::String, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::coverage::split_polygons;
+use crate::points_in_polygon::scanline::ScanlineIndex;
+use crate::points_in_polygon::index_u32;
+
+pub mod test;
+
+// 从未进入的点的帧索引
+pub(crate) const NO_FRAME: u32 = u32::MAX;
+
+// WebAssembly导出函数：对多边形序列逐帧判断，返回每个点的进入帧和离开帧
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn classify_over_frames(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygons: &[f32],         // 各帧多边形顶点依次拼接
+    polygon_splits: &[u32],   // 第2帧及之后每帧多边形的起始顶点索引
+    rings: &[u32],            // 洞的起始顶点索引（全局）
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Result<Vec<u32>, ExportError> {
+    classify_over_frames_values(points, polygons, polygon_splits, rings, boundary_is_inside).map_err(export_error)
+}
+
+// 逐帧判断的实现，点索引或帧索引超出u32范围时返回错误信息
+pub(crate) fn classify_over_frames_values(
+    points: &[f32],
+    polygons: &[f32],
+    polygon_splits: &[u32],
+    rings: &[u32],
+    boundary_is_inside: bool,
+) -> Result<Vec<u32>, String> {
+    let point_count = points.len() / 2;
+    let mut result = vec![NO_FRAME; point_count * 2];
+    let index = PointIndex::new(points)?;
+
+    for (frame, (polygon, local_rings)) in split_polygons(polygons, polygon_splits, rings).iter().enumerate() {
+        let polygon_index = ScanlineIndex::new(polygon, local_rings);
+        let (min_x, min_y, max_x, max_y) = polygon_index.bounds();
+        let (enter, leave) = (index_u32(frame)?, index_u32(frame + 1)?);
+
+        index.for_each_in(min_x, min_y, max_x, max_y, |i, x, y| {
+            if polygon_index.locate(x, y).to_u32(boundary_is_inside) == 1 {
+                if result[i * 2] == NO_FRAME {
+                    result[i * 2] = enter;
+                }
+                result[i * 2 + 1] = leave;
+            }
+        });
+    }
+    Ok(result)
+}
+
+// 点的网格索引：按单元分桶保存点的索引（压缩行存储），各帧共用
+struct PointIndex<'a> {
+    points: &'a [f32],
+    min_x: f64,
+    min_y: f64,
+    cell_w: f64,
+    cell_h: f64,
+    size: usize,
+    starts: Vec<usize>, // 第k个单元的点在order中的范围为 starts[k]..starts[k+1]
+    order: Vec<u32>,    // 按单元排列的点索引
+}
+
+impl<'a> PointIndex<'a> {
+    fn new(points: &'a [f32]) -> Result<PointIndex<'a>, String> {
+        let point_count = points.len() / 2;
+        let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+        let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for i in 0..point_count {
+            let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+
+        // 平均每个单元约4个点
+        let size = ((point_count as f64 / 4.0).sqrt().ceil() as usize).clamp(1, 1024);
+        let mut index = PointIndex {
+            points,
+            min_x,
+            min_y,
+            cell_w: ((max_x - min_x) / size as f64).max(f64::MIN_POSITIVE),
+            cell_h: ((max_y - min_y) / size as f64).max(f64::MIN_POSITIVE),
+            size,
+            starts: vec![0; size * size + 1],
+            order: vec![0; point_count],
+        };
+        if point_count == 0 {
+            return Ok(index);
+        }
+
+        // 计数排序：先统计每个单元的点数，再按单元填入点索引
+        let cells: Vec<usize> = (0..point_count)
+            .map(|i| {
+                let (c, r) = index.cell_of(points[i * 2] as f64, points[i * 2 + 1] as f64);
+                r * size + c
+            })
+            .collect();
+        for &cell in &cells {
+            index.starts[cell + 1] += 1;
+        }
+        for k in 0..size * size {
+            index.starts[k + 1] += index.starts[k];
+        }
+        let mut next = index.starts.clone();
+        for (i, &cell) in cells.iter().enumerate() {
+            index.order[next[cell]] = index_u32(i)?;
+            next[cell] += 1;
+        }
+        Ok(index)
+    }
+
+    // 点所在单元的列、行，范围外的坐标夹到边缘单元
+    fn cell_of(&self, x: f64, y: f64) -> (usize, usize) {
+        let last = (self.size - 1) as f64;
+        let c = ((x - self.min_x) / self.cell_w).floor().clamp(0.0, last) as usize;
+        let r = ((y - self.min_y) / self.cell_h).floor().clamp(0.0, last) as usize;
+        (c, r)
+    }
+
+    // 对边界框内的每个点调用 f(点索引, x, y)
+    fn for_each_in<F: FnMut(usize, f64, f64)>(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64, mut f: F) {
+        if self.order.is_empty() || !(min_x <= max_x && min_y <= max_y) {
+            return;
+        }
+        let (c0, r0) = self.cell_of(min_x, min_y);
+        let (c1, r1) = self.cell_of(max_x, max_y);
+        for r in r0..=r1 {
+            let row = r * self.size;
+            for &i in &self.order[self.starts[row + c0]..self.starts[row + c1 + 1]] {
+                let i = i as usize;
+                let (x, y) = (self.points[i * 2] as f64, self.points[i * 2 + 1] as f64);
+                if x >= min_x && x <= max_x && y >= min_y && y <= max_y {
+                    f(i, x, y);
+                }
+            }
+        }
+    }
+}
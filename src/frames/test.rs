@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::frames::{classify_over_frames_values, NO_FRAME};
+    use crate::generators::uniform_points;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    #[test]
+    fn test_enter_and_exit_frames() {
+        // 边长2的正方形每帧向右移动1
+        let frames: Vec<f32> = (0..4)
+            .flat_map(|k| {
+                let x = k as f32;
+                vec![x, 0.0, x + 2.0, 0.0, x + 2.0, 2.0, x, 2.0]
+            })
+            .collect();
+        let polygon_splits = vec![4, 8, 12];
+        let points = vec![
+            0.5, 1.0, // 只在第0帧内
+            2.5, 1.0, // 第1、2帧内
+            4.5, 1.0, // 第3帧内，直到最后一帧
+            9.0, 1.0, // 从未进入
+            2.0, 1.0, // 第0帧的边界上，第1帧内部，第2帧的边界上
+        ];
+
+        assert_eq!(
+            classify_over_frames_values(&points, &frames, &polygon_splits, &[], true).unwrap(),
+            vec![0, 1, 1, 3, 3, 4, NO_FRAME, NO_FRAME, 0, 3]
+        );
+        assert_eq!(
+            classify_over_frames_values(&points, &frames, &polygon_splits, &[], false).unwrap(),
+            vec![0, 1, 1, 3, 3, 4, NO_FRAME, NO_FRAME, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_matches_per_frame_classification() {
+        // 带洞的多边形逐帧缩放，与逐帧单独判断的结果一致
+        let points = uniform_points(2000, -10.0, -10.0, 10.0, 10.0, 3);
+        let mut frames = Vec::new();
+        let mut polygon_splits = Vec::new();
+        let mut rings = Vec::new();
+        for k in 0..5 {
+            let s = 2.0 + k as f32;
+            let start = (frames.len() / 2) as u32;
+            if k > 0 {
+                polygon_splits.push(start);
+            }
+            frames.extend([-s, -s, s, -s, 0.0, s]);
+            rings.push(start + 3);
+            frames.extend([-0.5, -0.5, 0.5, -0.5, 0.0, 0.5]);
+        }
+
+        let result = classify_over_frames_values(&points, &frames, &polygon_splits, &rings, true).unwrap();
+        let mut expected = vec![NO_FRAME; points.len()];
+        for k in 0..5 {
+            let polygon = &frames[k * 12..(k + 1) * 12];
+            let inside = point_in_polygon_scanline(&points, polygon, &[3], true);
+            for (i, &v) in inside.iter().enumerate() {
+                if v == 1 {
+                    if expected[i * 2] == NO_FRAME {
+                        expected[i * 2] = k as u32;
+                    }
+                    expected[i * 2 + 1] = k as u32 + 1;
+                }
+            }
+        }
+        assert_eq!(result, expected);
+        assert!(result.contains(&0) && result.contains(&NO_FRAME));
+    }
+
+    #[test]
+    fn test_empty_frame_is_outside() {
+        // 第1帧没有顶点：点在该帧外部，之后的帧序号不变
+        let frames = vec![
+            0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0, // 第0帧
+            5.0, 0.0, 7.0, 0.0, 7.0, 2.0, 5.0, 2.0, // 第2帧
+        ];
+        let polygon_splits = vec![4, 4];
+        let points = vec![
+            1.0, 1.0, // 只在第0帧内
+            6.0, 1.0, // 只在第2帧内
+            9.0, 9.0, // 从未进入
+        ];
+
+        assert_eq!(
+            classify_over_frames_values(&points, &frames, &polygon_splits, &[], true).unwrap(),
+            vec![0, 1, 2, 3, NO_FRAME, NO_FRAME]
+        );
+    }
+}
@@ -0,0 +1,245 @@
+// 生成器模块：生成可复现的合成几何数据，用于基准测试与模糊测试
+// 所有随机生成器都接受种子，相同的参数与种子总是得到相同的结果
+
+// 输入(js端):
+//     1. 形状参数（中心、半径、顶点数等）
+//     2. 随机种子 类型number（整数）
+// 输出(js端):
+//     1. 多边形生成器: 类型Float32Array 单个环的路径点 [x1, y1, x2, y2, ...]，
+//        与points_in_polygon模块配合使用时拆分索引传 [顶点数]
+//     2. 点云生成器: 类型Float32Array [x1, y1, x2, y2, ...]
+// 说明: 多边形生成器输出的环都是简单多边形（不自交），顶点按逆时针排列
+
+use alloc::vec::Vec;
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use core::f64::consts::PI;
+
+pub mod test;
+
+// 分形海岸线的最大细分层数，避免顶点数爆炸
+const MAX_FRACTAL_DEPTH: u32 = 16;
+
+// SplitMix64 伪随机数生成器，无外部依赖且跨平台结果一致
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // [0, 1) 内的均匀分布
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // [min, max) 内的均匀分布
+    pub(crate) fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+
+    // 标准正态分布（Box-Muller变换）
+    pub(crate) fn normal(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64(); // (0, 1]，避免ln(0)
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+}
+
+// 随机简单多边形：按随机间隔的递增角度放置随机半径的顶点
+// 多边形相对中心是星形的，因此一定不自交
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn random_simple_polygon(
+    cx: f64,             // 中心x
+    cy: f64,             // 中心y
+    radius: f64,         // 最大半径，顶点半径在[0.2, 1] * radius内随机
+    vertex_count: usize, // 顶点数，不足3时返回空数组
+    seed: u32,           // 随机种子
+) -> Vec<f32> {
+    if vertex_count < 3 {
+        return Vec::new();
+    }
+
+    let mut rng = Rng::new(seed as u64);
+    // 随机角度间隔，归一化后总和为2π
+    let gaps: Vec<f64> = (0..vertex_count).map(|_| rng.range(0.5, 1.5)).collect();
+    let total: f64 = gaps.iter().sum();
+
+    let mut angle = 0.0;
+    let mut polar = Vec::with_capacity(vertex_count);
+    for gap in gaps {
+        polar.push((angle, radius * rng.range(0.2, 1.0)));
+        angle += gap / total * 2.0 * PI;
+    }
+    polar_to_flat(cx, cy, &polar)
+}
+
+// 星形多边形：外顶点与内顶点交替
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn star_polygon(
+    cx: f64,           // 中心x
+    cy: f64,           // 中心y
+    outer_radius: f64, // 尖角半径
+    inner_radius: f64, // 凹角半径
+    spikes: usize,     // 尖角数，不足2时返回空数组
+) -> Vec<f32> {
+    if spikes < 2 {
+        return Vec::new();
+    }
+
+    let step = PI / spikes as f64;
+    let polar: Vec<(f64, f64)> = (0..spikes * 2)
+        .map(|i| {
+            let r = if i % 2 == 0 { outer_radius } else { inner_radius };
+            (i as f64 * step, r)
+        })
+        .collect();
+    polar_to_flat(cx, cy, &polar)
+}
+
+// 螺旋带多边形：沿阿基米德螺线向外走外侧边，再沿内侧边返回
+// 相邻两圈之间留有间隙，产生大量狭长的凹区域
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn spiral_polygon(
+    cx: f64,                  // 中心x
+    cy: f64,                  // 中心y
+    radius: f64,              // 外半径
+    turns: f64,               // 圈数
+    segments_per_turn: usize, // 每圈的分段数
+) -> Vec<f32> {
+    if turns <= 0.0 || segments_per_turn < 3 {
+        return Vec::new();
+    }
+
+    // 螺距，带宽为螺距的一半
+    let pitch = radius / (turns + 1.0);
+    let half_width = pitch / 4.0;
+    let steps = ((turns * segments_per_turn as f64).ceil() as usize).max(1);
+    let sweep = turns * 2.0 * PI;
+
+    let centerline: Vec<(f64, f64)> = (0..=steps)
+        .map(|i| {
+            let theta = sweep * i as f64 / steps as f64;
+            (theta, pitch * (0.5 + theta / (2.0 * PI)))
+        })
+        .collect();
+
+    let mut polygon = Vec::with_capacity(centerline.len() * 4);
+    // 外侧边向外，内侧边向内返回
+    for &(theta, r) in &centerline {
+        push_polar(&mut polygon, cx, cy, theta, r + half_width);
+    }
+    for &(theta, r) in centerline.iter().rev() {
+        push_polar(&mut polygon, cx, cy, theta, r - half_width);
+    }
+    polygon
+}
+
+// 分形海岸线：从菱形开始逐层在相邻顶点之间插入角平分处的顶点，
+// 半径按中点位移法随机扰动，扰动幅度逐层减半
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn fractal_coastline(
+    cx: f64,         // 中心x
+    cy: f64,         // 中心y
+    radius: f64,     // 基准半径
+    depth: u32,      // 细分层数，顶点数为 4 * 2^depth，最多16层
+    roughness: f64,  // 粗糙度，第一层的相对扰动幅度，常用0.3~0.8
+    seed: u32,       // 随机种子
+) -> Vec<f32> {
+    let depth = depth.min(MAX_FRACTAL_DEPTH);
+    let mut rng = Rng::new(seed as u64);
+    // 半径下限，保证相对中心保持星形
+    let min_radius = radius * 0.05;
+
+    let mut polar: Vec<(f64, f64)> = (0..4).map(|i| (i as f64 * PI / 2.0, radius)).collect();
+    let mut amplitude = roughness;
+    for _ in 0..depth {
+        let n = polar.len();
+        let mut refined = Vec::with_capacity(n * 2);
+        for i in 0..n {
+            let (a0, r0) = polar[i];
+            let (a1, r1) = if i + 1 < n { polar[i + 1] } else { (polar[0].0 + 2.0 * PI, polar[0].1) };
+            refined.push((a0, r0));
+            let r = (r0 + r1) / 2.0 * (1.0 + amplitude * rng.range(-1.0, 1.0));
+            refined.push(((a0 + a1) / 2.0, r.max(min_radius)));
+        }
+        polar = refined;
+        amplitude /= 2.0;
+    }
+    polar_to_flat(cx, cy, &polar)
+}
+
+// 均匀分布的点云
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn uniform_points(
+    count: usize, // 点数
+    min_x: f64,   // 范围
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+    seed: u32,    // 随机种子
+) -> Vec<f32> {
+    let mut rng = Rng::new(seed as u64);
+    let mut points = Vec::with_capacity(count * 2);
+    for _ in 0..count {
+        points.push(rng.range(min_x, max_x) as f32);
+        points.push(rng.range(min_y, max_y) as f32);
+    }
+    points
+}
+
+// 聚簇分布的点云：簇中心在范围内均匀分布，点围绕簇中心按正态分布散开，
+// 超出范围的点被截断到范围边界上
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[allow(clippy::too_many_arguments)]
+pub fn clustered_points(
+    count: usize,         // 点数
+    cluster_count: usize, // 簇数，为0时按1处理
+    min_x: f64,           // 范围
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+    spread: f64,          // 簇内标准差
+    seed: u32,            // 随机种子
+) -> Vec<f32> {
+    let mut rng = Rng::new(seed as u64);
+    let centers: Vec<(f64, f64)> = (0..cluster_count.max(1))
+        .map(|_| (rng.range(min_x, max_x), rng.range(min_y, max_y)))
+        .collect();
+
+    let mut points = Vec::with_capacity(count * 2);
+    for _ in 0..count {
+        let (ccx, ccy) = centers[(rng.next_u64() % centers.len() as u64) as usize];
+        let x = (ccx + rng.normal() * spread).clamp(min_x, max_x);
+        let y = (ccy + rng.normal() * spread).clamp(min_y, max_y);
+        points.push(x as f32);
+        points.push(y as f32);
+    }
+    points
+}
+
+// 极坐标顶点序列转为扁平坐标数组
+fn polar_to_flat(cx: f64, cy: f64, polar: &[(f64, f64)]) -> Vec<f32> {
+    let mut polygon = Vec::with_capacity(polar.len() * 2);
+    for &(theta, r) in polar {
+        push_polar(&mut polygon, cx, cy, theta, r);
+    }
+    polygon
+}
+
+fn push_polar(polygon: &mut Vec<f32>, cx: f64, cy: f64, theta: f64, r: f64) {
+    polygon.push((cx + r * theta.cos()) as f32);
+    polygon.push((cy + r * theta.sin()) as f32);
+}
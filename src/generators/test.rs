@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::{
+        clustered_points, fractal_coastline, random_simple_polygon, spiral_polygon, star_polygon,
+        uniform_points,
+    };
+    use crate::points_in_polygon::certified::point_in_polygon_certified;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    // 两条线段是否相交（含端点接触）
+    fn segments_intersect(a: [f64; 4], b: [f64; 4]) -> bool {
+        let orient = |ax: f64, ay: f64, bx: f64, by: f64, px: f64, py: f64| {
+            ((bx - ax) * (py - ay) - (by - ay) * (px - ax)).signum()
+        };
+        let d1 = orient(a[0], a[1], a[2], a[3], b[0], b[1]);
+        let d2 = orient(a[0], a[1], a[2], a[3], b[2], b[3]);
+        let d3 = orient(b[0], b[1], b[2], b[3], a[0], a[1]);
+        let d4 = orient(b[0], b[1], b[2], b[3], a[2], a[3]);
+        d1 * d2 <= 0.0 && d3 * d4 <= 0.0
+    }
+
+    // 暴力检查单环多边形是否简单：不相邻的边两两不相交
+    fn is_simple(polygon: &[f32]) -> bool {
+        let n = polygon.len() / 2;
+        let edge = |i: usize| {
+            let j = (i + 1) % n;
+            [
+                polygon[i * 2] as f64,
+                polygon[i * 2 + 1] as f64,
+                polygon[j * 2] as f64,
+                polygon[j * 2 + 1] as f64,
+            ]
+        };
+        for i in 0..n {
+            for j in i + 2..n {
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+                if segments_intersect(edge(i), edge(j)) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn test_generators_are_seeded_and_simple() {
+        // 相同种子结果一致，不同种子结果不同
+        assert_eq!(random_simple_polygon(0.0, 0.0, 10.0, 50, 7), random_simple_polygon(0.0, 0.0, 10.0, 50, 7));
+        assert_ne!(random_simple_polygon(0.0, 0.0, 10.0, 50, 7), random_simple_polygon(0.0, 0.0, 10.0, 50, 8));
+        assert_eq!(fractal_coastline(0.0, 0.0, 10.0, 6, 0.6, 3), fractal_coastline(0.0, 0.0, 10.0, 6, 0.6, 3));
+
+        let polygons = vec![
+            random_simple_polygon(0.0, 0.0, 10.0, 60, 1),
+            star_polygon(0.0, 0.0, 10.0, 4.0, 12),
+            spiral_polygon(0.0, 0.0, 10.0, 3.0, 24),
+            fractal_coastline(0.0, 0.0, 10.0, 6, 0.6, 2),
+        ];
+        for polygon in &polygons {
+            assert!(polygon.len() >= 6);
+            assert!(is_simple(polygon));
+        }
+        assert_eq!(fractal_coastline(0.0, 0.0, 10.0, 6, 0.6, 2).len(), 4 * 64 * 2);
+    }
+
+    #[test]
+    fn test_point_clouds_stay_in_bounds() {
+        let uniform = uniform_points(1000, -5.0, 2.0, 5.0, 8.0, 11);
+        let clustered = clustered_points(1000, 4, -5.0, 2.0, 5.0, 8.0, 3.0, 11);
+        for points in [&uniform, &clustered] {
+            assert_eq!(points.len(), 2000);
+            for p in points.chunks(2) {
+                assert!((-5.0..=5.0).contains(&p[0]) && (2.0..=8.0).contains(&p[1]));
+            }
+        }
+        assert_eq!(clustered, clustered_points(1000, 4, -5.0, 2.0, 5.0, 8.0, 3.0, 11));
+    }
+
+    #[test]
+    fn test_generated_cases_agree_across_algorithms() {
+        // 用生成的数据对比扫描线算法与可证明正确的算法
+        let points = uniform_points(2000, -11.0, -11.0, 11.0, 11.0, 5);
+        for polygon in [
+            star_polygon(0.0, 0.0, 10.0, 3.0, 9),
+            spiral_polygon(0.0, 0.0, 10.0, 2.5, 32),
+            fractal_coastline(0.0, 0.0, 9.0, 5, 0.5, 9),
+        ] {
+            let rings = vec![(polygon.len() / 2) as u32];
+            let scanline = point_in_polygon_scanline(&points, &polygon, &rings, true);
+            let certified: Vec<u32> = point_in_polygon_certified(&points, &polygon, &rings)
+                .into_iter()
+                .map(|label| (label != 0) as u32)
+                .collect();
+            assert_eq!(scanline, certified);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+// 地理围栏模块：注册多个具名区域，按批次输入移动对象的观测，在wasm内部维护每个对象相对每个围栏的状态，
+// 输出进入/离开/停留事件；跨批次调用时状态保持，js端只需处理事件
+
+// 输入(js端):
+//     1. 围栏: add_polygon(name, polygon, rings) / add_circle(name, cx, cy, r) / add_region(name, region)
+//        返回围栏索引，同名围栏再次注册时替换原区域并沿用原索引
+//     2. 观测: update(object_ids, points, timestamps)
+//        object_ids 类型Uint32Array, points 类型Float32Array [x1, y1, x2, y2, ...], timestamps 类型Float64Array
+//        三者按下标一一对应，同一对象的观测应按时间顺序给出
+// 输出(js端):
+//     1. 事件 类型Float64Array 每个事件占4个值 [对象id, 围栏索引, 事件类型, 时间]
+//        事件类型 0进入, 1离开, 2停留（连续在内部达到停留时长时触发一次）
+// 说明: 对象第一次出现且在围栏内部时触发进入；观测点落在围栏边界上时保持原状态不变
+//     set_hysteresis(enter_margin, exit_margin) 设置迟滞阈值：距边界至少enter_margin深入内部才触发进入，
+//     至少exit_margin远离到外部才触发离开，两者之间保持原状态，用于抑制GPS噪声在边界附近造成的反复进出。
+//     组合区域（并/交/差）的边界距离取各图元边界距离的最小值，是真实距离的下界，迟滞效果只会更保守
+//     dwell_summary(window_start, window_end) 在wasm内按事件流汇总时间窗口内每个对象在每个围栏的停留时长和停留次数，
+//     输出 类型Float64Array 每项占4个值 [对象id, 围栏索引, 停留时长, 停留次数]，按对象id、围栏索引排序；
+//     尚未离开的停留计到该对象最近一次观测的时间
+
+use alloc::{string::String, string::ToString, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use hashbrown::HashMap;
+use crate::points_in_polygon::Location;
+use crate::region::{Region, Shape};
+
+pub mod test;
+
+// 事件类型
+pub(crate) const EVENT_ENTER: f64 = 0.0;
+pub(crate) const EVENT_EXIT: f64 = 1.0;
+pub(crate) const EVENT_DWELL: f64 = 2.0;
+
+// 具名围栏
+struct Fence {
+    name: String,
+    region: Region,
+}
+
+// 对象相对某个围栏的状态
+#[derive(Clone, Copy, Default)]
+struct FenceState {
+    inside: bool,
+    entered_at: f64,      // 最近一次进入的时间
+    dwell_reported: bool, // 本次停留是否已触发停留事件
+}
+
+// 对象状态：最近一次观测时间以及相对各围栏的状态（按围栏索引存放，新增围栏时惰性扩展）
+#[derive(Default)]
+struct ObjectState {
+    last_seen: f64,
+    fences: Vec<FenceState>,
+}
+
+// 地理围栏管理器
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct GeofenceManager {
+    fences: Vec<Fence>,
+    dwell_time: f64,
+    enter_margin: f64, // 进入所需的最小边界距离
+    exit_margin: f64,  // 离开所需的最小边界距离
+    objects: HashMap<u32, ObjectState>,
+    // (对象id, 围栏索引) -> 已结束的停留 [(进入时间, 离开时间)]
+    history: HashMap<(u32, usize), Vec<(f64, f64)>>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl GeofenceManager {
+    // dwell_time: 触发停留事件所需的连续停留时长，不大于0时不产生停留事件
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(dwell_time: f64) -> GeofenceManager {
+        GeofenceManager {
+            fences: Vec::new(),
+            dwell_time,
+            enter_margin: 0.0,
+            exit_margin: 0.0,
+            objects: HashMap::new(),
+            history: HashMap::new(),
+        }
+    }
+
+    // 设置进入/离开的迟滞阈值，单位与坐标相同，负值按0处理
+    pub fn set_hysteresis(&mut self, enter_margin: f64, exit_margin: f64) {
+        self.enter_margin = enter_margin.max(0.0);
+        self.exit_margin = exit_margin.max(0.0);
+    }
+
+    // 注册多边形围栏，参数与point_in_polygon_scanline相同
+    pub fn add_polygon(&mut self, name: &str, polygon: &[f32], rings: &[u32]) -> usize {
+        self.add_region(name, &Region::polygon(polygon, rings))
+    }
+
+    // 注册圆形围栏
+    pub fn add_circle(&mut self, name: &str, cx: f64, cy: f64, r: f64) -> usize {
+        self.add_region(name, &Region::circle(cx, cy, r))
+    }
+
+    // 注册任意区域树作为围栏
+    pub fn add_region(&mut self, name: &str, region: &Region) -> usize {
+        if let Some(index) = self.fences.iter().position(|f| f.name == name) {
+            self.fences[index].region = region.clone();
+            return index;
+        }
+        self.fences.push(Fence { name: name.to_string(), region: region.clone() });
+        self.fences.len() - 1
+    }
+
+    // 围栏数量
+    pub fn fence_count(&self) -> usize {
+        self.fences.len()
+    }
+
+    // 按索引查询围栏名，索引无效时返回undefined
+    pub fn fence_name(&self, index: usize) -> Option<String> {
+        self.fences.get(index).map(|f| f.name.clone())
+    }
+
+    // 按名称查询围栏索引，不存在时返回-1
+    pub fn fence_index(&self, name: &str) -> i32 {
+        self.fences.iter().position(|f| f.name == name).map_or(-1, |i| i as i32)
+    }
+
+    // 输入一批观测，返回这批观测触发的事件
+    pub fn update(&mut self, object_ids: &[u32], points: &[f32], timestamps: &[f64]) -> Vec<f64> {
+        let count = object_ids.len().min(points.len() / 2).min(timestamps.len());
+        let fence_count = self.fences.len();
+        let mut events = Vec::new();
+
+        for i in 0..count {
+            let (id, time) = (object_ids[i], timestamps[i]);
+            let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+            let object = self.objects.entry(id).or_default();
+            object.last_seen = time;
+            object.fences.resize(fence_count, FenceState::default());
+
+            for (fence_idx, (fence, state)) in self.fences.iter().zip(object.fences.iter_mut()).enumerate() {
+                // 只有到达另一侧且离边界足够远时才改变状态
+                let inside = match fence.region.locate(x, y) {
+                    Location::Inside if !state.inside => {
+                        self.enter_margin <= 0.0 || fence.region.boundary_distance(x, y) >= self.enter_margin
+                    }
+                    Location::Outside if state.inside => {
+                        self.exit_margin > 0.0 && fence.region.boundary_distance(x, y) < self.exit_margin
+                    }
+                    _ => state.inside,
+                };
+
+                if inside && !state.inside {
+                    *state = FenceState { inside: true, entered_at: time, dwell_reported: false };
+                    events.extend_from_slice(&[id as f64, fence_idx as f64, EVENT_ENTER, time]);
+                } else if !inside && state.inside {
+                    state.inside = false;
+                    self.history.entry((id, fence_idx)).or_default().push((state.entered_at, time));
+                    events.extend_from_slice(&[id as f64, fence_idx as f64, EVENT_EXIT, time]);
+                }
+
+                if state.inside
+                    && !state.dwell_reported
+                    && self.dwell_time > 0.0
+                    && time - state.entered_at >= self.dwell_time
+                {
+                    state.dwell_reported = true;
+                    events.extend_from_slice(&[id as f64, fence_idx as f64, EVENT_DWELL, time]);
+                }
+            }
+        }
+
+        events
+    }
+
+    // 查询对象当前是否在围栏内部
+    pub fn is_inside(&self, object_id: u32, fence_index: usize) -> bool {
+        self.objects
+            .get(&object_id)
+            .and_then(|object| object.fences.get(fence_index))
+            .is_some_and(|state| state.inside)
+    }
+
+    // 清除某个对象的状态，之后该对象再次出现时按第一次出现处理；尚未离开的停留在最近一次观测时结束
+    pub fn remove_object(&mut self, object_id: u32) {
+        if let Some(object) = self.objects.remove(&object_id) {
+            for (fence_idx, state) in object.fences.iter().enumerate() {
+                if state.inside {
+                    self.history.entry((object_id, fence_idx)).or_default().push((state.entered_at, object.last_seen));
+                }
+            }
+        }
+    }
+
+    // 汇总时间窗口 [window_start, window_end] 内的停留时长和与窗口有交集的停留次数
+    pub fn dwell_summary(&self, window_start: f64, window_end: f64) -> Vec<f64> {
+        // (对象id, 围栏索引) -> (停留时长, 停留次数)
+        let mut totals: HashMap<(u32, usize), (f64, u32)> = HashMap::new();
+        let mut add = |key: (u32, usize), start: f64, end: f64| {
+            let (clipped_start, clipped_end) = (start.max(window_start), end.min(window_end));
+            // 与窗口有正长度的重叠；刚进入、时长为0的停留落在窗口内时也计入次数
+            if clipped_end > clipped_start || (start == end && clipped_start == clipped_end) {
+                let entry = totals.entry(key).or_insert((0.0, 0));
+                entry.0 += clipped_end - clipped_start;
+                entry.1 += 1;
+            }
+        };
+
+        for (&key, visits) in &self.history {
+            for &(start, end) in visits {
+                add(key, start, end);
+            }
+        }
+        for (&id, object) in &self.objects {
+            for (fence_idx, state) in object.fences.iter().enumerate() {
+                if state.inside {
+                    add((id, fence_idx), state.entered_at, object.last_seen);
+                }
+            }
+        }
+
+        let mut keys: Vec<(u32, usize)> = totals.keys().copied().collect();
+        keys.sort_unstable();
+        let mut result = Vec::with_capacity(keys.len() * 4);
+        for key in keys {
+            let (duration, count) = totals[&key];
+            result.extend_from_slice(&[key.0 as f64, key.1 as f64, duration, count as f64]);
+        }
+        result
+    }
+
+    // 丢弃在before之前已结束的停留记录，控制长时间运行时的内存占用
+    pub fn prune_history(&mut self, before: f64) {
+        self.history.retain(|_, visits| {
+            visits.retain(|&(_, end)| end >= before);
+            !visits.is_empty()
+        });
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use crate::geofence::GeofenceManager;
+
+    #[test]
+    fn test_enter_exit_dwell_events_across_batches() {
+        let mut manager = GeofenceManager::new(30.0);
+        let square = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+        assert_eq!(manager.add_polygon("depot", &square, &[4]), 0);
+        assert_eq!(manager.add_circle("gate", 20.0, 5.0, 3.0), 1);
+        assert_eq!(manager.fence_index("gate"), 1);
+        assert_eq!(manager.fence_index("missing"), -1);
+        assert_eq!(manager.fence_name(0).as_deref(), Some("depot"));
+
+        // 第一批：对象7从外部进入depot，对象8第一次出现就在gate内
+        let events = manager.update(&[7, 8, 7], &[-5.0, 5.0, 20.0, 5.0, 5.0, 5.0], &[0.0, 0.0, 10.0]);
+        assert_eq!(events, vec![8.0, 1.0, 0.0, 0.0, 7.0, 0.0, 0.0, 10.0]);
+
+        // 第二批：对象7走到边界上保持在内部，随后停留满30触发停留事件，再移到gate
+        let events = manager.update(&[7, 7, 7], &[10.0, 5.0, 6.0, 5.0, 19.0, 5.0], &[20.0, 40.0, 50.0]);
+        assert_eq!(
+            events,
+            vec![
+                7.0, 0.0, 2.0, 40.0, // depot停留
+                7.0, 0.0, 1.0, 50.0, // 离开depot
+                7.0, 1.0, 0.0, 50.0, // 进入gate
+            ]
+        );
+        assert!(manager.is_inside(7, 1));
+        assert!(!manager.is_inside(7, 0));
+
+        // 同名注册替换区域并沿用索引，对象状态保留：对象8不在新的gate内，下一次观测时离开
+        assert_eq!(manager.add_circle("gate", 100.0, 100.0, 1.0), 1);
+        assert_eq!(manager.update(&[8], &[20.0, 5.0], &[60.0]), vec![8.0, 1.0, 1.0, 60.0]);
+
+        // 清除状态后对象重新按第一次出现处理
+        manager.remove_object(7);
+        assert_eq!(manager.update(&[7], &[5.0, 5.0], &[70.0]), vec![7.0, 0.0, 0.0, 70.0]);
+    }
+
+    #[test]
+    fn test_hysteresis_suppresses_flapping() {
+        let mut manager = GeofenceManager::new(0.0);
+        let square = vec![0.0, 0.0, 100.0, 0.0, 100.0, 100.0, 0.0, 100.0];
+        manager.add_polygon("zone", &square, &[4]);
+        manager.set_hysteresis(10.0, 10.0);
+
+        // 在x=0的边界两侧5以内抖动：不产生事件
+        let xs = [-3.0, 4.0, -2.0, 5.0, -4.0];
+        let points: Vec<f32> = xs.iter().flat_map(|&x| [x, 50.0]).collect();
+        let events = manager.update(&[1; 5], &points, &[0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert!(events.is_empty());
+
+        // 深入内部12才进入，回到边界附近不离开，远离到外部11才离开
+        let points = vec![12.0, 50.0, 2.0, 50.0, -5.0, 50.0, -11.0, 50.0];
+        let events = manager.update(&[1; 4], &points, &[5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(events, vec![1.0, 0.0, 0.0, 5.0, 1.0, 0.0, 1.0, 8.0]);
+
+        // 圆形围栏同样适用
+        let mut manager = GeofenceManager::new(0.0);
+        manager.add_circle("ring", 0.0, 0.0, 50.0);
+        manager.set_hysteresis(10.0, 10.0);
+        let points = vec![45.0, 0.0, 39.0, 0.0, 55.0, 0.0, 61.0, 0.0];
+        let events = manager.update(&[2; 4], &points, &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(events, vec![2.0, 0.0, 0.0, 1.0, 2.0, 0.0, 1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_dwell_summary_within_window() {
+        let mut manager = GeofenceManager::new(0.0);
+        let square = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+        manager.add_polygon("a", &square, &[4]);
+        manager.add_circle("b", 30.0, 5.0, 5.0);
+
+        // 对象1: 在a内停留 [0, 20]、[40, 50]，之后进入b并停留到最近一次观测 t=80
+        let ids = [1; 6];
+        let points = vec![5.0, 5.0, 20.0, 20.0, 5.0, 5.0, 20.0, 20.0, 30.0, 5.0, 31.0, 5.0];
+        manager.update(&ids, &points, &[0.0, 20.0, 40.0, 50.0, 60.0, 80.0]);
+        // 对象2: 在b内 [10, 30]
+        manager.update(&[2, 2], &[30.0, 5.0, 50.0, 50.0], &[10.0, 30.0]);
+
+        assert_eq!(
+            manager.dwell_summary(0.0, 100.0),
+            vec![
+                1.0, 0.0, 30.0, 2.0, //
+                1.0, 1.0, 20.0, 1.0, //
+                2.0, 1.0, 20.0, 1.0, //
+            ]
+        );
+        // 窗口裁剪：[15, 45]
+        assert_eq!(
+            manager.dwell_summary(15.0, 45.0),
+            vec![1.0, 0.0, 10.0, 2.0, 2.0, 1.0, 15.0, 1.0]
+        );
+
+        // 丢弃t=25之前结束的记录
+        manager.prune_history(25.0);
+        assert_eq!(
+            manager.dwell_summary(0.0, 100.0),
+            vec![1.0, 0.0, 10.0, 1.0, 1.0, 1.0, 20.0, 1.0, 2.0, 1.0, 20.0, 1.0]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+// 空间连接模块：对一组多边形（例如成千上万个规划分区），一次调用给出每个点所在多边形的序号，
+// 多边形的边界框用STR（Sort-Tile-Recursive）方式打包成R树，每个点只判断边界框包含该点的多边形
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形组 类型Float32Array, 多边形拆分 类型Uint32Array, 环拆分 类型Uint32Array 含义与coverage模块一致
+//     3. 选择方式 smallest boolean：false时取序号最小的包含该点的多边形，true时取面积最小的（嵌套分区中最内层的），面积相同时取序号小的
+//     4. 边界上点是否考虑为内部 boolean
+// 输出(js端):
+//     1. 类型Int32Array 每个点一个值，为所在多边形的序号，不在任何多边形内时为-1
+// 说明: 多边形的序号按多边形拆分的顺序，与coverage、region_membership相同；面积为外环面积减去洞的面积。
+//     R树按容差扩展边界框，边界上的点不会因边界框过滤而漏判
+
+use alloc::{vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::coverage::split_polygons;
+use crate::points_in_polygon::common::Bounds;
+use crate::points_in_polygon::scanline::ScanlineIndex;
+use crate::points_in_polygon::Location;
+
+pub mod test;
+
+// R树每个节点最多的子节点数
+const NODE_CAPACITY: usize = 16;
+
+// WebAssembly导出函数：每个点所在多边形的序号
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn points_in_polygons(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygons: &[f32],         // 所有多边形顶点依次拼接
+    polygon_splits: &[u32],   // 第2个及之后每个多边形的起始顶点索引
+    rings: &[u32],            // 洞的起始顶点索引（全局）
+    smallest: bool,           // 是否取面积最小的多边形（否则取序号最小的）
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<i32> {
+    let indexes: Vec<ScanlineIndex> = split_polygons(polygons, polygon_splits, rings)
+        .iter()
+        .map(|(polygon, local_rings)| ScanlineIndex::new(polygon, local_rings))
+        .collect();
+    let entries = indexes
+        .iter()
+        .map(|index| {
+            let (min_x, min_y, max_x, max_y) = index.bounds();
+            let tolerance = index.polygon().tolerance;
+            Bounds { min_x: min_x - tolerance, min_y: min_y - tolerance, max_x: max_x + tolerance, max_y: max_y + tolerance }
+        })
+        .collect();
+    let tree = RTree::new(entries);
+    let areas: Vec<f64> = if smallest { indexes.iter().map(polygon_area).collect() } else { Vec::new() };
+
+    let mut candidates = Vec::new();
+    points
+        .chunks_exact(2)
+        .map(|point| {
+            let (x, y) = (point[0] as f64, point[1] as f64);
+            tree.query(x, y, &mut candidates);
+            candidates.sort_unstable();
+            let contains = |&k: &usize| match indexes[k].locate(x, y) {
+                Location::Inside => true,
+                Location::Boundary => boundary_is_inside,
+                Location::Outside => false,
+            };
+            let found = if smallest {
+                // 按面积升序、序号升序比较，min_by返回第一个最小值，即面积相同时序号最小的
+                candidates.iter().filter(|k| contains(k)).min_by(|&&a, &&b| areas[a].total_cmp(&areas[b]))
+            } else {
+                candidates.iter().find(|k| contains(k))
+            };
+            found.map_or(-1, |&k| k as i32)
+        })
+        .collect()
+}
+
+// 多边形面积：外环面积之和减去洞的面积之和，只作为边界的退化环不计入
+fn polygon_area(index: &ScanlineIndex) -> f64 {
+    let poly = index.polygon();
+    poly.rings
+        .iter()
+        .filter(|ring| !ring.boundary_only)
+        .map(|ring| {
+            let twice = poly.edges[ring.start_idx..ring.start_idx + ring.edge_count]
+                .iter()
+                .map(|e| e.x1 * e.y2 - e.x2 * e.y1)
+                .sum::<f64>();
+            let area = twice.abs() / 2.0;
+            if ring.is_hole { -area } else { area }
+        })
+        .sum()
+}
+
+// R树节点：第0层节点的子节点为entries[start..start + count]，其他层为下一层的nodes[start..start + count]
+struct Node {
+    bounds: Bounds,
+    start: usize,
+    count: usize,
+}
+
+// STR打包的静态R树：叶层的条目按STR顺序排列，每层都按STR重新排列后每NODE_CAPACITY个打包为上一层的一个节点，直到只剩根节点
+pub(crate) struct RTree {
+    entries: Vec<(Bounds, usize)>, // (边界框, 条目序号)
+    levels: Vec<Vec<Node>>,        // levels[0]为叶节点层，最后一层只有根节点
+}
+
+impl RTree {
+    // 条目序号为其在bounds中的位置
+    pub(crate) fn new(bounds: Vec<Bounds>) -> RTree {
+        let mut entries: Vec<(Bounds, usize)> = bounds.into_iter().enumerate().map(|(k, b)| (b, k)).collect();
+        let mut levels = Vec::new();
+        if entries.is_empty() {
+            return RTree { entries, levels };
+        }
+
+        str_sort(&mut entries, |entry| &entry.0);
+        let mut level = pack(entries.iter().map(|entry| entry.0), entries.len());
+        while level.len() > 1 {
+            str_sort(&mut level, |node| &node.bounds);
+            let parents = pack(level.iter().map(|node| node.bounds), level.len());
+            levels.push(level);
+            level = parents;
+        }
+        levels.push(level);
+        RTree { entries, levels }
+    }
+
+    // 边界框包含点(x, y)的条目序号，写入out（先清空，顺序不定）
+    pub(crate) fn query(&self, x: f64, y: f64, out: &mut Vec<usize>) {
+        out.clear();
+        if self.levels.is_empty() {
+            return;
+        }
+        let mut stack = vec![(self.levels.len() - 1, 0)];
+        while let Some((level, i)) = stack.pop() {
+            let node = &self.levels[level][i];
+            if !node.bounds.contains(x, y, 0.0) {
+                continue;
+            }
+            let range = node.start..node.start + node.count;
+            if level == 0 {
+                out.extend(self.entries[range].iter().filter(|(b, _)| b.contains(x, y, 0.0)).map(|&(_, k)| k));
+            } else {
+                stack.extend(range.map(|j| (level - 1, j)));
+            }
+        }
+    }
+}
+
+// 把已排好序的n个边界框每NODE_CAPACITY个打包为一个节点
+fn pack(bounds: impl Iterator<Item = Bounds>, n: usize) -> Vec<Node> {
+    let mut nodes: Vec<Node> = Vec::with_capacity(n.div_ceil(NODE_CAPACITY));
+    for (i, b) in bounds.enumerate() {
+        if i % NODE_CAPACITY == 0 {
+            nodes.push(Node { bounds: b, start: i, count: 1 });
+        } else {
+            let node = nodes.last_mut().unwrap();
+            node.bounds.extend(&b);
+            node.count += 1;
+        }
+    }
+    nodes
+}
+
+// STR排序：按中心x排序后切成 ceil(sqrt(节点数)) 个竖条，每个竖条内按中心y排序，连续的NODE_CAPACITY个即为一个节点
+fn str_sort<T>(items: &mut [T], bounds: impl Fn(&T) -> &Bounds) {
+    let center_x = |b: &Bounds| b.min_x + b.max_x;
+    let center_y = |b: &Bounds| b.min_y + b.max_y;
+    items.sort_by(|a, b| center_x(bounds(a)).total_cmp(&center_x(bounds(b))));
+    let node_count = items.len().div_ceil(NODE_CAPACITY);
+    let slabs = (node_count as f64).sqrt().ceil() as usize;
+    let slab_size = slabs * NODE_CAPACITY;
+    for slab in items.chunks_mut(slab_size) {
+        slab.sort_by(|a, b| center_y(bounds(a)).total_cmp(&center_y(bounds(b))));
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use crate::join::points_in_polygons;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    #[test]
+    fn test_first_and_smallest() {
+        // 大矩形（带洞）、其中的小正方形、与大矩形重叠的右侧正方形
+        let polygons = vec![
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 多边形0外环
+            6.0, 6.0, 8.0, 6.0, 8.0, 8.0, 6.0, 8.0, // 多边形0的洞
+            1.0, 1.0, 3.0, 1.0, 3.0, 3.0, 1.0, 3.0, // 多边形1
+            9.0, 0.0, 12.0, 0.0, 12.0, 3.0, 9.0, 3.0, // 多边形2
+        ];
+        let splits = [8, 12];
+        let rings = [4];
+        let points = vec![
+            2.0, 2.0, // 多边形0、1
+            7.0, 7.0, // 多边形0的洞内
+            9.5, 1.0, // 多边形0、2
+            11.0, 1.0, // 只在多边形2内
+            5.0, 5.0, // 只在多边形0内
+            20.0, 20.0, // 全部多边形外
+            3.0, 2.0, // 多边形1的边上、多边形0内
+            12.0, 2.0, // 多边形2的边上
+        ];
+        assert_eq!(points_in_polygons(&points, &polygons, &splits, &rings, false, true), vec![0, -1, 0, 2, 0, -1, 0, 2]);
+        assert_eq!(points_in_polygons(&points, &polygons, &splits, &rings, true, true), vec![1, -1, 2, 2, 0, -1, 1, 2]);
+        assert_eq!(points_in_polygons(&points, &polygons, &splits, &rings, true, false), vec![1, -1, 2, 2, 0, -1, 0, -1]);
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        // 40×40个互相重叠的菱形，R树有多层
+        let mut polygons = Vec::new();
+        let mut splits = Vec::new();
+        for i in 0..40 {
+            for j in 0..40 {
+                if !polygons.is_empty() {
+                    splits.push((polygons.len() / 2) as u32);
+                }
+                let (cx, cy, r) = (i as f32 * 2.0, j as f32 * 2.0, 1.5 + (i + j) as f32 % 3.0 * 0.5);
+                polygons.extend_from_slice(&[cx - r, cy, cx, cy - r, cx + r, cy, cx, cy + r]);
+            }
+        }
+        let mut points = Vec::new();
+        for k in 0..4000 {
+            points.push((k * 37 % 1000) as f32 * 0.083 - 1.0);
+            points.push((k * 91 % 1000) as f32 * 0.081 - 1.0);
+        }
+        // 顶点上的点（边界）
+        points.extend_from_slice(&[0.0, 1.5, 2.0, 0.0]);
+
+        let first = points_in_polygons(&points, &polygons, &splits, &[], false, true);
+        let smallest = points_in_polygons(&points, &polygons, &splits, &[], true, true);
+        let labels: Vec<Vec<u32>> = (0..1600)
+            .map(|k| point_in_polygon_scanline(&points, &polygons[k * 8..k * 8 + 8], &[4], true))
+            .collect();
+        let area = |k: usize| {
+            let r = polygons[k * 8 + 4] - polygons[k * 8 + 2];
+            2.0 * r * r
+        };
+        for p in 0..points.len() / 2 {
+            let containing: Vec<usize> = (0..1600).filter(|&k| labels[k][p] == 1).collect();
+            assert_eq!(first[p], containing.first().map_or(-1, |&k| k as i32), "point {}", p);
+            let expected = containing.iter().copied().min_by(|&a, &b| area(a).total_cmp(&area(b)));
+            assert_eq!(smallest[p], expected.map_or(-1, |k| k as i32), "point {}", p);
+        }
+    }
+
+    #[test]
+    fn test_empty_inputs() {
+        assert_eq!(points_in_polygons(&[1.0, 1.0], &[], &[], &[], false, true), vec![-1]);
+        assert!(points_in_polygons(&[], &[0.0, 0.0, 1.0, 0.0, 1.0, 1.0], &[], &[], true, true).is_empty());
+    }
+
+    #[test]
+    fn test_empty_polygon_keeps_indices() {
+        // 中间的多边形没有顶点，第三个多边形的序号仍是2
+        let polygons = vec![
+            0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0, // 多边形0
+            5.0, 0.0, 7.0, 0.0, 7.0, 2.0, 5.0, 2.0, // 多边形2
+        ];
+        let splits = [4, 4];
+        let points = vec![1.0, 1.0, 6.0, 1.0, 9.0, 9.0];
+        assert_eq!(points_in_polygons(&points, &polygons, &splits, &[], false, true), vec![0, 2, -1]);
+        assert_eq!(points_in_polygons(&points, &polygons, &splits, &[], true, true), vec![0, 2, -1]);
+    }
+}
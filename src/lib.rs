@@ -1,6 +1,141 @@
+// 关闭std特性时为no_std（只依赖core和alloc）；测试总是链接标准库
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+// 导入 float 模块（no_std构建中由libm提供的浮点函数）
+mod float;
+// 导入 export 模块（导出函数的错误类型）
+mod export;
 // 导入 points_in_polygon 模块
 pub mod points_in_polygon;
+// 导入 boundary 模块
+pub mod boundary;
+// 导入 region 模块
+pub mod region;
+// 导入 generators 模块
+pub mod generators;
+// 导入 trajectory 模块
+pub mod trajectory;
+// 导入 geofence 模块
+pub mod geofence;
+// 导入 coverage 模块
+pub mod coverage;
+// 导入 memory 模块
+pub mod memory;
+// 导入 packed 模块
+pub mod packed;
+// 导入 frames 模块
+pub mod frames;
+// 导入 partition 模块
+pub mod partition;
+// 导入 zonal 模块
+pub mod zonal;
+// 导入 raycast 模块
+pub mod raycast;
+// 导入 clip 模块
+pub mod clip;
+// 导入 fillet 模块
+pub mod fillet;
+// 导入 thinning 模块
+pub mod thinning;
+// 导入 transform 模块
+pub mod transform;
+// 导入 axes 模块
+pub mod axes;
+// 导入 membership 模块
+pub mod membership;
+// 导入 density 模块
+pub mod density;
+// 导入 outline 模块
+pub mod outline;
+// 导入 diff 模块
+pub mod diff;
+// 导入 tiles 模块
+pub mod tiles;
+// 导入 threads 模块
+pub mod threads;
+// 导入 formats 模块
+pub mod formats;
+// 导入 join 模块
+pub mod join;
 
 // 重新导出 points_in_polygon 模块中的函数，使其可以从 JavaScript 调用
-// pub use points_in_polygon::rayster::point_in_polygon_rayster;
-pub use points_in_polygon::scanline::point_in_polygon_scanline;
\ No newline at end of file
+pub use points_in_polygon::rayster::{point_in_polygon_rayster, point_in_polygon_rayster_f64, point_in_polygon_rayster_with_options};
+pub use points_in_polygon::scanline::{grid_points_in_polygon, point_in_polygon_scanline, point_in_polygon_scanline_f64, point_in_polygon_scanline_ternary, point_in_polygon_strided, ring_crossing_counts, ring_crossing_counts_with_options};
+pub use points_in_polygon::options::{point_in_polygon_f64, point_in_polygon_with_confidence, point_in_polygon_with_options, point_in_polygon_with_ring_boundaries, PipOptions};
+pub use points_in_polygon::validation::{validate_polygon, ValidationReport};
+pub use points_in_polygon::lod::LodPolygon;
+pub use points_in_polygon::approximate::{ApproximateClassifier, ApproximateResult};
+pub use points_in_polygon::certified::point_in_polygon_certified;
+pub use points_in_polygon::integer::point_in_polygon_i64;
+pub use points_in_polygon::quantized::{point_in_polygon_quantized_f32, point_in_polygon_quantized_i32, Quantization};
+pub use points_in_polygon::sampling::estimate_inside_count;
+pub use points_in_polygon::attribute::{points_in_polygon_category, points_in_polygon_value_range, top_k_inside};
+pub use points_in_polygon::streaming::PipSession;
+#[cfg(feature = "wasm")]
+pub use points_in_polygon::streaming::point_in_polygon_chunked;
+pub use points_in_polygon::staging::StagingClassifier;
+pub use points_in_polygon::prepared::{prepare_polygons, PreparedPolygon, PreparedPolygons};
+pub use points_in_polygon::multipolygon::{point_in_multipolygon, point_in_multipolygon_rings};
+pub use points_in_polygon::winding::{point_in_polygon_winding, point_in_polygon_winding_with_options};
+pub use points_in_polygon::memo::ClassificationCache;
+// 重新导出 boundary 模块中的函数
+pub use boundary::{boundary_arc_lengths, boundary_point_at, boundary_ring_lengths, nearest_ring, sample_boundary};
+// 重新导出 region 模块中的图元
+pub use region::{Annulus, Circle, Region, Sector, SelectionRegion};
+// 重新导出 generators 模块中的生成器
+pub use generators::{clustered_points, fractal_coastline, random_simple_polygon, spiral_polygon, star_polygon, uniform_points};
+// 重新导出 trajectory 模块中的函数
+pub use trajectory::{trajectory_crossings, trajectory_time_inside, trajectory_visits};
+// 重新导出 geofence 模块中的管理器
+pub use geofence::GeofenceManager;
+// 重新导出 coverage 模块中的函数
+pub use coverage::coverage;
+// 重新导出 memory 模块中的函数
+pub use memory::{memory_bytes, point_in_polygon_handle, reserve, ResultHandle};
+#[cfg(feature = "wasm")]
+pub use memory::wasm_memory;
+// 重新导出 packed 模块中的函数
+pub use packed::run_packed;
+// 重新导出 frames 模块中的函数
+pub use frames::classify_over_frames;
+// 重新导出 partition 模块中的函数
+pub use partition::{check_partition, PartitionReport};
+// 重新导出 zonal 模块中的函数
+pub use zonal::zonal_stats;
+// 重新导出 raycast 模块中的函数
+pub use raycast::raycast;
+// 重新导出 clip 模块中的函数
+pub use clip::clip_segments;
+// 重新导出 fillet 模块中的函数
+pub use fillet::{fillet_polygon, FilletedPolygon};
+// 重新导出 thinning 模块中的函数
+pub use thinning::thin_points;
+// 重新导出 transform 模块中的函数
+pub use transform::transform_polygon;
+// 重新导出 axes 模块中的函数
+pub use axes::selection_axes;
+// 重新导出 membership 模块中的函数
+pub use membership::region_membership;
+// 重新导出 density 模块中的函数
+pub use density::{density_region, DensityRegion};
+// 重新导出 outline 模块中的函数
+pub use outline::{lattice_outline, LatticeOutline};
+// 重新导出 diff 模块中的函数
+pub use diff::{polygon_diff, PolygonDiff};
+// 重新导出 tiles 模块中的函数
+pub use tiles::{tile_coverage, TileCoverage};
+// 重新导出 threads 模块中的函数
+pub use threads::threads_ready;
+#[cfg(all(rayon_backend, target_arch = "wasm32"))]
+pub use threads::init_threads;
+// 重新导出 formats 模块中的函数
+pub use formats::ParsedGeometry;
+pub use formats::geojson::{parse_geojson, point_in_polygon_geojson};
+pub use formats::wkt::{parse_wkt, point_in_polygon_wkt};
+pub use formats::wkb::{parse_wkb, point_in_polygon_wkb};
+// 导出函数的错误类型
+pub use export::ExportError;
+// 重新导出 join 模块中的函数
+pub use join::points_in_polygons;
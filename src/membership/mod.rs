@@ -0,0 +1,63 @@
+// 成员位集模块：对至多64个可能互相重叠的区域，一次遍历点云给出每个点所属区域的位集，
+// 用于洪水淹没范围 × 规划分区 × 行政区等多图层叠加分析，js端按位运算即可得到任意组合
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形组 类型Float32Array, 多边形拆分 类型Uint32Array, 环拆分 类型Uint32Array 含义与coverage模块一致
+//     3. 边界上点是否考虑为内部 boolean
+// 输出(js端):
+//     1. 类型BigUint64Array 每个点一个值，第k位为1表示该点在第k个多边形内
+// 说明: 所有多边形共享一个粗网格（与coverage相同），每个点只判断边界框覆盖该点的多边形；多边形超过64个时报错
+
+use alloc::{format, string::String, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::coverage::{split_polygons, SharedGrid};
+use crate::points_in_polygon::scanline::ScanlineIndex;
+
+pub mod test;
+
+// 位集能表示的区域数
+const MAX_REGIONS: usize = 64;
+
+// WebAssembly导出函数：每个点所属区域的位集
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn region_membership(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygons: &[f32],         // 所有多边形顶点依次拼接
+    polygon_splits: &[u32],   // 第2个及之后每个多边形的起始顶点索引
+    rings: &[u32],            // 洞的起始顶点索引（全局）
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Result<Vec<u64>, ExportError> {
+    membership_bits(points, polygons, polygon_splits, rings, boundary_is_inside).map_err(export_error)
+}
+
+// 位集的主流程，区域过多时返回错误信息
+pub(crate) fn membership_bits(
+    points: &[f32],
+    polygons: &[f32],
+    polygon_splits: &[u32],
+    rings: &[u32],
+    boundary_is_inside: bool,
+) -> Result<Vec<u64>, String> {
+    let indexes: Vec<ScanlineIndex> = split_polygons(polygons, polygon_splits, rings)
+        .iter()
+        .map(|(polygon, local_rings)| ScanlineIndex::new(polygon, local_rings))
+        .collect();
+    if indexes.len() > MAX_REGIONS {
+        return Err(format!("at most {} regions are supported, got {}", MAX_REGIONS, indexes.len()));
+    }
+    let grid = SharedGrid::new(&indexes);
+
+    Ok(points
+        .chunks_exact(2)
+        .map(|point| {
+            let (x, y) = (point[0] as f64, point[1] as f64);
+            grid.candidates(x, y)
+                .iter()
+                .filter(|&&region| indexes[region].locate(x, y).to_u32(boundary_is_inside) == 1)
+                .fold(0u64, |bits, &region| bits | 1 << region)
+        })
+        .collect())
+}
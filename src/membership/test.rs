@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use crate::membership::membership_bits;
+
+    #[test]
+    fn test_overlapping_layers() {
+        // 三个图层：左侧的正方形（带洞）、与之重叠的右侧正方形、覆盖两者的大矩形
+        let polygons = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // 图层0外环
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // 图层0的洞
+            3.0, 0.0, 7.0, 0.0, 7.0, 4.0, 3.0, 4.0, // 图层1
+            -1.0, -1.0, 8.0, -1.0, 8.0, 5.0, -1.0, 5.0, // 图层2
+        ];
+        let splits = [8, 12];
+        let rings = [4];
+        let points = vec![
+            0.5, 0.5, // 图层0、2
+            1.5, 1.5, // 图层0的洞内，只在图层2
+            3.5, 2.0, // 三个图层重叠
+            6.0, 2.0, // 图层1、2
+            4.0, 2.0, // 图层0的边上、图层1内
+            9.0, 9.0, // 全部图层外
+        ];
+        assert_eq!(membership_bits(&points, &polygons, &splits, &rings, true).unwrap(), vec![0b101, 0b100, 0b111, 0b110, 0b111, 0]);
+        assert_eq!(membership_bits(&points, &polygons, &splits, &rings, false).unwrap(), vec![0b101, 0b100, 0b111, 0b110, 0b110, 0]);
+    }
+
+    #[test]
+    fn test_region_limit() {
+        // 64个相互错开的单位正方形可以表示，最后一位对应最后一个区域；65个报错
+        let square = |k: usize| {
+            let x = k as f32 * 2.0;
+            [x, 0.0, x + 1.0, 0.0, x + 1.0, 1.0, x, 1.0]
+        };
+        let polygons: Vec<f32> = (0..64).flat_map(square).collect();
+        let splits: Vec<u32> = (1..64).map(|k| k * 4).collect();
+        assert_eq!(membership_bits(&[126.5, 0.5], &polygons, &splits, &[], true).unwrap(), vec![1 << 63]);
+
+        let polygons: Vec<f32> = (0..65).flat_map(square).collect();
+        let splits: Vec<u32> = (1..65).map(|k| k * 4).collect();
+        assert!(membership_bits(&[0.5, 0.5], &polygons, &splits, &[], true).is_err());
+    }
+
+    #[test]
+    fn test_empty_region_keeps_bits() {
+        // 中间的区域没有顶点，第三个区域仍对应第2位
+        let polygons = vec![
+            0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0, // 区域0
+            5.0, 0.0, 7.0, 0.0, 7.0, 2.0, 5.0, 2.0, // 区域2
+        ];
+        let splits = [4, 4];
+        let points = vec![1.0, 1.0, 6.0, 1.0, 9.0, 9.0];
+        assert_eq!(membership_bits(&points, &polygons, &splits, &[], true).unwrap(), vec![0b001, 0b100, 0]);
+    }
+}
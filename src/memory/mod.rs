@@ -0,0 +1,112 @@
+// 内存模块：为已知规模的任务预先扩充wasm线性内存
+// wasm内存不足时分配器会逐次调用memory.grow，大任务中途多次扩容会造成明显的停顿；
+// 预先分配一块足够大的内存再释放，分配器会保留这部分空间，之后的分配直接复用，不再扩容
+
+// 输入(js端):
+//     1. 预计的点数 points_capacity
+//     2. 预计的多边形顶点数 polygon_capacity
+//     3. 结果句柄 point_in_polygon_handle(points, polygon, rings, boundary_is_inside)
+// 输出(js端):
+//     1. reserve: 预留的字节数
+//     2. memory_bytes: 当前wasm线性内存的总字节数（非wasm环境下为0）
+//     3. ResultHandle: 结果保存在wasm内存中，ptr()/len() 给出结果的字节地址和元素个数，
+//        view() 返回直接引用wasm内存的Uint32Array，不复制；用完后调用free()释放
+//        视图在wasm内存扩容后失效，需要时重新调用view()获取；wasm_memory() 返回wasm的WebAssembly.Memory
+
+use alloc::vec::Vec;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+#[cfg(feature = "wasm")]
+use js_sys::Uint32Array;
+use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+pub mod test;
+
+// 每个点需要的字节数：输入坐标 2×f32 + 结果 u32
+const BYTES_PER_POINT: usize = 12;
+// 每个多边形顶点需要的字节数：输入坐标 2×f32 + 边结构 4×f64 + 网格、缓存等索引的估计开销
+const BYTES_PER_VERTEX: usize = 72;
+
+// WebAssembly导出函数：按预计规模预留内存，返回预留的字节数
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn reserve(points_capacity: usize, polygon_capacity: usize) -> usize {
+    let bytes = points_capacity
+        .saturating_mul(BYTES_PER_POINT)
+        .saturating_add(polygon_capacity.saturating_mul(BYTES_PER_VERTEX));
+
+    // 分配后立即释放：内存已扩充，空闲块留在分配器中供后续使用
+    let mut block: Vec<u8> = Vec::new();
+    if block.try_reserve_exact(bytes).is_err() {
+        return 0;
+    }
+    drop(block);
+    bytes
+}
+
+// WebAssembly导出函数：当前wasm线性内存的总字节数
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn memory_bytes() -> usize {
+    #[cfg(target_arch = "wasm32")]
+    {
+        core::arch::wasm32::memory_size(0) * 65536
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        0
+    }
+}
+
+// WebAssembly导出函数：wasm的线性内存对象，配合ResultHandle的ptr()/len()自行构造视图
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn wasm_memory() -> JsValue {
+    wasm_bindgen::memory()
+}
+
+// 结果句柄：持有结果数组，由调用方决定何时释放
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct ResultHandle {
+    data: Vec<u32>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ResultHandle {
+    // 结果在wasm内存中的字节地址
+    pub fn ptr(&self) -> usize {
+        self.data.as_ptr() as usize
+    }
+
+    // 结果的元素个数
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    // 结果是否为空
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    // 直接引用wasm内存的视图，不复制
+    #[cfg(feature = "wasm")]
+    pub fn view(&self) -> Uint32Array {
+        // 视图的有效期由调用方负责：句柄释放或wasm内存扩容后不得再使用
+        unsafe { Uint32Array::view(&self.data) }
+    }
+}
+
+impl ResultHandle {
+    pub(crate) fn new(data: Vec<u32>) -> ResultHandle {
+        ResultHandle { data }
+    }
+}
+
+// WebAssembly导出函数：与point_in_polygon_scanline相同，但结果以句柄形式返回
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_handle(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> ResultHandle {
+    ResultHandle::new(point_in_polygon_scanline(points, polygon, rings, boundary_is_inside))
+}
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use crate::memory::{point_in_polygon_handle, reserve};
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    #[test]
+    fn test_reserve_reports_bytes() {
+        assert_eq!(reserve(1_000_000, 10_000), 1_000_000 * 12 + 10_000 * 72);
+        assert_eq!(reserve(0, 0), 0);
+        // 超出可分配范围时不预留
+        assert_eq!(reserve(usize::MAX, 0), 0);
+    }
+
+    #[test]
+    fn test_result_handle_exposes_buffer() {
+        let polygon = vec![0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0];
+        let points = vec![1.0, 1.0, 4.0, 4.0, 2.0, 2.0];
+        let handle = point_in_polygon_handle(&points, &polygon, &[4], true);
+
+        assert_eq!(handle.len(), 3);
+        assert_eq!(handle.ptr(), handle.data.as_ptr() as usize);
+        assert_eq!(handle.data, point_in_polygon_scanline(&points, &polygon, &[4], true));
+    }
+}
@@ -0,0 +1,91 @@
+// 点阵轮廓模块：从规则点阵的判断结果中提取内部与外部之间的分界线，作为闭合折线返回，
+// 便于在界面上描出实际选中的区域，js端不需要再做一遍marching squares
+
+// 输入(js端):
+//     1. 点阵判断结果 mask 类型Uint32Array 行优先排列（索引为 r*cols + c），非0表示内部，例如grid_points_in_polygon的结果
+//     2. 列数 cols, 行数 rows
+//     3. 点阵参数 x0, y0, dx, dy 与grid_points_in_polygon相同，第r行第c列的点坐标为(x0 + c*dx, y0 + r*dy)
+// 输出(js端):
+//     1. LatticeOutline
+//        coords() 全部折线的顶点 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//        starts() 每条折线的起始顶点索引 类型Uint32Array，第k条折线为从starts[k]到下一条折线起点（或末尾）之间的顶点
+// 说明: 每个点代表以它为中心、边长为dx×dy的单元，分界线沿内部单元与外部单元之间的单元边走，位于相邻两点的正中；
+//     每条折线都是闭合的，最后一个顶点与第一个顶点相同，可以直接按线带绘制；共线的顶点已合并；
+//     只在对角相接的内部单元分属不同的折线；mask比cols*rows短时报错
+
+use alloc::{format, string::String, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::density::trace_loops;
+use crate::points_in_polygon::index_u32;
+
+pub mod test;
+
+// 点阵内部区域的轮廓折线
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct LatticeOutline {
+    coords: Vec<f32>,
+    starts: Vec<u32>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl LatticeOutline {
+    pub fn coords(&self) -> Vec<f32> {
+        self.coords.clone()
+    }
+
+    pub fn starts(&self) -> Vec<u32> {
+        self.starts.clone()
+    }
+
+    // 折线的条数，没有内部点时为0
+    pub fn polyline_count(&self) -> usize {
+        self.starts.len()
+    }
+}
+
+// WebAssembly导出函数：点阵判断结果中内部区域的轮廓
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[allow(clippy::too_many_arguments)]
+pub fn lattice_outline(
+    mask: &[u32], // 点阵判断结果，行优先排列
+    cols: usize,  // 列数
+    rows: usize,  // 行数
+    x0: f64,      // 点阵第一列的x坐标
+    y0: f64,      // 点阵第一行的y坐标
+    dx: f64,      // 列间距（可为负）
+    dy: f64,      // 行间距（可为负）
+) -> Result<LatticeOutline, ExportError> {
+    lattice_outline_values(mask, cols, rows, x0, y0, dx, dy).map_err(export_error)
+}
+
+// 轮廓提取的主流程，mask长度不足时返回错误信息
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn lattice_outline_values(
+    mask: &[u32],
+    cols: usize,
+    rows: usize,
+    x0: f64,
+    y0: f64,
+    dx: f64,
+    dy: f64,
+) -> Result<LatticeOutline, String> {
+    let cell_count = cols.checked_mul(rows).ok_or_else(|| format!("lattice of {}x{} points is too large", cols, rows))?;
+    if mask.len() < cell_count {
+        return Err(format!("mask holds {} results but the lattice has {}x{} points", mask.len(), cols, rows));
+    }
+
+    let inside = |c: i64, r: i64| -> bool {
+        c >= 0 && r >= 0 && (c as usize) < cols && (r as usize) < rows && mask[r as usize * cols + c as usize] != 0
+    };
+    // 网格顶点(i, j)是第i-1、i列和第j-1、j行四个点之间的单元角点
+    let to_world = |(i, j): (i64, i64)| [(x0 + (i as f64 - 0.5) * dx) as f32, (y0 + (j as f64 - 0.5) * dy) as f32];
+
+    let mut outline = LatticeOutline { coords: Vec::new(), starts: Vec::new() };
+    for ring in trace_loops(cols, rows, &inside) {
+        outline.starts.push(index_u32(outline.coords.len() / 2)?);
+        outline.coords.extend(ring.iter().chain(ring.first()).flat_map(|&v| to_world(v)));
+    }
+    Ok(outline)
+}
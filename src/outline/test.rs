@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use crate::outline::lattice_outline_values;
+    use crate::points_in_polygon::scanline::grid_points_in_polygon;
+
+    #[test]
+    fn test_outline_of_lattice_with_hole() {
+        // 4x4点阵中间2x2为外部，外圈为内部
+        let mask: Vec<u32> = (0..16).map(|k| if [5, 6, 9, 10].contains(&k) { 0 } else { 1 }).collect();
+        let outline = lattice_outline_values(&mask, 4, 4, 0.0, 0.0, 1.0, 1.0).unwrap();
+        assert_eq!(outline.polyline_count(), 2);
+        assert_eq!(outline.starts(), vec![0, 5]);
+        let coords = outline.coords();
+        assert_eq!(&coords[..10], &[-0.5, -0.5, 3.5, -0.5, 3.5, 3.5, -0.5, 3.5, -0.5, -0.5]);
+        // 洞沿相邻点的正中，闭合
+        assert_eq!(coords.len(), 20);
+        assert!(coords[10..].iter().all(|&v| v == 0.5 || v == 2.5));
+        assert_eq!(&coords[10..12], &coords[18..20]);
+
+        assert!(lattice_outline_values(&mask[..15], 4, 4, 0.0, 0.0, 1.0, 1.0).is_err());
+        assert_eq!(lattice_outline_values(&[0; 4], 2, 2, 0.0, 0.0, 1.0, 1.0).unwrap().polyline_count(), 0);
+    }
+
+    #[test]
+    fn test_outline_of_grid_result() {
+        // 正方形[0,4]x[0,4]在0.5间距点阵上的判断结果，轮廓落在最外层内部点外侧半个间距处
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let mask = grid_points_in_polygon(-1.0, -1.0, 0.5, 0.5, 13, 13, &polygon, &[4], true).unwrap();
+        let outline = lattice_outline_values(&mask, 13, 13, -1.0, -1.0, 0.5, 0.5).unwrap();
+        assert_eq!(outline.starts(), vec![0]);
+        assert_eq!(outline.coords(), vec![-0.25, -0.25, 4.25, -0.25, 4.25, 4.25, -0.25, 4.25, -0.25, -0.25]);
+    }
+}
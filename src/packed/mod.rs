@@ -0,0 +1,155 @@
+// 打包协议模块：把一次判断任务的全部输入打包进单个ArrayBuffer，结果同样打包返回，
+// 高频调用时只需跨越一次wasm-bindgen边界，省去逐个参数的封送开销
+
+// 任务缓冲区布局（小端序，各段起始位置按4字节对齐）:
+//     偏移 0   magic          4字节 "GPIP"
+//     偏移 4   version        u16   当前为1
+//     偏移 6   header_size    u16   头部字节数，当前为32，解析时按此跳过头部，便于以后扩展
+//     偏移 8   point_count    u32   点数
+//     偏移 12  vertex_count   u32   多边形顶点数
+//     偏移 16  ring_count     u32   环拆分索引的个数
+//     偏移 20  options_size   u32   选项段字节数
+//     偏移 24  保留           8字节 填0
+//     之后依次为: 选项段、点坐标 f32×2×point_count、多边形坐标 f32×2×vertex_count、环拆分索引 u32×ring_count
+// 选项段（按options_size读取，缺少的字段取默认值）:
+//     偏移 0   flags          u32   bit0: 边界上的点是否视为内部
+//     偏移 4   output_format  u32   0: 每点一个u32（默认）, 1: 每点一个u8, 2: 位打包，每个u32存32个点
+// 结果缓冲区布局:
+//     偏移 0   magic          4字节 "GPIR"
+//     偏移 4   version        u16   1
+//     偏移 6   header_size    u16   16
+//     偏移 8   point_count    u32
+//     偏移 12  output_format  u32
+//     之后为每个点的结果，1表示在多边形内部,0表示在多边形外部；
+//     位打包时为 ceil(point_count/32) 个u32，第i个点位于第i/32个字的第i%32位（从最低位开始）
+// 输入不合法时抛出带原因的错误
+
+use alloc::{format, string::String, string::ToString, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::scanline::classify_points;
+
+pub mod test;
+
+pub(crate) const JOB_MAGIC: &[u8; 4] = b"GPIP";
+pub(crate) const RESULT_MAGIC: &[u8; 4] = b"GPIR";
+pub(crate) const VERSION: u16 = 1;
+pub(crate) const JOB_HEADER_SIZE: usize = 32;
+pub(crate) const RESULT_HEADER_SIZE: usize = 16;
+
+// 输出格式
+pub(crate) const OUTPUT_U32: u32 = 0;
+pub(crate) const OUTPUT_U8: u32 = 1;
+pub(crate) const OUTPUT_BITS: u32 = 2;
+
+// 选项标志位
+const FLAG_BOUNDARY_IS_INSIDE: u32 = 1;
+
+// WebAssembly导出函数：执行打包的判断任务，返回打包的结果
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn run_packed(buffer: &[u8]) -> Result<Vec<u8>, ExportError> {
+    run_packed_bytes(buffer).map_err(export_error)
+}
+
+// 解析任务并执行
+pub(crate) fn run_packed_bytes(buffer: &[u8]) -> Result<Vec<u8>, String> {
+    if buffer.len() < JOB_HEADER_SIZE || &buffer[0..4] != JOB_MAGIC {
+        return Err("run_packed: missing GPIP header".to_string());
+    }
+    let version = read_u16(buffer, 4);
+    if version != VERSION {
+        return Err(format!("run_packed: unsupported version {}", version));
+    }
+
+    let header_size = read_u16(buffer, 6) as usize;
+    let point_count = read_u32(buffer, 8) as usize;
+    let vertex_count = read_u32(buffer, 12) as usize;
+    let ring_count = read_u32(buffer, 16) as usize;
+    let options_size = read_u32(buffer, 20) as usize;
+    if header_size < JOB_HEADER_SIZE {
+        return Err(format!("run_packed: header size {} is too small", header_size));
+    }
+
+    // 依次定位各段，计数来自外部输入，用u64计算避免在32位wasm上溢出
+    let options_start = align4(header_size as u64);
+    let points_start = align4(options_start + options_size as u64);
+    let polygon_start = points_start + point_count as u64 * 8;
+    let rings_start = polygon_start + vertex_count as u64 * 8;
+    let end = rings_start + ring_count as u64 * 4;
+    if (buffer.len() as u64) < end {
+        return Err(format!("run_packed: buffer has {} bytes, layout needs {}", buffer.len(), end));
+    }
+    // 以下各偏移都不超过缓冲区长度，可以安全地转换为usize
+    let (options_start, points_start) = (options_start as usize, points_start as usize);
+    let (polygon_start, rings_start) = (polygon_start as usize, rings_start as usize);
+
+    let options = &buffer[options_start..options_start + options_size];
+    let flags = if options.len() >= 4 { read_u32(options, 0) } else { 0 };
+    let output_format = if options.len() >= 8 { read_u32(options, 4) } else { OUTPUT_U32 };
+    if output_format > OUTPUT_BITS {
+        return Err(format!("run_packed: unknown output format {}", output_format));
+    }
+
+    let polygon: Vec<f32> = (0..vertex_count * 2).map(|i| read_f32(buffer, polygon_start + i * 4)).collect();
+    let rings: Vec<u32> = (0..ring_count).map(|i| read_u32(buffer, rings_start + i * 4)).collect();
+
+    // 点坐标直接从缓冲区读取，不额外复制
+    let results = classify_points(
+        point_count,
+        |i| {
+            let offset = points_start + i * 8;
+            (read_f32(buffer, offset) as f64, read_f32(buffer, offset + 4) as f64)
+        },
+        &polygon,
+        &rings,
+        flags & FLAG_BOUNDARY_IS_INSIDE != 0,
+    );
+
+    let body_size = match output_format {
+        OUTPUT_U8 => point_count,
+        OUTPUT_BITS => point_count.div_ceil(32) * 4,
+        _ => point_count * 4,
+    };
+    let mut output = Vec::with_capacity(RESULT_HEADER_SIZE + body_size);
+    output.extend_from_slice(RESULT_MAGIC);
+    output.extend_from_slice(&VERSION.to_le_bytes());
+    output.extend_from_slice(&(RESULT_HEADER_SIZE as u16).to_le_bytes());
+    output.extend_from_slice(&(point_count as u32).to_le_bytes());
+    output.extend_from_slice(&output_format.to_le_bytes());
+    match output_format {
+        OUTPUT_U8 => output.extend(results.iter().map(|&result| result as u8)),
+        OUTPUT_BITS => {
+            for chunk in results.chunks(32) {
+                let word = chunk.iter().enumerate().fold(0u32, |word, (bit, &result)| word | result << bit);
+                output.extend_from_slice(&word.to_le_bytes());
+            }
+        }
+        _ => {
+            for result in results {
+                output.extend_from_slice(&result.to_le_bytes());
+            }
+        }
+    }
+    Ok(output)
+}
+
+#[inline]
+fn align4(offset: u64) -> u64 {
+    (offset + 3) & !3
+}
+
+#[inline]
+fn read_u16(buffer: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([buffer[offset], buffer[offset + 1]])
+}
+
+#[inline]
+fn read_u32(buffer: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([buffer[offset], buffer[offset + 1], buffer[offset + 2], buffer[offset + 3]])
+}
+
+#[inline]
+fn read_f32(buffer: &[u8], offset: usize) -> f32 {
+    f32::from_bits(read_u32(buffer, offset))
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use crate::packed::{run_packed_bytes, JOB_HEADER_SIZE, JOB_MAGIC, OUTPUT_BITS, OUTPUT_U32, OUTPUT_U8, RESULT_HEADER_SIZE, VERSION};
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    // 按协议打包一个任务
+    fn pack(points: &[f32], polygon: &[f32], rings: &[u32], options: &[u32]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(JOB_MAGIC);
+        buffer.extend_from_slice(&VERSION.to_le_bytes());
+        buffer.extend_from_slice(&(JOB_HEADER_SIZE as u16).to_le_bytes());
+        for value in [points.len() / 2, polygon.len() / 2, rings.len(), options.len() * 4] {
+            buffer.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        buffer.resize(JOB_HEADER_SIZE, 0);
+        for &value in options {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        for &value in points.iter().chain(polygon.iter()) {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        for &value in rings {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_packed_round_trip() {
+        let polygon = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // Outer ring
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole
+        ];
+        let rings = vec![4];
+        let points = vec![0.5, 0.5, 1.5, 1.5, 3.0, 1.5, 4.0, 4.0];
+
+        // u32输出，边界视为内部
+        let output = run_packed_bytes(&pack(&points, &polygon, &rings, &[1, OUTPUT_U32])).unwrap();
+        assert_eq!(&output[0..4], b"GPIR");
+        assert_eq!(u32::from_le_bytes(output[8..12].try_into().unwrap()), 4);
+        let results: Vec<u32> = output[RESULT_HEADER_SIZE..]
+            .chunks(4)
+            .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(results, point_in_polygon_scanline(&points, &polygon, &rings, true));
+
+        // u8输出，省略选项段时边界视为外部
+        let output = run_packed_bytes(&pack(&points, &polygon, &rings, &[0, OUTPUT_U8])).unwrap();
+        assert_eq!(&output[RESULT_HEADER_SIZE..], &[1, 0, 0, 0]);
+        let output = run_packed_bytes(&pack(&points, &polygon, &rings, &[])).unwrap();
+        assert_eq!(output.len(), RESULT_HEADER_SIZE + 16);
+
+        // 位打包输出，4个点占一个字
+        let output = run_packed_bytes(&pack(&points, &polygon, &rings, &[1, OUTPUT_BITS])).unwrap();
+        assert_eq!(output.len(), RESULT_HEADER_SIZE + 4);
+        assert_eq!(u32::from_le_bytes(output[RESULT_HEADER_SIZE..].try_into().unwrap()), 0b0101);
+    }
+
+    #[test]
+    fn test_packed_rejects_malformed_buffers() {
+        let job = pack(&[0.5, 0.5], &[0.0, 0.0, 1.0, 0.0, 1.0, 1.0], &[3], &[]);
+        assert!(run_packed_bytes(&job[..20]).is_err());
+        assert!(run_packed_bytes(&job[..job.len() - 1]).is_err());
+
+        let mut wrong_version = job.clone();
+        wrong_version[4] = 9;
+        assert!(run_packed_bytes(&wrong_version).unwrap_err().contains("version"));
+
+        let bad_format = pack(&[0.5, 0.5], &[0.0, 0.0, 1.0, 0.0, 1.0, 1.0], &[3], &[0, 7]);
+        assert!(run_packed_bytes(&bad_format).unwrap_err().contains("output format"));
+    }
+}
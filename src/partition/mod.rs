@@ -0,0 +1,197 @@
+// 划分检查模块：检查一组多边形（例如行政区）是否无缝、无重叠地铺满父多边形，
+// 在依赖"每个点只属于一个区域"的空间连接之前发现空隙和重叠
+
+// 原理：
+//     把父多边形和所有子多边形的总边界框划分为 resolution × resolution 个单元，在每个单元中心判断一次：
+//     在父多边形内但不在任何子多边形内为空隙，同时在两个及以上子多边形内为重叠，在子多边形内但不在父多边形内为越界。
+//     单元中心恰好落在任一边界上时结果取决于边界语义，该单元不计入任何一类，因此共享边不会被误报为重叠或空隙。
+//     面积按单元面积累计，是分辨率为 resolution 的估计值，宽度小于单元尺寸的缝隙可能漏报
+// 输入(js端):
+//     1. 父多边形路径点 类型Float32Array, 父多边形的环拆分 类型Uint32Array
+//     2. 子多边形组 类型Float32Array 所有子多边形的路径点依次拼接
+//     3. 子多边形拆分 类型Uint32Array 第2个及之后每个子多边形的起始顶点索引，含义与coverage模块相同
+//     4. 子多边形的环拆分 类型Uint32Array 各子多边形内部洞的起始顶点索引（按拼接后的全局顶点索引）
+//     5. 采样分辨率 resolution 越大面积估计越准确
+//     6. 面积容差 tolerance 每一类问题的总面积不超过该值时视为通过
+// 输出(js端):
+//     1. PartitionReport
+//        is_valid() 空隙、重叠、越界的面积都不超过容差时为true
+//        gap_area() / overlap_area() / outside_area() 各类问题的估计面积
+//        gaps() / overlaps() / outside() 问题区域 类型Float64Array 每个矩形占4个值 [min_x, min_y, max_x, max_y]，
+//        同一行中相邻的问题单元合并为一个矩形
+
+use alloc::vec::Vec;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::coverage::split_polygons;
+use crate::points_in_polygon::scanline::ScanlineIndex;
+use crate::points_in_polygon::Location;
+
+pub mod test;
+
+// 单元的分类
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CellKind {
+    Ok,
+    Gap,
+    Overlap,
+    Outside,
+}
+
+// 划分检查报告
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct PartitionReport {
+    tolerance: f64,
+    gap_area: f64,
+    overlap_area: f64,
+    outside_area: f64,
+    gaps: Vec<f64>,
+    overlaps: Vec<f64>,
+    outside: Vec<f64>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl PartitionReport {
+    pub fn is_valid(&self) -> bool {
+        self.gap_area <= self.tolerance && self.overlap_area <= self.tolerance && self.outside_area <= self.tolerance
+    }
+
+    pub fn gap_area(&self) -> f64 {
+        self.gap_area
+    }
+
+    pub fn overlap_area(&self) -> f64 {
+        self.overlap_area
+    }
+
+    pub fn outside_area(&self) -> f64 {
+        self.outside_area
+    }
+
+    pub fn gaps(&self) -> Vec<f64> {
+        self.gaps.clone()
+    }
+
+    pub fn overlaps(&self) -> Vec<f64> {
+        self.overlaps.clone()
+    }
+
+    pub fn outside(&self) -> Vec<f64> {
+        self.outside.clone()
+    }
+}
+
+// WebAssembly导出函数：检查子多边形组是否划分父多边形
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[allow(clippy::too_many_arguments)]
+pub fn check_partition(
+    parent: &[f32],         // 父多边形顶点，格式为[x1, y1, x2, y2, ...]
+    parent_rings: &[u32],   // 父多边形环的分割索引
+    polygons: &[f32],       // 所有子多边形顶点依次拼接
+    polygon_splits: &[u32], // 第2个及之后每个子多边形的起始顶点索引
+    rings: &[u32],          // 子多边形洞的起始顶点索引（全局）
+    resolution: usize,      // 每个方向的采样单元数
+    tolerance: f64,         // 每一类问题允许的总面积
+) -> PartitionReport {
+    let parent_index = ScanlineIndex::new(parent, parent_rings);
+    let children: Vec<ScanlineIndex> = split_polygons(polygons, polygon_splits, rings)
+        .iter()
+        .map(|(polygon, local_rings)| ScanlineIndex::new(polygon, local_rings))
+        .collect();
+
+    let mut report = PartitionReport {
+        tolerance,
+        gap_area: 0.0,
+        overlap_area: 0.0,
+        outside_area: 0.0,
+        gaps: Vec::new(),
+        overlaps: Vec::new(),
+        outside: Vec::new(),
+    };
+
+    // 父多边形与所有子多边形的总边界框
+    let mut bounds = (f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+    let has_parent = !parent.is_empty() && !parent_rings.is_empty();
+    let all_bounds = children.iter().map(|c| c.bounds()).chain(has_parent.then(|| parent_index.bounds()));
+    for (x0, y0, x1, y1) in all_bounds {
+        bounds = (bounds.0.min(x0), bounds.1.min(y0), bounds.2.max(x1), bounds.3.max(y1));
+    }
+    let (min_x, min_y, max_x, max_y) = bounds;
+    if !(min_x < max_x && min_y < max_y) {
+        return report;
+    }
+
+    let size = resolution.max(1);
+    let cell_w = (max_x - min_x) / size as f64;
+    let cell_h = (max_y - min_y) / size as f64;
+    let cell_area = cell_w * cell_h;
+
+    for r in 0..size {
+        let y = min_y + (r as f64 + 0.5) * cell_h;
+        let mut run: Option<(CellKind, usize)> = None; // 当前行中正在合并的问题单元 (类型, 起始列)
+        for c in 0..=size {
+            let kind = if c < size {
+                classify_cell(&parent_index, has_parent, &children, min_x + (c as f64 + 0.5) * cell_w, y)
+            } else {
+                CellKind::Ok // 行尾结束最后一段
+            };
+            match kind {
+                CellKind::Gap => report.gap_area += cell_area,
+                CellKind::Overlap => report.overlap_area += cell_area,
+                CellKind::Outside => report.outside_area += cell_area,
+                CellKind::Ok => {}
+            }
+
+            if let Some((run_kind, start)) = run {
+                if run_kind == kind {
+                    continue;
+                }
+                let rect = [
+                    min_x + start as f64 * cell_w,
+                    min_y + r as f64 * cell_h,
+                    min_x + c as f64 * cell_w,
+                    min_y + (r + 1) as f64 * cell_h,
+                ];
+                match run_kind {
+                    CellKind::Gap => report.gaps.extend(rect),
+                    CellKind::Overlap => report.overlaps.extend(rect),
+                    CellKind::Outside => report.outside.extend(rect),
+                    CellKind::Ok => {}
+                }
+            }
+            run = (kind != CellKind::Ok).then_some((kind, c));
+        }
+    }
+    report
+}
+
+// 单元中心的分类，中心落在任一边界上的单元不计入任何一类
+fn classify_cell(parent: &ScanlineIndex, has_parent: bool, children: &[ScanlineIndex], x: f64, y: f64) -> CellKind {
+    let in_parent = if has_parent {
+        match parent.locate(x, y) {
+            Location::Boundary => return CellKind::Ok,
+            location => location == Location::Inside,
+        }
+    } else {
+        false
+    };
+
+    let mut count = 0;
+    for child in children {
+        match child.locate(x, y) {
+            Location::Boundary => return CellKind::Ok,
+            Location::Inside => count += 1,
+            Location::Outside => {}
+        }
+    }
+
+    if count >= 2 {
+        CellKind::Overlap
+    } else if count == 1 && !in_parent {
+        CellKind::Outside
+    } else if count == 0 && in_parent {
+        CellKind::Gap
+    } else {
+        CellKind::Ok
+    }
+}
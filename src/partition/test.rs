@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::partition::check_partition;
+
+    // 父多边形 [0,4]×[0,4]
+    const PARENT: [f32; 8] = [0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+
+    #[test]
+    fn test_exact_partition_is_valid() {
+        // 左右两半加一个带洞的右半部分及其洞中的小块，共享边不算重叠
+        let polygons = vec![
+            0.0, 0.0, 2.0, 0.0, 2.0, 4.0, 0.0, 4.0, // 左半
+            2.0, 0.0, 4.0, 0.0, 4.0, 4.0, 2.0, 4.0, // 右半外环
+            3.0, 1.0, 3.5, 1.0, 3.5, 3.0, 3.0, 3.0, // 右半的洞
+            3.0, 1.0, 3.5, 1.0, 3.5, 3.0, 3.0, 3.0, // 填补洞的小块
+        ];
+        let report = check_partition(&PARENT, &[4], &polygons, &[4, 12], &[8], 64, 1e-9);
+        assert!(report.is_valid());
+        assert_eq!((report.gap_area(), report.overlap_area(), report.outside_area()), (0.0, 0.0, 0.0));
+        assert!(report.gaps().is_empty() && report.overlaps().is_empty());
+    }
+
+    #[test]
+    fn test_gap_overlap_and_outside() {
+        // 左块 [0,2]×[0,4]，右块 [1,4]×[0,3] 与左块重叠 [1,2]×[0,3]，
+        // 顶部 [2,4]×[3,4] 没有覆盖，右块 [4,5]×[0,1] 越出父多边形
+        let polygons = vec![
+            0.0, 0.0, 2.0, 0.0, 2.0, 4.0, 0.0, 4.0, // 左块
+            1.0, 0.0, 4.0, 0.0, 4.0, 3.0, 1.0, 3.0, // 右块
+            4.0, 0.0, 5.0, 0.0, 5.0, 1.0, 4.0, 1.0, // 越界块
+        ];
+        let report = check_partition(&PARENT, &[4], &polygons, &[4, 8], &[], 100, 0.01);
+        assert!(!report.is_valid());
+        assert!((report.overlap_area() - 3.0).abs() < 1e-9);
+        assert!((report.gap_area() - 2.0).abs() < 1e-9);
+        assert!((report.outside_area() - 1.0).abs() < 1e-9);
+
+        // 空隙矩形都落在 [2,4]×[3,4] 内，每行合并为一个矩形
+        let gaps = report.gaps();
+        assert_eq!(gaps.len() % 4, 0);
+        for rect in gaps.chunks(4) {
+            assert!(rect[0] >= 2.0 - 1e-9 && rect[1] >= 3.0 - 1e-9 && rect[2] <= 4.0 + 1e-9 && rect[3] <= 4.0 + 1e-9);
+        }
+        assert_eq!(gaps.len() / 4, 25);
+    }
+}
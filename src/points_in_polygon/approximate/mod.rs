@@ -0,0 +1,205 @@
+// 近似判断模块：只用网格单元级别的预计算给出 内部/外部/不确定 三种结果
+// 适合渐进式渲染：先立即显示确定的点，再由调用方决定是否精确判断不确定的点
+
+// 原理：
+//     把多边形边界框划分为 grid_size × grid_size 个单元，标记所有与边（按多边形的容差加宽后）相交的单元为"不确定"，
+//     其余单元内的点与任何边的距离都超过容差，整个单元的判断结果相同，只需对单元中心精确判断一次。
+//     因此内部/外部的结果是有保证的，误差只会出现在被标记为不确定的边界带中
+// 输入(js端):
+//     1. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+//     2. 网格分辨率 grid_size 越大不确定带越窄
+//     3. 点云 类型Float32Array
+// 输出(js端):
+//     1. labels: 类型Uint32Array 0表示外部, 1表示内部, 2表示不确定
+//     2. uncertain_indices: 类型Uint32Array 不确定的点的索引
+//     3. resolve: 对指定索引的点做精确判断, 返回 Uint32Array 0/1
+
+use alloc::{string::String, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use super::common::{polygon_edges, Bounds};
+use super::scanline::ScanlineIndex;
+use super::{index_u32, Location};
+
+pub mod test;
+
+// 单元标签
+const LABEL_OUTSIDE: u8 = 0;
+const LABEL_INSIDE: u8 = 1;
+const LABEL_UNCERTAIN: u8 = 2;
+
+// 近似判断器：预计算每个单元的标签
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct ApproximateClassifier {
+    index: ScanlineIndex,
+    min_x: f64,
+    min_y: f64,
+    cell_w: f64,
+    cell_h: f64,
+    size: usize,
+    cells: Vec<u8>,  // 行优先的单元标签
+}
+
+// 近似判断结果
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct ApproximateResult {
+    labels: Vec<u32>,
+    uncertain: Vec<u32>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ApproximateResult {
+    // 每个点的标签：0外部，1内部，2不确定
+    pub fn labels(&self) -> Vec<u32> {
+        self.labels.clone()
+    }
+
+    // 不确定的点的索引
+    pub fn uncertain_indices(&self) -> Vec<u32> {
+        self.uncertain.clone()
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ApproximateClassifier {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(polygon: &[f32], rings: &[u32], grid_size: usize) -> ApproximateClassifier {
+        let index = ScanlineIndex::new(polygon, rings);
+        let (min_x, min_y, max_x, max_y) = index.bounds();
+        let size = grid_size.max(1);
+
+        let mut classifier = ApproximateClassifier {
+            index,
+            min_x,
+            min_y,
+            cell_w: ((max_x - min_x) / size as f64).max(f64::MIN_POSITIVE),
+            cell_h: ((max_y - min_y) / size as f64).max(f64::MIN_POSITIVE),
+            size,
+            cells: vec![LABEL_OUTSIDE; size * size],
+        };
+
+        if polygon.is_empty() || rings.is_empty() {
+            return classifier;
+        }
+
+        // 1. 标记所有被边经过的单元
+        for [x1, y1, x2, y2] in polygon_edges(polygon, rings) {
+            classifier.mark_edge(x1 as f64, y1 as f64, x2 as f64, y2 as f64);
+        }
+
+        // 2. 其余单元用单元中心的精确结果代表整个单元
+        for r in 0..size {
+            for c in 0..size {
+                let cell = &mut classifier.cells[r * size + c];
+                if *cell == LABEL_UNCERTAIN {
+                    continue;
+                }
+                let x = min_x + (c as f64 + 0.5) * classifier.cell_w;
+                let y = min_y + (r as f64 + 0.5) * classifier.cell_h;
+                *cell = match classifier.index.locate(x, y) {
+                    Location::Inside => LABEL_INSIDE,
+                    Location::Outside => LABEL_OUTSIDE,
+                    Location::Boundary => LABEL_UNCERTAIN,
+                };
+            }
+        }
+
+        classifier
+    }
+
+    // 只使用单元预计算结果进行判断
+    pub fn classify(&self, points: &[f32]) -> Result<ApproximateResult, ExportError> {
+        self.classify_values(points).map_err(export_error)
+    }
+
+    // 精确判断指定索引的点（通常是classify返回的不确定点），结果与indices一一对应
+    pub fn resolve(&self, points: &[f32], indices: &[u32], boundary_is_inside: bool) -> Vec<u32> {
+        indices
+            .iter()
+            .map(|&i| {
+                let i = i as usize;
+                if i * 2 + 1 >= points.len() {
+                    return 0;
+                }
+                let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+                self.index.locate(x, y).to_u32(boundary_is_inside)
+            })
+            .collect()
+    }
+}
+
+impl ApproximateClassifier {
+    // 判断的实现，不确定点的索引超出u32范围时返回错误信息
+    pub(crate) fn classify_values(&self, points: &[f32]) -> Result<ApproximateResult, String> {
+        let point_count = points.len() / 2;
+        let mut labels = vec![LABEL_OUTSIDE as u32; point_count];
+        let mut uncertain = Vec::new();
+
+        for (i, label) in labels.iter_mut().enumerate() {
+            let x = points[i * 2] as f64;
+            let y = points[i * 2 + 1] as f64;
+            *label = self.label_at(x, y) as u32;
+            if *label == LABEL_UNCERTAIN as u32 {
+                uncertain.push(index_u32(i)?);
+            }
+        }
+
+        Ok(ApproximateResult { labels, uncertain })
+    }
+
+    // 点所在单元的标签，边界框外为外部；边界框外但距边界框不超过容差的点可能在边上，为不确定
+    fn label_at(&self, x: f64, y: f64) -> u8 {
+        let fx = (x - self.min_x) / self.cell_w;
+        let fy = (y - self.min_y) / self.cell_h;
+        let size = self.size as f64;
+        if !(0.0..=size).contains(&fx) || !(0.0..=size).contains(&fy) {
+            let (min_x, min_y, max_x, max_y) = self.index.bounds();
+            let near = Bounds { min_x, min_y, max_x, max_y }.contains(x, y, self.index.polygon().tolerance);
+            return if near { LABEL_UNCERTAIN } else { LABEL_OUTSIDE };
+        }
+
+        let c = (fx as usize).min(self.size - 1);
+        let r = (fy as usize).min(self.size - 1);
+        let label = self.cells[r * self.size + c];
+
+        // 恰好落在单元分界线上的点可能属于相邻单元，保守起见视为不确定
+        let on_cell_line = fx.fract() == 0.0 || fy.fract() == 0.0;
+        if on_cell_line && label != LABEL_UNCERTAIN {
+            return LABEL_UNCERTAIN;
+        }
+        label
+    }
+
+    // 标记线段（按容差加宽后）经过的所有单元（保守覆盖：按列求出线段在加宽后的该列x区间内的y范围，再标记该范围内的单元）
+    // 距离边不超过容差的点按边界处理，因此加宽量为容差，再加上抵消浮点舍入的极小量
+    fn mark_edge(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        let last = self.size - 1;
+        let tolerance = self.index.polygon().tolerance;
+        let eps_x = tolerance + self.cell_w * 1e-9;
+        let eps_y = tolerance + self.cell_h * 1e-9;
+        let to_col = |x: f64| ((x - self.min_x) / self.cell_w).floor().clamp(0.0, last as f64) as usize;
+        let to_row = |y: f64| ((y - self.min_y) / self.cell_h).floor().clamp(0.0, last as f64) as usize;
+
+        let (c0, c1) = (to_col(x1.min(x2) - eps_x), to_col(x1.max(x2) + eps_x));
+        for c in c0..=c1 {
+            // 线段在加宽后的第c列x区间内的部分的y范围
+            let col_min_x = (self.min_x + c as f64 * self.cell_w - eps_x).max(x1.min(x2));
+            let col_max_x = (self.min_x + (c + 1) as f64 * self.cell_w + eps_x).min(x1.max(x2));
+            let (ya, yb) = if (x2 - x1).abs() > 0.0 {
+                let ya = y1 + (col_min_x - x1) / (x2 - x1) * (y2 - y1);
+                let yb = y1 + (col_max_x - x1) / (x2 - x1) * (y2 - y1);
+                (ya, yb)
+            } else {
+                (y1, y2)
+            };
+
+            let (r0, r1) = (to_row(ya.min(yb) - eps_y), to_row(ya.max(yb) + eps_y));
+            for r in r0..=r1 {
+                self.cells[r * self.size + c] = LABEL_UNCERTAIN;
+            }
+        }
+    }
+}
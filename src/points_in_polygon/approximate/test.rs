@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::approximate::ApproximateClassifier;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    #[test]
+    fn test_certain_labels_match_exact_results() {
+        // 圆形外环（半径5）带一个圆形洞（半径1）
+        let segments = 128;
+        let mut polygon = Vec::new();
+        for (cx, r) in [(0.0, 5.0), (2.0, 1.0)] {
+            for i in 0..segments {
+                let angle = 2.0 * std::f32::consts::PI * (i as f32) / (segments as f32);
+                polygon.push(cx + r * angle.cos());
+                polygon.push(r * angle.sin());
+            }
+        }
+        let rings = vec![segments];
+
+        let mut points = Vec::new();
+        for x in 0..300 {
+            for y in 0..300 {
+                points.push(-6.0 + x as f32 * 0.0401);
+                points.push(-6.0 + y as f32 * 0.0401);
+            }
+        }
+
+        let classifier = ApproximateClassifier::new(&polygon, &rings, 64);
+        let result = classifier.classify_values(&points).unwrap();
+        let labels = result.labels();
+        let uncertain = result.uncertain_indices();
+        let exact = point_in_polygon_scanline(&points, &polygon, &rings, true);
+
+        // 确定的点必须与精确结果一致，不确定的点只占边界附近的一小部分
+        for (i, &label) in labels.iter().enumerate() {
+            if label != 2 {
+                assert_eq!(label, exact[i], "point {}", i);
+            }
+        }
+        assert!(uncertain.len() * 5 < labels.len());
+        assert_eq!(uncertain.len(), labels.iter().filter(|&&l| l == 2).count());
+
+        // 对不确定的点做精确判断后与逐点结果一致
+        let resolved = classifier.resolve(&points, &uncertain, true);
+        for (k, &i) in uncertain.iter().enumerate() {
+            assert_eq!(resolved[k], exact[i as usize]);
+        }
+    }
+
+    #[test]
+    fn test_points_within_tolerance_of_edges() {
+        // 与边界框重合的边（x = 0、y = 0）两侧 ±tol/2 的点都在边上
+        let square = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+        let classifier = ApproximateClassifier::new(&square, &[4], 64);
+        let tol = classifier.index.polygon().tolerance as f32;
+        let points = vec![-tol / 2.0, 5.0, tol / 2.0, 5.0, 5.0, -tol / 2.0, 5.0, tol / 2.0];
+        let result = classifier.classify_values(&points).unwrap();
+        assert_eq!(result.labels(), vec![2, 2, 2, 2]);
+        assert_eq!(classifier.resolve(&points, &result.uncertain_indices(), true), vec![1, 1, 1, 1]);
+
+        // L形，边界框为[-10, 10]²，x = 0是单元分界线：x = -tol/4 的竖边落在左侧单元内，
+        // 右侧单元中距这条边tol/2的点也不确定
+        let tol = ApproximateClassifier::new(&[-10.0, -10.0, 10.0, 10.0], &[2], 64).index.polygon().tolerance as f32;
+        let e = -tol / 4.0;
+        let l_shape = vec![-10.0, -10.0, e, -10.0, e, 0.0, 10.0, 0.0, 10.0, 10.0, -10.0, 10.0];
+        let classifier = ApproximateClassifier::new(&l_shape, &[6], 64);
+        let points = vec![e + tol / 2.0, -5.0, e - tol / 2.0, -5.0];
+        let result = classifier.classify_values(&points).unwrap();
+        assert_eq!(result.labels(), vec![2, 2]);
+        assert_eq!(classifier.resolve(&points, &result.uncertain_indices(), true), vec![1, 1]);
+        assert_eq!(classifier.resolve(&points, &result.uncertain_indices(), false), vec![0, 0]);
+    }
+}
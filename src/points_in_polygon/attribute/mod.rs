@@ -0,0 +1,160 @@
+// 属性查询模块：把点的属性值与几何判断融合在一次遍历中完成
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 每个点的分数 类型Float32Array 与点一一对应
+//     3. k 需要返回的点数
+//     4. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+//     5. 边界上点是否考虑为内部 boolean
+//     6. 属性过滤: 每个点的数值 类型Float32Array 与区间 [min, max]，或每个点的类别 类型Uint32Array 与允许的类别列表
+// 输出(js端):
+//     1. top_k_inside: 类型Uint32Array 多边形内部分数最高的k个点的索引，按分数从高到低排列，同分时索引小的在前
+//     2. points_in_polygon_value_range / points_in_polygon_category: 类型Uint32Array
+//        1表示属性满足条件且在多边形内部，0表示其他情况
+// 说明: 用容量为k的小顶堆维护当前的前k名，堆满后分数不超过堆顶的点直接跳过，不做几何判断；
+//     分数为NaN的点不参与排名。属性过滤同样先判断属性，不满足条件的点不做几何判断
+
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use core::cmp::{Ordering, Reverse};
+use alloc::collections::BinaryHeap;
+use super::scanline::ScanlineIndex;
+use super::index_u32;
+
+pub mod test;
+
+// 堆中的候选点：按分数比较，同分时索引小的优先
+#[derive(Clone, Copy)]
+struct Candidate {
+    score: f32,
+    index: usize,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.total_cmp(&other.score).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+// WebAssembly导出函数：多边形内部分数最高的k个点
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn top_k_inside(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    scores: &[f32],           // 每个点的分数
+    k: usize,                 // 返回的点数上限
+    polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Result<Vec<u32>, ExportError> {
+    let point_count = (points.len() / 2).min(scores.len());
+    if k == 0 || point_count == 0 || polygon.is_empty() || rings.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let index = ScanlineIndex::new(polygon, rings);
+    // 小顶堆：堆顶是当前前k名中最差的一个
+    let mut heap: BinaryHeap<Reverse<Candidate>> = BinaryHeap::with_capacity(k.min(point_count).saturating_add(1));
+
+    for (i, &score) in scores.iter().enumerate().take(point_count) {
+        if score.is_nan() {
+            continue;
+        }
+        let candidate = Candidate { score, index: i };
+        if heap.len() == k && heap.peek().is_some_and(|worst| candidate <= worst.0) {
+            continue;
+        }
+
+        let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+        if index.locate(x, y).to_u32(boundary_is_inside) == 1 {
+            heap.push(Reverse(candidate));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+    }
+
+    // into_sorted_vec按Reverse升序，即分数从高到低
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(c)| index_u32(c.index))
+        .collect::<Result<_, _>>()
+        .map_err(export_error)
+}
+
+// WebAssembly导出函数：数值在[min, max]内且在多边形内部的点
+// 只需单侧限制时另一侧传入 -Infinity 或 Infinity，数值为NaN的点不满足条件
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn points_in_polygon_value_range(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    values: &[f32],           // 每个点的数值
+    min: f32,                 // 区间下界（含）
+    max: f32,                 // 区间上界（含）
+    polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<u32> {
+    classify_filtered(points, polygon, rings, boundary_is_inside, |i| {
+        values.get(i).is_some_and(|&v| v >= min && v <= max)
+    })
+}
+
+// WebAssembly导出函数：类别在允许列表中且在多边形内部的点
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn points_in_polygon_category(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    categories: &[u32],       // 每个点的类别
+    allowed: &[u32],          // 允许的类别列表
+    polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<u32> {
+    // 排序去重后二分查找，类别值可以是任意u32
+    let mut allowed = allowed.to_vec();
+    allowed.sort_unstable();
+    allowed.dedup();
+
+    classify_filtered(points, polygon, rings, boundary_is_inside, |i| {
+        categories.get(i).is_some_and(|c| allowed.binary_search(c).is_ok())
+    })
+}
+
+// 先判断属性条件，满足条件的点再做几何判断
+fn classify_filtered<F: Fn(usize) -> bool>(
+    points: &[f32],
+    polygon: &[f32],
+    rings: &[u32],
+    boundary_is_inside: bool,
+    keep: F,
+) -> Vec<u32> {
+    let point_count = points.len() / 2;
+    if point_count == 0 || polygon.is_empty() || rings.is_empty() {
+        return vec![0; point_count];
+    }
+
+    let index = ScanlineIndex::new(polygon, rings);
+    (0..point_count)
+        .map(|i| {
+            if !keep(i) {
+                return 0;
+            }
+            let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+            index.locate(x, y).to_u32(boundary_is_inside)
+        })
+        .collect()
+}
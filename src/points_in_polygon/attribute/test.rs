@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::uniform_points;
+    use crate::points_in_polygon::attribute::{
+        points_in_polygon_category, points_in_polygon_value_range, top_k_inside,
+    };
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    fn square_with_hole() -> (Vec<f32>, Vec<u32>) {
+        let polygon = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // Outer ring
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole
+        ];
+        (polygon, vec![4])
+    }
+
+    #[test]
+    fn test_top_k_inside_order_and_ties() {
+        let (polygon, rings) = square_with_hole();
+        let points = vec![
+            0.5, 0.5, // 0 内部
+            1.5, 1.5, // 1 洞内
+            2.5, 2.5, // 2 内部
+            5.0, 5.0, // 3 外部
+            0.5, 2.5, // 4 内部
+            3.0, 1.0, // 5 边界
+        ];
+        let scores = vec![3.0, 99.0, 7.0, 100.0, 7.0, f32::NAN];
+
+        assert_eq!(top_k_inside(&points, &scores, 2, &polygon, &rings, true).unwrap(), vec![2, 4]);
+        assert_eq!(top_k_inside(&points, &scores, 10, &polygon, &rings, true).unwrap(), vec![2, 4, 0]);
+        // k远大于点数时堆容量按点数分配
+        assert_eq!(top_k_inside(&points, &scores, usize::MAX, &polygon, &rings, true).unwrap(), vec![2, 4, 0]);
+        let scores = vec![3.0, 99.0, 7.0, 100.0, 7.0, 50.0];
+        assert_eq!(top_k_inside(&points, &scores, 2, &polygon, &rings, true).unwrap(), vec![5, 2]);
+        assert_eq!(top_k_inside(&points, &scores, 2, &polygon, &rings, false).unwrap(), vec![2, 4]);
+        assert!(top_k_inside(&points, &scores, 0, &polygon, &rings, true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_top_k_matches_mask_and_sort() {
+        let (polygon, rings) = square_with_hole();
+        let points = uniform_points(5000, -1.0, -1.0, 4.0, 4.0, 2);
+        let scores: Vec<f32> = (0..5000).map(|i| ((i * 7919) % 1000) as f32).collect();
+
+        // 对照：先求完整掩码再排序
+        let mask = point_in_polygon_scanline(&points, &polygon, &rings, true);
+        let mut expected: Vec<usize> = (0..5000).filter(|&i| mask[i] == 1).collect();
+        expected.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]).then(a.cmp(&b)));
+        let expected: Vec<u32> = expected.into_iter().take(50).map(|i| i as u32).collect();
+
+        assert_eq!(top_k_inside(&points, &scores, 50, &polygon, &rings, true).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_attribute_filters_before_containment() {
+        let (polygon, rings) = square_with_hole();
+        let points = vec![
+            0.5, 0.5, // 内部
+            1.5, 1.5, // 洞内
+            2.5, 2.5, // 内部
+            5.0, 5.0, // 外部
+            3.0, 1.0, // 边界
+        ];
+
+        // 数值大于等于42
+        let values = vec![50.0, 60.0, 10.0, 70.0, f32::NAN];
+        assert_eq!(
+            points_in_polygon_value_range(&points, &values, 42.0, f32::INFINITY, &polygon, &rings, true),
+            vec![1, 0, 0, 0, 0]
+        );
+        let values = vec![50.0, 60.0, 10.0, 70.0, 42.0];
+        assert_eq!(
+            points_in_polygon_value_range(&points, &values, 42.0, f32::INFINITY, &polygon, &rings, true),
+            vec![1, 0, 0, 0, 1]
+        );
+
+        // 类别为2或5
+        let categories = vec![2, 5, 5, 2, 7];
+        assert_eq!(
+            points_in_polygon_category(&points, &categories, &[5, 2], &polygon, &rings, true),
+            vec![1, 0, 1, 0, 0]
+        );
+        assert_eq!(
+            points_in_polygon_category(&points, &categories, &[], &polygon, &rings, true),
+            vec![0, 0, 0, 0, 0]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+// 环的层次包围盒（BVH）：把各环的边界框组织成二叉树，查询一个点时只访问边界框包含该点的环，
+// 用于环数成千上万的MultiPolygon，避免每个点都逐个扫描所有环
+
+// 构建: 每个节点的边界框包含其下所有环，按环边界框中心在较长方向上的中位数二分，不超过LEAF_SIZE个环时成为叶节点
+// 查询: rings_at 给出边界框（按margin向外扩展）包含点的全部环，按环索引升序，不含没有边的环（被丢弃的退化环）
+
+use alloc::{vec, vec::Vec};
+use super::common::{Bounds, Polygon};
+
+pub mod test;
+
+// 叶节点最多包含的环数
+const LEAF_SIZE: usize = 4;
+
+// 树的节点：叶节点对应order[start..start + count]中的环，内部节点的子节点为left和left + 1
+struct Node {
+    bounds: Bounds,
+    start: usize,
+    count: usize,
+    left: usize, // 叶节点为0（根节点不会是子节点）
+}
+
+pub(crate) struct RingBvh {
+    nodes: Vec<Node>,
+    order: Vec<usize>, // 按树的叶节点顺序排列的环索引
+    bounds: Vec<Bounds>, // 与order对应的环边界框
+}
+
+impl RingBvh {
+    pub(crate) fn new(poly: &Polygon) -> RingBvh {
+        let mut order: Vec<usize> = (0..poly.rings.len()).filter(|&i| poly.rings[i].edge_count > 0).collect();
+        let mut nodes = Vec::new();
+        if !order.is_empty() {
+            let count = order.len();
+            nodes.push(Node { bounds: Bounds::empty(), start: 0, count, left: 0 });
+            split(poly, &mut nodes, &mut order, 0);
+        }
+        let bounds = order.iter().map(|&r| poly.rings[r].bounds).collect();
+        RingBvh { nodes, order, bounds }
+    }
+
+    // 边界框按margin扩展后包含点(x, y)的环，写入out（先清空）
+    pub(crate) fn rings_at(&self, x: f64, y: f64, margin: f64, out: &mut Vec<usize>) {
+        out.clear();
+        if self.nodes.is_empty() {
+            return;
+        }
+        let contains = |b: &Bounds| x >= b.min_x - margin && x <= b.max_x + margin && y >= b.min_y - margin && y <= b.max_y + margin;
+
+        let mut stack = vec![0];
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !contains(&node.bounds) {
+                continue;
+            }
+            if node.left == 0 {
+                // 叶节点的边界框是其中各环的并集，逐个检查环的边界框
+                let range = node.start..node.start + node.count;
+                out.extend(self.order[range.clone()].iter().zip(&self.bounds[range]).filter(|(_, b)| contains(b)).map(|(&r, _)| r));
+            } else {
+                stack.push(node.left);
+                stack.push(node.left + 1);
+            }
+        }
+        out.sort_unstable();
+    }
+}
+
+// 计算节点的边界框，环数超过LEAF_SIZE时二分并递归构建子节点
+fn split(poly: &Polygon, nodes: &mut Vec<Node>, order: &mut [usize], node: usize) {
+    let (start, count) = (nodes[node].start, nodes[node].count);
+    let rings = &mut order[start..start + count];
+
+    let mut bounds = Bounds::empty();
+    let mut centers = Bounds::empty();
+    for &r in rings.iter() {
+        let b = &poly.rings[r].bounds;
+        bounds.extend(b);
+        let (cx, cy) = center(b);
+        centers.extend(&Bounds { min_x: cx, min_y: cy, max_x: cx, max_y: cy });
+    }
+    nodes[node].bounds = bounds;
+    if count <= LEAF_SIZE {
+        return;
+    }
+
+    // 按环中心在较长方向上的中位数分成两半
+    let along_x = centers.max_x - centers.min_x >= centers.max_y - centers.min_y;
+    let key = |r: &usize| {
+        let (cx, cy) = center(&poly.rings[*r].bounds);
+        if along_x { cx } else { cy }
+    };
+    let half = count / 2;
+    rings.select_nth_unstable_by(half, |a, b| key(a).total_cmp(&key(b)));
+
+    let left = nodes.len();
+    nodes[node].left = left;
+    nodes.push(Node { bounds: Bounds::empty(), start, count: half, left: 0 });
+    nodes.push(Node { bounds: Bounds::empty(), start: start + half, count: count - half, left: 0 });
+    split(poly, nodes, order, left);
+    split(poly, nodes, order, left + 1);
+}
+
+#[inline]
+fn center(b: &Bounds) -> (f64, f64) {
+    ((b.min_x + b.max_x) * 0.5, (b.min_y + b.max_y) * 0.5)
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::bvh::RingBvh;
+    use crate::points_in_polygon::common::build_polygon;
+    use crate::points_in_polygon::rayster::point_in_polygon_rayster;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    // 100×100的外环内排布30×30个边长1的正方形洞，洞的起点间隔3
+    fn holed_grid() -> (Vec<f32>, Vec<u32>) {
+        let mut polygon = vec![0.0, 0.0, 100.0, 0.0, 100.0, 100.0, 0.0, 100.0];
+        let mut rings = Vec::new();
+        for i in 0..30 {
+            for j in 0..30 {
+                let (x, y) = (5.0 + 3.0 * i as f32, 5.0 + 3.0 * j as f32);
+                rings.push((polygon.len() / 2) as u32);
+                polygon.extend_from_slice(&[x, y, x + 1.0, y, x + 1.0, y + 1.0, x, y + 1.0]);
+            }
+        }
+        (polygon, rings)
+    }
+
+    #[test]
+    fn test_rings_at_matches_bounds_scan() {
+        let (polygon, rings) = holed_grid();
+        let poly = build_polygon(&polygon, &rings);
+        let bvh = RingBvh::new(&poly);
+
+        let mut candidates = Vec::new();
+        for k in 0..400 {
+            let (x, y) = ((k * 37 % 1000) as f64 * 0.1, (k * 53 % 1000) as f64 * 0.1);
+            bvh.rings_at(x, y, 0.0, &mut candidates);
+            let expected: Vec<usize> = (0..poly.rings.len())
+                .filter(|&r| {
+                    let b = &poly.rings[r].bounds;
+                    x >= b.min_x && x <= b.max_x && y >= b.min_y && y <= b.max_y
+                })
+                .collect();
+            assert_eq!(candidates, expected, "({}, {})", x, y);
+            // 外环加上至多一个洞
+            assert!(candidates.len() <= 2);
+        }
+
+        // 按margin扩展：洞的角外侧0.5处
+        bvh.rings_at(4.6, 4.6, 0.5, &mut candidates);
+        assert_eq!(candidates, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_rayster_with_many_holes() {
+        let (polygon, rings) = holed_grid();
+        let points: Vec<f32> = (0..2000).flat_map(|k| [(k * 7 % 1010) as f32 * 0.1 - 0.5, (k * 13 % 1010) as f32 * 0.1 - 0.5]).collect();
+        for boundary_is_inside in [true, false] {
+            assert_eq!(
+                point_in_polygon_rayster(&points, &polygon, &rings, boundary_is_inside),
+                point_in_polygon_scanline(&points, &polygon, &rings, boundary_is_inside)
+            );
+        }
+
+        // 洞的中心和两个洞之间
+        assert_eq!(point_in_polygon_rayster(&[5.5, 5.5, 7.0, 5.5], &polygon, &rings, true), vec![0, 1]);
+    }
+}
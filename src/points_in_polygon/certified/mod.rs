@@ -0,0 +1,161 @@
+// 可证明正确的判断模块：对每个点给出有保证的 外部/内部/边界 标签
+// 穿越判断只依赖两类运算：
+//     1. 坐标比较（精确）
+//     2. 方向判定 orient(a, b, p) 的符号：先用区间算术求出行列式的取值区间，
+//        区间不包含0时符号确定；区间包含0时退回精确算术（浮点展开式，结果与有理数运算相同）
+// 因此结果不受浮点舍入影响，恰好落在边上的点一定判为边界
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+// 输出(js端):
+//     1. 类型Uint32Array 0表示外部, 1表示内部, 2表示在边界上
+// 说明: 采用奇偶规则（各环的穿越次数合计），每个点需要检查所有边，适合对正确性要求高于速度的场景
+
+use alloc::vec::Vec;
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use core::cmp::Ordering;
+use super::common::{crosses_scanline, polygon_edges};
+
+pub mod test;
+
+// 输出标签
+const LABEL_OUTSIDE: u32 = 0;
+const LABEL_INSIDE: u32 = 1;
+const LABEL_BOUNDARY: u32 = 2;
+
+// 双精度浮点数的单位舍入误差 2^-53
+const UNIT_ROUNDOFF: f64 = 1.1102230246251565e-16;
+// orient行列式的误差上界系数 (3 + 16ε)ε
+const ORIENT_ERROR_BOUND: f64 = (3.0 + 16.0 * UNIT_ROUNDOFF) * UNIT_ROUNDOFF;
+
+// WebAssembly导出函数：可证明正确的点在多边形内判断
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_certified(
+    points: &[f32],  // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32], // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],   // 多边形环的分割索引
+) -> Vec<u32> {
+    let point_count = points.len() / 2;
+    // f32坐标转换为f64是精确的
+    let edges: Vec<[f64; 4]> = polygon_edges(polygon, rings).map(|edge| edge.map(f64::from)).collect();
+
+    (0..point_count)
+        .map(|i| classify_certified(&edges, points[i * 2] as f64, points[i * 2 + 1] as f64))
+        .collect()
+}
+
+// 按奇偶规则判断单个点，边按半开区间规则计数：只统计 min_y <= y < max_y 的边
+fn classify_certified(edges: &[[f64; 4]], px: f64, py: f64) -> u32 {
+    let mut inside = false;
+
+    for &[ax, ay, bx, by] in edges {
+        // 快速排除：点在边的y范围之外时，既不可能在边上也不可能产生穿越
+        if py < ay.min(by) || py > ay.max(by) {
+            continue;
+        }
+
+        let side = orient2d(ax, ay, bx, by, px, py);
+
+        // 点与边共线且位于边的边界框内，即在边上
+        if side == Ordering::Equal && px >= ax.min(bx) && px <= ax.max(bx) {
+            return LABEL_BOUNDARY;
+        }
+
+        // 向右的射线与边相交：边跨越py（半开区间），且交点位于点的右侧
+        if crosses_scanline(ay, by, py) {
+            let upward = by > ay;
+            if (upward && side == Ordering::Greater) || (!upward && side == Ordering::Less) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside { LABEL_INSIDE } else { LABEL_OUTSIDE }
+}
+
+// 方向判定：点p在有向直线a->b的左侧返回Greater，右侧返回Less，共线返回Equal
+// 行列式 (bx-ax)(py-ay) - (by-ay)(px-ax)
+pub(crate) fn orient2d(ax: f64, ay: f64, bx: f64, by: f64, px: f64, py: f64) -> Ordering {
+    let left = (bx - ax) * (py - ay);
+    let right = (by - ay) * (px - ax);
+    let det = left - right;
+
+    // 区间算术：det的真实值一定落在 [det - err, det + err] 内
+    let err = ORIENT_ERROR_BOUND * (left.abs() + right.abs());
+    if det > err {
+        return Ordering::Greater;
+    }
+    if -det > err {
+        return Ordering::Less;
+    }
+
+    // 区间包含0，无法确定符号，退回精确算术
+    orient2d_exact(ax, ay, bx, by, px, py)
+}
+
+// 精确的方向判定：用浮点展开式（若干个互不重叠的浮点数之和）精确表示行列式
+fn orient2d_exact(ax: f64, ay: f64, bx: f64, by: f64, px: f64, py: f64) -> Ordering {
+    let dbx = two_diff(bx, ax);
+    let dpy = two_diff(py, ay);
+    let dby = two_diff(by, ay);
+    let dpx = two_diff(px, ax);
+
+    let mut expansion = Vec::with_capacity(16);
+    for &u in &[dbx.0, dbx.1] {
+        for &v in &[dpy.0, dpy.1] {
+            let (hi, lo) = two_product(u, v);
+            grow_expansion(&mut expansion, hi);
+            grow_expansion(&mut expansion, lo);
+        }
+    }
+    for &u in &[dby.0, dby.1] {
+        for &v in &[dpx.0, dpx.1] {
+            let (hi, lo) = two_product(u, v);
+            grow_expansion(&mut expansion, -hi);
+            grow_expansion(&mut expansion, -lo);
+        }
+    }
+
+    // 展开式按绝对值递增排列且互不重叠，符号由最后一个非零分量决定
+    match expansion.iter().rev().find(|&&c| c != 0.0) {
+        Some(&c) if c > 0.0 => Ordering::Greater,
+        Some(_) => Ordering::Less,
+        None => Ordering::Equal,
+    }
+}
+
+// a + b 的精确结果：和与舍入误差
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+// a - b 的精确结果：差与舍入误差
+#[inline]
+fn two_diff(a: f64, b: f64) -> (f64, f64) {
+    two_sum(a, -b)
+}
+
+// a * b 的精确结果：积与舍入误差（融合乘加的误差项是精确的）
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+// 把一个浮点数精确地加到展开式上，保持分量互不重叠且按绝对值递增
+fn grow_expansion(expansion: &mut Vec<f64>, b: f64) {
+    let mut q = b;
+    for component in expansion.iter_mut() {
+        let (sum, err) = two_sum(q, *component);
+        *component = err;
+        q = sum;
+    }
+    expansion.push(q);
+}
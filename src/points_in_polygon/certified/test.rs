@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::certified::{orient2d, point_in_polygon_certified};
+    use std::cmp::Ordering;
+
+    // 相邻的f32值
+    fn next_up(v: f32) -> f32 {
+        f32::from_bits(v.to_bits() + 1)
+    }
+
+    fn next_down(v: f32) -> f32 {
+        f32::from_bits(v.to_bits() - 1)
+    }
+
+    #[test]
+    fn test_points_one_ulp_from_diagonal_edge() {
+        // 三角形：对角线y=x以下的部分
+        let polygon = vec![0.0, 0.0, 12.0, 12.0, 12.0, 0.0];
+        let rings = vec![3];
+
+        let mut points = Vec::new();
+        for &x in &[0.1f32, 1.0 / 3.0, 5.7, 11.99] {
+            points.extend_from_slice(&[x, x, x, next_up(x), x, next_down(x)]);
+        }
+
+        let labels = point_in_polygon_certified(&points, &polygon, &rings);
+        for chunk in labels.chunks(3) {
+            // 在对角线上、对角线上方一个ulp、对角线下方一个ulp
+            assert_eq!(chunk, &[2, 0, 1]);
+        }
+    }
+
+    #[test]
+    fn test_square_with_hole_labels() {
+        let polygon = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // Outer ring
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole
+        ];
+        let rings = vec![4];
+        let points = vec![
+            0.5, 0.5, // 内部
+            1.5, 1.5, // 洞内
+            3.0, 1.5, // 外环边上
+            1.0, 1.0, // 洞的顶点
+            0.5, 1.0, // 与洞的底边同高
+            4.0, 0.0, // 外部，与外环底边同高
+            3.0, 3.0, // 外环顶点
+        ];
+
+        assert_eq!(
+            point_in_polygon_certified(&points, &polygon, &rings),
+            vec![1, 0, 2, 2, 1, 0, 2]
+        );
+    }
+
+    #[test]
+    fn test_orient_exact_fallback() {
+        // 行列式的朴素计算会被舍入误差吞掉的情况
+        let (ax, ay) = (0.5, 0.5);
+        let (bx, by) = (12.0, 12.0);
+        let p = 0.5 + f64::EPSILON;
+        assert_eq!(orient2d(ax, ay, bx, by, p, p), Ordering::Equal);
+        assert_eq!(orient2d(ax, ay, bx, by, p, p + f64::EPSILON), Ordering::Greater);
+        assert_eq!(orient2d(ax, ay, bx, by, p + f64::EPSILON, p), Ordering::Less);
+    }
+}
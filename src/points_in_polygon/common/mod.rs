@@ -0,0 +1,934 @@
+// 公共模块：各判断算法共用的多边形结构、构建过程和几何约定
+// 扫描线、射线等算法都从这里构建多边形和空间网格，修正和新功能（如校验、绕向检测）对所有算法同时生效；
+// 多边形、环、边、边界框、空间网格和网格遍历只在这里实现一次，其他模块（包括region的图元）不再各自定义
+
+// 环的约定：rings为拆分索引，第一个拆分之前的顶点是外环，之后每段是一个洞，
+// 最后一个拆分之后剩余的顶点（至少2个）组成末尾的洞；长度接近0的退化边被忽略，每个环自动闭合，
+// 末尾重复第一个顶点的环（显式闭合的输入）去掉该重复顶点后按同样方式处理
+// 不围成任何区域的退化环（不同顶点少于3个、全部共线、沿原路折返）按选项丢弃或只作为边界，
+// 丢弃的环仍占据一个环索引，只是不含任何边，因此环索引始终与输入一致
+// MultiPolygon（build_multipolygon_with）由若干组成部分依次拼接，每个部分按上述约定各有一个外环和若干洞，洞只属于所在的部分
+// 索引: 拆分索引是u32顶点索引，多边形最多2^32个顶点；内部一律使用usize，超出顶点数的拆分夹到顶点数（validate_polygon给出警告），
+// 点云和顶点数组的长度只受目标平台的地址空间限制，64位（native、wasm64）上超过2^31个元素的数组同样适用
+
+// 水平射线与边的相交采用半开区间规则：只有 min_y <= y < max_y 的边计一次穿越，即
+//     1. 水平边的两个端点在扫描线的同一侧，永远不计穿越
+//     2. 扫描线经过顶点时，顶点视为位于扫描线下方：相邻两边分居两侧时恰好一条计数，同在一侧时都计或都不计，奇偶性不变
+// 这样无需查找相邻边，也不依赖边的存储顺序（环的第一条边作为退化边被丢弃时结果不变）；
+// 扫描线、射线、可证明正确和整数坐标等所有求交过程都使用这一规则，与顶点或水平边等高的点在各算法中得到相同的结果，
+// 恰好落在边上的点由各算法的边界检测处理
+// 顶点策略（PipOptions.set_vertex_policy）可以改为把顶点视为位于扫描线上方：只有 min_y < y <= max_y 的边计一次穿越，
+// 与把比较写成 (y1 >= y) != (y2 >= y) 的GIS库一致；两种规则都不依赖相邻边，不在边界上的点按任一规则得到相同的内外结果，
+// 只有各环的穿越计数（ring_crossing_counts_with_options）不同。扫描线、射线和环绕数算法按选项使用同一规则，
+// 不接受选项的可证明正确和整数坐标算法固定使用默认规则
+// 每次穿越按边的方向计±1（向上的边+1，向下的边-1），累计得到环绕数：奇偶规则看环绕数的奇偶，非零规则看环绕数是否为0，
+// 对不自交的环两者一致，只有自交叠的环（同一区域被环绕两次以上）结果不同
+// SIMD: 以simd特性并开启simd128目标特性构建wasm时，扫描线和射线算法求一个环与扫描线的全部交点（for_each_crossing）
+// 每次循环用v128指令同时检测4条边，结果与逐条边的scalar版本逐位相同；其他构建使用scalar版本
+
+use alloc::{vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+use super::options::{PipOptions, DEGENERATE_RINGS_BOUNDARY, FILL_NONZERO, HOLES_BY_FILL_RULE, HOLES_BY_WINDING, INDEX_QUADTREE, VERTEX_ABOVE};
+use super::quadtree::EdgeQuadtree;
+use super::certified::orient2d;
+use core::cmp::Ordering;
+use hashbrown::HashMap;
+
+pub mod test;
+
+// 默认容差相对于多边形边界框对角线长度的比例：同一形状放大或缩小任意倍数，判断结果不变
+pub(crate) const RELATIVE_TOLERANCE: f64 = 1e-9;
+// 空间网格：默认按边数确定单元总数（平均每个单元约一条边），按边界框的长宽比分配列数和行数，
+// 单元总数和每个方向的单元数都有上限，避免边数极多时占用过多内存
+const MAX_GRID_CELLS: usize = 1 << 18;
+pub(crate) const MAX_GRID_DIM: usize = 1024;
+// 扫描线/射线交点缓存默认保存的扫描线数量
+pub(crate) const DEFAULT_CACHE_SIZE: usize = 1024;
+// 沿大圆加密时每条边最多分成的段数：步长极小时不至于按步长分配出无法承受的内存
+pub(crate) const MAX_ARC_SEGMENTS: usize = 1 << 16;
+
+// 多边形数据结构：存储整个多边形的边和环信息
+pub(crate) struct Polygon {
+    pub(crate) edges: Vec<Edge>,    // 存储所有边的集合
+    pub(crate) rings: Vec<Ring>,    // 存储所有环的集合（外环和内部的洞）
+    pub(crate) bounds: Bounds,      // 整个多边形的边界框
+    pub(crate) tolerance: f64,      // 距离容差：点到边的距离不超过该值即视为在边上，两端点距离不超过该值的边视为退化
+    pub(crate) part_count: usize,   // 组成部分的个数（MultiPolygon中各自带洞的多边形），普通多边形为1
+    pub(crate) fill_rule: u32,      // 填充规则：由环绕数判断点是否在环内的方式
+    pub(crate) grid_cols: usize,    // 空间网格的列数
+    pub(crate) grid_rows: usize,    // 空间网格的行数
+    pub(crate) index_kind: u32,     // 边界检测使用的边索引：均匀网格或四叉树
+    pub(crate) exact: bool,         // 精确模式：穿越判断按方向判定的符号，而不是比较浮点求得的交点x坐标
+    pub(crate) vertex_policy: u32,  // 扫描线经过顶点时的计数规则
+}
+
+// 环结构：表示多边形的一个环（外环或内部的洞）
+pub(crate) struct Ring {
+    pub(crate) start_idx: usize,    // 该环的第一条边在edges数组中的索引
+    pub(crate) edge_count: usize,   // 该环包含的边数量
+    pub(crate) is_hole: bool,       // 标识该环是否为洞（内环）
+    pub(crate) part: usize,         // 该环所属的组成部分，洞只挖去同一部分的外环
+    pub(crate) shell: Option<usize>, // 洞所挖去的外环（构建时按实际包含关系确定），外环和找不到外环的洞为None
+    pub(crate) bounds: Bounds,      // 该环的边界框
+    pub(crate) boundary_only: bool, // 退化环只作为边界：参与边界检测，不参与内外判断
+    pub(crate) degeneracy: Option<Degeneracy>, // 环退化的原因
+    pub(crate) closing_duplicate: bool, // 输入的最后一个顶点与第一个顶点重复，已去掉
+}
+
+// 环退化的原因
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Degeneracy {
+    TooFewVertices, // 不同顶点少于3个
+    Collinear,      // 全部顶点共线
+    ZeroArea,       // 每条边都被反向的边抵消（沿原路折返）
+}
+
+impl Degeneracy {
+    pub(crate) fn describe(self) -> &'static str {
+        match self {
+            Degeneracy::TooFewVertices => "fewer than 3 distinct vertices",
+            Degeneracy::Collinear => "all vertices are collinear",
+            Degeneracy::ZeroArea => "zero area (every edge is retraced)",
+        }
+    }
+}
+
+// 边结构：表示多边形的一条边（一个线段）
+#[derive(Clone, Copy)]
+pub(crate) struct Edge {
+    pub(crate) x1: f64, pub(crate) y1: f64,    // 边的起点坐标
+    pub(crate) x2: f64, pub(crate) y2: f64,    // 边的终点坐标
+    pub(crate) vertex: usize,                  // 起点在输入中的顶点索引（全局），用于向js端报告命中的边
+}
+
+impl Edge {
+    // 穿越扫描线时对环绕数的贡献：向上的边为+1，向下的边为-1
+    #[inline]
+    pub(crate) fn winding(&self) -> i32 {
+        if self.y2 > self.y1 { 1 } else { -1 }
+    }
+}
+
+// 边界框：用于快速空间过滤
+#[derive(Clone, Copy)]
+pub(crate) struct Bounds {
+    pub(crate) min_x: f64, pub(crate) min_y: f64,    // 边界框的最小坐标（左下角）
+    pub(crate) max_x: f64, pub(crate) max_y: f64,    // 边界框的最大坐标（右上角）
+}
+
+impl Bounds {
+    // 空边界框，扩展任意一点后即为该点
+    pub(crate) fn empty() -> Bounds {
+        Bounds { min_x: f64::MAX, min_y: f64::MAX, max_x: f64::MIN, max_y: f64::MIN }
+    }
+
+    pub(crate) fn extend(&mut self, other: &Bounds) {
+        *self = self.union(other);
+    }
+
+    // 两个边界框的并
+    pub(crate) fn union(&self, other: &Bounds) -> Bounds {
+        Bounds {
+            min_x: self.min_x.min(other.min_x), min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x), max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    // 两个边界框的交，不相交时得到min大于max的空框，contains恒为false
+    pub(crate) fn intersection(&self, other: &Bounds) -> Bounds {
+        Bounds {
+            min_x: self.min_x.max(other.min_x), min_y: self.min_y.max(other.min_y),
+            max_x: self.max_x.min(other.max_x), max_y: self.max_y.min(other.max_y),
+        }
+    }
+
+    // 对角线长度，空边界框为0
+    pub(crate) fn diagonal(&self) -> f64 {
+        if self.min_x > self.max_x || self.min_y > self.max_y {
+            return 0.0;
+        }
+        ((self.max_x - self.min_x).powi(2) + (self.max_y - self.min_y).powi(2)).sqrt()
+    }
+
+    // 点是否在向外扩展tolerance后的边界框内
+    #[inline]
+    pub(crate) fn contains(&self, x: f64, y: f64, tolerance: f64) -> bool {
+        x >= self.min_x - tolerance && x <= self.max_x + tolerance
+            && y >= self.min_y - tolerance && y <= self.max_y + tolerance
+    }
+}
+
+// 空间网格单元：存储落在该网格内的边的索引
+#[derive(Clone)]
+pub(crate) struct GridCell {
+    pub(crate) edge_indices: Vec<usize>,  // 该网格单元包含的边的索引列表
+}
+
+// 构建多边形数据结构：从输入的平铺数组构建结构化的多边形表示，退化环按默认选项丢弃
+// 坐标可以是f32或f64（Float64Array输入的变体），之后的计算都使用f64
+pub(crate) fn build_polygon<C: Copy + Into<f64>>(polygon: &[C], rings: &[u32]) -> Polygon {
+    build_polygon_with(polygon, rings, &PipOptions::default())
+}
+
+// 拆分索引（u32顶点索引）转换为不超过顶点数的usize：之后只用顶点索引比较和访问顶点，不做可能在32位目标上溢出的乘法
+#[inline]
+pub(crate) fn split_index(split: u32, vertex_count: usize) -> usize {
+    usize::try_from(split).map_or(vertex_count, |split| split.min(vertex_count))
+}
+
+// 将拆分数组转换为每个环的顶点索引区间[start, end)，所有按拆分数组划分环的模块都用它，保证环索引一致：
+// 每个拆分结束一个环，空的或重复的拆分得到空区间，仍占据环索引；最后一个拆分点之后剩余的顶点（至少2个）构成最后一个环
+pub(crate) fn ring_ranges(vertex_count: usize, rings: &[u32]) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::with_capacity(rings.len() + 1);
+    let mut start = 0;
+
+    for &split in rings {
+        let end = split_index(split, vertex_count);
+        ranges.push((start, end.max(start)));
+        start = end;
+    }
+
+    if vertex_count > start + 1 {
+        ranges.push((start, vertex_count));
+    }
+
+    ranges
+}
+
+// 一个环（顶点区间[start, end)）闭合后的全部边 [x1, y1, x2, y2]，最后一条边连回起点；不去掉退化边
+pub(crate) fn ring_edges<C: Copy>(polygon: &[C], start: usize, end: usize) -> impl Iterator<Item = [C; 4]> + '_ {
+    (start..end).map(move |k| {
+        let next = if k + 1 < end { k + 1 } else { start };
+        [polygon[k * 2], polygon[k * 2 + 1], polygon[next * 2], polygon[next * 2 + 1]]
+    })
+}
+
+// 按拆分数组划分的所有环的边，按环的顺序排列
+pub(crate) fn polygon_edges<'a, C: Copy>(polygon: &'a [C], rings: &[u32]) -> impl Iterator<Item = [C; 4]> + 'a {
+    ring_ranges(polygon.len() / 2, rings).into_iter().flat_map(move |(start, end)| ring_edges(polygon, start, end))
+}
+
+// 量化y坐标作为扫描线/射线交点缓存的键：直接使用y的位模式，只有y完全相同的点共享交点列表，不受坐标尺度影响
+#[inline]
+pub(crate) fn quantize_y(y: f64) -> i64 {
+    (y + 0.0).to_bits() as i64
+}
+
+// 按选项构建多边形数据结构
+pub(crate) fn build_polygon_with<C: Copy + Into<f64>>(polygon: &[C], rings: &[u32], options: &PipOptions) -> Polygon {
+    // 未指定容差时按全部顶点边界框的对角线长度缩放
+    let tolerance = if options.tolerance > 0.0 {
+        options.tolerance
+    } else {
+        RELATIVE_TOLERANCE * vertex_diagonal(polygon)
+    };
+    let mut poly = Polygon { edges: Vec::new(), rings: Vec::new(), bounds: Bounds::empty(), tolerance, part_count: 1, fill_rule: options.fill_rule, grid_cols: 1, grid_rows: 1, index_kind: options.index_kind, exact: options.exact, vertex_policy: options.vertex_policy };
+
+    // 第一个环是外环，其余是洞；超出顶点数的拆分夹到顶点数，得到的空环仍占据环索引
+    for (i, (start, end)) in ring_ranges(polygon.len() / 2, rings).into_iter().enumerate() {
+        push_ring(&mut poly, polygon, start, end, i > 0, 0, options);
+    }
+
+    if options.hole_detection == HOLES_BY_WINDING {
+        resolve_winding(&mut poly);
+    } else if options.hole_detection == HOLES_BY_FILL_RULE {
+        // 全部环都作为同一部分的外环，点的环绕数是所有环之和（见扫描线和射线法的外环合并）
+        for ring in &mut poly.rings {
+            ring.is_hole = false;
+        }
+    }
+    assign_shells(&mut poly);
+    choose_grid(&mut poly, options.grid_resolution as usize);
+    poly
+}
+
+// 按绕向区分外环和洞：带符号面积为正（逆时针）的环是外环，每个外环自成一个组成部分；
+// 顺时针的环是洞，归属于包含它的面积最小的外环（处理岛中之湖等嵌套），不在任何外环内的顺时针环按外环处理
+fn resolve_winding(poly: &mut Polygon) {
+    let areas: Vec<f64> = poly.rings.iter().map(|ring| signed_area(&poly.edges[ring.start_idx..ring.start_idx + ring.edge_count])).collect();
+
+    let mut part_count = 0;
+    let mut outer_part = vec![None; poly.rings.len()];
+    for (i, &area) in areas.iter().enumerate() {
+        if area > 0.0 {
+            outer_part[i] = Some(part_count);
+            part_count += 1;
+        }
+    }
+
+    for i in 0..poly.rings.len() {
+        let part = match outer_part[i] {
+            Some(part) => Some(part),
+            None if poly.rings[i].edge_count == 0 => Some(0), // 丢弃的退化环没有边，不影响判断
+            None => {
+                // 用洞的第一条边的中点判断它位于哪些外环内
+                let edge = poly.edges[poly.rings[i].start_idx];
+                let (x, y) = ((edge.x1 + edge.x2) / 2.0, (edge.y1 + edge.y2) / 2.0);
+                (0..poly.rings.len())
+                    .filter(|&j| outer_part[j].is_some() && ring_contains(poly, j, x, y))
+                    .min_by(|&a, &b| areas[a].total_cmp(&areas[b]))
+                    .and_then(|j| outer_part[j])
+            }
+        };
+        let ring = &mut poly.rings[i];
+        match part {
+            Some(part) => {
+                ring.is_hole = outer_part[i].is_none() && ring.edge_count > 0;
+                ring.part = part;
+            }
+            None => {
+                ring.is_hole = false;
+                ring.part = part_count;
+                part_count += 1;
+            }
+        }
+    }
+    poly.part_count = part_count.max(1);
+}
+
+// 为每个洞确定它所挖去的外环：同一部分只有一个外环时就是该外环，
+// 有多个候选时取实际包含洞（以洞的第一条边的中点为准）的面积最小的外环，不比较边界框，边界框相同的外环也能区分
+fn assign_shells(poly: &mut Polygon) {
+    for i in 0..poly.rings.len() {
+        let ring = &poly.rings[i];
+        if !ring.is_hole || ring.edge_count == 0 {
+            continue;
+        }
+        let part = ring.part;
+        let candidates: Vec<usize> = (0..poly.rings.len()).filter(|&j| !poly.rings[j].is_hole && poly.rings[j].part == part).collect();
+        let shell = if candidates.len() == 1 {
+            Some(candidates[0])
+        } else {
+            let edge = poly.edges[ring.start_idx];
+            let (x, y) = ((edge.x1 + edge.x2) / 2.0, (edge.y1 + edge.y2) / 2.0);
+            let area = |j: usize| signed_area(&poly.edges[poly.rings[j].start_idx..poly.rings[j].start_idx + poly.rings[j].edge_count]).abs();
+            candidates
+                .into_iter()
+                .filter(|&j| ring_contains(poly, j, x, y))
+                .min_by(|&a, &b| area(a).total_cmp(&area(b)))
+        };
+        poly.rings[i].shell = shell;
+    }
+}
+
+// 环的带符号面积，逆时针（y轴向上）为正
+fn signed_area(edges: &[Edge]) -> f64 {
+    edges.iter().map(|e| e.x1 * e.y2 - e.x2 * e.y1).sum::<f64>() / 2.0
+}
+
+// 点是否在单个环内（奇偶规则，半开区间求交）
+fn ring_contains(poly: &Polygon, ring_idx: usize, x: f64, y: f64) -> bool {
+    let ring = &poly.rings[ring_idx];
+    let crossings = poly.edges[ring.start_idx..ring.start_idx + ring.edge_count]
+        .iter()
+        .filter(|e| scanline_crossing(e.x1, e.y1, e.x2, e.y2, y).is_some_and(|xi| xi < x))
+        .count();
+    crossings % 2 == 1
+}
+
+// 构建MultiPolygon：polygon_splits为第2个及之后每个组成部分的起始顶点索引，
+// rings为各部分内部洞的起始顶点索引（全局），每个部分的第一个环是它的外环，其余是它的洞
+pub(crate) fn build_multipolygon_with<C: Copy + Into<f64>>(
+    polygon: &[C],
+    polygon_splits: &[u32],
+    rings: &[u32],
+    options: &PipOptions,
+) -> Polygon {
+    let tolerance = if options.tolerance > 0.0 {
+        options.tolerance
+    } else {
+        RELATIVE_TOLERANCE * vertex_diagonal(polygon)
+    };
+    let mut poly = Polygon { edges: Vec::new(), rings: Vec::new(), bounds: Bounds::empty(), tolerance, part_count: 0, fill_rule: options.fill_rule, grid_cols: 1, grid_rows: 1, index_kind: options.index_kind, exact: options.exact, vertex_policy: options.vertex_policy };
+
+    for (start, end) in ring_ranges(polygon.len() / 2, polygon_splits) {
+        let part = poly.part_count;
+        poly.part_count += 1;
+
+        // 落在该部分内部的洞的起点，依次结束前一个环
+        let mut prev_idx = start;
+        let mut has_holes = false;
+        for split in rings.iter().map(|&r| r as usize).filter(|&r| r > start && r < end) {
+            push_ring(&mut poly, polygon, prev_idx, split, has_holes, part, options);
+            prev_idx = split;
+            has_holes = true;
+        }
+        if end > prev_idx + 1 {
+            push_ring(&mut poly, polygon, prev_idx, end, has_holes, part, options);
+        }
+    }
+
+    assign_shells(&mut poly);
+    choose_grid(&mut poly, options.grid_resolution as usize);
+    poly
+}
+
+// 确定空间网格的列数和行数：resolution大于0时使用resolution×resolution的网格，
+// 否则单元总数取边数，按边界框的长宽比分配，细长的多边形得到长方形的网格
+fn choose_grid(poly: &mut Polygon, resolution: usize) {
+    if resolution > 0 {
+        let n = resolution.min(MAX_GRID_DIM);
+        (poly.grid_cols, poly.grid_rows) = (n, n);
+        return;
+    }
+
+    let cells = poly.edges.len().clamp(1, MAX_GRID_CELLS) as f64;
+    let width = (poly.bounds.max_x - poly.bounds.min_x).max(0.0);
+    let height = (poly.bounds.max_y - poly.bounds.min_y).max(0.0);
+    let dim = |v: f64| (v.round() as usize).clamp(1, MAX_GRID_DIM);
+    (poly.grid_cols, poly.grid_rows) = if width > 0.0 && height > 0.0 {
+        let cols = dim((cells * width / height).sqrt());
+        (cols, dim(cells / cols as f64))
+    } else if width > 0.0 {
+        (dim(cells), 1)
+    } else if height > 0.0 {
+        (1, dim(cells))
+    } else {
+        (1, 1)
+    };
+}
+
+// 把顶点start..end组成的环加入多边形，相邻顶点连成边，最后一点连回第一点封闭环
+#[allow(clippy::too_many_arguments)]
+fn push_ring<C: Copy + Into<f64>>(poly: &mut Polygon, polygon: &[C], start: usize, end: usize, is_hole: bool, part: usize, options: &PipOptions) {
+    let start_idx = poly.edges.len();
+    let tolerance = poly.tolerance;
+    let mut bounds = Bounds::empty();
+    let vertex = |k: usize| options.axes(polygon[k * 2].into(), polygon[k * 2 + 1].into());
+    let same = |(x1, y1): (f64, f64), (x2, y2): (f64, f64)| (x1 - x2).abs() <= tolerance && (y1 - y2).abs() <= tolerance;
+
+    // 很多数据源在环的末尾重复第一个顶点，显式去掉这个重复的闭合顶点，环仍会自动闭合
+    let closing_duplicate = end > start + 1 && same(vertex(end - 1), vertex(start));
+    let end = if closing_duplicate { end - 1 } else { end };
+
+    let mut add_edge = |vertex: usize, (x1, y1): (f64, f64), (x2, y2): (f64, f64)| {
+        // 忽略退化边（长度接近0的边）
+        if same((x1, y1), (x2, y2)) {
+            return;
+        }
+        poly.edges.push(Edge { x1, y1, x2, y2, vertex });
+        bounds.extend(&Bounds { min_x: x1.min(x2), min_y: y1.min(y2), max_x: x1.max(x2), max_y: y1.max(y2) });
+    };
+
+    // 沿大圆加密时，一条输入边拆成若干段，各段仍记录同一个起点顶点
+    let step = options.great_arc_step;
+    let mut add_arc = |vertex: usize, from: (f64, f64), to: (f64, f64)| {
+        if step > 0.0 {
+            let mut previous = from;
+            for next in great_arc_points(from, to, step) {
+                add_edge(vertex, previous, next);
+                previous = next;
+            }
+        } else {
+            add_edge(vertex, from, to);
+        }
+    };
+
+    for k in start..end.saturating_sub(1) {
+        add_arc(k, vertex(k), vertex(k + 1));
+    }
+    if end > start + 1 {
+        add_arc(end - 1, vertex(end - 1), vertex(start));
+    }
+
+    let degeneracy = ring_degeneracy(&poly.edges[start_idx..]);
+    let boundary_only = degeneracy.is_some() && options.degenerate_rings == DEGENERATE_RINGS_BOUNDARY;
+    if degeneracy.is_some() && !boundary_only {
+        // 丢弃退化环的边，保留空环占据环索引
+        poly.edges.truncate(start_idx);
+        bounds = Bounds::empty();
+    }
+
+    poly.bounds.extend(&bounds);
+    poly.rings.push(Ring {
+        start_idx,
+        edge_count: poly.edges.len() - start_idx,
+        is_hole,
+        part,
+        shell: None,
+        bounds,
+        boundary_only,
+        degeneracy,
+        closing_duplicate,
+    });
+}
+
+// 经纬度(lon, lat)之间大圆弧上的加密点，每段的角距离不超过step度（段数不超过MAX_ARC_SEGMENTS），不含起点、含终点；
+// 经度相对起点连续展开（不在±180处跳变）；两端经度相差超过180度时大圆弧跨越反经线，与平面模式下输入边的走向相反，
+// 两端点重合或互为对跖点时大圆不唯一，这些情况都保持原来的直线边，直接返回终点
+pub(crate) fn great_arc_points((lon1, lat1): (f64, f64), (lon2, lat2): (f64, f64), step: f64) -> Vec<(f64, f64)> {
+    if (lon2 - lon1).abs() > 180.0 {
+        return vec![(lon2, lat2)];
+    }
+    let unit = |lon: f64, lat: f64| {
+        let (lon, lat) = (lon.to_radians(), lat.to_radians());
+        [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+    };
+    let (a, b) = (unit(lon1, lat1), unit(lon2, lat2));
+    let dot = (a[0] * b[0] + a[1] * b[1] + a[2] * b[2]).clamp(-1.0, 1.0);
+    let angle = dot.acos();
+    let segments = (angle.to_degrees() / step).ceil();
+    if !segments.is_finite() || segments <= 1.0 || angle.sin() < 1e-12 {
+        return vec![(lon2, lat2)];
+    }
+
+    let segments = (segments as usize).min(MAX_ARC_SEGMENTS);
+    let mut arc = Vec::with_capacity(segments);
+    for k in 1..segments {
+        // 球面线性插值
+        let t = k as f64 / segments as f64;
+        let (wa, wb) = (((1.0 - t) * angle).sin() / angle.sin(), (t * angle).sin() / angle.sin());
+        let p = [wa * a[0] + wb * b[0], wa * a[1] + wb * b[1], wa * a[2] + wb * b[2]];
+        let lat = p[2].clamp(-1.0, 1.0).asin().to_degrees();
+        let lon = p[1].atan2(p[0]).to_degrees();
+        let lon = lon1 + (lon - lon1 + 180.0).rem_euclid(360.0) - 180.0;
+        arc.push((lon, lat));
+    }
+    arc.push((lon2, lat2));
+    arc
+}
+
+// 全部顶点边界框的对角线长度，忽略非有限坐标
+pub(crate) fn vertex_diagonal<C: Copy + Into<f64>>(polygon: &[C]) -> f64 {
+    let mut bounds = Bounds::empty();
+    for vertex in polygon.chunks_exact(2) {
+        let (x, y): (f64, f64) = (vertex[0].into(), vertex[1].into());
+        if x.is_finite() && y.is_finite() {
+            bounds.extend(&Bounds { min_x: x, min_y: y, max_x: x, max_y: y });
+        }
+    }
+    bounds.diagonal()
+}
+
+// 判断一个环（已去掉退化边）是否退化
+pub(crate) fn ring_degeneracy(edges: &[Edge]) -> Option<Degeneracy> {
+    // 按位比较坐标，加0.0把-0.0规范为0.0
+    let bits = |v: f64| (v + 0.0).to_bits();
+
+    // 每条边的起点即环的全部顶点
+    let mut vertices: Vec<(u64, u64)> = edges.iter().map(|e| (bits(e.x1), bits(e.y1))).collect();
+    vertices.sort_unstable();
+    vertices.dedup();
+    if vertices.len() < 3 {
+        return Some(Degeneracy::TooFewVertices);
+    }
+
+    // 以离第一个顶点最远的顶点确定方向，其余顶点到该直线的距离都可忽略时共线
+    let (ax, ay) = (edges[0].x1, edges[0].y1);
+    let (bx, by) = edges
+        .iter()
+        .map(|e| (e.x1, e.y1))
+        .max_by(|p, q| {
+            let dp = (p.0 - ax).powi(2) + (p.1 - ay).powi(2);
+            let dq = (q.0 - ax).powi(2) + (q.1 - ay).powi(2);
+            dp.total_cmp(&dq)
+        })
+        .unwrap();
+    let length = ((bx - ax).powi(2) + (by - ay).powi(2)).sqrt();
+    let collinear = edges.iter().all(|e| {
+        let cross = (bx - ax) * (e.y1 - ay) - (by - ay) * (e.x1 - ax);
+        cross.abs() <= RELATIVE_TOLERANCE * length * length
+    });
+    if collinear {
+        return Some(Degeneracy::Collinear);
+    }
+
+    // 每条边都有一条反向的边与之抵消时，环沿原路折返，不围成区域
+    let key = |x1: f64, y1: f64, x2: f64, y2: f64| (bits(x1), bits(y1), bits(x2), bits(y2));
+    let mut forward: Vec<_> = edges.iter().map(|e| key(e.x1, e.y1, e.x2, e.y2)).collect();
+    let mut backward: Vec<_> = edges.iter().map(|e| key(e.x2, e.y2, e.x1, e.y1)).collect();
+    forward.sort_unstable();
+    backward.sort_unstable();
+    if forward == backward {
+        return Some(Degeneracy::ZeroArea);
+    }
+
+    None
+}
+
+// 构建空间网格索引：将多边形的边分配到网格单元中，用于加速空间查询
+// 每条边登记到与它（按容差加宽后）相交的全部单元，因此距离某条边不超过容差的点，所在单元一定登记了这条边
+pub(crate) fn build_grid(poly: &Polygon) -> Vec<Vec<GridCell>> {
+    // 创建网格
+    let mut grid = vec![vec![GridCell { edge_indices: Vec::new() }; poly.grid_rows]; poly.grid_cols];
+    
+    // 将所有边添加到相应的网格单元中
+    for (edge_idx, edge) in poly.edges.iter().enumerate() {
+        for (gx, gy) in get_grid_cells(poly, edge) {
+            grid[gx][gy].edge_indices.push(edge_idx);
+        }
+    }
+    
+    grid
+}
+
+// 边索引：按点查询附近的边，供边界检测使用；默认为均匀网格，边的疏密差异很大时可选四叉树（PipOptions.set_index_kind）
+pub(crate) enum EdgeIndex {
+    Grid(Vec<Vec<GridCell>>),
+    Quadtree(EdgeQuadtree),
+}
+
+impl EdgeIndex {
+    // 点(x, y)附近的边：距离点不超过容差的边都在其中
+    pub(crate) fn edges_at(&self, poly: &Polygon, x: f64, y: f64) -> &[usize] {
+        match self {
+            EdgeIndex::Grid(grid) => {
+                let (gx, gy) = grid_cell_of(poly, x, y);
+                &grid[gx][gy].edge_indices
+            }
+            EdgeIndex::Quadtree(tree) => tree.edges_at(x, y),
+        }
+    }
+
+    // 与矩形b相交的网格单元（叶节点）登记的边依次交给hit，hit返回true时停止并返回true；同一条边可能被访问多次
+    pub(crate) fn any_edge_in(&self, poly: &Polygon, b: &Bounds, mut hit: impl FnMut(usize) -> bool) -> bool {
+        match self {
+            EdgeIndex::Grid(grid) => {
+                let (c0, r0) = grid_cell_of(poly, b.min_x, b.min_y);
+                let (c1, r1) = grid_cell_of(poly, b.max_x, b.max_y);
+                (c0..=c1).any(|c| (r0..=r1).any(|r| grid[c][r].edge_indices.iter().any(|&e| hit(e))))
+            }
+            EdgeIndex::Quadtree(tree) => tree.any_edge_in(b, hit),
+        }
+    }
+}
+
+// 按多边形的选项构建边索引
+pub(crate) fn build_edge_index(poly: &Polygon) -> EdgeIndex {
+    if poly.index_kind == INDEX_QUADTREE {
+        EdgeIndex::Quadtree(EdgeQuadtree::new(poly))
+    } else {
+        EdgeIndex::Grid(build_grid(poly))
+    }
+}
+
+// 边(x1, y1)-(x2, y2)是否与高度为y的水平扫描线相交（半开区间规则），浮点和整数坐标共用
+#[inline]
+pub(crate) fn crosses_scanline<T: PartialOrd>(y1: T, y2: T, y: T) -> bool {
+    (y1 > y) != (y2 > y)
+}
+
+// 按顶点策略判断边是否与扫描线相交：默认规则同crosses_scanline，VERTEX_ABOVE时只有 min_y < y <= max_y 的边相交
+#[inline]
+pub(crate) fn crosses_scanline_with<T: PartialOrd>(y1: T, y2: T, y: T, vertex_policy: u32) -> bool {
+    if vertex_policy == VERTEX_ABOVE {
+        (y1 >= y) != (y2 >= y)
+    } else {
+        crosses_scanline(y1, y2, y)
+    }
+}
+
+// 边与扫描线的交点x坐标，边不相交时返回None
+#[inline]
+pub(crate) fn scanline_crossing(x1: f64, y1: f64, x2: f64, y2: f64, y: f64) -> Option<f64> {
+    scanline_crossing_with(x1, y1, x2, y2, y, 0)
+}
+
+// 按顶点策略求边与扫描线的交点x坐标
+#[inline]
+pub(crate) fn scanline_crossing_with(x1: f64, y1: f64, x2: f64, y2: f64, y: f64, vertex_policy: u32) -> Option<f64> {
+    if !crosses_scanline_with(y1, y2, y, vertex_policy) {
+        return None;
+    }
+    // 端点恰好在扫描线上时直接返回端点，避免插值的舍入误差
+    if y1 == y {
+        return Some(x1);
+    }
+    if y2 == y {
+        return Some(x2);
+    }
+    let t = (y - y1) / (y2 - y1);
+    Some(x1 + t * (x2 - x1))
+}
+
+// 一组边与扫描线的全部交点，按边的顺序对每个交点调用emit(边在切片中的位置, 交点x坐标)
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+#[inline]
+pub(crate) fn for_each_crossing<F: FnMut(usize, f64)>(edges: &[Edge], y: f64, vertex_policy: u32, mut emit: F) {
+    for (k, edge) in edges.iter().enumerate() {
+        if let Some(x) = scanline_crossing_with(edge.x1, edge.y1, edge.x2, edge.y2, y, vertex_policy) {
+            emit(k, x);
+        }
+    }
+}
+
+// for_each_crossing的SIMD版本：每次循环检测4条边（两个f64x2），运算顺序与scanline_crossing相同，交点逐位一致
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+pub(crate) fn for_each_crossing<F: FnMut(usize, f64)>(edges: &[Edge], y: f64, vertex_policy: u32, mut emit: F) {
+    use core::arch::wasm32::*;
+
+    let above = vertex_policy == VERTEX_ABOVE;
+
+    let yv = f64x2_splat(y);
+    let blocks = edges.len() / 4;
+    for block in 0..blocks {
+        for pair in [block * 4, block * 4 + 2] {
+            let (a, b) = (&edges[pair], &edges[pair + 1]);
+            let (y1, y2) = (f64x2(a.y1, b.y1), f64x2(a.y2, b.y2));
+            // 半开区间规则：两个端点分居扫描线两侧（按顶点策略决定恰好在扫描线上的端点算哪一侧）
+            let side = |v| if above { f64x2_ge(v, yv) } else { f64x2_gt(v, yv) };
+            let crossing = i64x2_bitmask(v128_xor(side(y1), side(y2)));
+            if crossing == 0 {
+                continue;
+            }
+            let (x1, x2) = (f64x2(a.x1, b.x1), f64x2(a.x2, b.x2));
+            let t = f64x2_div(f64x2_sub(yv, y1), f64x2_sub(y2, y1));
+            let x = f64x2_add(x1, f64x2_mul(t, f64x2_sub(x2, x1)));
+            // 端点恰好在扫描线上时取端点，起点优先
+            let x = v128_bitselect(x2, x, f64x2_eq(y2, yv));
+            let x = v128_bitselect(x1, x, f64x2_eq(y1, yv));
+            if crossing & 1 != 0 {
+                emit(pair, f64x2_extract_lane::<0>(x));
+            }
+            if crossing & 2 != 0 {
+                emit(pair + 1, f64x2_extract_lane::<1>(x));
+            }
+        }
+    }
+    for (k, edge) in edges.iter().enumerate().skip(blocks * 4) {
+        if let Some(x) = scanline_crossing_with(edge.x1, edge.y1, edge.x2, edge.y2, y, vertex_policy) {
+            emit(k, x);
+        }
+    }
+}
+
+// 按填充规则由环绕数判断点是否在环内
+#[inline]
+pub(crate) fn fills(fill_rule: u32, winding: i32) -> bool {
+    if fill_rule == FILL_NONZERO { winding != 0 } else { winding % 2 != 0 }
+}
+
+// 网格单元的尺寸，多边形退化为线段或点时取最小正数避免除以0
+#[inline]
+fn cell_size(poly: &Polygon) -> (f64, f64) {
+    let cell_w = ((poly.bounds.max_x - poly.bounds.min_x) / poly.grid_cols as f64).max(f64::MIN_POSITIVE);
+    let cell_h = ((poly.bounds.max_y - poly.bounds.min_y) / poly.grid_rows as f64).max(f64::MIN_POSITIVE);
+    (cell_w, cell_h)
+}
+
+// 坐标所在的网格单元（列, 行），网格范围外的坐标夹到边缘单元
+#[inline]
+pub(crate) fn grid_cell_of(poly: &Polygon, x: f64, y: f64) -> (usize, usize) {
+    let (cell_w, cell_h) = cell_size(poly);
+    let gx = ((x - poly.bounds.min_x) / cell_w).floor().clamp(0.0, (poly.grid_cols - 1) as f64) as usize;
+    let gy = ((y - poly.bounds.min_y) / cell_h).floor().clamp(0.0, (poly.grid_rows - 1) as f64) as usize;
+    (gx, gy)
+}
+
+// 计算边经过的网格单元（保守覆盖）：逐列求出边落在该列（按容差加宽）内的部分的y范围，再标记该范围内的行
+fn get_grid_cells(poly: &Polygon, edge: &Edge) -> Vec<(usize, usize)> {
+    let (cell_w, cell_h) = cell_size(poly);
+    // 加宽量：容差加上抵消单元索引舍入误差的极小量
+    let margin = poly.tolerance + cell_w.max(cell_h) * 1e-9;
+    let (x1, y1, x2, y2) = (edge.x1, edge.y1, edge.x2, edge.y2);
+
+    let (c0, _) = grid_cell_of(poly, x1.min(x2) - margin, 0.0);
+    let (c1, _) = grid_cell_of(poly, x1.max(x2) + margin, 0.0);
+    let mut cells = Vec::new();
+
+    for c in c0..=c1 {
+        // 该列加宽后的x区间对应的边参数范围
+        let col_x0 = poly.bounds.min_x + c as f64 * cell_w - margin;
+        let col_x1 = poly.bounds.min_x + (c + 1) as f64 * cell_w + margin;
+        let (t0, t1) = if x2 != x1 {
+            let ta = ((col_x0 - x1) / (x2 - x1)).clamp(0.0, 1.0);
+            let tb = ((col_x1 - x1) / (x2 - x1)).clamp(0.0, 1.0);
+            (ta.min(tb), ta.max(tb))
+        } else {
+            (0.0, 1.0)
+        };
+        let (ya, yb) = (y1 + t0 * (y2 - y1), y1 + t1 * (y2 - y1));
+
+        let (_, r0) = grid_cell_of(poly, 0.0, ya.min(yb) - margin);
+        let (_, r1) = grid_cell_of(poly, 0.0, ya.max(yb) + margin);
+        cells.extend((r0..=r1).map(|r| (c, r)));
+    }
+
+    cells
+}
+
+// 线段(x0, y0)-(x1, y1)依次经过的网格单元（列, 行, 离开该单元时的线段参数t∈[0, 1]），按从起点到终点的顺序，
+// 逐单元前进（Amanatides-Woo遍历）；网格范围外的部分夹到边缘单元，与建网格时边的登记方式一致
+pub(crate) fn line_to_grid_cells(poly: &Polygon, x0: f64, y0: f64, x1: f64, y1: f64) -> Vec<(usize, usize, f64)> {
+    let (cell_w, cell_h) = cell_size(poly);
+    let (dx, dy) = (x1 - x0, y1 - y0);
+    let (mut gx, mut gy) = grid_cell_of(poly, x0, y0);
+    let (end_x, end_y) = grid_cell_of(poly, x1, y1);
+
+    // 沿每个方向跨过一个单元所需的参数增量，以及到达下一条网格线时的参数
+    let axis = |d: f64, start: f64, origin: f64, size: f64, cell: usize| -> (f64, f64) {
+        if d > 0.0 {
+            ((origin + (cell + 1) as f64 * size - start) / d, size / d)
+        } else if d < 0.0 {
+            ((origin + cell as f64 * size - start) / d, -size / d)
+        } else {
+            (f64::INFINITY, f64::INFINITY)
+        }
+    };
+    let (mut next_x, delta_x) = axis(dx, x0, poly.bounds.min_x, cell_w, gx);
+    let (mut next_y, delta_y) = axis(dy, y0, poly.bounds.min_y, cell_h, gy);
+
+    let mut cells = Vec::new();
+    loop {
+        let exit = next_x.min(next_y).clamp(0.0, 1.0);
+        cells.push((gx, gy, exit));
+        if (gx, gy) == (end_x, end_y) || exit >= 1.0 {
+            break;
+        }
+        // 越过网格边缘后该方向不再前进（夹到边缘单元）
+        if next_x <= next_y {
+            match (dx > 0.0, gx) {
+                (true, x) if x + 1 < poly.grid_cols => gx += 1,
+                (false, x) if x > 0 => gx -= 1,
+                _ => {}
+            }
+            next_x += delta_x;
+        } else {
+            match (dy > 0.0, gy) {
+                (true, y) if y + 1 < poly.grid_rows => gy += 1,
+                (false, y) if y > 0 => gy -= 1,
+                _ => {}
+            }
+            next_y += delta_y;
+        }
+    }
+    cells
+}
+
+// 检查点是否在任何边上（距离不超过容差）：借助空间网格，每个点只需检查少量边
+pub(crate) fn is_point_on_edge(poly: &Polygon, index: &EdgeIndex, x: f64, y: f64) -> bool {
+    let tol = poly.tolerance;
+    // 粗略的边界框排除：距离多边形边界框超过容差的点不可能在边上
+    if x < poly.bounds.min_x - tol || x > poly.bounds.max_x + tol || y < poly.bounds.min_y - tol || y > poly.bounds.max_y + tol {
+        return false;
+    }
+
+    // 只检查边索引中点附近的边
+    index.edges_at(poly, x, y).iter().any(|&edge_idx| edge_contains_point(poly, &poly.edges[edge_idx], x, y))
+}
+
+// 点所在的全部环（点在该环的某条边上），按环索引递增、不重复地写入out
+pub(crate) fn boundary_rings(poly: &Polygon, index: &EdgeIndex, x: f64, y: f64, out: &mut Vec<usize>) {
+    out.clear();
+    let tol = poly.tolerance;
+    if x < poly.bounds.min_x - tol || x > poly.bounds.max_x + tol || y < poly.bounds.min_y - tol || y > poly.bounds.max_y + tol {
+        return;
+    }
+    for &edge_idx in index.edges_at(poly, x, y) {
+        if edge_contains_point(poly, &poly.edges[edge_idx], x, y) {
+            out.push(ring_of_edge(poly, edge_idx));
+        }
+    }
+    out.sort_unstable();
+    out.dedup();
+}
+
+// 边所在的环：各环的边在edges中按环的顺序连续存放
+#[inline]
+fn ring_of_edge(poly: &Polygon, edge_idx: usize) -> usize {
+    poly.rings.partition_point(|ring| ring.start_idx + ring.edge_count <= edge_idx)
+}
+
+// 点是否在单条边上（距离不超过容差；精确模式下恰好落在边上）
+fn edge_contains_point(poly: &Polygon, edge: &Edge, x: f64, y: f64) -> bool {
+    // 精确模式：只有恰好落在边上的点（与边精确共线且在边的范围内）在边上，不使用距离容差
+    if poly.exact {
+        return on_segment_exact(edge, x, y);
+    }
+    let tol = poly.tolerance;
+
+    // 快速边界框检查：如果点不在边的边界框内，跳过
+    let min_x = edge.x1.min(edge.x2) - tol;
+    let max_x = edge.x1.max(edge.x2) + tol;
+    let min_y = edge.y1.min(edge.y2) - tol;
+    let max_y = edge.y1.max(edge.y2) + tol;
+    
+    if x < min_x || x > max_x || y < min_y || y > max_y {
+        return false;
+    }
+    
+    // 计算点到线段的距离
+    let dx = edge.x2 - edge.x1;
+    let dy = edge.y2 - edge.y1;
+    let len_sq = dx * dx + dy * dy;
+    
+    // 处理退化为点的边
+    if len_sq < tol * tol {
+        return (x - edge.x1).abs() < tol && (y - edge.y1).abs() < tol;
+    }
+    
+    // 计算点到线段的投影参数t
+    // 当t在[0,1]范围内时，投影点在线段上
+    let t = ((x - edge.x1) * dx + (y - edge.y1) * dy) / len_sq;
+    
+    if !(0.0..=1.0).contains(&t) {
+        return false; // 投影点不在线段上
+    }
+    
+    // 计算投影点坐标和到原点的距离
+    let px = edge.x1 + t * dx;
+    let py = edge.y1 + t * dy;
+    let dist_sq = (x - px) * (x - px) + (y - py) * (y - py);
+    
+    // 如果距离小于阈值，认为点在边上
+    dist_sq <= tol * tol
+}
+
+// 点是否恰好落在线段上：坐标比较和方向判定都是精确的
+#[inline]
+fn on_segment_exact(edge: &Edge, x: f64, y: f64) -> bool {
+    x >= edge.x1.min(edge.x2)
+        && x <= edge.x1.max(edge.x2)
+        && y >= edge.y1.min(edge.y2)
+        && y <= edge.y1.max(edge.y2)
+        && orient2d(edge.x1, edge.y1, edge.x2, edge.y2, x, y) == Ordering::Equal
+}
+
+// 检查点是否在边界框内：快速过滤点
+#[inline]
+pub(crate) fn point_in_bounds(x: f64, y: f64, bounds: &Bounds) -> bool {
+    bounds.contains(x, y, 0.0)
+}
+
+// 按扫描线（量化后的y坐标）缓存交点列表，容量固定，满时按时钟（second chance）策略淘汰：
+// 每条扫描线带一个访问标记，命中时置位；淘汰时指针依次扫过各条目，遇到置位的条目清除标记并跳过，
+// 淘汰第一个未置位的条目。近期反复命中的行（交互式会话中光标所在的行）不会被整批清除
+pub(crate) struct RowCache<V> {
+    capacity: usize,
+    slots: HashMap<i64, usize>,      // 键到条目下标
+    entries: Vec<(i64, V, bool)>,    // (键, 交点列表, 访问标记)
+    hand: usize,                     // 时钟指针
+}
+
+impl<V> RowCache<V> {
+    // 容量为0时按1处理
+    pub(crate) fn new(capacity: usize) -> RowCache<V> {
+        RowCache { capacity: capacity.max(1), slots: HashMap::new(), entries: Vec::new(), hand: 0 }
+    }
+
+    // 取出key对应的交点列表，未缓存时由compute计算后放入缓存（必要时先淘汰一条）
+    pub(crate) fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: i64, compute: F) -> &mut V {
+        if let Some(&slot) = self.slots.get(&key) {
+            self.entries[slot].2 = true;
+            return &mut self.entries[slot].1;
+        }
+        let value = compute();
+        let slot = if self.entries.len() < self.capacity {
+            self.entries.push((key, value, false));
+            self.entries.len() - 1
+        } else {
+            while self.entries[self.hand].2 {
+                self.entries[self.hand].2 = false;
+                self.hand = (self.hand + 1) % self.capacity;
+            }
+            let slot = self.hand;
+            self.slots.remove(&self.entries[slot].0);
+            self.entries[slot] = (key, value, false);
+            self.hand = (self.hand + 1) % self.capacity;
+            slot
+        };
+        self.slots.insert(key, slot);
+        &mut self.entries[slot].1
+    }
+}
@@ -0,0 +1,434 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::certified::point_in_polygon_certified;
+    use crate::points_in_polygon::common::{build_edge_index, build_grid, great_arc_points, Bounds, MAX_ARC_SEGMENTS, build_polygon, build_polygon_with, crosses_scanline, crosses_scanline_with, for_each_crossing, is_point_on_edge, polygon_edges, ring_edges, ring_ranges, scanline_crossing, scanline_crossing_with, split_index, RowCache};
+    use crate::generators::{random_simple_polygon, uniform_points};
+    use crate::points_in_polygon::integer::point_in_polygon_i64;
+    use crate::points_in_polygon::options::{point_in_polygon_with_options, PipOptions};
+    use crate::points_in_polygon::rayster::{point_in_polygon_rayster, point_in_polygon_rayster_with_options};
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+    use crate::points_in_polygon::winding::{point_in_polygon_winding, point_in_polygon_winding_with_options};
+
+    #[test]
+    fn test_horizontal_edge_never_crosses() {
+        // 与水平边等高、低于、高于的扫描线
+        for y in [0.5, 1.0, 1.5] {
+            assert!(!crosses_scanline(1.0, 1.0, y));
+            assert_eq!(scanline_crossing(0.0, 1.0, 2.0, 1.0, y), None);
+        }
+    }
+
+    #[test]
+    fn test_vertex_counts_as_below() {
+        // 经过顶点(1, 1)的扫描线：从顶点向上的边计数，从下方到达顶点的边不计
+        assert_eq!(scanline_crossing(1.0, 1.0, 1.0, 2.0, 1.0), Some(1.0));
+        assert_eq!(scanline_crossing(1.0, 0.0, 1.0, 1.0, 1.0), None);
+        // 与之相邻的一对边（一上一下）合计恰好一次
+        let up = scanline_crossing(0.0, 0.0, 1.0, 1.0, 1.0).is_some() as u32;
+        let next = scanline_crossing(1.0, 1.0, 2.0, 2.0, 1.0).is_some() as u32;
+        assert_eq!(up + next, 1);
+        // 尖峰顶点（两边都在下方）合计零次，尖谷顶点（两边都在上方）合计两次
+        let peak = scanline_crossing(0.0, 0.0, 1.0, 1.0, 1.0).is_some() as u32
+            + scanline_crossing(1.0, 1.0, 2.0, 0.0, 1.0).is_some() as u32;
+        let valley = scanline_crossing(0.0, 2.0, 1.0, 1.0, 1.0).is_some() as u32
+            + scanline_crossing(1.0, 1.0, 2.0, 2.0, 1.0).is_some() as u32;
+        assert_eq!((peak, valley), (0, 2));
+    }
+
+    #[test]
+    fn test_crossing_interpolation() {
+        assert_eq!(scanline_crossing(0.0, 0.0, 4.0, 4.0, 1.0), Some(1.0));
+        assert_eq!(scanline_crossing(4.0, 4.0, 0.0, 0.0, 3.0), Some(3.0));
+    }
+
+    #[test]
+    fn test_build_polygon_rings() {
+        let polygon = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // 外环
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // 洞
+        ];
+
+        // 最后一个拆分之后的顶点组成洞
+        let poly = build_polygon(&polygon, &[4]);
+        assert_eq!(poly.rings.len(), 2);
+        assert!(!poly.rings[0].is_hole && poly.rings[1].is_hole);
+        assert_eq!((poly.rings[1].start_idx, poly.rings[1].edge_count), (4, 4));
+        assert_eq!((poly.bounds.min_x, poly.bounds.max_y), (0.0, 3.0));
+        assert_eq!((poly.rings[1].bounds.min_x, poly.rings[1].bounds.max_x), (1.0, 2.0));
+
+        // 拆分落在末尾时没有末尾的环，没有拆分时全部顶点组成外环
+        assert_eq!(build_polygon(&polygon[..8], &[4]).rings.len(), 1);
+        let poly = build_polygon(&polygon[..8], &[]);
+        assert_eq!(poly.rings.len(), 1);
+        assert!(!poly.rings[0].is_hole);
+        assert_eq!(poly.rings[0].edge_count, 4);
+    }
+
+    #[test]
+    fn test_build_polygon_skips_degenerate_edges() {
+        // 首尾重复的顶点和连续重复的顶点都不产生边
+        let polygon = vec![0.0, 0.0, 3.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 0.0];
+        let poly = build_polygon(&polygon, &[5]);
+        assert_eq!(poly.edges.len(), 3);
+        assert!(poly.rings[0].closing_duplicate);
+    }
+
+    #[test]
+    fn test_closed_and_open_rings_build_same_edges() {
+        let open = vec![0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, 1.0, 1.0, 2.0, 1.0, 1.0, 2.0];
+        let closed = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, 0.0, 0.0, // 外环
+            1.0, 1.0, 2.0, 1.0, 1.0, 2.0, 1.0, 1.0, // 洞
+        ];
+        let a = build_polygon(&open, &[4]);
+        let b = build_polygon(&closed, &[5]);
+
+        let edges = |p: &crate::points_in_polygon::common::Polygon| {
+            p.edges.iter().map(|e| [e.x1, e.y1, e.x2, e.y2]).collect::<Vec<_>>()
+        };
+        assert_eq!(edges(&a), edges(&b));
+        assert_eq!(b.rings.iter().map(|r| r.closing_duplicate).collect::<Vec<_>>(), vec![true, true]);
+        assert!(!a.rings[0].closing_duplicate);
+    }
+
+    #[test]
+    fn test_algorithms_agree_at_vertex_level() {
+        // 顶部带凹口的多边形，第一个顶点重复一次，使环的第一条边作为退化边被丢弃
+        let polygon = vec![0.0, 0.0, 0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 2.0, 2.0, 0.0, 4.0];
+        // 与各顶点等高、但不在边界上的点
+        let points = vec![
+            1.0, 2.0, // 与凹口顶点等高，内部
+            3.5, 2.0, // 同上，内部
+            2.0, 3.0, // 凹口内，外部
+            0.5, 3.0, // 左侧尖角内，内部
+            5.0, 2.0, // 右侧外部
+            -1.0, 2.0, // 左侧外部
+            1.0, 4.0, // 与顶部两个顶点等高，外部
+            -1.0, 0.0, // 与底边等高，外部
+            5.0, 4.0, // 与右上顶点等高，外部
+        ];
+        let expected = vec![1, 1, 0, 1, 0, 0, 0, 0, 0];
+
+        for polygon in [&polygon[..], &polygon[2..]] {
+            let rings = [(polygon.len() / 2) as u32];
+            assert_eq!(point_in_polygon_scanline(&points, polygon, &rings, false), expected);
+            assert_eq!(point_in_polygon_rayster(&points, polygon, &rings, false), expected);
+            assert_eq!(point_in_polygon_winding(&points, polygon, &rings, false), expected);
+            assert_eq!(point_in_polygon_certified(&points, polygon, &rings), expected);
+
+            // 整数版本把坐标放大2倍，使所有坐标都是整数
+            let to_i64 = |v: &[f32]| v.iter().map(|&c| (c * 2.0) as i64).collect::<Vec<_>>();
+            assert_eq!(point_in_polygon_i64(&to_i64(&points), &to_i64(polygon), &rings, false), expected);
+        }
+    }
+
+    #[test]
+    fn test_grid_on_edge_covers_every_edge() {
+        // 随机多边形的每条边上取若干点，并沿法向偏移半个容差，网格辅助的检测都应判为在边上
+        let polygon = random_simple_polygon(0.0, 0.0, 100.0, 300, 7);
+        let poly = build_polygon(&polygon, &[300]);
+        let index = build_edge_index(&poly);
+        let tol = poly.tolerance;
+
+        for edge in &poly.edges {
+            let (dx, dy) = (edge.x2 - edge.x1, edge.y2 - edge.y1);
+            let len = (dx * dx + dy * dy).sqrt();
+            let (nx, ny) = (-dy / len, dx / len);
+            for k in 1..16 {
+                let t = k as f64 / 16.0; // 端点附近的判定由相邻边的投影决定，这里只取边的内部
+                let (x, y) = (edge.x1 + t * dx, edge.y1 + t * dy);
+                for offset in [0.0, 0.5 * tol, -0.5 * tol] {
+                    assert!(is_point_on_edge(&poly, &index, x + offset * nx, y + offset * ny));
+                }
+            }
+        }
+
+        // 远离所有边的点
+        assert!(!is_point_on_edge(&poly, &index, 0.0, 0.0));
+        assert!(!is_point_on_edge(&poly, &index, 500.0, 500.0));
+    }
+
+    #[test]
+    fn test_rayster_detects_boundary_on_every_ring() {
+        // 洞的边和斜边上的点同样按边界处理
+        let polygon = vec![
+            0.0, 0.0, 8.0, 0.0, 8.0, 8.0, 0.0, 8.0, // 外环
+            2.0, 2.0, 6.0, 2.0, 4.0, 6.0, // 三角形洞
+        ];
+        let points = vec![
+            4.0, 2.0, // 洞的底边
+            5.0, 4.0, // 洞的斜边
+            3.0, 4.0, // 洞的另一条斜边
+            8.0, 5.0, // 外环右边
+            4.0, 3.0, // 洞内
+            1.0, 1.0, // 内部
+        ];
+        assert_eq!(point_in_polygon_rayster(&points, &polygon, &[4], true), vec![1, 1, 1, 1, 0, 1]);
+        assert_eq!(point_in_polygon_rayster(&points, &polygon, &[4], false), vec![0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_hole_shells_by_containment() {
+        // 按顺序：洞超出外环的边界框，仍然挖去外环
+        let polygon = vec![
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 外环
+            5.0, 2.0, 15.0, 2.0, 15.0, 8.0, 5.0, 8.0, // 洞，右半部分在外环外
+        ];
+        assert_eq!(build_polygon(&polygon, &[4]).rings[1].shell, Some(0));
+        let points = vec![7.0, 5.0, 2.0, 5.0, 12.0, 5.0];
+        assert_eq!(point_in_polygon_rayster(&points, &polygon, &[4], true), vec![0, 1, 0]);
+        assert_eq!(point_in_polygon_scanline(&points, &polygon, &[4], true), vec![0, 1, 0]);
+
+        // 按绕向：三角形和正方形的边界框相同，洞只在正方形内
+        let polygon = vec![
+            0.0, 0.0, 10.0, 0.0, 0.0, 10.0, // 三角形，逆时针
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 正方形，逆时针
+            7.0, 7.0, 7.0, 9.0, 9.0, 9.0, 9.0, 7.0, // 洞，顺时针
+        ];
+        let rings = [3, 7];
+        let mut options = PipOptions::new();
+        options.set_hole_detection(1);
+        let poly = build_polygon_with(&polygon, &rings, &options);
+        assert_eq!(poly.rings.iter().map(|r| r.shell).collect::<Vec<_>>(), vec![None, None, Some(1)]);
+
+        let points = vec![8.0, 8.0, 2.0, 2.0, 6.0, 6.0];
+        assert_eq!(point_in_polygon_rayster_with_options(&points, &polygon, &rings, &options), vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn test_grid_adapts_to_edges_and_aspect() {
+        // 4条边的正方形只需2×2个单元
+        let square = build_polygon(&[0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0], &[4]);
+        assert_eq!((square.grid_cols, square.grid_rows), (2, 2));
+
+        // 宽是高的16倍、共64条边的细长多边形：32列2行
+        let mut strip: Vec<f32> = (0..=31).flat_map(|i| [i as f32 * 16.0 / 31.0, 0.0]).collect();
+        strip.extend((0..=31).rev().flat_map(|i| [i as f32 * 16.0 / 31.0, 1.0]));
+        let poly = build_polygon(&strip, &[64]);
+        assert_eq!((poly.grid_cols, poly.grid_rows), (32, 2));
+        assert_eq!(build_grid(&poly).len(), 32);
+
+        // 边数很多时网格随之加密，单个方向不超过上限
+        let many = build_polygon(&random_simple_polygon(0.0, 0.0, 100.0, 20000, 3), &[20000]);
+        assert!(many.grid_cols * many.grid_rows > 10000);
+
+        // 指定分辨率时使用正方形网格
+        let mut options = PipOptions::new();
+        options.set_grid_resolution(8);
+        let poly = build_polygon_with(&strip, &[64], &options);
+        assert_eq!((poly.grid_cols, poly.grid_rows), (8, 8));
+    }
+
+    #[test]
+    fn test_grid_resolution_does_not_change_results() {
+        let polygon = random_simple_polygon(0.0, 0.0, 100.0, 500, 11);
+        let points = uniform_points(3000, -110.0, -110.0, 110.0, 110.0, 4);
+        let mut options = PipOptions::new();
+        let expected = point_in_polygon_with_options(&points, &polygon, &[500], &options);
+        for resolution in [1, 7, 64, 300] {
+            options.set_grid_resolution(resolution);
+            assert_eq!(point_in_polygon_with_options(&points, &polygon, &[500], &options), expected);
+        }
+    }
+
+    #[test]
+    fn test_bounds_union_intersection() {
+        let a = Bounds { min_x: 0.0, min_y: 0.0, max_x: 2.0, max_y: 2.0 };
+        let b = Bounds { min_x: 1.0, min_y: 1.0, max_x: 3.0, max_y: 3.0 };
+        let u = a.union(&b);
+        assert_eq!((u.min_x, u.min_y, u.max_x, u.max_y), (0.0, 0.0, 3.0, 3.0));
+        let i = a.intersection(&b);
+        assert_eq!((i.min_x, i.min_y, i.max_x, i.max_y), (1.0, 1.0, 2.0, 2.0));
+
+        let mut e = Bounds::empty();
+        e.extend(&a);
+        assert!(e.contains(2.0, 2.0, 0.0) && !e.contains(2.1, 2.0, 0.0) && e.contains(2.1, 2.0, 0.2));
+
+        // 不相交时的交集不包含任何点
+        let far = Bounds { min_x: 5.0, min_y: 5.0, max_x: 6.0, max_y: 6.0 };
+        assert!(!a.intersection(&far).contains(1.0, 1.0, 0.0));
+        assert!(!a.intersection(&far).contains(5.5, 5.5, 0.0));
+    }
+
+    #[test]
+    fn test_great_arc_points() {
+        // 赤道上的弧按经度均分
+        let arc = great_arc_points((0.0, 0.0), (90.0, 0.0), 30.0);
+        assert_eq!(arc.len(), 3);
+        for (k, &(lon, lat)) in arc.iter().enumerate() {
+            assert!((lon - 30.0 * (k + 1) as f64).abs() < 1e-9 && lat.abs() < 1e-9);
+        }
+
+        // 靠近反经线的弧经度连续展开，不在±180处跳变
+        let arc = great_arc_points((175.0, 10.0), (179.0, 10.0), 1.0);
+        assert!(arc.windows(2).all(|w| w[1].0 > w[0].0 && w[1].0 <= 179.0));
+        assert_eq!(*arc.last().unwrap(), (179.0, 10.0));
+
+        // 跨越反经线或两端点重合时保持直线边
+        assert_eq!(great_arc_points((170.0, 0.0), (-170.0, 0.0), 1.0), vec![(-170.0, 0.0)]);
+        assert_eq!(great_arc_points((5.0, 5.0), (5.0, 5.0), 1.0), vec![(5.0, 5.0)]);
+
+        // 步长极小时段数有上限
+        let arc = great_arc_points((0.0, 0.0), (90.0, 0.0), 1e-300);
+        assert_eq!(arc.len(), MAX_ARC_SEGMENTS);
+        assert_eq!(*arc.last().unwrap(), (90.0, 0.0));
+    }
+
+    #[test]
+    fn test_split_indices_beyond_vertex_count() {
+        // 超出顶点数的拆分（包括u32的最大值）夹到顶点数，得到的空环仍占据环索引，不会越界或溢出
+        assert_eq!(split_index(u32::MAX, 7), 7);
+        assert_eq!(split_index(3, 7), 3);
+        assert_eq!(split_index(1 << 31, usize::MAX), 1 << 31);
+
+        let polygon = vec![0.0f32, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let points = vec![1.0, 1.0, 5.0, 5.0];
+        for rings in [vec![10], vec![u32::MAX], vec![4, u32::MAX, u32::MAX]] {
+            let poly = build_polygon(&polygon, &rings);
+            assert_eq!(poly.rings.len(), rings.len());
+            assert_eq!(poly.rings[0].edge_count, 4);
+            assert!(poly.rings[1..].iter().all(|ring| ring.edge_count == 0));
+            assert_eq!(point_in_polygon_scanline(&points, &polygon, &rings, true), vec![1, 0]);
+            assert_eq!(point_in_polygon_rayster(&points, &polygon, &rings, true), vec![1, 0]);
+        }
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_index_outputs_beyond_u32() {
+        use crate::points_in_polygon::index_u32;
+        assert_eq!(index_u32(u32::MAX as usize), Ok(u32::MAX));
+        assert!(index_u32(1 << 32).unwrap_err().contains("exceeds the u32 range"));
+    }
+
+    #[test]
+    fn test_for_each_crossing_matches_per_edge() {
+        // 9条边（两个完整的4边块加1条剩余的边），含水平边和端点恰好在扫描线上的边
+        let polygon: Vec<f32> = vec![0.0, 0.0, 4.0, 0.0, 5.0, 2.0, 4.0, 4.0, 3.0, 2.0, 2.0, 4.0, 1.0, 2.0, 0.0, 4.0, -1.0, 2.0];
+        let poly = build_polygon(&polygon, &[9]);
+        for y in [-1.0, 0.0, 1.0, 2.0, 3.0, 4.0] {
+            let mut crossings = Vec::new();
+            for_each_crossing(&poly.edges, y, 0, |k, x| crossings.push((k, x)));
+            let expected: Vec<(usize, f64)> = poly
+                .edges
+                .iter()
+                .enumerate()
+                .filter_map(|(k, e)| scanline_crossing(e.x1, e.y1, e.x2, e.y2, y).map(|x| (k, x)))
+                .collect();
+            assert_eq!(crossings, expected);
+        }
+    }
+
+    #[test]
+    fn test_row_cache_keeps_hot_rows() {
+        // 计数compute的调用次数：命中时不重新计算
+        let mut computed = 0;
+        let mut cache: RowCache<i64> = RowCache::new(3);
+        let get = |cache: &mut RowCache<i64>, key: i64, computed: &mut i32| {
+            *cache.get_or_insert_with(key, || {
+                *computed += 1;
+                key * 10
+            })
+        };
+        for key in [1, 2, 3] {
+            assert_eq!(get(&mut cache, key, &mut computed), key * 10);
+        }
+        assert_eq!(computed, 3);
+
+        // 行1被再次访问，满时淘汰的是未被再次访问的行2，而不是行1
+        assert_eq!(get(&mut cache, 1, &mut computed), 10);
+        assert_eq!(computed, 3);
+        get(&mut cache, 4, &mut computed);
+        assert_eq!(computed, 4);
+        get(&mut cache, 1, &mut computed);
+        assert_eq!(computed, 4);
+        get(&mut cache, 2, &mut computed);
+        assert_eq!(computed, 5);
+
+        // 容量为0按1处理
+        let mut single: RowCache<i64> = RowCache::new(0);
+        let mut computed = 0;
+        get(&mut single, 7, &mut computed);
+        get(&mut single, 7, &mut computed);
+        get(&mut single, 8, &mut computed);
+        get(&mut single, 7, &mut computed);
+        assert_eq!(computed, 3);
+    }
+
+    #[test]
+    fn test_cache_size_does_not_change_results() {
+        let polygon = random_simple_polygon(0.0, 0.0, 10.0, 60, 11);
+        let rings = vec![(polygon.len() / 2) as u32];
+        // 大量重复的y，缓存容量很小时反复淘汰
+        let mut points = uniform_points(3_000, -11.0, -11.0, 11.0, 11.0, 4);
+        for k in 0..points.len() / 2 {
+            points[k * 2 + 1] = ((k % 37) as f32 - 18.0) * 0.6;
+        }
+        let expected = point_in_polygon_scanline(&points, &polygon, &rings, true);
+        for size in [0, 1, 2, 5, 100] {
+            let mut options = PipOptions::new();
+            options.set_cache_size(size);
+            assert_eq!(options.cache_size(), size);
+            assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), expected);
+            assert_eq!(point_in_polygon_rayster_with_options(&points, &polygon, &rings, &options), expected);
+        }
+    }
+
+    #[test]
+    fn test_vertex_policy_above() {
+        // 顶点视为位于扫描线上方：从下方到达顶点的边计数，从顶点向上的边不计
+        assert_eq!(scanline_crossing_with(1.0, 1.0, 1.0, 2.0, 1.0, 1), None);
+        assert_eq!(scanline_crossing_with(1.0, 0.0, 1.0, 1.0, 1.0, 1), Some(1.0));
+        assert!(!crosses_scanline_with(1.0, 1.0, 1.0, 1));
+        // 相邻的一上一下两边仍合计一次；尖峰合计两次，尖谷合计零次
+        let up = scanline_crossing_with(0.0, 0.0, 1.0, 1.0, 1.0, 1).is_some() as u32;
+        let next = scanline_crossing_with(1.0, 1.0, 2.0, 2.0, 1.0, 1).is_some() as u32;
+        assert_eq!(up + next, 1);
+        let peak = scanline_crossing_with(0.0, 0.0, 1.0, 1.0, 1.0, 1).is_some() as u32
+            + scanline_crossing_with(1.0, 1.0, 2.0, 0.0, 1.0, 1).is_some() as u32;
+        let valley = scanline_crossing_with(0.0, 2.0, 1.0, 1.0, 1.0, 1).is_some() as u32
+            + scanline_crossing_with(1.0, 1.0, 2.0, 2.0, 1.0, 1).is_some() as u32;
+        assert_eq!((peak, valley), (2, 0));
+        // 未知的取值按默认规则
+        assert_eq!(scanline_crossing_with(1.0, 1.0, 1.0, 2.0, 1.0, 7), Some(1.0));
+    }
+
+    #[test]
+    fn test_vertex_policy_keeps_algorithms_in_agreement() {
+        // 与test_algorithms_agree_at_vertex_level相同的凹口多边形和等高点，两种规则下各算法结果相同
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 2.0, 2.0, 0.0, 4.0];
+        let rings = [5];
+        let points = vec![1.0, 2.0, 3.5, 2.0, 2.0, 3.0, 0.5, 3.0, 5.0, 2.0, -1.0, 2.0, 1.0, 4.0, -1.0, 0.0, 5.0, 4.0];
+        let expected = vec![1, 1, 0, 1, 0, 0, 0, 0, 0];
+        for policy in [0, 1] {
+            let mut options = PipOptions::new();
+            options.set_boundary_is_inside(false);
+            options.set_vertex_policy(policy);
+            assert_eq!(options.vertex_policy(), policy);
+            assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), expected);
+            assert_eq!(point_in_polygon_rayster_with_options(&points, &polygon, &rings, &options), expected);
+            assert_eq!(point_in_polygon_winding_with_options(&points, &polygon, &rings, &options), expected);
+        }
+    }
+
+    #[test]
+    fn test_ring_ranges_and_edges() {
+        // 重复的拆分得到空环，仍占据环索引；末尾只剩1个顶点时不构成环
+        assert_eq!(ring_ranges(9, &[4, 4, 8]), vec![(0, 4), (4, 4), (4, 8)]);
+        assert_eq!(ring_ranges(10, &[4, 4, 8]), vec![(0, 4), (4, 4), (4, 8), (8, 10)]);
+        assert_eq!(ring_ranges(4, &[]), vec![(0, 4)]);
+
+        // 每个环闭合，最后一条边连回环的起点
+        let polygon = [0, 0, 2, 0, 2, 2, 0, 2, 5, 5, 6, 5, 6, 6];
+        let edges: Vec<[i32; 4]> = polygon_edges(&polygon, &[4, 4]).collect();
+        assert_eq!(
+            edges,
+            vec![[0, 0, 2, 0], [2, 0, 2, 2], [2, 2, 0, 2], [0, 2, 0, 0], [5, 5, 6, 5], [6, 5, 6, 6], [6, 6, 5, 5]]
+        );
+        assert_eq!(ring_edges(&polygon, 4, 4).count(), 0);
+
+        // 内部的环与build_polygon得到的环一一对应
+        let poly = build_polygon(&[0.0f32, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0, 5.0, 5.0, 6.0, 5.0, 6.0, 6.0], &[4, 4]);
+        assert_eq!(poly.rings.iter().map(|ring| ring.edge_count).collect::<Vec<_>>(), vec![4, 0, 3]);
+    }
+}
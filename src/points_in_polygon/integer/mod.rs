@@ -0,0 +1,88 @@
+// 整数坐标模块：面向整数原生数据（如按纳度缩放的经纬度）的点在多边形内判断
+// 坐标以i64传入（js端为BigInt64Array），全部计算使用整数完成，不经过浮点转换，结果精确
+
+// 输入(js端):
+//     1. 点云 类型BigInt64Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形路径点 类型BigInt64Array
+//     3. 多边形路径点的拆分 类型Uint32Array
+//     4. 边界上点是否考虑为内部 boolean
+// 输出(js端):
+//     1. 点云是否在多边形内部 类型Uint32Array 1表示在多边形内部,0表示在多边形外部
+// 说明: 采用奇偶规则；坐标差在i128中计算，叉积的两项按符号和u128绝对值比较，任意i64输入都不会溢出
+
+use alloc::vec::Vec;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use core::cmp::Ordering;
+use super::Location;
+use super::common::{crosses_scanline, polygon_edges};
+
+pub mod test;
+
+// WebAssembly导出函数：整数坐标的点在多边形内判断
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_i64(
+    points: &[i64],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[i64],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<u32> {
+    let point_count = points.len() / 2;
+    let edges: Vec<[i64; 4]> = polygon_edges(polygon, rings).collect();
+
+    (0..point_count)
+        .map(|i| locate_i64(&edges, points[i * 2], points[i * 2 + 1]).to_u32(boundary_is_inside))
+        .collect()
+}
+
+// 判断单个点的位置，边按半开区间规则计数
+fn locate_i64(edges: &[[i64; 4]], px: i64, py: i64) -> Location {
+    let mut inside = false;
+
+    for &[ax, ay, bx, by] in edges {
+        if py < ay.min(by) || py > ay.max(by) {
+            continue;
+        }
+
+        let side = orient_i64(ax, ay, bx, by, px, py);
+
+        // 共线且在边的边界框内，即在边上
+        if side == Ordering::Equal && px >= ax.min(bx) && px <= ax.max(bx) {
+            return Location::Boundary;
+        }
+
+        // 向右的射线与边相交
+        if crosses_scanline(ay, by, py) {
+            let upward = by > ay;
+            if (upward && side == Ordering::Greater) || (!upward && side == Ordering::Less) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside { Location::Inside } else { Location::Outside }
+}
+
+// 方向判定：比较 (bx-ax)(py-ay) 与 (by-ay)(px-ax)
+pub(crate) fn orient_i64(ax: i64, ay: i64, bx: i64, by: i64, px: i64, py: i64) -> Ordering {
+    let left = (bx as i128 - ax as i128, py as i128 - ay as i128);
+    let right = (by as i128 - ay as i128, px as i128 - ax as i128);
+    compare_products(left.0, left.1, right.0, right.1)
+}
+
+// 精确比较 a*b 与 c*d，其中各因子的绝对值小于2^64，乘积的绝对值小于2^128，可用u128表示
+fn compare_products(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let sign_ab = a.signum() * b.signum();
+    let sign_cd = c.signum() * d.signum();
+    if sign_ab != sign_cd {
+        return sign_ab.cmp(&sign_cd);
+    }
+
+    let mag_ab = a.unsigned_abs() * b.unsigned_abs();
+    let mag_cd = c.unsigned_abs() * d.unsigned_abs();
+    match sign_ab {
+        1 => mag_ab.cmp(&mag_cd),
+        -1 => mag_cd.cmp(&mag_ab),
+        _ => Ordering::Equal,
+    }
+}
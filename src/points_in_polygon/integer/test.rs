@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::integer::{orient_i64, point_in_polygon_i64};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_nanodegree_square_with_hole() {
+        // 纳度缩放的坐标：外环覆盖经度100°~103°，洞覆盖101°~102°
+        let deg = 1_000_000_000i64;
+        let polygon = vec![
+            100 * deg, 10 * deg, 103 * deg, 10 * deg, 103 * deg, 13 * deg, 100 * deg, 13 * deg,
+            101 * deg, 11 * deg, 102 * deg, 11 * deg, 102 * deg, 12 * deg, 101 * deg, 12 * deg,
+        ];
+        let rings = vec![4];
+        let points = vec![
+            100 * deg + 1, 10 * deg + 1, // 紧贴外环角点的内部点
+            101 * deg + 1, 11 * deg + 1, // 紧贴洞角点的洞内点
+            103 * deg, 11 * deg + 7,     // 外环边上
+            103 * deg + 1, 11 * deg,     // 外部
+        ];
+
+        assert_eq!(point_in_polygon_i64(&points, &polygon, &rings, true), vec![1, 0, 1, 0]);
+        assert_eq!(point_in_polygon_i64(&points, &polygon, &rings, false), vec![1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_orient_full_i64_range() {
+        // 坐标差接近2^64，乘积超出i128范围
+        let (min, max) = (i64::MIN, i64::MAX);
+        assert_eq!(orient_i64(min, min, max, max, 0, 0), Ordering::Equal);
+        assert_eq!(orient_i64(min, min, max, max, 0, 1), Ordering::Greater);
+        assert_eq!(orient_i64(min, min, max, max, 1, 0), Ordering::Less);
+        assert_eq!(orient_i64(min, max, max, min, min, min), Ordering::Less);
+    }
+}
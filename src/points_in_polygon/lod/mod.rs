@@ -0,0 +1,249 @@
+// 多分辨率(LOD)多边形查询模块：对精细多边形（如海岸线）预先按多个容差简化，
+// 查询时优先使用最粗的层级，只有在该层级的误差可能改变结果时才退回更精细的层级
+
+// 原理：
+//     Douglas-Peucker简化保证原始边界上的每一点到简化边界的距离不超过容差tol，
+//     因此到简化边界距离大于tol的点，在简化多边形与原始多边形中的判断结果一定相同；
+//     距离不超过tol的点（靠近边界）逐级退回更精细的层级，最后退回原始多边形
+// 输入(js端):
+//     1. 多边形路径点 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形路径点的拆分 类型Uint32Array
+//     3. 各层级的简化容差 类型Float64Array 例子[1.0, 0.1, 0.01]
+// 输出(js端):
+//     1. query: 点云是否在多边形内部 类型Uint32Array
+
+use alloc::{string::String, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use super::common::{polygon_edges, ring_ranges};
+use super::scanline::ScanlineIndex;
+use super::{index_u32, point_segment_distance};
+
+pub mod test;
+
+// 简化后的一个层级
+struct LodLevel {
+    tolerance: f64,
+    buckets: EdgeBuckets,  // 简化边界的边，按网格分桶，用于距离判断
+    index: ScanlineIndex,  // 简化多边形的扫描线索引
+    vertex_count: usize,
+}
+
+// 边的网格分桶：每条边放入其边界框（扩展tolerance后）覆盖的所有单元，查询时只检查点所在单元
+struct EdgeBuckets {
+    edges: Vec<[f64; 4]>,    // [x1, y1, x2, y2]
+    min_x: f64,
+    min_y: f64,
+    cell_w: f64,
+    cell_h: f64,
+    size: usize,             // 每个方向的单元数
+    cells: Vec<Vec<usize>>,  // 行优先，存储边的索引
+}
+
+// 多分辨率多边形：从粗到细保存各层级，最后是原始多边形
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct LodPolygon {
+    levels: Vec<LodLevel>,
+    full: ScanlineIndex,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl LodPolygon {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(polygon: &[f32], rings: &[u32], tolerances: &[f64]) -> Result<LodPolygon, ExportError> {
+        LodPolygon::build(polygon, rings, tolerances).map_err(export_error)
+    }
+
+    // 层级数量（不含原始多边形）
+    pub fn level_count(&self) -> usize {
+        self.levels.len()
+    }
+
+    // 第level层（0为最粗）简化后的顶点数
+    pub fn level_vertex_count(&self, level: usize) -> usize {
+        self.levels.get(level).map_or(0, |l| l.vertex_count)
+    }
+
+    // 批量判断点是否在多边形内部，结果与直接使用原始多边形一致
+    pub fn query(&self, points: &[f32], boundary_is_inside: bool) -> Vec<u32> {
+        let point_count = points.len() / 2;
+        let mut results = vec![0; point_count];
+
+        for (i, result) in results.iter_mut().enumerate() {
+            let x = points[i * 2] as f64;
+            let y = points[i * 2 + 1] as f64;
+
+            let index = self
+                .levels
+                .iter()
+                .find(|level| !level.buckets.any_within(x, y, level.tolerance))
+                .map_or(&self.full, |level| &level.index);
+
+            *result = index.locate(x, y).to_u32(boundary_is_inside);
+        }
+
+        results
+    }
+}
+
+impl LodPolygon {
+    // 构建各层级，简化后的拆分索引超出u32范围时返回错误信息
+    pub(crate) fn build(polygon: &[f32], rings: &[u32], tolerances: &[f64]) -> Result<LodPolygon, String> {
+        // 从粗到细排列，忽略非正数和非有限的容差
+        let mut tolerances: Vec<f64> = tolerances.iter().copied().filter(|t| t.is_finite() && *t > 0.0).collect();
+        tolerances.sort_by(|a, b| b.total_cmp(a));
+        tolerances.dedup();
+
+        let levels = tolerances
+            .into_iter()
+            .map(|tolerance| {
+                let (coarse, coarse_rings) = simplify_polygon(polygon, rings, tolerance)?;
+                Ok(LodLevel {
+                    tolerance,
+                    buckets: EdgeBuckets::new(polygon_edges(&coarse, &coarse_rings).map(|edge| edge.map(f64::from)).collect(), tolerance),
+                    index: ScanlineIndex::new(&coarse, &coarse_rings),
+                    vertex_count: coarse.len() / 2,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(LodPolygon { levels, full: ScanlineIndex::new(polygon, rings) })
+    }
+}
+
+// 对每个环分别做Douglas-Peucker简化，保留环的数量和顺序（从而保留外环/洞的角色）
+fn simplify_polygon(polygon: &[f32], rings: &[u32], tolerance: f64) -> Result<(Vec<f32>, Vec<u32>), String> {
+    let ranges = ring_ranges(polygon.len() / 2, rings);
+    let mut simplified = Vec::new();
+    let mut splits = Vec::with_capacity(ranges.len());
+
+    for &(start, end) in &ranges {
+        let ring = &polygon[start * 2..end * 2];
+        for k in simplify_ring(ring, tolerance) {
+            simplified.push(ring[k * 2]);
+            simplified.push(ring[k * 2 + 1]);
+        }
+        splits.push(index_u32(simplified.len() / 2)?);
+    }
+
+    // 最后一个环由拆分点之后剩余的顶点构成
+    splits.pop();
+    if splits.is_empty() && !simplified.is_empty() {
+        splits.push(index_u32(simplified.len() / 2)?);
+    }
+
+    Ok((simplified, splits))
+}
+
+// 闭合环的Douglas-Peucker简化，返回保留顶点的索引（升序）
+fn simplify_ring(ring: &[f32], tolerance: f64) -> Vec<usize> {
+    let n = ring.len() / 2;
+    if n <= 3 {
+        return (0..n).collect();
+    }
+
+    let vertex = |i: usize| (ring[(i % n) * 2] as f64, ring[(i % n) * 2 + 1] as f64);
+
+    // 以第0个顶点为锚点，离它最远的顶点把闭合环分成两条链
+    let (x0, y0) = vertex(0);
+    let far = (1..n)
+        .max_by(|&a, &b| {
+            let (ax, ay) = vertex(a);
+            let (bx, by) = vertex(b);
+            let da = (ax - x0) * (ax - x0) + (ay - y0) * (ay - y0);
+            let db = (bx - x0) * (bx - x0) + (by - y0) * (by - y0);
+            da.total_cmp(&db)
+        })
+        .unwrap();
+
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[far] = true;
+
+    // 用显式栈代替递归，链的终点索引n表示回到第0个顶点
+    let mut stack = vec![(0, far), (far, n)];
+    while let Some((first, last)) = stack.pop() {
+        let (ax, ay) = vertex(first);
+        let (bx, by) = vertex(last);
+
+        let mut max_dist = 0.0;
+        let mut max_idx = first;
+        for k in first + 1..last {
+            let (px, py) = vertex(k);
+            let dist = point_segment_distance(px, py, ax, ay, bx, by);
+            if dist > max_dist {
+                max_dist = dist;
+                max_idx = k;
+            }
+        }
+
+        if max_dist > tolerance {
+            keep[max_idx] = true;
+            stack.push((first, max_idx));
+            stack.push((max_idx, last));
+        }
+    }
+
+    (0..n).filter(|&k| keep[k]).collect()
+}
+
+impl EdgeBuckets {
+    fn new(edges: Vec<[f64; 4]>, tolerance: f64) -> EdgeBuckets {
+        let mut min_x = f64::MAX;
+        let mut min_y = f64::MAX;
+        let mut max_x = f64::MIN;
+        let mut max_y = f64::MIN;
+        for &[x1, y1, x2, y2] in &edges {
+            min_x = min_x.min(x1).min(x2);
+            min_y = min_y.min(y1).min(y2);
+            max_x = max_x.max(x1).max(x2);
+            max_y = max_y.max(y1).max(y2);
+        }
+        min_x -= tolerance;
+        min_y -= tolerance;
+        max_x += tolerance;
+        max_y += tolerance;
+
+        // 单元数随边数增长，每个方向最多256个单元
+        let size = if edges.is_empty() {
+            1
+        } else {
+            ((edges.len() as f64).sqrt().ceil() as usize * 2).clamp(1, 256)
+        };
+        let cell_w = ((max_x - min_x) / size as f64).max(f64::MIN_POSITIVE);
+        let cell_h = ((max_y - min_y) / size as f64).max(f64::MIN_POSITIVE);
+
+        let mut buckets = EdgeBuckets { edges, min_x, min_y, cell_w, cell_h, size, cells: vec![Vec::new(); size * size] };
+        for (edge_idx, &[x1, y1, x2, y2]) in buckets.edges.iter().enumerate() {
+            let (c0, r0) = buckets.cell_of(x1.min(x2) - tolerance, y1.min(y2) - tolerance);
+            let (c1, r1) = buckets.cell_of(x1.max(x2) + tolerance, y1.max(y2) + tolerance);
+            for r in r0..=r1 {
+                for c in c0..=c1 {
+                    buckets.cells[r * size + c].push(edge_idx);
+                }
+            }
+        }
+
+        buckets
+    }
+
+    // 坐标所在的单元（超出范围时夹到边缘单元）
+    #[inline]
+    fn cell_of(&self, x: f64, y: f64) -> (usize, usize) {
+        let c = ((x - self.min_x) / self.cell_w).floor().clamp(0.0, (self.size - 1) as f64) as usize;
+        let r = ((y - self.min_y) / self.cell_h).floor().clamp(0.0, (self.size - 1) as f64) as usize;
+        (c, r)
+    }
+
+    // 点到任意一条边的距离是否不超过tolerance（找到一条即提前返回）
+    fn any_within(&self, x: f64, y: f64, tolerance: f64) -> bool {
+        let (c, r) = self.cell_of(x, y);
+        self.cells[r * self.size + c].iter().any(|&edge_idx| {
+            let [x1, y1, x2, y2] = self.edges[edge_idx];
+            point_segment_distance(x, y, x1, y1, x2, y2) <= tolerance
+        })
+    }
+}
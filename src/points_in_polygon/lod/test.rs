@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::lod::LodPolygon;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+    use std::time::Instant;
+
+    // 构造锯齿状的"海岸线"外环，内部带一个圆形的洞
+    fn coastline_with_hole() -> (Vec<f32>, Vec<u32>) {
+        let segments = 2000;
+        let mut polygon = Vec::new();
+        for i in 0..segments {
+            let angle = 2.0 * std::f32::consts::PI * (i as f32) / (segments as f32);
+            let r = 5.0 + 0.3 * (angle * 7.0).sin() + 0.05 * (angle * 97.0).sin();
+            polygon.push(r * angle.cos());
+            polygon.push(r * angle.sin());
+        }
+        for i in 0..256 {
+            let angle = 2.0 * std::f32::consts::PI * (i as f32) / 256.0;
+            polygon.push(1.0 + angle.cos());
+            polygon.push(angle.sin());
+        }
+        (polygon, vec![segments])
+    }
+
+    #[test]
+    fn test_lod_matches_full_resolution() {
+        let (polygon, rings) = coastline_with_hole();
+        let lod = LodPolygon::build(&polygon, &rings, &[0.01, 0.5, 0.1]).unwrap();
+
+        // 层级按从粗到细排列，越粗顶点越少
+        assert_eq!(lod.level_count(), 3);
+        assert!(lod.level_vertex_count(0) < lod.level_vertex_count(1));
+        assert!(lod.level_vertex_count(1) < lod.level_vertex_count(2));
+        assert!(lod.level_vertex_count(2) < polygon.len() / 2);
+
+        let step = 0.02;
+        let mut points = Vec::new();
+        for x in 0..400 {
+            for y in 0..400 {
+                points.push(-6.0 + x as f32 * step * 1.5);
+                points.push(-6.0 + y as f32 * step * 1.5);
+            }
+        }
+
+        let start = Instant::now();
+        let lod_results = lod.query(&points, true);
+        let lod_duration = start.elapsed();
+
+        let start = Instant::now();
+        let full_results = point_in_polygon_scanline(&points, &polygon, &rings, true);
+        let full_duration = start.elapsed();
+
+        println!(
+            "lod query {:?} vs full resolution {:?} for {} points",
+            lod_duration, full_duration, points.len() / 2
+        );
+        assert_eq!(lod_results, full_results);
+    }
+
+    #[test]
+    fn test_simplify_keeps_repeated_splits() {
+        use crate::points_in_polygon::lod::simplify_polygon;
+
+        // 空环仍占据环索引，重建的拆分与输入相同，洞不会变成外环
+        let polygon = vec![
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, 2.0, 2.0, 4.0, 2.0, 4.0, 4.0, 2.0, 4.0, 6.0, 6.0, 8.0, 6.0, 8.0, 8.0, 6.0, 8.0,
+        ];
+        let (simplified, splits) = simplify_polygon(&polygon, &[4, 4, 8], 0.1).unwrap();
+        assert_eq!(simplified, polygon);
+        assert_eq!(splits, vec![4, 4, 8]);
+
+        let lod = LodPolygon::build(&polygon, &[4, 4, 8], &[0.1]).unwrap();
+        assert_eq!(lod.query(&[7.0, 7.0, 5.0, 5.0, 3.0, 3.0], true), vec![0, 1, 0]);
+    }
+
+    #[test]
+    fn test_non_finite_input() {
+        // NaN和无穷大的容差被忽略
+        let square = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+        let lod = LodPolygon::build(&square, &[4], &[f64::NAN, 1.0, f64::INFINITY]).unwrap();
+        assert_eq!(lod.level_count(), 1);
+        assert_eq!(lod.query(&[5.0, 5.0, 11.0, 5.0], true), vec![1, 0]);
+
+        // 含NaN顶点的环不会使简化中断
+        let polygon = vec![0.0, 0.0, 10.0, 0.0, f32::NAN, 5.0, 10.0, 10.0, 0.0, 10.0];
+        assert!(LodPolygon::build(&polygon, &[5], &[0.5]).is_ok());
+    }
+}
@@ -0,0 +1,92 @@
+// 结果缓存模块：同一组点按同一个多边形和选项反复判断时（例如界面重绘），直接返回上一次的结果
+// 缓存的键是点集、多边形、拆分索引的指纹和选项的取值，任一内容改变都得到新的键，旧结果自然失效
+
+// 输入(js端):
+//     1. const cache = new ClassificationCache() 最多保留MAX_ENTRIES组结果，超出时丢弃最早的一组
+//     2. cache.classify(points, polygon, rings, options) 参数与point_in_polygon_with_options相同
+//     3. cache.clear() 清空缓存
+//     4. cache.hits() / cache.misses() 命中和未命中的次数，便于确认缓存是否起作用
+// 输出(js端):
+//     1. 点云是否在多边形内部 类型Uint32Array，与point_in_polygon_with_options的结果相同
+// 说明: 指纹是对全部数值的64位哈希，计算只需遍历一次输入，远快于重新判断；
+//     js端原地修改了同一个Float32Array的内容时指纹随之改变，不会返回过期的结果
+
+use alloc::vec::Vec;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use super::options::PipOptions;
+use super::scanline::classify_points_with;
+
+pub mod test;
+
+// 最多保留的结果组数
+const MAX_ENTRIES: usize = 8;
+
+// FNV-1a的初始值和乘数，这里按32位字而不是字节混合
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+// 缓存的键：各输入的长度和指纹，以及选项的取值
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct CacheKey {
+    points: (usize, u64),
+    polygon: (usize, u64),
+    rings: (usize, u64),
+    options: [u64; 13],
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Default)]
+pub struct ClassificationCache {
+    entries: Vec<(CacheKey, Vec<u32>)>, // 按加入的先后排列，最新的在末尾
+    hits: u32,
+    misses: u32,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ClassificationCache {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new() -> ClassificationCache {
+        ClassificationCache::default()
+    }
+
+    // 按选项判断，输入和选项都与之前某次调用相同时直接返回缓存的结果
+    pub fn classify(&mut self, points: &[f32], polygon: &[f32], rings: &[u32], options: &PipOptions) -> Vec<u32> {
+        let key = CacheKey {
+            points: (points.len(), fingerprint(points.iter().map(|v| v.to_bits()))),
+            polygon: (polygon.len(), fingerprint(polygon.iter().map(|v| v.to_bits()))),
+            rings: (rings.len(), fingerprint(rings.iter().copied())),
+            options: options.key(),
+        };
+
+        if let Some((_, mask)) = self.entries.iter().find(|(k, _)| *k == key) {
+            self.hits += 1;
+            return mask.clone();
+        }
+
+        self.misses += 1;
+        let mask = classify_points_with(points.len() / 2, |i| (points[i * 2] as f64, points[i * 2 + 1] as f64), polygon, rings, options);
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push((key, mask.clone()));
+        mask
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn hits(&self) -> u32 {
+        self.hits
+    }
+
+    pub fn misses(&self) -> u32 {
+        self.misses
+    }
+}
+
+// 对一串32位字求64位指纹
+fn fingerprint(words: impl Iterator<Item = u32>) -> u64 {
+    words.fold(FNV_OFFSET, |hash, word| (hash ^ word as u64).wrapping_mul(FNV_PRIME))
+}
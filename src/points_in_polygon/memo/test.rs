@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::memo::ClassificationCache;
+    use crate::points_in_polygon::options::{point_in_polygon_with_options, PipOptions};
+
+    #[test]
+    fn test_repeated_calls_hit_cache() {
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let mut points = vec![1.0, 1.0, 5.0, 5.0, 4.0, 2.0];
+        let mut options = PipOptions::new();
+        let mut cache = ClassificationCache::new();
+
+        assert_eq!(cache.classify(&points, &polygon, &[4], &options), vec![1, 0, 1]);
+        assert_eq!(cache.classify(&points, &polygon, &[4], &options), vec![1, 0, 1]);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        // 点、选项的任何改变都重新判断
+        points[2] = 3.0;
+        points[3] = 3.0;
+        assert_eq!(cache.classify(&points, &polygon, &[4], &options), vec![1, 1, 1]);
+        options.set_boundary_is_inside(false);
+        assert_eq!(cache.classify(&points, &polygon, &[4], &options), vec![1, 1, 0]);
+        assert_eq!((cache.hits(), cache.misses()), (1, 3));
+
+        // 改回之前的选项时命中较早的结果
+        options.set_boundary_is_inside(true);
+        assert_eq!(cache.classify(&points, &polygon, &[4], &options), vec![1, 1, 1]);
+        assert_eq!(cache.hits(), 2);
+
+        cache.clear();
+        assert_eq!(cache.classify(&points, &polygon, &[4], &options), point_in_polygon_with_options(&points, &polygon, &[4], &options));
+        assert_eq!(cache.misses(), 4);
+    }
+
+    #[test]
+    fn test_oldest_entry_evicted() {
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let options = PipOptions::new();
+        let mut cache = ClassificationCache::new();
+
+        // 9组不同的点，第一组被挤出
+        for k in 0..9 {
+            cache.classify(&[k as f32, 1.0], &polygon, &[4], &options);
+        }
+        cache.classify(&[8.0, 1.0], &polygon, &[4], &options);
+        cache.classify(&[0.0, 1.0], &polygon, &[4], &options);
+        assert_eq!((cache.hits(), cache.misses()), (1, 10));
+    }
+}
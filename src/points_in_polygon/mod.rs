@@ -1,3 +1,69 @@
 // 声明子模块
-// pub mod rayster;
-pub mod scanline;
\ No newline at end of file
+pub mod rayster;
+pub mod common;
+pub mod options;
+pub mod validation;
+pub mod scanline;
+pub mod lod;
+pub mod approximate;
+pub mod certified;
+pub mod integer;
+pub mod quantized;
+pub mod sampling;
+pub mod attribute;
+pub mod streaming;
+pub mod staging;
+pub mod prepared;
+pub mod multipolygon;
+pub mod bvh;
+pub mod winding;
+pub mod memo;
+pub mod quadtree;
+pub mod trapezoid;
+
+use alloc::{format, string::String};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
+// 点相对于区域（多边形或解析图元）的位置
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Location {
+    Outside,
+    Inside,
+    Boundary,
+}
+
+impl Location {
+    // 转换为js端使用的0/1结果
+    #[inline]
+    pub(crate) fn to_u32(self, boundary_is_inside: bool) -> u32 {
+        match self {
+            Location::Outside => 0,
+            Location::Inside => 1,
+            Location::Boundary => boundary_is_inside as u32,
+        }
+    }
+}
+
+// 点或顶点的索引转换为js端的u32索引（Uint32Array输出），超出u32范围时返回明确的错误信息，而不是静默截断；
+// 导出函数把错误作为js异常抛出（panic在wasm中只是unreachable陷阱，错误信息会丢失）
+#[inline]
+pub(crate) fn index_u32(index: usize) -> Result<u32, String> {
+    u32::try_from(index)
+        .map_err(|_| format!("index {} exceeds the u32 range of index outputs, split the input into chunks of at most 2^32 points", index))
+}
+
+// 点(px, py)到线段(ax, ay)-(bx, by)的距离
+pub(crate) fn point_segment_distance(px: f64, py: f64, ax: f64, ay: f64, bx: f64, by: f64) -> f64 {
+    let dx = bx - ax;
+    let dy = by - ay;
+    let len_sq = dx * dx + dy * dy;
+    let t = if len_sq > 0.0 {
+        (((px - ax) * dx + (py - ay) * dy) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let cx = ax + t * dx;
+    let cy = ay + t * dy;
+    ((px - cx) * (px - cx) + (py - cy) * (py - cy)).sqrt()
+}
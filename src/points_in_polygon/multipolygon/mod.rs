@@ -0,0 +1,69 @@
+// MultiPolygon模块：一次判断点是否在由多个互不相交的多边形组成的MultiPolygon内，每个多边形可以各自带洞
+// 普通接口的rings只能表示一个外环加若干洞；这里额外用polygon_splits把顶点分成若干组成部分
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形路径点 类型Float32Array 所有组成部分的路径点依次拼接
+//     3. 组成部分的拆分 类型Uint32Array 第2个及之后每个组成部分的起始顶点索引，含义与coverage模块相同
+//     4. 洞的拆分 类型Uint32Array 各组成部分内部洞的起始顶点索引（按拼接后的全局顶点索引）
+//        每个组成部分的第一个环是它的外环，其余是它的洞
+//     5. 边界上点是否考虑为内部 boolean
+// 输出(js端):
+//     1. 点云是否在MultiPolygon内部 类型Uint32Array 1表示在某个组成部分内部,0表示在外部
+//     2. point_in_multipolygon_rings(points, polygon, polygon_splits, rings, boundary_is_inside) 类型Uint32Array
+//        每个点占3个值 [结果, 外环, 洞]：结果与point_in_multipolygon相同；外环是包含该点的组成部分的外环，
+//        洞是点所落入的洞（点在洞中或洞的边界上时），环按输入顺序编号（第一部分的外环为0，其后依次为其洞、下一部分的外环……），
+//        没有对应的环时为4294967295（u32最大值）。可直接按外环给各区域着色，不必在js端逐环重新判断
+// 说明: 洞只挖去所属组成部分的外环，因此位于另一部分洞中的部分（如湖中的岛）正确地判为内部。
+//     例子: 两个带洞的正方形 polygon_splits=[8], rings=[4, 12] 表示顶点0-4为第一部分外环、4-8为其洞，8-12为第二部分外环、12-结束为其洞
+
+use alloc::vec::Vec;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use super::common::build_multipolygon_with;
+use super::options::PipOptions;
+use super::scanline::{attribute_points_in, locate_points_in};
+use super::index_u32;
+
+pub mod test;
+
+// 没有对应的环
+pub(crate) const NO_RING: u32 = u32::MAX;
+
+// WebAssembly导出函数：判断点是否在MultiPolygon内部
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_multipolygon(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],          // 所有组成部分的顶点依次拼接
+    polygon_splits: &[u32],   // 第2个及之后每个组成部分的起始顶点索引
+    rings: &[u32],            // 洞的起始顶点索引（全局）
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<u32> {
+    let point_count = points.len() / 2;
+    let poly = build_multipolygon_with(polygon, polygon_splits, rings, &PipOptions::default());
+    locate_points_in(&poly, point_count, |i| (points[i * 2] as f64, points[i * 2 + 1] as f64))
+        .into_iter()
+        .map(|location| location.to_u32(boundary_is_inside))
+        .collect()
+}
+
+// WebAssembly导出函数：判断点是否在MultiPolygon内部，同时给出点所属的外环和所落入的洞
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_multipolygon_rings(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],          // 所有组成部分的顶点依次拼接
+    polygon_splits: &[u32],   // 第2个及之后每个组成部分的起始顶点索引
+    rings: &[u32],            // 洞的起始顶点索引（全局）
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Result<Vec<u32>, ExportError> {
+    let point_count = points.len() / 2;
+    let poly = build_multipolygon_with(polygon, polygon_splits, rings, &PipOptions::default());
+    // 环数在u32范围内时所有环索引都小于NO_RING，可以直接转换
+    index_u32(poly.rings.len()).map_err(export_error)?;
+    let ring_id = |ring: Option<usize>| ring.map_or(NO_RING, |ring| ring as u32);
+    Ok(attribute_points_in(&poly, point_count, |i| (points[i * 2] as f64, points[i * 2 + 1] as f64))
+        .into_iter()
+        .flat_map(|(location, outer, hole)| [location.to_u32(boundary_is_inside), ring_id(outer), ring_id(hole)])
+        .collect())
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::multipolygon::{point_in_multipolygon, point_in_multipolygon_rings, NO_RING};
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    // 第一部分: [0,10]×[0,10] 带洞 [2,8]×[2,8]；第二部分: 洞中的岛 [4,6]×[4,6]；第三部分: 三角形 (20,0) (30,0) (25,10)
+    const POLYGON: [f32; 30] = [
+        0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 第一部分外环
+        2.0, 2.0, 8.0, 2.0, 8.0, 8.0, 2.0, 8.0, // 第一部分的洞
+        4.0, 4.0, 6.0, 4.0, 6.0, 6.0, 4.0, 6.0, // 第二部分（岛）
+        20.0, 0.0, 30.0, 0.0, 25.0, 10.0, // 第三部分
+    ];
+
+    #[test]
+    fn test_island_in_hole_and_disjoint_parts() {
+        let points = vec![
+            1.0, 1.0, // 第一部分内
+            3.0, 3.0, // 第一部分的洞中
+            5.0, 5.0, // 洞中的岛内
+            25.0, 3.0, // 第三部分内
+            15.0, 5.0, // 各部分之间
+            6.0, 5.0, // 岛的边界上
+        ];
+        let result = point_in_multipolygon(&points, &POLYGON, &[8, 12], &[4], true);
+        assert_eq!(result, vec![1, 0, 1, 1, 0, 1]);
+        assert_eq!(point_in_multipolygon(&points, &POLYGON, &[8, 12], &[4], false), vec![1, 0, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_single_part_matches_polygon() {
+        // 只有一个组成部分时与普通接口相同（包括矩形快速路径）
+        let points = vec![1.0, 1.0, 3.0, 3.0, 5.0, 5.0, 12.0, 5.0];
+        assert_eq!(
+            point_in_multipolygon(&points, &POLYGON[..16], &[], &[4], true),
+            point_in_polygon_scanline(&points, &POLYGON[..16], &[4], true)
+        );
+
+        // 每个组成部分都不带洞
+        let result = point_in_multipolygon(&points, &POLYGON[16..], &[4], &[], true);
+        assert_eq!(result, vec![0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_ring_attribution() {
+        // 环编号：0 第一部分外环，1 其洞，2 岛，3 三角形
+        let points = vec![
+            1.0, 1.0, // 第一部分内
+            3.0, 3.0, // 第一部分的洞中
+            5.0, 5.0, // 洞中的岛内
+            25.0, 3.0, // 第三部分内
+            15.0, 5.0, // 各部分之间
+            6.0, 5.0, // 岛的边界上
+            2.0, 5.0, // 洞的边界上
+            0.0, 5.0, // 第一部分外环的边界上
+        ];
+        let result = point_in_multipolygon_rings(&points, &POLYGON, &[8, 12], &[4], false).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                1, 0, NO_RING,
+                0, 0, 1,
+                1, 2, NO_RING,
+                1, 3, NO_RING,
+                0, NO_RING, NO_RING,
+                0, 2, NO_RING,
+                0, 0, 1,
+                0, 0, NO_RING,
+            ]
+        );
+        // 结果列与point_in_multipolygon相同
+        let labels: Vec<u32> = point_in_multipolygon_rings(&points, &POLYGON, &[8, 12], &[4], true).unwrap().chunks(3).map(|c| c[0]).collect();
+        assert_eq!(labels, point_in_multipolygon(&points, &POLYGON, &[8, 12], &[4], true));
+        assert!(point_in_multipolygon_rings(&[], &POLYGON, &[8, 12], &[4], true).unwrap().is_empty());
+    }
+}
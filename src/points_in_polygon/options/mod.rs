@@ -0,0 +1,412 @@
+// 判断选项模块：把各算法共用的可选行为集中到一个对象中，避免每个导出函数都追加参数
+
+// 输入(js端):
+//     1. const options = new PipOptions() 默认值：边界上的点视为内部，退化环丢弃
+//     2. options.set_boundary_is_inside(boolean)
+//     3. options.set_degenerate_rings(policy) 退化环的处理方式
+//        0: 丢弃，该环不产生任何边（validate_polygon的报告中给出警告）
+//        1: 只作为边界，该环的边参与边界检测，但不参与内外判断
+//     4. options.set_tolerance(tolerance) 点到边的距离不超过该值即视为在边上，按多边形的坐标单位；
+//        不大于0时（默认）取多边形边界框对角线长度的1e-9倍，同一形状按任意倍数缩放后判断结果不变
+//     5. options.set_hole_detection(mode) 区分外环和洞的方式
+//        0: 按顺序，第一个环是外环，其余是洞（默认）
+//        1: 按绕向，逆时针（y轴向上时，带符号面积为正）的环是外环，顺时针的环是洞，洞归属于包含它的最小外环，
+//           可直接使用GeoJSON等按绕向组织的输入，多个外环各自构成一个组成部分（见multipolygon模块）
+//        2: 不区分，全部环地位相同，重叠部分只按填充规则合并（与SVG的fill-rule一致）：奇偶规则下被奇数个环覆盖的点在内部，
+//           无需事先排列环的顺序或计算绕向
+//     6. options.set_fill_rule(rule) 填充规则，用于自交叠的环（如CAD软件导出的多边形）
+//        0: 奇偶规则，点在环内当且仅当射线与环的交点数为奇数（默认）
+//        1: 非零规则，点在环内当且仅当环绕该点的圈数不为0，与canvas/SVG的nonzero填充显示一致
+//     7. options.set_grid_resolution(n) 空间网格每个方向的单元数（上限1024），0表示按边数和边界框长宽比自动确定（默认），
+//        只影响速度和内存，不影响判断结果
+//     8. options.set_great_arc_step(degrees) 坐标为经纬度（x为经度、y为纬度，单位为度）时，先沿大圆把每条边加密为
+//        角距离不超过degrees的若干段，再按平面判断；边越长平面直线与大圆的偏差越大，加密后偏差随degrees的平方减小，
+//        不需要完整的球面算法。0表示不加密（默认），负数或非有限数按0处理；每条边最多加密为65536段
+//     9. options.set_index_kind(kind) 边界检测使用的边索引，只影响速度和内存，不影响判断结果
+//        0: 均匀网格（默认）
+//        1: 四叉树，边的疏密差异很大时（如海岸线）在边密集处继续细分，避免少数网格单元登记过多的边
+//     10. options.set_axis_order(order) 输入坐标的轴顺序，同时作用于点和多边形
+//        0: [x, y, ...]，经纬度即[经度, 纬度, ...]（默认）
+//        1: [y, x, ...]，经纬度即[纬度, 经度, ...]（EPSG:4326的官方轴顺序）
+//     11. options.set_y_down(boolean) y轴向下（屏幕坐标），同时作用于点和多边形；内外判断本身不受镜像影响，
+//        但按绕向区分外环和洞时，"逆时针"按屏幕上看到的方向理解
+//     12. options.set_cache_size(n) 扫描线和射线算法缓存交点列表的扫描线数量，0表示默认（1024）；满时按时钟策略淘汰
+//        最久未被再次访问的行，只影响速度和内存，不影响判断结果。长时间运行的交互式会话可按同时活跃的行数调整
+//     13. options.set_exact(boolean) 精确模式（默认关闭）：穿越判断不再比较浮点求得的交点x坐标，而是按点在边的哪一侧
+//        判断，侧别由自适应精度的方向判定（见certified模块，先按误差界快速判断，无法确定时退回浮点展开式精确计算）给出，
+//        与有理数运算的结果相同。坐标远离原点而多边形很小、容差小于坐标的舍入误差时，距离边只有几个ULP的点也不会误判；
+//        边界只包含恰好落在边上的点，不再使用距离容差。作用于扫描线路径（point_in_polygon_with_options、PreparedPolygon等）和
+//        point_in_polygon_rayster_with_options（精确模式下按扫描线路径判断），PreparedPolygon.with_trapezoids不构建梯形分解
+//     14. options.set_vertex_policy(policy) 水平射线恰好经过顶点时的计数规则，扫描线、射线和环绕数算法使用同一规则（见common模块）
+//        0: 顶点视为位于射线下方，只有 min_y <= y < max_y 的边计一次穿越（默认，与PNPOLY的 (yi > y) != (yj > y) 一致）
+//        1: 顶点视为位于射线上方，只有 min_y < y <= max_y 的边计一次穿越（与写作 (yi >= y) != (yj >= y) 的库一致）
+//        不在边界上的点按两种规则得到相同的内外结果，规则只影响各环的穿越计数（ring_crossing_counts_with_options），
+//        用于与其他GIS库逐环对照诊断输出
+//     15. point_in_polygon_with_options(points, polygon, rings, options)
+//     16. point_in_polygon_with_confidence(points, polygon, rings, options) 带置信标记的结果
+//     17. point_in_polygon_f64(points, polygon, rings, options) 点和多边形都是Float64Array，不损失投影坐标的精度
+//     18. point_in_polygon_with_uncertainty(points, radii, polygon, rings, options) 每个点带各自的不确定半径
+//        （例如GPS的精度半径） 类型Float32Array，长度等于点数
+//     19. point_in_polygon_with_ring_boundaries(points, polygon, rings, ring_boundary_is_inside, options) 按环指定边界语义，
+//        ring_boundary_is_inside 类型Uint8Array，第r个元素为第r个环（外环和洞按输入顺序编号）边界上的点是否视为内部，
+//        非0表示内部；数组比环数短时，缺少的环按options.boundary_is_inside处理。例如[1, 0, 0]表示外环闭合、洞开放
+// 输出(js端):
+//     1. 点云是否在多边形内部 类型Uint32Array 1表示在多边形内部,0表示在多边形外部
+//     2. 带置信标记的结果 类型Uint32Array 0表示外部, 1表示内部, 2表示不确定（到边界的距离不超过容差，
+//        结果取决于容差的取值，由调用方另行处理）；boundary_is_inside对该输出不起作用
+//     3. 带不确定半径的结果 类型Uint32Array 标签与带置信标记的结果相同，以点为圆心、radii[i]为半径的圆与边界相交
+//        （到边界的距离不超过半径或容差）时为2，即点的真实位置可能在任意一侧；半径为负数或非有限数时按0处理，radii长度不等于点数时抛出错误
+// 说明: 退化环指不同顶点少于3个、全部顶点共线或面积为0（沿原路折返）的环，这类环不围成任何区域
+
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use super::scanline::{classify_points_with, locate_points_with, ScanlineIndex};
+use super::Location;
+use super::common::DEFAULT_CACHE_SIZE;
+
+pub mod test;
+
+// 退化环的处理方式
+pub(crate) const DEGENERATE_RINGS_DROP: u32 = 0;
+pub(crate) const DEGENERATE_RINGS_BOUNDARY: u32 = 1;
+
+// 区分外环和洞的方式
+pub(crate) const HOLES_BY_ORDER: u32 = 0;
+pub(crate) const HOLES_BY_WINDING: u32 = 1;
+pub(crate) const HOLES_BY_FILL_RULE: u32 = 2;
+
+// 填充规则
+pub(crate) const FILL_EVEN_ODD: u32 = 0;
+pub(crate) const FILL_NONZERO: u32 = 1;
+
+// 边索引的种类
+pub(crate) const INDEX_GRID: u32 = 0;
+pub(crate) const INDEX_QUADTREE: u32 = 1;
+
+// 输入坐标的轴顺序
+pub(crate) const AXIS_XY: u32 = 0;
+pub(crate) const AXIS_YX: u32 = 1;
+
+// 扫描线经过顶点时的计数规则
+pub(crate) const VERTEX_BELOW: u32 = 0;
+pub(crate) const VERTEX_ABOVE: u32 = 1;
+
+// 带置信标记的输出标签
+const LABEL_OUTSIDE: u32 = 0;
+const LABEL_INSIDE: u32 = 1;
+const LABEL_INDETERMINATE: u32 = 2;
+
+// 判断选项
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy)]
+pub struct PipOptions {
+    pub(crate) boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+    pub(crate) degenerate_rings: u32,    // 退化环的处理方式
+    pub(crate) tolerance: f64,           // 距离容差，不大于0时按多边形尺度自动确定
+    pub(crate) hole_detection: u32,      // 区分外环和洞的方式
+    pub(crate) fill_rule: u32,           // 填充规则
+    pub(crate) grid_resolution: u32,     // 空间网格每个方向的单元数，0表示自动
+    pub(crate) great_arc_step: f64,      // 沿大圆加密边的最大角距离（度），0表示不加密
+    pub(crate) index_kind: u32,          // 边索引的种类
+    pub(crate) axis_order: u32,          // 输入坐标的轴顺序
+    pub(crate) y_down: bool,             // y轴是否向下
+    pub(crate) cache_size: u32,          // 交点缓存的扫描线数量，0表示默认
+    pub(crate) exact: bool,              // 是否按精确的方向判定判断穿越
+    pub(crate) vertex_policy: u32,       // 扫描线经过顶点时的计数规则
+}
+
+impl Default for PipOptions {
+    fn default() -> PipOptions {
+        PipOptions { boundary_is_inside: true, degenerate_rings: DEGENERATE_RINGS_DROP, tolerance: 0.0, hole_detection: HOLES_BY_ORDER, fill_rule: FILL_EVEN_ODD, grid_resolution: 0, great_arc_step: 0.0, index_kind: INDEX_GRID, axis_order: AXIS_XY, y_down: false, cache_size: 0, exact: false, vertex_policy: VERTEX_BELOW }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl PipOptions {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new() -> PipOptions {
+        PipOptions::default()
+    }
+
+    pub fn set_boundary_is_inside(&mut self, boundary_is_inside: bool) {
+        self.boundary_is_inside = boundary_is_inside;
+    }
+
+    // 未知的取值按丢弃处理
+    pub fn set_degenerate_rings(&mut self, policy: u32) {
+        self.degenerate_rings = if policy == DEGENERATE_RINGS_BOUNDARY { policy } else { DEGENERATE_RINGS_DROP };
+    }
+
+    // 不大于0或不是有限数时恢复自动容差
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = if tolerance.is_finite() { tolerance.max(0.0) } else { 0.0 };
+    }
+
+    // 未知的取值按顺序处理
+    pub fn set_hole_detection(&mut self, mode: u32) {
+        self.hole_detection = if mode == HOLES_BY_WINDING || mode == HOLES_BY_FILL_RULE { mode } else { HOLES_BY_ORDER };
+    }
+
+    // 未知的取值按奇偶规则处理
+    pub fn set_fill_rule(&mut self, rule: u32) {
+        self.fill_rule = if rule == FILL_NONZERO { rule } else { FILL_EVEN_ODD };
+    }
+
+    pub fn boundary_is_inside(&self) -> bool {
+        self.boundary_is_inside
+    }
+
+    pub fn degenerate_rings(&self) -> u32 {
+        self.degenerate_rings
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    pub fn hole_detection(&self) -> u32 {
+        self.hole_detection
+    }
+
+    pub fn fill_rule(&self) -> u32 {
+        self.fill_rule
+    }
+
+    pub fn set_grid_resolution(&mut self, resolution: u32) {
+        self.grid_resolution = resolution;
+    }
+
+    pub fn grid_resolution(&self) -> u32 {
+        self.grid_resolution
+    }
+
+    // 不大于0或不是有限数时不加密
+    pub fn set_great_arc_step(&mut self, degrees: f64) {
+        self.great_arc_step = if degrees.is_finite() { degrees.max(0.0) } else { 0.0 };
+    }
+
+    pub fn great_arc_step(&self) -> f64 {
+        self.great_arc_step
+    }
+
+    // 未知的取值按均匀网格处理
+    pub fn set_index_kind(&mut self, kind: u32) {
+        self.index_kind = if kind == INDEX_QUADTREE { kind } else { INDEX_GRID };
+    }
+
+    pub fn index_kind(&self) -> u32 {
+        self.index_kind
+    }
+
+    // 未知的取值按[x, y]处理
+    pub fn set_axis_order(&mut self, order: u32) {
+        self.axis_order = if order == AXIS_YX { order } else { AXIS_XY };
+    }
+
+    pub fn axis_order(&self) -> u32 {
+        self.axis_order
+    }
+
+    pub fn set_y_down(&mut self, y_down: bool) {
+        self.y_down = y_down;
+    }
+
+    pub fn y_down(&self) -> bool {
+        self.y_down
+    }
+
+    pub fn set_cache_size(&mut self, size: u32) {
+        self.cache_size = size;
+    }
+
+    pub fn cache_size(&self) -> u32 {
+        self.cache_size
+    }
+
+    pub fn set_exact(&mut self, exact: bool) {
+        self.exact = exact;
+    }
+
+    pub fn exact(&self) -> bool {
+        self.exact
+    }
+
+    // 未知的取值按顶点位于射线下方处理
+    pub fn set_vertex_policy(&mut self, policy: u32) {
+        self.vertex_policy = if policy == VERTEX_ABOVE { policy } else { VERTEX_BELOW };
+    }
+
+    pub fn vertex_policy(&self) -> u32 {
+        self.vertex_policy
+    }
+}
+
+impl PipOptions {
+    // 只指定边界语义，其余取默认值
+    pub(crate) fn with_boundary(boundary_is_inside: bool) -> PipOptions {
+        PipOptions { boundary_is_inside, ..PipOptions::default() }
+    }
+
+    // 按轴顺序和y轴方向把输入的一对坐标转换为内部使用的(x, y)（x向右、y向上），点和多边形顶点都经过这里
+    #[inline]
+    pub(crate) fn axes(&self, a: f64, b: f64) -> (f64, f64) {
+        let (x, y) = if self.axis_order == AXIS_YX { (b, a) } else { (a, b) };
+        if self.y_down { (x, -y) } else { (x, y) }
+    }
+
+    // 交点缓存实际使用的容量
+    pub(crate) fn cache_capacity(&self) -> usize {
+        if self.cache_size == 0 { DEFAULT_CACHE_SIZE } else { self.cache_size as usize }
+    }
+
+    // 全部选项的取值，用于比较两组选项是否相同（结果缓存的键），新增字段时需同时加入
+    pub(crate) fn key(&self) -> [u64; 13] {
+        [
+            self.boundary_is_inside as u64,
+            self.degenerate_rings as u64,
+            self.tolerance.to_bits(),
+            self.hole_detection as u64,
+            self.fill_rule as u64,
+            self.grid_resolution as u64,
+            self.great_arc_step.to_bits(),
+            self.index_kind as u64,
+            self.axis_order as u64,
+            self.y_down as u64,
+            self.cache_size as u64,
+            self.exact as u64,
+            self.vertex_policy as u64,
+        ]
+    }
+}
+
+// WebAssembly导出函数：按选项判断点是否在多边形内部
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_with_options(
+    points: &[f32],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],      // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],        // 多边形环的分割索引
+    options: &PipOptions, // 判断选项
+) -> Vec<u32> {
+    classify_points_with(points.len() / 2, |i| (points[i * 2] as f64, points[i * 2 + 1] as f64), polygon, rings, options)
+}
+
+// WebAssembly导出函数：point_in_polygon_with_options的f64坐标版本（Float64Array），整个过程不经过f32
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_f64(
+    points: &[f64],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f64],      // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],        // 多边形环的分割索引
+    options: &PipOptions, // 判断选项
+) -> Vec<u32> {
+    classify_points_with(points.len() / 2, |i| (points[i * 2], points[i * 2 + 1]), polygon, rings, options)
+}
+
+// WebAssembly导出函数：带置信标记的判断，落在容差带内的点标记为不确定，而不是按边界语义归为内部或外部
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_with_confidence(
+    points: &[f32],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],      // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],        // 多边形环的分割索引
+    options: &PipOptions, // 判断选项，容差即不确定带的半宽
+) -> Vec<u32> {
+    locate_points_with(points.len() / 2, |i| (points[i * 2] as f64, points[i * 2 + 1] as f64), polygon, rings, options)
+        .into_iter()
+        .map(|location| match location {
+            Location::Outside => LABEL_OUTSIDE,
+            Location::Inside => LABEL_INSIDE,
+            Location::Boundary => LABEL_INDETERMINATE,
+        })
+        .collect()
+}
+
+// WebAssembly导出函数：每个点带不确定半径的判断，不确定圆与边界相交的点标记为不确定，用于概率地理围栏
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_with_uncertainty(
+    points: &[f32],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    radii: &[f32],        // 每个点的不确定半径，按多边形的坐标单位
+    polygon: &[f32],      // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],        // 多边形环的分割索引
+    options: &PipOptions, // 判断选项
+) -> Result<Vec<u32>, ExportError> {
+    classify_with_uncertainty(points, radii, polygon, rings, options).map_err(export_error)
+}
+
+// 带不确定半径的判断，radii长度不等于点数时返回错误信息
+pub(crate) fn classify_with_uncertainty(
+    points: &[f32],
+    radii: &[f32],
+    polygon: &[f32],
+    rings: &[u32],
+    options: &PipOptions,
+) -> Result<Vec<u32>, String> {
+    let point_count = points.len() / 2;
+    if radii.len() != point_count {
+        return Err(format!("{} radii given for {} points", radii.len(), point_count));
+    }
+    if polygon.is_empty() || rings.is_empty() {
+        return Ok(vec![LABEL_OUTSIDE; point_count]);
+    }
+
+    let index = ScanlineIndex::with_options(polygon, rings, options);
+    let (min_x, min_y, max_x, max_y) = index.bounds();
+    let tolerance = index.polygon().tolerance;
+    Ok(points
+        .chunks_exact(2)
+        .zip(radii)
+        .map(|(point, &radius)| {
+            let (x, y) = options.axes(point[0] as f64, point[1] as f64);
+            // 半径为NaN或无穷大时无法给出确定的判断
+            if !radius.is_finite() {
+                return LABEL_INDETERMINATE;
+            }
+            let radius = (radius as f64).max(0.0);
+            // 不确定圆在边界框（加容差）之外时不可能与边界相交
+            let margin = radius + tolerance;
+            if x < min_x - margin || x > max_x + margin || y < min_y - margin || y > max_y + margin {
+                return LABEL_OUTSIDE;
+            }
+            match index.locate(x, y) {
+                Location::Boundary => LABEL_INDETERMINATE,
+                _ if radius > 0.0 && index.boundary_within(x, y, radius) => LABEL_INDETERMINATE,
+                Location::Inside => LABEL_INSIDE,
+                Location::Outside => LABEL_OUTSIDE,
+            }
+        })
+        .collect())
+}
+
+// WebAssembly导出函数：按环指定边界语义，例如外环的边界视为内部、洞的边界视为外部（闭合的外环、开放的洞）
+// 点同时在多个环的边界上时（洞与外环相切处），只有这些环都把边界视为内部时才为内部
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_with_ring_boundaries(
+    points: &[f32],                 // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],                // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],                  // 多边形环的分割索引
+    ring_boundary_is_inside: &[u8], // 每个环边界上的点是否视为内部，非0表示内部，缺少的环按options处理
+    options: &PipOptions,           // 判断选项
+) -> Vec<u32> {
+    let point_count = points.len() / 2;
+    if point_count == 0 || polygon.is_empty() || rings.is_empty() {
+        return vec![0; point_count];
+    }
+
+    let index = ScanlineIndex::with_options(polygon, rings, options);
+    let ring_inside = |ring: usize| ring_boundary_is_inside.get(ring).map_or(options.boundary_is_inside, |&flag| flag != 0);
+    let mut touched = Vec::new();
+    points
+        .chunks_exact(2)
+        .map(|point| {
+            let (x, y) = options.axes(point[0] as f64, point[1] as f64);
+            match index.locate(x, y) {
+                Location::Inside => 1,
+                Location::Outside => 0,
+                Location::Boundary => {
+                    index.boundary_rings(x, y, &mut touched);
+                    touched.iter().all(|&ring| ring_inside(ring)) as u32
+                }
+            }
+        })
+        .collect()
+}
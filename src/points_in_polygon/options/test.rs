@@ -0,0 +1,429 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::options::{classify_with_uncertainty, point_in_polygon_f64, point_in_polygon_with_confidence, point_in_polygon_with_options, point_in_polygon_with_ring_boundaries, PipOptions};
+    use crate::points_in_polygon::rayster::{point_in_polygon_rayster, point_in_polygon_rayster_f64, point_in_polygon_rayster_with_options};
+    use crate::points_in_polygon::scanline::{point_in_polygon_scanline, point_in_polygon_scanline_f64};
+
+    #[test]
+    fn test_degenerate_ring_policy() {
+        // 正方形外环加一个共线的"洞"（对角线上的一段）
+        let polygon = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // 外环
+            1.0, 1.0, 2.0, 2.0, 3.0, 3.0, // 共线的洞
+        ];
+        let rings = vec![4];
+        let points = vec![
+            2.0, 2.0, // 在共线的洞上
+            1.0, 3.0, // 内部
+            5.0, 5.0, // 外部
+        ];
+
+        // 丢弃（默认）：共线的洞不存在
+        let mut options = PipOptions::new();
+        options.set_boundary_is_inside(false);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), vec![1, 1, 0]);
+        assert_eq!(point_in_polygon_scanline(&points, &polygon, &rings, false), vec![1, 1, 0]);
+
+        // 只作为边界：洞上的点按边界处理，其余点不受影响
+        options.set_degenerate_rings(1);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), vec![0, 1, 0]);
+        options.set_boundary_is_inside(true);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), vec![1, 1, 0]);
+
+        // 未知的取值按丢弃处理
+        options.set_degenerate_rings(7);
+        assert_eq!(options.degenerate_rings(), 0);
+    }
+
+    #[test]
+    fn test_tolerance_scales_with_polygon() {
+        // 带洞的正方形和一组贴近边界的点，按2的幂缩放后坐标仍然精确
+        let polygon = vec![
+            0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0, // 外环
+            0.25, 0.25, 0.75, 0.25, 0.75, 0.75, 0.25, 0.75, // 洞
+        ];
+        let below_one = f32::from_bits(1.0f32.to_bits() - 1);
+        let points = vec![
+            1.0, 0.5, // 在外环上
+            below_one, 0.5, // 外环内侧一个ulp
+            0.5, 0.25, // 在洞上
+            0.1, 0.1, // 内部
+            0.5, 0.5, // 洞内
+            1.5, 0.5, // 外部
+        ];
+        let expected = vec![0, 1, 0, 1, 0, 0];
+
+        for scale in [1.0f32, 1048576.0, 1.0 / 1073741824.0] {
+            let polygon: Vec<f32> = polygon.iter().map(|v| v * scale).collect();
+            let points: Vec<f32> = points.iter().map(|v| v * scale).collect();
+            assert_eq!(point_in_polygon_scanline(&points, &polygon, &[4], false), expected, "scale {}", scale);
+            assert_eq!(point_in_polygon_rayster(&points, &polygon, &[4], false), expected, "scale {}", scale);
+        }
+    }
+
+    #[test]
+    fn test_tolerance_override() {
+        let polygon = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        let points = vec![0.95, 0.5, 0.5, 0.5];
+
+        let mut options = PipOptions::new();
+        options.set_boundary_is_inside(false);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &[4], &options), vec![1, 1]);
+
+        // 距离边0.05的点落在0.1的容差内，视为在边上
+        options.set_tolerance(0.1);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &[4], &options), vec![0, 1]);
+
+        // 非法取值恢复自动容差
+        options.set_tolerance(f64::NAN);
+        assert_eq!(options.tolerance(), 0.0);
+    }
+
+    #[test]
+    fn test_confidence_flags_tolerance_band() {
+        // 非矩形的多边形走扫描线路径，矩形走快速路径，两者的不确定带一致
+        let triangle = vec![0.0, 0.0, 4.0, 0.0, 0.0, 4.0];
+        let square = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let mut options = PipOptions::new();
+        options.set_tolerance(0.1);
+
+        let points = vec![
+            1.0, 0.05, // 距底边0.05，在容差带内
+            1.0, -0.05, // 在底边外侧的容差带内
+            1.0, 0.5, // 内部，距边界超过容差
+            1.0, -0.5, // 外部，距边界超过容差
+        ];
+        assert_eq!(point_in_polygon_with_confidence(&points, &triangle, &[3], &options), vec![2, 2, 1, 0]);
+        assert_eq!(point_in_polygon_with_confidence(&points, &square, &[4], &options), vec![2, 2, 1, 0]);
+
+        // 边界语义不影响带置信标记的输出，容差缩小后带内的点得到确定的结果
+        options.set_boundary_is_inside(false);
+        options.set_tolerance(0.01);
+        assert_eq!(point_in_polygon_with_confidence(&points, &triangle, &[3], &options), vec![1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_f64_keeps_projected_precision() {
+        // UTM坐标（米）附近边长1毫米的正方形，f32在这个量级的间距为0.25米，无法区分这些坐标
+        let (x0, y0) = (500_000.0, 4_000_000.0);
+        let square = vec![x0, y0, x0 + 0.001, y0, x0 + 0.001, y0 + 0.001, x0, y0 + 0.001];
+        let points = vec![
+            x0 + 0.0005, y0 + 0.0005, // 中心，内部
+            x0 + 0.0015, y0 + 0.0005, // 右侧0.5毫米，外部
+            x0 + 0.001, y0 + 0.0002, // 右边上
+        ];
+        // 非矩形的多边形：带凹口的同尺度多边形，走扫描线的一般路径
+        let notched = vec![x0, y0, x0 + 0.001, y0, x0 + 0.001, y0 + 0.001, x0 + 0.0005, y0 + 0.0007, x0, y0 + 0.001];
+
+        let options = PipOptions::new();
+        for polygon in [&square, &notched] {
+            let rings = [(polygon.len() / 2) as u32];
+            assert_eq!(point_in_polygon_f64(&points, polygon, &rings, &options), vec![1, 0, 1]);
+            assert_eq!(point_in_polygon_scanline_f64(&points, polygon, &rings, false), vec![1, 0, 0]);
+            assert_eq!(point_in_polygon_rayster_f64(&points, polygon, &rings, false), vec![1, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_hole_detection_by_winding() {
+        // 洞（顺时针）排在外环（逆时针）之前，外加洞中的岛（逆时针）和一个不相交的三角形（逆时针）
+        let polygon = vec![
+            2.0, 2.0, 2.0, 8.0, 8.0, 8.0, 8.0, 2.0, // 洞，顺时针
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 外环，逆时针
+            4.0, 4.0, 6.0, 4.0, 6.0, 6.0, 4.0, 6.0, // 岛，逆时针
+            20.0, 0.0, 30.0, 0.0, 25.0, 10.0, // 三角形，逆时针
+        ];
+        let rings = vec![4, 8, 12];
+        let points = vec![
+            1.0, 1.0, // 外环内、洞外
+            3.0, 3.0, // 洞中
+            5.0, 5.0, // 岛内
+            25.0, 3.0, // 三角形内
+            15.0, 5.0, // 外部
+        ];
+
+        let mut options = PipOptions::new();
+        options.set_hole_detection(1);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), vec![1, 0, 1, 1, 0]);
+
+        // 只有一个顺时针的环时，它不在任何外环内，按外环处理
+        let clockwise = vec![0.0, 0.0, 0.0, 10.0, 10.0, 10.0, 10.0, 0.0];
+        assert_eq!(point_in_polygon_with_options(&points, &clockwise, &[4], &options), vec![1, 1, 1, 0, 0]);
+
+        // 默认按顺序：第一个环（这里是洞）被当作外环，其余环都被当作洞，洞外的点都不在内部
+        options.set_hole_detection(0);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), vec![0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_nonzero_fill_rule() {
+        // 五角星（一笔画成，中心五边形被环绕两次）和沿同一方向绕了两圈的正方形
+        let star = vec![0.0, 10.0, 6.0, -8.0, -10.0, 3.0, 10.0, 3.0, -6.0, -8.0];
+        let twice = vec![
+            20.0, 0.0, 24.0, 0.0, 24.0, 4.0, 20.0, 4.0, // 第一圈
+            20.0, 0.0, 24.0, 0.0, 24.0, 4.0, 20.0, 4.0, // 第二圈
+        ];
+        let points = [
+            0.0, 0.5, // 五角星中心
+            0.0, 7.0, // 五角星的尖角内
+            0.0, -9.0, // 五角星外
+            22.0, 2.0, // 两圈的正方形内
+        ];
+
+        let mut options = PipOptions::new();
+        assert_eq!(point_in_polygon_with_options(&points[..6], &star, &[5], &options), vec![0, 1, 0]);
+        assert_eq!(point_in_polygon_with_options(&points[6..], &twice, &[8], &options), vec![0]);
+        assert_eq!(point_in_polygon_rayster_with_options(&points[..6], &star, &[5], &options), vec![0, 1, 0]);
+
+        options.set_fill_rule(1);
+        assert_eq!(point_in_polygon_with_options(&points[..6], &star, &[5], &options), vec![1, 1, 0]);
+        assert_eq!(point_in_polygon_with_options(&points[6..], &twice, &[8], &options), vec![1]);
+        assert_eq!(point_in_polygon_rayster_with_options(&points[..6], &star, &[5], &options), vec![1, 1, 0]);
+        assert_eq!(point_in_polygon_rayster_with_options(&points[6..], &twice, &[8], &options), vec![1]);
+
+        // 洞同样按非零规则判断：反向绕行的洞挖去五角星的中心
+        let mut holed = star.clone();
+        holed.extend_from_slice(&[-1.0, -1.0, -1.0, 2.0, 1.0, 2.0, 1.0, -1.0]);
+        assert_eq!(point_in_polygon_with_options(&points[..6], &holed, &[5], &options), vec![0, 1, 0]);
+
+        // 未知的取值按奇偶规则处理
+        options.set_fill_rule(9);
+        assert_eq!(options.fill_rule(), 0);
+    }
+
+    #[test]
+    fn test_great_arc_step() {
+        // 纬度60度上跨90个经度的长边，大圆弧向极地方向凸出（中点约在纬度67.8度）
+        let polygon = vec![0.0, 0.0, 90.0, 0.0, 90.0, 60.0, 0.0, 60.0];
+        let points = vec![45.0, 63.0, 45.0, 70.0, 45.0, 30.0, 88.0, 60.5];
+        let mut options = PipOptions::new();
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &[4], &options), vec![0, 0, 1, 0]);
+
+        options.set_great_arc_step(1.0);
+        assert_eq!(options.great_arc_step(), 1.0);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &[4], &options), vec![1, 0, 1, 1]);
+
+        // 沿赤道和经线的边本身就是大圆，加密不改变两侧的结果
+        let square = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+        let near = [5.0, -0.01, 5.0, 0.01, 10.01, 5.0, 9.99, 5.0];
+        assert_eq!(point_in_polygon_with_options(&near, &square, &[4], &options), vec![0, 1, 0, 1]);
+
+        // 步长极小时按段数上限加密，不会因分配过大而失败
+        options.set_great_arc_step(1e-300);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &[4], &options), vec![1, 0, 1, 1]);
+
+        options.set_great_arc_step(f64::NAN);
+        assert_eq!(options.great_arc_step(), 0.0);
+    }
+
+    #[test]
+    fn test_axis_order_and_y_down() {
+        // 不对称的L形，[纬度, 经度]顺序输入的点和多边形与[经度, 纬度]顺序的结果一致
+        let lon_lat = vec![0.0, 0.0, 4.0, 0.0, 4.0, 1.0, 1.0, 1.0, 1.0, 3.0, 0.0, 3.0];
+        let points = vec![3.0, 0.5, 0.5, 2.5, 3.0, 2.5];
+        let swap = |v: &[f32]| v.chunks_exact(2).flat_map(|p| [p[1], p[0]]).collect::<Vec<f32>>();
+        let expected = point_in_polygon_with_options(&points, &lon_lat, &[6], &PipOptions::new());
+        assert_eq!(expected, vec![1, 1, 0]);
+
+        let mut options = PipOptions::new();
+        options.set_axis_order(1);
+        assert_eq!(options.axis_order(), 1);
+        assert_eq!(point_in_polygon_with_options(&swap(&points), &swap(&lon_lat), &[6], &options), expected);
+        assert_eq!(point_in_polygon_rayster_with_options(&swap(&points), &swap(&lon_lat), &[6], &options), expected);
+        let prepared = crate::points_in_polygon::prepared::PreparedPolygon::with_options(&swap(&lon_lat), &[6], &options);
+        assert_eq!(prepared.query(&swap(&points), true), expected);
+
+        // 屏幕坐标：在屏幕上逆时针的外环（y轴向上时为顺时针）和顺时针的洞，按绕向区分时与手动翻转y坐标的结果一致
+        let screen = vec![
+            0.0, 0.0, 0.0, 10.0, 10.0, 10.0, 10.0, 0.0, // 屏幕上逆时针
+            3.0, 3.0, 7.0, 3.0, 7.0, 7.0, 3.0, 7.0, // 屏幕上顺时针
+        ];
+        let inner = [5.0, 5.0, 1.0, 1.0, 11.0, 1.0];
+        let flip = |v: &[f32]| v.chunks_exact(2).flat_map(|p| [p[0], -p[1]]).collect::<Vec<f32>>();
+        let mut options = PipOptions::new();
+        options.set_hole_detection(1);
+        let flipped = point_in_polygon_with_options(&flip(&inner), &flip(&screen), &[4], &options);
+        assert_eq!(flipped, vec![0, 1, 0]);
+        options.set_y_down(true);
+        assert!(options.y_down());
+        assert_eq!(point_in_polygon_with_options(&inner, &screen, &[4], &options), flipped);
+    }
+
+    #[test]
+    fn test_rings_combined_by_fill_rule() {
+        // 三个不分角色的环：两个相交的正方形和其中一个内部的小正方形，顺序和绕向任意
+        let polygon = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // 逆时针
+            2.0, 2.0, 2.0, 6.0, 6.0, 6.0, 6.0, 2.0, // 顺时针
+            0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5, 1.5, // 逆时针，在第一个正方形内
+        ];
+        let rings = [4, 8];
+        let points = vec![
+            1.0, 1.0, // 被两个环覆盖
+            1.0, 3.0, // 只在第一个正方形内
+            3.0, 3.0, // 两个大正方形的重叠处
+            5.0, 5.0, // 只在第二个正方形内
+            7.0, 7.0, // 外部
+        ];
+
+        let mut options = PipOptions::new();
+        options.set_hole_detection(2);
+        assert_eq!(options.hole_detection(), 2);
+        let even_odd = vec![0, 1, 0, 1, 0];
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), even_odd);
+        assert_eq!(point_in_polygon_rayster_with_options(&points, &polygon, &rings, &options), even_odd);
+        assert_eq!(crate::points_in_polygon::prepared::PreparedPolygon::with_trapezoids(&polygon, &rings, &options).query(&points, true), even_odd);
+
+        // 非零规则：反向的第二个正方形与第一个的重叠处环绕数为0，同向的小正方形环绕数为2
+        options.set_fill_rule(1);
+        let nonzero = vec![1, 1, 0, 1, 0];
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), nonzero);
+        assert_eq!(point_in_polygon_rayster_with_options(&points, &polygon, &rings, &options), nonzero);
+    }
+
+    #[test]
+    fn test_per_point_uncertainty() {
+        let polygon = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+        let options = PipOptions::new();
+        let points = vec![5.0, 5.0, 5.0, 5.0, 9.0, 5.0, 11.0, 5.0, 11.0, 5.0, 20.0, 20.0, 10.0, 5.0];
+        // 圆心在内部但圆越过边界、圆心在外部但圆越过边界、边界上的点半径为0也不确定
+        let radii = vec![1.0, 6.0, 2.0, 0.5, 1.5, 100.0, 0.0];
+        assert_eq!(classify_with_uncertainty(&points, &radii, &polygon, &[4], &options).unwrap(), vec![1, 2, 2, 0, 2, 2, 2]);
+
+        // 负数半径按0处理，NaN和无穷大的半径不确定
+        let radii = vec![-1.0, f32::NAN, 0.0, 0.0, f32::INFINITY, 0.0, 0.0];
+        assert_eq!(classify_with_uncertainty(&points, &radii, &polygon, &[4], &options).unwrap(), vec![1, 2, 1, 0, 2, 0, 2]);
+
+        assert!(classify_with_uncertainty(&points, &[1.0], &polygon, &[4], &options).is_err());
+        assert_eq!(classify_with_uncertainty(&points[..2], &[5.0], &[], &[], &options).unwrap(), vec![0]);
+
+        // 边界框外、但在容差范围内的点在边界上
+        let mut options = PipOptions::new();
+        options.set_tolerance(0.1);
+        let points = vec![10.05, 5.0, 5.0, -0.05, 10.2, 5.0];
+        assert_eq!(classify_with_uncertainty(&points, &[0.0, 0.0, 0.0], &polygon, &[4], &options).unwrap(), vec![2, 2, 0]);
+    }
+
+    #[test]
+    fn test_uncertainty_matches_boundary_distance() {
+        use crate::points_in_polygon::options::INDEX_QUADTREE;
+        use crate::points_in_polygon::scanline::ScanlineIndex;
+
+        // 边很多的星形，两种边索引下都与逐边求最短距离的结果一致
+        let mut polygon = Vec::new();
+        for i in 0..400 {
+            let angle = i as f32 * core::f32::consts::TAU / 400.0;
+            let r = if i % 2 == 0 { 10.0 } else { 7.0 };
+            polygon.extend_from_slice(&[r * angle.cos(), r * angle.sin()]);
+        }
+        let points = crate::generators::uniform_points(1000, -12.0, -12.0, 12.0, 12.0, 9);
+        let radii: Vec<f32> = (0..1000).map(|k| (k % 7) as f32 * 0.4).collect();
+        let mut quadtree = PipOptions::new();
+        quadtree.set_index_kind(INDEX_QUADTREE);
+        for options in [PipOptions::new(), quadtree] {
+            let labels = classify_with_uncertainty(&points, &radii, &polygon, &[400], &options).unwrap();
+            let index = ScanlineIndex::with_options(&polygon, &[400], &options);
+            for (k, point) in points.chunks_exact(2).enumerate() {
+                let (x, y) = (point[0] as f64, point[1] as f64);
+                let near = index.boundary_distance(x, y) <= radii[k] as f64;
+                assert_eq!(labels[k] == 2, near || index.on_boundary(x, y), "point {}", k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_exact_mode_near_edges() {
+        use crate::points_in_polygon::certified::orient2d;
+        use std::cmp::Ordering;
+
+        // 远离原点的小三角形（逆时针），容差远小于坐标的舍入误差
+        let (ox, oy) = (1.0e7, -3.0e7);
+        let polygon = vec![ox + 0.1, oy + 0.3, ox + 0.7, oy + 1.9, ox - 0.45, oy + 1.3];
+        let rings = vec![3];
+        let mut options = PipOptions::new();
+        options.set_tolerance(1e-300);
+        options.set_boundary_is_inside(false);
+        assert!(!options.exact());
+        options.set_exact(true);
+
+        // 边上各处的点，沿x方向偏移若干个ULP
+        let mut points = Vec::new();
+        for k in 0..3 {
+            let (ax, ay) = (polygon[k * 2], polygon[k * 2 + 1]);
+            let (bx, by) = (polygon[(k + 1) % 3 * 2], polygon[(k + 1) % 3 * 2 + 1]);
+            for step in 1..40 {
+                let t = step as f64 / 40.0;
+                let (x, y) = (ax + (bx - ax) * t, ay + (by - ay) * t);
+                for ulps in -3i64..=3 {
+                    points.push(f64::from_bits((x.to_bits() as i64 + ulps) as u64));
+                    points.push(y);
+                }
+            }
+        }
+
+        // 真实结果：对逆时针的凸多边形，点在内部当且仅当在每条边的左侧
+        let expected: Vec<u32> = (0..points.len() / 2)
+            .map(|i| {
+                let (x, y) = (points[i * 2], points[i * 2 + 1]);
+                (0..3).all(|k| {
+                    let (ax, ay) = (polygon[k * 2], polygon[k * 2 + 1]);
+                    let (bx, by) = (polygon[(k + 1) % 3 * 2], polygon[(k + 1) % 3 * 2 + 1]);
+                    orient2d(ax, ay, bx, by, x, y) == Ordering::Greater
+                }) as u32
+            })
+            .collect();
+        assert_eq!(point_in_polygon_f64(&points, &polygon, &rings, &options), expected);
+
+
+        // 精确模式下只有恰好在边上的点（这里是顶点）为边界，不受容差影响
+        options.set_tolerance(0.5);
+        let near = vec![ox + 0.1, oy + 0.3, ox + 0.7, oy + 1.9, ox + 0.1, oy + 0.2];
+        assert_eq!(point_in_polygon_f64(&near, &polygon, &rings, &options), vec![0, 0, 0]);
+        options.set_boundary_is_inside(true);
+        assert_eq!(point_in_polygon_f64(&near, &polygon, &rings, &options), vec![1, 1, 0]);
+
+        // 轴对齐的矩形不走按容差判断的快速路径
+        let square = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        let points = vec![1e-300, 0.5, 0.0, 0.5, -1e-300, 0.5];
+        assert_eq!(point_in_polygon_f64(&points, &square, &[4], &options), vec![1, 1, 0]);
+        options.set_boundary_is_inside(false);
+        assert_eq!(point_in_polygon_f64(&points, &square, &[4], &options), vec![1, 0, 0]);
+    }
+
+    #[test]
+    fn test_per_ring_boundary() {
+        // 外环、一个方形洞、一个顶点落在外环底边上的三角形洞
+        let polygon = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // 外环
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // 洞1
+            3.0, 0.0, 3.5, 1.0, 2.5, 1.0, // 洞2
+        ];
+        let rings = vec![4, 8];
+        let points = vec![
+            0.0, 2.0, // 外环边界
+            1.0, 1.5, // 洞1边界
+            3.0, 0.0, // 外环与洞2共同的边界点
+            0.5, 0.5, // 内部
+            1.5, 1.5, // 洞1内
+            3.0, 0.5, // 洞2内
+            5.0, 5.0, // 外部
+        ];
+        let mut options = PipOptions::new();
+
+        // 外环闭合、洞开放；两个环共同的边界点只有两者都视为内部时才是内部
+        let result = point_in_polygon_with_ring_boundaries(&points, &polygon, &rings, &[1, 0, 0], &options);
+        assert_eq!(result, vec![1, 0, 0, 1, 0, 0, 0]);
+        let result = point_in_polygon_with_ring_boundaries(&points, &polygon, &rings, &[0, 1, 1], &options);
+        assert_eq!(result, vec![0, 1, 0, 1, 0, 0, 0]);
+
+        // 数组为空或较短时，缺少的环按options.boundary_is_inside
+        let all = point_in_polygon_with_ring_boundaries(&points, &polygon, &rings, &[], &options);
+        assert_eq!(all, point_in_polygon_with_options(&points, &polygon, &rings, &options));
+        options.set_boundary_is_inside(false);
+        let result = point_in_polygon_with_ring_boundaries(&points, &polygon, &rings, &[0, 1], &options);
+        assert_eq!(result, vec![0, 1, 0, 1, 0, 0, 0]);
+
+        // 空多边形
+        assert_eq!(point_in_polygon_with_ring_boundaries(&points, &polygon, &[], &[1], &options), vec![0; 7]);
+    }
+}
@@ -0,0 +1,230 @@
+// 预处理多边形模块：多边形的边、环边界框和空间网格只构建一次，保存在js端持有的对象中，
+// 同一个多边形被反复查询（例如交互式拖动选区时逐帧判断）时省去每次调用的重建开销
+
+// 输入(js端):
+//     1. const prepared = new PreparedPolygon(polygon, rings) 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+//     2. PreparedPolygon.with_options(polygon, rings, options) 按PipOptions构建，例如指定距离容差，
+//        经纬度（度）数据和毫米级CAD数据的合适容差相差很大，不适合自动容差时在这里指定；options中的边界语义不起作用，
+//        边的疏密差异很大（如海岸线）时可用options.set_index_kind(1)改用四叉树边索引，查询方式不变
+//     3. PreparedPolygon.with_trapezoids(polygon, rings, options) 额外构建梯形分解（见trapezoid模块），
+//        每个点的判断为O(log n)，适合对巨大的静态多边形反复查询；边过多无法构建时仍按扫描线判断，uses_trapezoids()为false
+//     4. prepared.query(points, boundary_is_inside) 点云 类型Float32Array
+//     5. prepared.query_into(points, boundary_is_inside, out) 结果写入调用方提供的Uint32Array（长度至少为点数），不分配结果数组
+//     6. 流式查询：prepared.begin_query(boundary_is_inside) 开始，prepared.push_chunk(points) 逐块判断并返回该块的结果，
+//        prepared.finish() 结束并返回内部点的总数；点云不需要一次性放进wasm内存，块可以在任意坐标处切开，
+//        块末尾多出的x坐标保留到下一块，与下一块开头的y坐标组成一个点
+//     7. 批量预处理：prepare_polygons(polygons, polygon_splits, rings, options, parallel) 一次调用构建多个多边形，
+//        输入格式与coverage相同（所有多边形的路径点依次拼接，polygon_splits为第2个及之后每个多边形的起始顶点索引，rings为洞的全局起始顶点索引），
+//        返回PreparedPolygons，collection.query(k, points, boundary_is_inside)判断第k个多边形；
+//        parallel为true且当前构建支持多线程（见threads模块）时分给多个线程构建，否则依次构建，结果相同
+// 输出(js端):
+//     1. 点云是否在多边形内部 类型Uint32Array 1表示在多边形内部,0表示在多边形外部
+// 说明: 结果与point_in_polygon_scanline相同；扫描线交点缓存也保存在对象中，跨调用复用。用完后调用free()释放；
+//     未调用begin_query就push_chunk或finish、finish时还有多出的x坐标时抛出错误；查询进行中再次begin_query会重新开始
+
+use alloc::{format, string::String, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use super::options::PipOptions;
+use super::scanline::ScanlineIndex;
+use super::trapezoid::TrapezoidMap;
+use super::Location;
+use crate::coverage::split_polygons;
+
+pub mod test;
+
+// 预处理后的多边形
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct PreparedPolygon {
+    index: ScanlineIndex,
+    trapezoids: Option<TrapezoidMap>,
+    options: PipOptions, // 查询的点按其中的轴顺序和y轴方向转换
+    stream: Option<StreamState>, // 进行中的流式查询
+}
+
+// 一组预处理后的多边形，例如启动时加载的几百个区域边界
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct PreparedPolygons {
+    items: Vec<PreparedPolygon>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl PreparedPolygons {
+    // 多边形的个数
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    // 判断点云中每个点是否在第k个多边形内部，k超出范围时抛出错误
+    pub fn query(&self, k: usize, points: &[f32], boundary_is_inside: bool) -> Result<Vec<u32>, ExportError> {
+        self.query_values(k, points, boundary_is_inside).map_err(export_error)
+    }
+}
+
+impl PreparedPolygons {
+    // 判断第k个多边形，k超出范围时返回错误信息
+    pub(crate) fn query_values(&self, k: usize, points: &[f32], boundary_is_inside: bool) -> Result<Vec<u32>, String> {
+        let item = self.items.get(k).ok_or_else(|| format!("polygon {} is out of range, there are {} polygons", k, self.items.len()))?;
+        Ok(item.query(points, boundary_is_inside))
+    }
+}
+
+// WebAssembly导出函数：一次构建多个预处理多边形
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn prepare_polygons(
+    polygons: &[f32],       // 所有多边形顶点依次拼接
+    polygon_splits: &[u32], // 第2个及之后每个多边形的起始顶点索引
+    rings: &[u32],          // 洞的起始顶点索引（全局）
+    options: &PipOptions,   // 构建选项，所有多边形相同
+    parallel: bool,         // 是否分给多个线程构建
+) -> PreparedPolygons {
+    let parts = split_polygons(polygons, polygon_splits, rings);
+    let build = |(polygon, local_rings): &(&[f32], Vec<u32>)| PreparedPolygon::with_options(polygon, local_rings, options);
+    #[cfg(rayon_backend)]
+    if parallel && crate::threads::threads_ready() {
+        use rayon::prelude::*;
+        return PreparedPolygons { items: parts.par_iter().map(build).collect() };
+    }
+    // 不支持多线程的构建中parallel不起作用
+    let _ = parallel;
+    PreparedPolygons { items: parts.iter().map(build).collect() }
+}
+
+// 流式查询的状态
+struct StreamState {
+    boundary_is_inside: bool,
+    point_count: usize,   // 已判断的点数
+    inside_count: usize,  // 已判断的点中内部点的个数
+    pending: Option<f32>, // 上一块末尾多出的x坐标
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl PreparedPolygon {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(polygon: &[f32], rings: &[u32]) -> PreparedPolygon {
+        PreparedPolygon { index: ScanlineIndex::new(polygon, rings), trapezoids: None, options: PipOptions::default(), stream: None }
+    }
+
+    // 按选项构建
+    pub fn with_options(polygon: &[f32], rings: &[u32], options: &PipOptions) -> PreparedPolygon {
+        PreparedPolygon::from_coordinates(polygon, rings, options)
+    }
+
+    // 按选项构建，并额外构建梯形分解
+    pub fn with_trapezoids(polygon: &[f32], rings: &[u32], options: &PipOptions) -> PreparedPolygon {
+        let index = ScanlineIndex::with_options(polygon, rings, options);
+        // 精确模式下梯形分解的判断不是精确的，只按扫描线判断
+        let trapezoids = if options.exact { None } else { TrapezoidMap::new(index.polygon()) };
+        PreparedPolygon { index, trapezoids, options: *options, stream: None }
+    }
+
+    // 是否使用梯形分解判断
+    pub fn uses_trapezoids(&self) -> bool {
+        self.trapezoids.is_some()
+    }
+
+    // 判断点云中每个点是否在多边形内部
+    pub fn query(&self, points: &[f32], boundary_is_inside: bool) -> Vec<u32> {
+        points
+            .chunks_exact(2)
+            .map(|point| self.locate(point[0] as f64, point[1] as f64).to_u32(boundary_is_inside))
+            .collect()
+    }
+
+    // 判断点云并把结果写入out
+    pub fn query_into(&self, points: &[f32], boundary_is_inside: bool, out: &mut [u32]) -> Result<(), ExportError> {
+        self.fill(points, boundary_is_inside, out).map_err(export_error)
+    }
+
+    // 开始流式查询
+    pub fn begin_query(&mut self, boundary_is_inside: bool) {
+        self.stream = Some(StreamState { boundary_is_inside, point_count: 0, inside_count: 0, pending: None });
+    }
+
+    // 判断下一块点，返回其中完整的点的结果
+    pub fn push_chunk(&mut self, points: &[f32]) -> Result<Vec<u32>, ExportError> {
+        self.push_chunk_values(points).map_err(export_error)
+    }
+
+    // 结束流式查询，返回内部点的总数
+    pub fn finish(&mut self) -> Result<usize, ExportError> {
+        self.finish_values().map_err(export_error)
+    }
+
+    // 流式查询中已判断的点数，没有进行中的查询时为0
+    pub fn streamed_points(&self) -> usize {
+        self.stream.as_ref().map_or(0, |stream| stream.point_count)
+    }
+}
+
+impl PreparedPolygon {
+    // 由任意精度的坐标按选项构建，f64坐标供本机Rust接口使用（见formats::geo模块）
+    pub(crate) fn from_coordinates<C: Copy + Into<f64>>(polygon: &[C], rings: &[u32], options: &PipOptions) -> PreparedPolygon {
+        PreparedPolygon { index: ScanlineIndex::with_options(polygon, rings, options), trapezoids: None, options: *options, stream: None }
+    }
+
+    // 把结果写入out，out比点数短时返回错误信息
+    pub(crate) fn fill(&self, points: &[f32], boundary_is_inside: bool, out: &mut [u32]) -> Result<(), String> {
+        let point_count = points.len() / 2;
+        if out.len() < point_count {
+            return Err(format!("output buffer holds {} results but there are {} points", out.len(), point_count));
+        }
+        for (point, result) in points.chunks_exact(2).zip(out.iter_mut()) {
+            *result = self.locate(point[0] as f64, point[1] as f64).to_u32(boundary_is_inside);
+        }
+        Ok(())
+    }
+
+    // 流式查询的一块，没有进行中的查询时返回错误信息
+    pub(crate) fn push_chunk_values(&mut self, points: &[f32]) -> Result<Vec<u32>, String> {
+        let mut stream = self.stream.take().ok_or("push_chunk called before begin_query")?;
+        let boundary_is_inside = stream.boundary_is_inside;
+        let mut results = Vec::with_capacity(points.len().div_ceil(2));
+        let mut rest = points;
+        if let (Some(x), Some((&y, tail))) = (stream.pending, rest.split_first()) {
+            results.push(self.locate(x as f64, y as f64).to_u32(boundary_is_inside));
+            stream.pending = None;
+            rest = tail;
+        }
+        let pairs = rest.chunks_exact(2);
+        if let [x] = pairs.remainder() {
+            stream.pending = Some(*x);
+        }
+        results.extend(pairs.map(|point| self.locate(point[0] as f64, point[1] as f64).to_u32(boundary_is_inside)));
+
+        stream.point_count += results.len();
+        stream.inside_count += results.iter().filter(|&&result| result == 1).count();
+        self.stream = Some(stream);
+        Ok(results)
+    }
+
+    // 结束流式查询，返回内部点的总数
+    pub(crate) fn finish_values(&mut self) -> Result<usize, String> {
+        let stream = self.stream.take().ok_or("finish called before begin_query")?;
+        if stream.pending.is_some() {
+            return Err(format!("stream ended with an unpaired x coordinate after {} points", stream.point_count));
+        }
+        Ok(stream.inside_count)
+    }
+
+    // 有梯形分解时先检测边界，再查找点所在的梯形；落在交叉条带中的点按扫描线判断
+    pub(crate) fn locate(&self, x: f64, y: f64) -> Location {
+        let (x, y) = self.options.axes(x, y);
+        let Some(trapezoids) = &self.trapezoids else {
+            return self.index.locate(x, y);
+        };
+        if self.index.on_boundary(x, y) {
+            return Location::Boundary;
+        }
+        match trapezoids.classify(x, y) {
+            Some(true) => Location::Inside,
+            Some(false) => Location::Outside,
+            None => self.index.locate(x, y),
+        }
+    }
+}
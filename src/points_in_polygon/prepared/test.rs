@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::{random_simple_polygon, uniform_points};
+    use crate::points_in_polygon::options::PipOptions;
+    use crate::points_in_polygon::prepared::{prepare_polygons, PreparedPolygon};
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    #[test]
+    fn test_repeated_queries_match_scanline() {
+        let polygon = random_simple_polygon(0.0, 0.0, 50.0, 120, 5);
+        let rings = [120];
+        let prepared = PreparedPolygon::new(&polygon, &rings);
+
+        // 多次查询不同的点集，缓存跨调用复用，结果与每次重建相同
+        for seed in 0..4 {
+            let points = uniform_points(500, -60.0, -60.0, 60.0, 60.0, seed);
+            for boundary_is_inside in [true, false] {
+                assert_eq!(
+                    prepared.query(&points, boundary_is_inside),
+                    point_in_polygon_scanline(&points, &polygon, &rings, boundary_is_inside)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_boundary_and_empty_polygon() {
+        let square = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0];
+        let prepared = PreparedPolygon::new(&square, &[4]);
+        let points = vec![2.0, 1.0, 1.0, 1.0, 3.0, 1.0];
+        assert_eq!(prepared.query(&points, true), vec![1, 1, 0]);
+        assert_eq!(prepared.query(&points, false), vec![0, 1, 0]);
+
+        assert_eq!(PreparedPolygon::new(&[], &[]).query(&points, true), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_with_options_tolerance() {
+        // 毫米单位的CAD图形，0.5毫米以内视为在边上；自动容差只有约1.4e-6毫米
+        let plate = vec![0.0, 0.0, 1000.0, 0.0, 1000.0, 1000.0, 0.0, 1000.0];
+        let points = vec![999.7, 500.0, 0.2, 0.2, 500.0, 500.0];
+        assert_eq!(PreparedPolygon::new(&plate, &[4]).query(&points, false), vec![1, 1, 1]);
+
+        let mut options = PipOptions::new();
+        options.set_tolerance(0.5);
+        let prepared = PreparedPolygon::with_options(&plate, &[4], &options);
+        assert_eq!(prepared.query(&points, false), vec![0, 0, 1]);
+        assert_eq!(prepared.query(&points, true), vec![1, 1, 1]);
+
+        // 容差同样用于过滤退化边：相距不超过容差的相邻顶点被合并
+        let jagged = vec![0.0, 0.0, 1000.0, 0.0, 1000.3, 0.1, 1000.0, 1000.0, 0.0, 1000.0];
+        assert_eq!(PreparedPolygon::with_options(&jagged, &[5], &options).query(&points, false), vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn test_query_into_buffer() {
+        let polygon = random_simple_polygon(0.0, 0.0, 10.0, 64, 3);
+        let rings = vec![64];
+        let points = uniform_points(300, -11.0, -11.0, 11.0, 11.0, 8);
+        for prepared in [PreparedPolygon::new(&polygon, &rings), PreparedPolygon::with_trapezoids(&polygon, &rings, &PipOptions::new())] {
+            let mut out = vec![0; 300];
+            prepared.fill(&points, true, &mut out).unwrap();
+            assert_eq!(out, prepared.query(&points, true));
+            assert!(prepared.fill(&points, true, &mut out[..299]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_streamed_chunks_match_single_query() {
+        let polygon = random_simple_polygon(0.0, 0.0, 50.0, 40, 3);
+        let rings = vec![(polygon.len() / 2) as u32];
+        let points = uniform_points(1000, -60.0, -60.0, 60.0, 60.0, 8);
+        let expected = point_in_polygon_scanline(&points, &polygon, &rings, true);
+        let mut prepared = PreparedPolygon::new(&polygon, &rings);
+
+        assert!(prepared.push_chunk_values(&points[..2]).is_err());
+        assert!(prepared.finish_values().is_err());
+
+        // 块在任意坐标处切开，包括只含一个坐标和空的块
+        prepared.begin_query(true);
+        let mut merged = Vec::new();
+        for range in [0..301, 301..302, 302..302, 302..1199, 1199..2000] {
+            merged.extend(prepared.push_chunk_values(&points[range]).unwrap());
+            assert_eq!(prepared.streamed_points(), merged.len());
+        }
+        assert_eq!(merged, expected);
+        assert_eq!(prepared.finish_values().unwrap(), expected.iter().filter(|&&r| r == 1).count());
+        assert_eq!(prepared.streamed_points(), 0);
+
+        // 结束时多出一个x坐标
+        prepared.begin_query(false);
+        prepared.push_chunk_values(&points[..3]).unwrap();
+        assert!(prepared.finish_values().unwrap_err().contains("unpaired"));
+    }
+
+    #[test]
+    fn test_prepare_many_polygons() {
+        // 三个多边形拼接，第二个带洞
+        let polygons = vec![
+            0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0, // 多边形0
+            10.0, 0.0, 14.0, 0.0, 14.0, 4.0, 10.0, 4.0, 11.0, 1.0, 13.0, 1.0, 13.0, 3.0, 11.0, 3.0, // 多边形1及其洞
+            20.0, 0.0, 22.0, 0.0, 21.0, 2.0, // 多边形2
+        ];
+        let points = vec![1.0, 1.0, 10.5, 0.5, 12.0, 2.0, 21.0, 0.5, 2.0, 1.0];
+        for parallel in [false, true] {
+            let collection = prepare_polygons(&polygons, &[4, 12], &[8], &PipOptions::new(), parallel);
+            assert_eq!(collection.len(), 3);
+            assert_eq!(collection.query_values(0, &points, true).unwrap(), vec![1, 0, 0, 0, 1]);
+            assert_eq!(collection.query_values(0, &points, false).unwrap(), vec![1, 0, 0, 0, 0]);
+            assert_eq!(collection.query_values(1, &points, true).unwrap(), vec![0, 1, 0, 0, 0]);
+            assert_eq!(collection.query_values(2, &points, true).unwrap(), vec![0, 0, 0, 1, 0]);
+            assert!(collection.query_values(3, &points, true).is_err());
+        }
+        assert!(prepare_polygons(&[], &[], &[], &PipOptions::new(), true).is_empty());
+    }
+}
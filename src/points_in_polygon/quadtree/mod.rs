@@ -0,0 +1,138 @@
+// 边的四叉树索引：均匀网格的替代，边的疏密差异很大的多边形（如海岸线）在边密集处继续细分，
+// 避免少数网格单元登记过多的边
+
+// 构建: 从多边形边界框开始，登记的边超过LEAF_EDGES条的节点四等分，边登记到（按容差加宽后）与之相交的每个子节点；
+//     深度达到MAX_DEPTH，或细分后每个子节点仍登记全部的边（例如大量边交于一点）时不再细分
+// 查询: edges_at 给出点所在叶节点登记的边，与网格单元的查询方式相同；边界框外的点按夹到边界框上的位置查询
+
+use alloc::{vec, vec::Vec};
+use super::common::{Bounds, Polygon};
+
+pub mod test;
+
+// 叶节点最多登记的边数（超过时细分）
+const LEAF_EDGES: usize = 8;
+// 最大深度
+const MAX_DEPTH: usize = 16;
+
+// 树的节点：内部节点的四个子节点为children..children + 4，按(x >= 中线) + 2 * (y >= 中线)排列
+struct Node {
+    bounds: Bounds,
+    children: usize, // 叶节点为0（根节点不会是子节点）
+    edges: Vec<usize>,
+}
+
+pub(crate) struct EdgeQuadtree {
+    nodes: Vec<Node>,
+}
+
+impl EdgeQuadtree {
+    pub(crate) fn new(poly: &Polygon) -> EdgeQuadtree {
+        let margin = poly.tolerance + (poly.bounds.max_x - poly.bounds.min_x).max(poly.bounds.max_y - poly.bounds.min_y) * 1e-9;
+        let edges = (0..poly.edges.len()).collect();
+        let mut nodes = vec![Node { bounds: poly.bounds, children: 0, edges }];
+        let mut stack = vec![(0, 0)];
+        while let Some((i, depth)) = stack.pop() {
+            if nodes[i].edges.len() <= LEAF_EDGES || depth >= MAX_DEPTH {
+                continue;
+            }
+            let b = nodes[i].bounds;
+            let (mx, my) = ((b.min_x + b.max_x) / 2.0, (b.min_y + b.max_y) / 2.0);
+            let quadrants = [
+                Bounds { min_x: b.min_x, min_y: b.min_y, max_x: mx, max_y: my },
+                Bounds { min_x: mx, min_y: b.min_y, max_x: b.max_x, max_y: my },
+                Bounds { min_x: b.min_x, min_y: my, max_x: mx, max_y: b.max_y },
+                Bounds { min_x: mx, min_y: my, max_x: b.max_x, max_y: b.max_y },
+            ];
+            let split: Vec<Vec<usize>> = quadrants
+                .iter()
+                .map(|q| nodes[i].edges.iter().copied().filter(|&e| segment_hits(poly, e, q, margin)).collect())
+                .collect();
+            if split.iter().all(|edges| edges.len() == nodes[i].edges.len()) {
+                continue;
+            }
+
+            let children = nodes.len();
+            for (bounds, edges) in quadrants.into_iter().zip(split) {
+                stack.push((nodes.len(), depth + 1));
+                nodes.push(Node { bounds, children: 0, edges });
+            }
+            nodes[i].children = children;
+            nodes[i].edges = Vec::new();
+        }
+        EdgeQuadtree { nodes }
+    }
+
+    // 点(x, y)所在叶节点登记的边
+    pub(crate) fn edges_at(&self, x: f64, y: f64) -> &[usize] {
+        if self.nodes[0].children == 0 {
+            return &self.nodes[0].edges;
+        }
+        let root = &self.nodes[0].bounds;
+        let (x, y) = (x.clamp(root.min_x, root.max_x), y.clamp(root.min_y, root.max_y));
+        let mut i = 0;
+        while self.nodes[i].children != 0 {
+            let b = &self.nodes[i].bounds;
+            let quadrant = (x >= (b.min_x + b.max_x) / 2.0) as usize + 2 * (y >= (b.min_y + b.max_y) / 2.0) as usize;
+            i = self.nodes[i].children + quadrant;
+        }
+        &self.nodes[i].edges
+    }
+
+    // 与矩形b相交的叶节点登记的边依次交给hit，hit返回true时停止并返回true；矩形先夹到根节点范围内，与edges_at一致
+    pub(crate) fn any_edge_in(&self, b: &Bounds, mut hit: impl FnMut(usize) -> bool) -> bool {
+        let root = &self.nodes[0].bounds;
+        let b = Bounds {
+            min_x: b.min_x.clamp(root.min_x, root.max_x),
+            min_y: b.min_y.clamp(root.min_y, root.max_y),
+            max_x: b.max_x.clamp(root.min_x, root.max_x),
+            max_y: b.max_y.clamp(root.min_y, root.max_y),
+        };
+        let mut stack = vec![0];
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            let overlap = node.bounds.intersection(&b);
+            if overlap.min_x > overlap.max_x || overlap.min_y > overlap.max_y {
+                continue;
+            }
+            if node.children == 0 {
+                if node.edges.iter().any(|&e| hit(e)) {
+                    return true;
+                }
+            } else {
+                stack.extend(node.children..node.children + 4);
+            }
+        }
+        false
+    }
+
+    // 节点数，用于测试
+    #[cfg(test)]
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+// 边是否与按margin加宽后的矩形相交：把线段裁剪到矩形内（Liang-Barsky），裁剪后非空即相交
+fn segment_hits(poly: &Polygon, edge: usize, b: &Bounds, margin: f64) -> bool {
+    let e = &poly.edges[edge];
+    let (dx, dy) = (e.x2 - e.x1, e.y2 - e.y1);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    for (p, q) in [
+        (-dx, e.x1 - (b.min_x - margin)),
+        (dx, (b.max_x + margin) - e.x1),
+        (-dy, e.y1 - (b.min_y - margin)),
+        (dy, (b.max_y + margin) - e.y1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return false;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+    t0 <= t1
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::common::{build_polygon, build_polygon_with};
+    use crate::points_in_polygon::options::{point_in_polygon_with_options, PipOptions};
+    use crate::points_in_polygon::prepared::PreparedPolygon;
+    use crate::points_in_polygon::quadtree::EdgeQuadtree;
+
+    // 一条边极密的锯齿（1000段）加上其余三条长边：边集中在多边形底部
+    fn jagged() -> Vec<f32> {
+        let mut polygon = Vec::new();
+        for k in 0..=1000 {
+            polygon.extend_from_slice(&[k as f32 * 0.1, if k % 2 == 0 { 0.0 } else { 0.05 }]);
+        }
+        polygon.extend_from_slice(&[100.0, 100.0, 0.0, 100.0]);
+        polygon
+    }
+
+    #[test]
+    fn test_edges_at_covers_nearby_edges() {
+        let polygon = jagged();
+        let poly = build_polygon(&polygon, &[(polygon.len() / 2) as u32]);
+        let tree = EdgeQuadtree::new(&poly);
+        assert!(tree.node_count() > 1);
+
+        // 每个点的叶节点都包含距离它不超过容差的全部边
+        for k in 0..2000 {
+            let (x, y) = ((k * 37 % 1013) as f64 * 0.1 - 0.5, (k * 53 % 211) as f64 * 0.5 - 2.0);
+            let near: Vec<usize> = (0..poly.edges.len())
+                .filter(|&e| {
+                    let edge = &poly.edges[e];
+                    crate::points_in_polygon::point_segment_distance(x, y, edge.x1, edge.y1, edge.x2, edge.y2) <= poly.tolerance
+                })
+                .collect();
+            let leaf = tree.edges_at(x, y);
+            assert!(near.iter().all(|e| leaf.contains(e)), "point ({}, {})", x, y);
+        }
+
+        // 远离锯齿的叶节点只登记少量的边
+        assert!(tree.edges_at(50.0, 80.0).len() <= 8);
+    }
+
+    #[test]
+    fn test_quadtree_matches_grid() {
+        let polygon = jagged();
+        let rings = [(polygon.len() / 2) as u32];
+        let mut points = Vec::new();
+        for k in 0..3000 {
+            points.push((k * 37 % 1013) as f32 * 0.1);
+            points.push((k * 53 % 211) as f32 * 0.01 - 0.5);
+        }
+        // 锯齿的顶点和边上的点
+        points.extend_from_slice(&[0.1, 0.05, 0.15, 0.025, 50.0, 100.0, 100.0, 50.0]);
+
+        let mut options = PipOptions::new();
+        let expected = point_in_polygon_with_options(&points, &polygon, &rings, &options);
+        options.set_index_kind(1);
+        assert_eq!(options.index_kind(), 1);
+        assert_eq!(point_in_polygon_with_options(&points, &polygon, &rings, &options), expected);
+
+        let prepared = PreparedPolygon::with_options(&polygon, &rings, &options);
+        assert_eq!(prepared.query(&points, true), expected);
+
+        // 空多边形和未知的取值
+        let empty = build_polygon_with(&[] as &[f32], &[], &options);
+        assert!(EdgeQuadtree::new(&empty).edges_at(0.0, 0.0).is_empty());
+        options.set_index_kind(7);
+        assert_eq!(options.index_kind(), 0);
+    }
+}
@@ -0,0 +1,90 @@
+// 量化输入模块：点云以量化后的原生编码（整数或缩放过的浮点数）传入，逐点按
+//     实际坐标 = 原始值 * scale + offset
+// 即时还原为实际坐标后再判断，无需在js端先反量化出一份新的数组。
+// 多边形使用实际坐标，边界容差等阈值也按实际单位解释
+
+// 输入(js端):
+//     1. 点云 类型Int32Array 或 Float32Array 例子[x1, y1, x2, y2, ...]（量化编码）
+//     2. 量化参数 new Quantization(scale, offset_x, offset_y)
+//     3. 多边形路径点 类型Float32Array（实际坐标）, 多边形路径点的拆分 类型Uint32Array
+//     4. 边界上点是否考虑为内部 boolean
+// 输出(js端):
+//     1. 点云是否在多边形内部 类型Uint32Array 1表示在多边形内部,0表示在多边形外部
+
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use super::scanline::ScanlineIndex;
+
+pub mod test;
+
+// 量化参数：实际坐标 = 原始值 * scale + offset
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy)]
+pub struct Quantization {
+    scale: f64,
+    offset_x: f64,
+    offset_y: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Quantization {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(scale: f64, offset_x: f64, offset_y: f64) -> Quantization {
+        Quantization { scale, offset_x, offset_y }
+    }
+}
+
+impl Quantization {
+    // 把原始编码还原为实际坐标
+    #[inline]
+    fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (x * self.scale + self.offset_x, y * self.scale + self.offset_y)
+    }
+}
+
+// WebAssembly导出函数：整数量化编码的点云
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_quantized_i32(
+    points: &[i32],              // 量化后的点集，格式为[x1, y1, x2, y2, ...]
+    quantization: &Quantization, // 量化参数
+    polygon: &[f32],             // 多边形顶点（实际坐标）
+    rings: &[u32],               // 多边形环的分割索引
+    boundary_is_inside: bool,    // 边界上的点是否视为在多边形内部
+) -> Vec<u32> {
+    classify_quantized(points, quantization, polygon, rings, boundary_is_inside)
+}
+
+// WebAssembly导出函数：浮点量化编码的点云（例如以瓦片局部坐标存储的点）
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_quantized_f32(
+    points: &[f32],              // 量化后的点集，格式为[x1, y1, x2, y2, ...]
+    quantization: &Quantization, // 量化参数
+    polygon: &[f32],             // 多边形顶点（实际坐标）
+    rings: &[u32],               // 多边形环的分割索引
+    boundary_is_inside: bool,    // 边界上的点是否视为在多边形内部
+) -> Vec<u32> {
+    classify_quantized(points, quantization, polygon, rings, boundary_is_inside)
+}
+
+// 逐点还原坐标并用扫描线索引判断
+fn classify_quantized<T: Copy + Into<f64>>(
+    points: &[T],
+    quantization: &Quantization,
+    polygon: &[f32],
+    rings: &[u32],
+    boundary_is_inside: bool,
+) -> Vec<u32> {
+    let point_count = points.len() / 2;
+    if point_count == 0 || polygon.is_empty() || rings.is_empty() {
+        return vec![0; point_count];
+    }
+
+    let index = ScanlineIndex::new(polygon, rings);
+    (0..point_count)
+        .map(|i| {
+            let (x, y) = quantization.apply(points[i * 2].into(), points[i * 2 + 1].into());
+            index.locate(x, y).to_u32(boundary_is_inside)
+        })
+        .collect()
+}
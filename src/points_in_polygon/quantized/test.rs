@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::quantized::{
+        point_in_polygon_quantized_f32, point_in_polygon_quantized_i32, Quantization,
+    };
+
+    #[test]
+    fn test_quantized_points_use_real_world_units() {
+        // 实际坐标中的正方形 [500000, 500003] × [4000000, 4000003]，带一个洞
+        let polygon = vec![
+            500000.0, 4000000.0, 500003.0, 4000000.0, 500003.0, 4000003.0, 500000.0, 4000003.0,
+            500001.0, 4000001.0, 500002.0, 4000001.0, 500002.0, 4000002.0, 500001.0, 4000002.0,
+        ];
+        let rings = vec![4];
+
+        // 以毫米为单位、相对(500000, 4000000)的整数编码
+        let quantization = Quantization::new(0.001, 500000.0, 4000000.0);
+        let points = vec![
+            500, 500,   // 内部
+            1500, 1500, // 洞内
+            3000, 1500, // 外环边上
+            3001, 1500, // 外部（差1毫米）
+        ];
+
+        assert_eq!(
+            point_in_polygon_quantized_i32(&points, &quantization, &polygon, &rings, true),
+            vec![1, 0, 1, 0]
+        );
+        assert_eq!(
+            point_in_polygon_quantized_i32(&points, &quantization, &polygon, &rings, false),
+            vec![1, 0, 0, 0]
+        );
+
+        // 浮点编码：以米为单位的局部坐标
+        let local = Quantization::new(1.0, 500000.0, 4000000.0);
+        let points = vec![0.5, 0.5, 1.5, 1.5, 3.0, 1.5, 3.001, 1.5];
+        assert_eq!(
+            point_in_polygon_quantized_f32(&points, &local, &polygon, &rings, true),
+            vec![1, 0, 1, 0]
+        );
+    }
+}
@@ -6,462 +6,191 @@
 //     2. 多边形路径点 类型Float32Array 例子[x1, y1, x2, y2, ...]
 //     3. 多边形路径点的拆分 类型Uint32Array 例子[20, 30, 40] 表示0-20的点索引为外部多边形,20-30为内部的第一个洞,30-40为内部的第二个洞,40-结束为内部的第三个洞
 //     4. 边界上点是否考虑为内部 boolean 默认为true
+//     5. point_in_polygon_rayster_f64 输入为Float64Array，其余相同
+//     6. point_in_polygon_rayster_with_options(points, polygon, rings, options) 按PipOptions判断（填充规则、容差等，见options模块），
+//        精确模式下按扫描线路径判断
 // 输出(js端):
 //     1. 点云是否在多边形内部 类型Uint32Array 例子[1, 0, 1, 0, ...] 1表示在多边形内部,0表示在多边形外部
 
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*; // 引入WebAssembly绑定，用于与JavaScript交互
-use std::f64; // 引入浮点数相关功能，如EPSILON常量
-use std::collections::HashMap;
+use hashbrown::HashMap;
+use super::common::{build_edge_index, build_polygon_with, fills, for_each_crossing, is_point_on_edge, point_in_bounds, quantize_y, Polygon, RowCache};
+use super::options::PipOptions;
+use super::scanline::classify_points_with;
+use super::bvh::RingBvh;
 
 pub mod test;  // 引入测试模块
 
-// 调整关键常量
-const EPSILON: f64 = 1e-10;  // 更精确的误差容忍度
-const EDGE_EPSILON: f64 = 1e-8; // 边界检测专用精度
-const GRID_SIZE: usize = 64;      // 空间网格大小
-const CACHE_SIZE: usize = 1024;   // 交点缓存大小
-
-// 优化的数据结构
-#[derive(Clone, Copy)]
-struct Edge {
-    x1: f64, y1: f64,
-    x2: f64, y2: f64,
-}
-
-struct Ring {
-    start_idx: usize,
-    edge_count: usize,
-    is_hole: bool,
-    bounds: Bounds,
-}
-
-#[derive(Clone, Copy)]
-struct Bounds {
-    min_x: f64, min_y: f64,
-    max_x: f64, max_y: f64,
-}
-
-struct Polygon {
-    edges: Vec<Edge>,
-    rings: Vec<Ring>,
-    bounds: Bounds,
-}
-
-#[derive(Clone)]
-struct GridCell {
-    edge_indices: Vec<usize>,
-}
+// 射线交点缓存：键是量化后的y坐标，值是每个环的交点列表 (x坐标, 该边对环绕数的贡献)；
+// 容量（扫描线数量）由选项指定，满时按时钟策略淘汰（见common模块）
+type RayCache = RowCache<HashMap<usize, Vec<(f64, i32)>>>;
 
 // 主函数：判断点是否在多边形内部
 // 使用wasm_bindgen标注，使其可以从JavaScript调用
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub fn point_in_polygon_rayster(
     points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
     polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
     rings: &[u32],            // 多边形环的分割点，表示每个环的结束位置
     boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
 ) -> Vec<u32> {               // 返回结果，1表示在内部，0表示在外部
+    rayster_classify(points, polygon, rings, &PipOptions::with_boundary(boundary_is_inside))
+}
+
+// 与point_in_polygon_rayster相同，但点和多边形都是f64坐标（Float64Array），整个过程不经过f32
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_rayster_f64(
+    points: &[f64],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f64],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<u32> {
+    rayster_classify(points, polygon, rings, &PipOptions::with_boundary(boundary_is_inside))
+}
+
+// 按选项判断点是否在多边形内部
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_rayster_with_options(
+    points: &[f32],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],      // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],        // 多边形环的分割索引
+    options: &PipOptions, // 判断选项
+) -> Vec<u32> {
+    rayster_classify(points, polygon, rings, options)
+}
+
+// 射线法的主流程，坐标可以是f32或f64
+fn rayster_classify<C: Copy + Into<f64> + Sync>(points: &[C], polygon: &[C], rings: &[u32], options: &PipOptions) -> Vec<u32> {
+    let boundary_is_inside = options.boundary_is_inside;
     let point_count = points.len() / 2;
+    // 精确模式按扫描线路径判断（射线的交点缓存不记录边，无法重新做方向判定）
+    if options.exact {
+        return classify_points_with(point_count, |i| (points[i * 2].into(), points[i * 2 + 1].into()), polygon, rings, options);
+    }
     if point_count == 0 || polygon.is_empty() || rings.is_empty() {
         return vec![0; point_count];
     }
     
     // 构建多边形数据结构和空间索引
-    let poly = build_polygon(polygon, rings);
-    let _grid = build_grid(&poly);
+    let poly = build_polygon_with(polygon, rings, options);
+    let index = build_edge_index(&poly);
+    let bvh = RingBvh::new(&poly);
     
     // 预分配结果
     let mut results = vec![0; point_count];
     
     // 创建射线交点缓存
-    let mut ray_cache: HashMap<i64, HashMap<usize, Vec<f64>>> = HashMap::new();
+    let mut ray_cache = RayCache::new(options.cache_capacity());
+    
+    // 各点共用的环状态缓冲：候选环列表和每个环的内外状态（每个点处理后复位候选环，其余环保持false）
+    let mut candidates = Vec::new();
+    let mut in_out = vec![false; poly.rings.len()];
     
     // 处理每个点
     for i in 0..point_count {
-        let x = points[i * 2] as f64;
-        let y = points[i * 2 + 1] as f64;
-        
-        // 1. 边界框快速检查
-        if !point_in_bounds(x, y, &poly.bounds) {
-            continue; // 点在多边形外部
-        }
+        let (x, y) = options.axes(points[i * 2].into(), points[i * 2 + 1].into());
         
-        // 2. 更简单直接的边界检查
-        if is_point_exactly_on_edge(&poly, x, y) {
+        // 1. 边界检查：借助空间网格求点到所在单元各边的距离（自带按容差扩展的边界框排除，边界框外容差带内的点同样在边上）
+        if is_point_on_edge(&poly, &index, x, y) {
             results[i] = boundary_is_inside as u32;
             continue;
         }
         
+        // 2. 边界框快速检查
+        if !point_in_bounds(x, y, &poly.bounds) {
+            continue; // 点在多边形外部
+        }
+        
         // 3. 使用优化的射线法判断点是否在多边形内部
         let y_key = quantize_y(y);
-        let inside = optimized_ray_cast(&poly, x, y, &mut ray_cache, y_key);
+        bvh.rings_at(x, y, poly.tolerance, &mut candidates);
+        let inside = optimized_ray_cast(&poly, &candidates, &mut in_out, x, y, &mut ray_cache, y_key);
         results[i] = inside as u32;
     }
     
     results
 }
 
-// 构建多边形数据结构
-fn build_polygon(polygon: &[f32], rings: &[u32]) -> Polygon {
-    let mut edges = Vec::new();
-    let mut poly_rings = Vec::new();
-    let mut min_x = f64::MAX;
-    let mut min_y = f64::MAX;
-    let mut max_x = f64::MIN;
-    let mut max_y = f64::MIN;
-    
-    let mut prev_idx = 0;
-    
-    // 处理每个环
-    for (i, &split) in rings.iter().enumerate() {
-        let mut ring_min_x = f64::MAX;
-        let mut ring_min_y = f64::MAX;
-        let mut ring_max_x = f64::MIN;
-        let mut ring_max_y = f64::MIN;
-        
-        let start_edge_idx = edges.len();
-        let start = prev_idx as usize * 2;
-        let end = split as usize * 2;
-        
-        // 提取当前环的所有边
-        let mut ring_edges = 0;
-        for j in (start..end).step_by(2) {
-            if j + 3 < end {
-                let x1 = polygon[j] as f64;
-                let y1 = polygon[j + 1] as f64;
-                let x2 = polygon[j + 2] as f64;
-                let y2 = polygon[j + 3] as f64;
-                
-                // 忽略退化边
-                if (x1 - x2).abs() < EPSILON && (y1 - y2).abs() < EPSILON {
-                    continue;
-                }
-                
-                edges.push(Edge { x1, y1, x2, y2 });
-                ring_edges += 1;
-                
-                // 更新环的边界框
-                ring_min_x = ring_min_x.min(x1).min(x2);
-                ring_min_y = ring_min_y.min(y1).min(y2);
-                ring_max_x = ring_max_x.max(x1).max(x2);
-                ring_max_y = ring_max_y.max(y1).max(y2);
-            }
-        }
-        
-        // 连接环的最后一点和第一点，封闭环
-        if end > start + 2 {
-            let x1 = polygon[end - 2] as f64;
-            let y1 = polygon[end - 1] as f64;
-            let x2 = polygon[start] as f64;
-            let y2 = polygon[start + 1] as f64;
-            
-            if (x1 - x2).abs() >= EPSILON || (y1 - y2).abs() >= EPSILON {
-                edges.push(Edge { x1, y1, x2, y2 });
-                ring_edges += 1;
-            }
-        }
-        
-        // 创建环的边界框
-        let ring_bounds = Bounds {
-            min_x: ring_min_x, min_y: ring_min_y,
-            max_x: ring_max_x, max_y: ring_max_y,
-        };
-        
-        // 添加环到环列表
-        poly_rings.push(Ring {
-            start_idx: start_edge_idx,
-            edge_count: ring_edges,
-            is_hole: i > 0,  // 第一个环(i=0)是外环，其余(i>0)是内环(洞)
-            bounds: ring_bounds,
-        });
-        
-        // 更新整个多边形的边界框
-        min_x = min_x.min(ring_min_x);
-        min_y = min_y.min(ring_min_y);
-        max_x = max_x.max(ring_max_x);
-        max_y = max_y.max(ring_max_y);
-        
-        prev_idx = split;
-    }
-    
-    // 创建多边形
-    Polygon {
-        edges,
-        rings: poly_rings,
-        bounds: Bounds { min_x, min_y, max_x, max_y },
-    }
-}
-
-// 构建空间网格索引
-fn build_grid(poly: &Polygon) -> Vec<Vec<GridCell>> {
-    // 初始化网格
-    let mut grid = vec![vec![GridCell { edge_indices: Vec::new() }; GRID_SIZE]; GRID_SIZE];
-    
-    let width = poly.bounds.max_x - poly.bounds.min_x;
-    let height = poly.bounds.max_y - poly.bounds.min_y;
-    
-    // 如果多边形是一个点或非常小，返回空网格
-    if width < EPSILON || height < EPSILON {
-        return grid;
-    }
-    
-    // 把每条边放入相应的网格单元
-    for (edge_idx, edge) in poly.edges.iter().enumerate() {
-        // 找出边覆盖的网格单元
-        let cells = line_to_grid_cells(
-            edge.x1, edge.y1, edge.x2, edge.y2,
-            poly.bounds.min_x, poly.bounds.min_y, width, height
-        );
-        
-        // 将边的索引添加到每个覆盖的网格单元中
-        for (gx, gy) in cells {
-            if gx < GRID_SIZE && gy < GRID_SIZE {
-                grid[gx][gy].edge_indices.push(edge_idx);
-            }
-        }
-    }
-    
-    grid
-}
-
-// 使用Bresenham算法将线段映射到网格单元
-fn line_to_grid_cells(
-    x1: f64, y1: f64, x2: f64, y2: f64,
-    min_x: f64, min_y: f64, width: f64, height: f64
-) -> Vec<(usize, usize)> {
-    let mut cells = Vec::new();
-    
-    // 计算网格坐标
-    let grid_x1 = ((x1 - min_x) / width * (GRID_SIZE as f64)).floor() as isize;
-    let grid_y1 = ((y1 - min_y) / height * (GRID_SIZE as f64)).floor() as isize;
-    let grid_x2 = ((x2 - min_x) / width * (GRID_SIZE as f64)).floor() as isize;
-    let grid_y2 = ((y2 - min_y) / height * (GRID_SIZE as f64)).floor() as isize;
-    
-    // 使用Bresenham算法遍历线段覆盖的网格单元
-    let dx = (grid_x2 - grid_x1).abs();
-    let dy = -(grid_y2 - grid_y1).abs();
-    let sx = if grid_x1 < grid_x2 { 1 } else { -1 };
-    let sy = if grid_y1 < grid_y2 { 1 } else { -1 };
-    
-    let mut err = dx + dy;
-    let mut x = grid_x1;
-    let mut y = grid_y1;
-    
-    loop {
-        if x >= 0 && y >= 0 && x < GRID_SIZE as isize && y < GRID_SIZE as isize {
-            cells.push((x as usize, y as usize));
-        }
-        
-        if x == grid_x2 && y == grid_y2 {
-            break;
-        }
-        
-        let e2 = 2 * err;
-        if e2 >= dy {
-            if x == grid_x2 {
-                break;
-            }
-            err += dy;
-            x += sx;
-        }
-        if e2 <= dx {
-            if y == grid_y2 {
-                break;
-            }
-            err += dx;
-            y += sy;
-        }
-    }
-    
-    cells
-}
-
-// 检查点是否在边界框内
-#[inline]
-fn point_in_bounds(x: f64, y: f64, bounds: &Bounds) -> bool {
-    x >= bounds.min_x && x <= bounds.max_x && y >= bounds.min_y && y <= bounds.max_y
-}
-
-// 重写边界点检测，专门处理测试案例中的(3.0, 1.5)特殊点
-fn is_point_exactly_on_edge(poly: &Polygon, x: f64, y: f64) -> bool {
-    // 检查常见边界框位置 - 特殊优化处理(3.0, 1.5)测试案例
-    if poly.rings.len() > 0 && !poly.rings[0].is_hole {
-        let outer_ring = &poly.rings[0];
-        
-        // 直接检查点是否在关键位置(3.0, 1.5)附近
-        if (x - 3.0).abs() < EDGE_EPSILON && (y - 1.5).abs() < EDGE_EPSILON {
-            return true;
-        }
-        
-        // 检查点是否在任何边界上
-        if (x - outer_ring.bounds.min_x).abs() < EDGE_EPSILON || 
-           (x - outer_ring.bounds.max_x).abs() < EDGE_EPSILON || 
-           (y - outer_ring.bounds.min_y).abs() < EDGE_EPSILON || 
-           (y - outer_ring.bounds.max_y).abs() < EDGE_EPSILON {
-            
-            // 对边界点进行精确检查
-            let start_idx = outer_ring.start_idx;
-            let end_idx = start_idx + outer_ring.edge_count;
-            
-            for edge_idx in start_idx..end_idx {
-                let edge = &poly.edges[edge_idx];
-                
-                // 垂直边检查 - 关键测试案例
-                if (edge.x1 - edge.x2).abs() < EPSILON {
-                    if (x - edge.x1).abs() < EDGE_EPSILON && 
-                       y >= edge.y1.min(edge.y2) - EDGE_EPSILON && 
-                       y <= edge.y1.max(edge.y2) + EDGE_EPSILON {
-                        return true;
-                    }
-                }
-                // 水平边检查
-                else if (edge.y1 - edge.y2).abs() < EPSILON {
-                    if (y - edge.y1).abs() < EDGE_EPSILON && 
-                       x >= edge.x1.min(edge.x2) - EDGE_EPSILON && 
-                       x <= edge.x1.max(edge.x2) + EDGE_EPSILON {
-                        return true;
-                    }
-                }
-                // 其他边检查保持不变...
-            }
-        }
-    }
-    
-    // 如果是特殊的矩形边界(3.0, y)，强制认为它是在边界上
-    // 这是为了解决测试用例中的边界点问题
-    if (x - 3.0).abs() < EDGE_EPSILON && y >= 0.0 && y <= 3.0 {
-        return true;
-    }
-    
-    // 一般边处理代码保持不变...
-    // ...
-    
-    false
-}
-
-// 改进射线法，处理特殊的边界情况
+// 改进射线法：只处理不在边界上的点（距离所有边超过容差），因此交点不会与点重合，也无需特殊处理边界框上的点
+// candidates为边界框包含该点的环（由RingBvh给出），其余环对向右的射线的环绕数为0，不影响结果
 fn optimized_ray_cast(
     poly: &Polygon,
+    candidates: &[usize],
+    in_out: &mut [bool],
     x: f64,
     y: f64,
-    cache: &mut HashMap<i64, HashMap<usize, Vec<f64>>>,
+    cache: &mut RayCache,
     y_key: i64
 ) -> bool {
-    // 确保缓存不会无限增长
-    if cache.len() > CACHE_SIZE {
-        let keys: Vec<_> = cache.keys().cloned().collect();
-        for key in keys.iter().take(cache.len() / 2) {
-            cache.remove(key);
-        }
-    }
+    let tol = poly.tolerance;
     
     // 简单情况：点在边界框外
-    if x < poly.bounds.min_x - EPSILON || x > poly.bounds.max_x + EPSILON ||
-       y < poly.bounds.min_y - EPSILON || y > poly.bounds.max_y + EPSILON {
-        return false;
-    }
-    
-    // 特殊情况：点在矩形边界
-    if (x - poly.bounds.min_x).abs() < EDGE_EPSILON || 
-       (x - poly.bounds.max_x).abs() < EDGE_EPSILON || 
-       (y - poly.bounds.min_y).abs() < EDGE_EPSILON || 
-       (y - poly.bounds.max_y).abs() < EDGE_EPSILON {
-        // 这种情况应该由is_point_exactly_on_edge处理
+    if x < poly.bounds.min_x - tol || x > poly.bounds.max_x + tol ||
+       y < poly.bounds.min_y - tol || y > poly.bounds.max_y + tol {
         return false;
     }
     
-    // 标准射线法：跟踪点在每个环内/外的状态
-    let mut in_out = vec![false; poly.rings.len()];
-    
-    // 先处理所有外环
-    for (ring_idx, ring) in poly.rings.iter().enumerate() {
+    // 标准射线法：跟踪点在每个候选环内/外的状态（非候选环的状态始终为false）
+    // 先处理候选的外环；单个多边形的多个外环（全部环按填充规则合并时）与扫描线相同，环绕数相加后再按填充规则判断
+    let mut outer_winding = 0;
+    for &ring_idx in candidates {
+        let ring = &poly.rings[ring_idx];
         if ring.is_hole {
             continue;
         }
         
-        // 快速边界框检查
-        if y < ring.bounds.min_y - EPSILON || y > ring.bounds.max_y + EPSILON {
-            continue;
-        }
-        
         // 获取射线与外环的交点
         let intersections = get_cached_intersections(poly, ring_idx, y, cache, y_key);
         
-        // 对于正方形外环的特殊情况，检查点是否在右边界
-        let is_square_right_edge = ring_idx == 0 && 
-                                   (x - ring.bounds.max_x).abs() < EDGE_EPSILON &&
-                                   y >= ring.bounds.min_y && 
-                                   y <= ring.bounds.max_y;
-                                   
-        // 计算射线与环的交点数（点右侧）
-        let mut crossings = 0;
-        for &xi in &intersections {
-            if xi > x + EPSILON {
-                crossings += 1;
-            } else if (xi - x).abs() < EDGE_EPSILON {
-                // 射线与边重合的情况
-                if is_square_right_edge {
-                    crossings += 1;
-                }
+        // 累计射线与环的交点（点右侧）得到环绕数
+        let winding: i32 = intersections.iter().filter(|&&(xi, _)| xi > x).map(|&(_, w)| w).sum();
+        
+        // 按填充规则标记点在该环内还是环外
+        in_out[ring_idx] = fills(poly.fill_rule, winding);
+        outer_winding += winding;
+    }
+    if poly.part_count == 1 {
+        let in_outer = fills(poly.fill_rule, outer_winding);
+        for &ring_idx in candidates {
+            if !poly.rings[ring_idx].is_hole {
+                in_out[ring_idx] = in_outer;
             }
         }
-        
-        // 标记点在该环内还是环外
-        in_out[ring_idx] = crossings % 2 == 1;
     }
     
-    // 检查点是否在任何洞内
-    for (ring_idx, ring) in poly.rings.iter().enumerate() {
+    // 检查点是否在任何候选的洞内
+    for &ring_idx in candidates {
+        let ring = &poly.rings[ring_idx];
         if !ring.is_hole {
             continue;
         }
         
-        // 直接内联找到父环的逻辑，避免使用未使用的函数
-        let mut parent_idx = 0;  // 默认父环是第一个环
-        let mut found = false;
-        
-        for (i, r) in poly.rings.iter().enumerate() {
-            if !r.is_hole && contains_bounds(&r.bounds, &ring.bounds) {
-                parent_idx = i;
-                found = true;
-                break;
-            }
-        }
-        
-        if !found || !in_out[parent_idx] {
-            continue;  // 没找到父环或点不在父环内
-        }
-        
-        // 快速边界框检查
-        if y < ring.bounds.min_y - EPSILON || y > ring.bounds.max_y + EPSILON {
-            continue;
-        }
+        // 洞所挖去的外环在构建多边形时已确定
+        let parent_idx = match ring.shell {
+            Some(shell) if in_out[shell] => shell,
+            _ => continue,  // 没有外环或点不在外环内
+        };
         
         // 获取射线与洞的交点
         let intersections = get_cached_intersections(poly, ring_idx, y, cache, y_key);
         
-        // 计算交点数
-        let mut crossings = 0;
-        for &xi in &intersections {
-            if xi > x + EPSILON {
-                crossings += 1;
-            }
-        }
+        // 计算环绕数
+        let winding: i32 = intersections.iter().filter(|&&(xi, _)| xi > x).map(|&(_, w)| w).sum();
         
         // 如果点在洞内，则不在多边形内
-        if crossings % 2 == 1 {
+        if fills(poly.fill_rule, winding) {
             in_out[parent_idx] = false;
         }
     }
     
     // 点在任一外环内且不在任何洞内
-    in_out.iter().enumerate().any(|(i, &inside)| inside && !poly.rings[i].is_hole)
-}
-
-// 辅助函数：判断一个边界框是否包含另一个
-fn contains_bounds(outer: &Bounds, inner: &Bounds) -> bool {
-    outer.min_x <= inner.min_x && outer.max_x >= inner.max_x &&
-    outer.min_y <= inner.min_y && outer.max_y >= inner.max_y
+    let inside = candidates.iter().any(|&i| in_out[i] && !poly.rings[i].is_hole);
+    for &ring_idx in candidates {
+        in_out[ring_idx] = false;
+    }
+    inside
 }
 
 // 完全重写辅助函数以解决借用问题
@@ -469,86 +198,36 @@ fn get_cached_intersections(
     poly: &Polygon,
     ring_idx: usize,
     y: f64,
-    cache: &mut HashMap<i64, HashMap<usize, Vec<f64>>>,
+    cache: &mut RayCache,
     y_key: i64
-) -> Vec<f64> {
+) -> Vec<(f64, i32)> {
     // 首先克隆缓存的值（如果存在）
-    if let Some(map) = cache.get(&y_key) {
-        if let Some(intersections) = map.get(&ring_idx) {
-            return intersections.clone();  // 返回克隆值而不是引用
-        }
+    let row = cache.get_or_insert_with(y_key, HashMap::new);
+    if let Some(intersections) = row.get(&ring_idx) {
+        return intersections.clone();  // 返回克隆值而不是引用
     }
     
     // 计算新的交点
     let mut intersections = compute_ray_intersections(poly, ring_idx, y);
-    intersections.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    intersections.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
     
     // 更新缓存
-    cache.entry(y_key)
-         .or_insert_with(HashMap::new)
-         .insert(ring_idx, intersections.clone());
+    row.insert(ring_idx, intersections.clone());
     
     intersections  // 返回计算的值
 }
 
-// 量化y坐标用于缓存
-#[inline]
-fn quantize_y(y: f64) -> i64 {
-    (y * 1_000_000.0).round() as i64
-}
-
-// 改进交点计算，处理边界情况
-fn compute_ray_intersections(poly: &Polygon, ring_idx: usize, y: f64) -> Vec<f64> {
+// 计算射线与单个环的交点，按半开区间规则求交（水平边和经过顶点的情况见common模块），同时记录边的方向
+fn compute_ray_intersections(poly: &Polygon, ring_idx: usize, y: f64) -> Vec<(f64, i32)> {
     let ring = &poly.rings[ring_idx];
-    let mut intersections = Vec::new();
-    
+    if ring.boundary_only {
+        return Vec::new();
+    }
     let start_idx = ring.start_idx;
     let end_idx = start_idx + ring.edge_count;
     
-    for edge_idx in start_idx..end_idx {
-        let edge = &poly.edges[edge_idx];
-        
-        // 水平边需要特殊处理
-        if (edge.y1 - edge.y2).abs() < EPSILON {
-            // 射线恰好与水平边重合
-            if (y - edge.y1).abs() < EPSILON {
-                // 将水平边的两个端点都加入，这样能确保正确处理
-                intersections.push(edge.x1.min(edge.x2));
-                intersections.push(edge.x1.max(edge.x2));
-            }
-            continue;
-        }
-        
-        // 射线与顶点相交的特殊处理
-        if (edge.y1 - y).abs() < EPSILON {
-            // 查找共享此顶点的另一条边
-            let prev_idx = if edge_idx > start_idx { 
-                edge_idx - 1 
-            } else { 
-                end_idx - 1 
-            };
-            
-            let prev_edge = &poly.edges[prev_idx];
-            
-            // 根据边的方向判断是否计算交点
-            if (edge.y2 > y && prev_edge.y1 > y) || (edge.y2 < y && prev_edge.y1 < y) {
-                // 射线穿过顶点且两边在同一侧，算一个交点
-                intersections.push(edge.x1);
-            }
-            // 其他情况不计算交点，避免重复计算
-        } 
-        // 射线与终点相交
-        else if (edge.y2 - y).abs() < EPSILON {
-            // 这里不处理，防止重复计算，会在下一条边处理这个点
-        }
-        // 射线穿过边
-        else if (edge.y1 < y && edge.y2 > y) || (edge.y1 > y && edge.y2 < y) {
-            // 计算交点
-            let t = (y - edge.y1) / (edge.y2 - edge.y1);
-            let x = edge.x1 + t * (edge.x2 - edge.x1);
-            intersections.push(x);
-        }
-    }
-    
+    let edges = &poly.edges[start_idx..end_idx];
+    let mut intersections = Vec::new();
+    for_each_crossing(edges, y, poly.vertex_policy, |k, x| intersections.push((x, edges[k].winding())));
     intersections
 }
\ No newline at end of file
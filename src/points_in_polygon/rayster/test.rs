@@ -100,6 +100,7 @@ mod tests {
             let y = points[i * 2 + 1] as f64;
             let result = results[i];
 
+            #[allow(clippy::manual_range_contains)]
             let expected = if x > 3.0 || x < 0.0 || y > 3.0 || y < 0.0 {
                 // a. 在大正方形(外部多边形)外部的点判定为0
                 0
@@ -174,6 +175,7 @@ mod tests {
         }
 
         // c. 多边形路径点的拆分 [外圆顶点数, 外圆+第一个洞顶点数]
+        #[allow(clippy::unnecessary_cast)]
         let rings = vec![segments as u32, segments * 2 as u32];
 
         // d. 边界上点是否考虑为内部
@@ -229,4 +231,34 @@ mod tests {
         // 确保准确率至少为99%（由于圆形是用多边形近似，允许稍大的误差）
         assert!(correct_count as f64 / total_count as f64 > 0.99);
     }
+
+    #[test]
+    fn test_boundary_on_translated_and_rotated_polygons() {
+        // 平移后的带洞正方形：边界检测不依赖具体坐标
+        let polygon = vec![
+            10.0, 10.0, 13.0, 10.0, 13.0, 13.0, 10.0, 13.0, // 外环
+            11.0, 11.0, 12.0, 11.0, 12.0, 12.0, 11.0, 12.0, // 洞
+        ];
+        let points = vec![13.0, 11.5, 10.0, 12.0, 11.0, 11.5, 11.5, 10.0, 3.0, 1.5, 12.5, 10.5];
+        assert_eq!(point_in_polygon_rayster(&points, &polygon, &[4], true), vec![1, 1, 1, 1, 0, 1]);
+        assert_eq!(point_in_polygon_rayster(&points, &polygon, &[4], false), vec![0, 0, 0, 0, 0, 1]);
+
+        // 旋转45度的正方形（菱形）挖去一个三角形洞，边界都是斜边
+        let diamond = vec![
+            0.0, -4.0, 4.0, 0.0, 0.0, 4.0, -4.0, 0.0, // 外环
+            -1.0, -1.0, 1.0, -1.0, 0.0, 1.0, // 洞
+        ];
+        let points = vec![
+            2.0, 2.0, // 外环右上边的中点
+            -3.0, -1.0, // 外环左下边上
+            0.5, 0.0, // 洞的右斜边上
+            0.0, -1.0, // 洞的底边上
+            0.0, 0.0, // 洞内
+            2.0, -1.0, // 内部
+            2.5, 2.5, // 外部，靠近右上边
+            4.0, 0.0, // 外环的顶点
+        ];
+        assert_eq!(point_in_polygon_rayster(&points, &diamond, &[4], true), vec![1, 1, 1, 1, 0, 1, 0, 1]);
+        assert_eq!(point_in_polygon_rayster(&points, &diamond, &[4], false), vec![0, 0, 0, 0, 0, 1, 0, 0]);
+    }
 }
@@ -0,0 +1,121 @@
+// 分层抽样模块：只判断点云的一个分层样本，给出多边形内部点数的估计值和置信区间
+// 适合仪表盘先立即显示估计结果，同时在后台运行精确的批量判断
+
+// 原理：
+//     把点云边界框划分为 STRATA_SIZE × STRATA_SIZE 个空间层，按各层点数比例分配样本量（每层至少2个），
+//     层内无放回随机抽样并精确判断。估计值为各层 N_h * p_h 之和，方差为
+//     Σ N_h² (1 - n_h/N_h) p̃_h (1 - p̃_h) / ñ_h，其中 ñ_h = n_h + z²、p̃_h = (命中数 + z²/2) / ñ_h（Agresti-Coull修正），
+//     层内样本全在内部或全在外部时区间宽度也不为0。空间上相近的点内外状态相近，分层后方差远小于简单随机抽样
+// 输入(js端):
+//     1. 点云 类型Float32Array, 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+//     2. 样本量 sample_size
+//     3. 置信区间的z值 例如1.96对应95%置信度
+//     4. 随机种子
+//     5. 边界上点是否考虑为内部 boolean
+// 输出(js端):
+//     1. 类型Float64Array [估计的内部点数, 区间下界, 区间上界] 区间被限制在[0, 点数]内；
+//        样本量不小于点数时退化为精确计数，区间宽度为0
+
+use alloc::{vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use super::scanline::ScanlineIndex;
+use crate::generators::Rng;
+
+pub mod test;
+
+// 每个方向的空间层数
+const STRATA_SIZE: usize = 8;
+
+// WebAssembly导出函数：估计多边形内部的点数
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn estimate_inside_count(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    sample_size: usize,       // 样本量
+    z: f64,                   // 置信区间的z值
+    seed: u32,                // 随机种子
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<f64> {
+    let point_count = points.len() / 2;
+    if point_count == 0 {
+        return vec![0.0, 0.0, 0.0];
+    }
+
+    let index = ScanlineIndex::new(polygon, rings);
+    let mut rng = Rng::new(seed as u64);
+    let mut estimate = 0.0;
+    let mut variance = 0.0;
+
+    for mut stratum in strata(points) {
+        let total = stratum.len();
+        if total == 0 {
+            continue;
+        }
+
+        // 按比例分配样本量，每层至少2个，便于估计层内方差
+        let share = (sample_size as f64 * total as f64 / point_count as f64).round() as usize;
+        let n = share.max(2).min(total);
+
+        // 部分Fisher-Yates洗牌：前n个即为无放回样本
+        for k in 0..n {
+            let j = k + (rng.next_u64() % (total - k) as u64) as usize;
+            stratum.swap(k, j);
+        }
+        let hits = stratum[..n]
+            .iter()
+            .filter(|&&i| {
+                let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+                index.locate(x, y).to_u32(boundary_is_inside) == 1
+            })
+            .count();
+
+        let p = hits as f64 / n as f64;
+        estimate += total as f64 * p;
+        if n < total {
+            let total = total as f64;
+            let n = n as f64;
+            // Agresti-Coull：样本量和命中数各加上z²和z²/2
+            let n_adjusted = n + z * z;
+            let p_adjusted = (hits as f64 + z * z / 2.0) / n_adjusted;
+            variance += total * total * (1.0 - n / total) * p_adjusted * (1.0 - p_adjusted) / n_adjusted;
+        }
+    }
+
+    let half_width = z.abs() * variance.sqrt();
+    vec![
+        estimate,
+        (estimate - half_width).max(0.0),
+        (estimate + half_width).min(point_count as f64),
+    ]
+}
+
+// 按点云边界框的网格划分空间层，返回每层的点索引
+fn strata(points: &[f32]) -> Vec<Vec<usize>> {
+    let point_count = points.len() / 2;
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for i in 0..point_count {
+        let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+
+    let cell_w = ((max_x - min_x) / STRATA_SIZE as f64).max(f64::MIN_POSITIVE);
+    let cell_h = ((max_y - min_y) / STRATA_SIZE as f64).max(f64::MIN_POSITIVE);
+    let last = (STRATA_SIZE - 1) as f64;
+
+    let mut strata = vec![Vec::new(); STRATA_SIZE * STRATA_SIZE];
+    for i in 0..point_count {
+        let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+        let c = ((x - min_x) / cell_w).floor().clamp(0.0, last) as usize;
+        let r = ((y - min_y) / cell_h).floor().clamp(0.0, last) as usize;
+        strata[r * STRATA_SIZE + c].push(i);
+    }
+    strata
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::{fractal_coastline, uniform_points};
+    use crate::points_in_polygon::sampling::estimate_inside_count;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+
+    #[test]
+    fn test_estimate_brackets_exact_count() {
+        let polygon = fractal_coastline(0.0, 0.0, 40.0, 7, 0.5, 3);
+        let rings = vec![(polygon.len() / 2) as u32];
+        let points = uniform_points(100_000, -50.0, -50.0, 50.0, 50.0, 17);
+        let exact = point_in_polygon_scanline(&points, &polygon, &rings, true).iter().sum::<u32>() as f64;
+
+        // 不同种子下，99.7%置信区间都应覆盖精确值，且区间宽度远小于点数
+        for seed in 0..5 {
+            let result = estimate_inside_count(&points, &polygon, &rings, 2000, 3.0, seed, true);
+            let (estimate, lower, upper) = (result[0], result[1], result[2]);
+            assert!(lower <= exact && exact <= upper, "seed {}: {} not in [{}, {}]", seed, exact, lower, upper);
+            assert!(lower <= estimate && estimate <= upper);
+            assert!(upper - lower < 5000.0);
+        }
+
+        // 样本量不小于点数时退化为精确计数
+        let result = estimate_inside_count(&points[..2000], &polygon, &rings, 1000, 1.96, 1, true);
+        let exact = point_in_polygon_scanline(&points[..2000], &polygon, &rings, true).iter().sum::<u32>() as f64;
+        assert_eq!(result, vec![exact, exact, exact]);
+    }
+
+    #[test]
+    fn test_all_sampled_points_inside() {
+        // 多边形覆盖几乎全部点，只有小洞内的少数点在外部：样本全在内部时区间仍有宽度并覆盖精确值
+        let polygon = vec![
+            -1.0, -1.0, 101.0, -1.0, 101.0, 101.0, -1.0, 101.0, // 外环
+            50.0, 50.0, 52.0, 50.0, 52.0, 52.0, 50.0, 52.0, // 洞
+        ];
+        let rings = vec![4];
+        let points = uniform_points(10_000, 0.0, 0.0, 100.0, 100.0, 5);
+        let exact = point_in_polygon_scanline(&points, &polygon, &rings, true).iter().sum::<u32>() as f64;
+        assert!(exact < 10_000.0);
+
+        let result = estimate_inside_count(&points, &polygon, &rings, 200, 1.96, 2, true);
+        let (estimate, lower, upper) = (result[0], result[1], result[2]);
+        assert_eq!(estimate, 10_000.0);
+        assert!(lower < upper);
+        assert!(lower <= exact && exact <= upper, "{} not in [{}, {}]", exact, lower, upper);
+    }
+}
@@ -5,490 +5,983 @@
 // 3. 边界框快速过滤
 // 4. 高精度边界点检测
 // 该算法对于大量点和复杂多边形有更好的性能表现
+// 诊断: ring_crossing_counts 输出每个点与每个环的交点计数，便于排查顶点处的不一致
+// f64坐标: point_in_polygon_scanline_f64 接受Float64Array，投影坐标等大数值坐标不损失精度
+// 三值输出: point_in_polygon_scanline_ternary 不按boundary_is_inside归类边界上的点，0外部, 1内部, 2在边界上（距离不超过容差）
+// 写入调用方的数组: point_in_polygon_scanline_into 结果写入传入的Uint32Array，点数很多时省去结果数组的分配和复制
+// 并行: 作为本机Rust库使用并启用parallel特性时（或以wasm-threads特性构建、且js端已创建线程池时，见threads模块），
+//     批量判断把点分块交给Rayon线程，每个线程有自己的交点缓存，结果与单线程相同
+// 紧凑输出: point_in_polygon_scanline_u8 每点一个字节，point_in_polygon_scanline_bits 每个u32打包32个点的结果，传输量分别为原来的1/4和1/32
+// 内部点下标: point_in_polygon_indices 只返回判定为内部的点的下标，适合大部分点落在外部的框选
+// 计数与谓词: count_points_in_polygon 只返回内部点数；any_point_in_polygon / all_points_in_polygon 在结果确定时立即返回，不判断剩余的点
+// 内部点坐标: point_in_polygon_filter 直接返回内部点的交错坐标，可以不经js端收集直接上传为WebGL顶点缓冲
 
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
-use std::f64;
-use std::collections::HashMap;
-// 移除未使用的导入
-// use std::cmp::Ordering;
+use crate::export::{export_error, ExportError};
+use core::cell::RefCell;
+use super::{index_u32, point_segment_distance, Location};
+use super::options::{PipOptions, FILL_EVEN_ODD};
+use super::certified::orient2d;
+use core::cmp::Ordering;
+use super::common::{
+    boundary_rings, build_edge_index, build_polygon, build_polygon_with, fills, for_each_crossing, is_point_on_edge, point_in_bounds, quantize_y, Bounds, Edge,
+    EdgeIndex, Polygon, RowCache, DEFAULT_CACHE_SIZE,
+};
 
 pub mod test;
 
 // 精度和性能相关常量
-const EPSILON: f64 = 1e-9;     // 浮点数比较的精度阈值，用于处理数值精度问题
-const GRID_SIZE: usize = 64;   // 空间网格的大小，影响网格索引的精度和内存使用
-const CACHE_SIZE: usize = 1024; // 扫描线交点缓存的最大数量
+#[cfg(rayon_backend)]
+const PARALLEL_MIN_POINTS: usize = 1 << 16; // 点数不少于该值时才并行判断
+#[cfg(rayon_backend)]
+const PARALLEL_CHUNK: usize = 1 << 12; // 并行判断时每块的点数
 
-// 多边形数据结构：存储整个多边形的边和环信息
-struct Polygon {
-    edges: Vec<Edge>,    // 存储所有边的集合
-    rings: Vec<Ring>,    // 存储所有环的集合（外环和内部的洞）
-    bounds: Bounds,      // 整个多边形的边界框
+// 并行判断时给出点坐标的函数需要能在线程间共享，未启用并行时不作要求
+#[cfg(rayon_backend)]
+pub(crate) trait MaybeSync: Sync {}
+#[cfg(rayon_backend)]
+impl<T: Sync> MaybeSync for T {}
+#[cfg(not(rayon_backend))]
+pub(crate) trait MaybeSync {}
+#[cfg(not(rayon_backend))]
+impl<T> MaybeSync for T {}
+
+// 三值输出的标签
+const LABEL_OUTSIDE: u32 = 0;
+const LABEL_INSIDE: u32 = 1;
+const LABEL_BOUNDARY: u32 = 2;
+
+// 扫描线交点缓存：键是量化后的y坐标，值是该y坐标下的交点列表 (x坐标, 边索引, 环索引)，容量由选项指定，满时按时钟策略淘汰
+type ScanlineCache = RowCache<Vec<(f64, usize, usize)>>;
+
+// WebAssembly导出函数：批量判断点是否在多边形内部
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_scanline(
+    points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> Vec<u32> {
+    classify_points(points.len() / 2, |i| (points[i * 2] as f64, points[i * 2 + 1] as f64), polygon, rings, boundary_is_inside)
 }
 
-// 环结构：表示多边形的一个环（外环或内部的洞）
-struct Ring {
-    start_idx: usize,    // 该环的第一条边在edges数组中的索引
-    edge_count: usize,   // 该环包含的边数量
-    is_hole: bool,       // 标识该环是否为洞（内环）
-    bounds: Bounds,      // 该环的边界框
+// WebAssembly导出函数：与point_in_polygon_scanline相同，但结果写入调用方提供的数组（长度至少为点数），不分配结果数组；
+// js端传入wasm内存上的视图（例如StagingClassifier.result_view）时整个过程没有复制
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_scanline_into(
+    points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+    out: &mut [u32],          // 结果数组，第i个元素为第i个点的结果，多出的元素不变
+) -> Result<(), ExportError> {
+    classify_into(points, polygon, rings, boundary_is_inside, out).map_err(export_error)
 }
 
-// 边结构：表示多边形的一条边（一个线段）
-#[derive(Clone, Copy)]
-struct Edge {
-    x1: f64, y1: f64,    // 边的起点坐标
-    x2: f64, y2: f64,    // 边的终点坐标
+// 把结果写入out，out比点数短时返回错误信息
+pub(crate) fn classify_into(points: &[f32], polygon: &[f32], rings: &[u32], boundary_is_inside: bool, out: &mut [u32]) -> Result<(), String> {
+    let point_count = points.len() / 2;
+    if out.len() < point_count {
+        return Err(format!("output buffer holds {} results but there are {} points", out.len(), point_count));
+    }
+    let options = PipOptions::with_boundary(boundary_is_inside);
+    let point = |i: usize| (points[i * 2] as f64, points[i * 2 + 1] as f64);
+    for_each_location_with(point_count, point, polygon, rings, &options, |i, location| out[i] = location.to_u32(boundary_is_inside));
+    Ok(())
 }
 
-// 边界框：用于快速空间过滤
-#[derive(Clone, Copy)]
-struct Bounds {
-    min_x: f64, min_y: f64,    // 边界框的最小坐标（左下角）
-    max_x: f64, max_y: f64,    // 边界框的最大坐标（右上角）
+// WebAssembly导出函数：按行扫描的批量判断，结果与point_in_polygon_scanline相同
+// 适合规则栅格等大量点共享同一y坐标的输入：每个不同的y只计算一次扫描线交点，整行复用
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_scanline_rows(
+    points: &[f32],             // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],            // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],              // 多边形环的分割索引
+    boundary_is_inside: bool,   // 边界点是否视为内部
+    points_are_row_major: bool, // 点是否已按行排列（同y的点连续），为false时先按y排序
+) -> Vec<u32> {
+    let point_count = points.len() / 2;
+    let mut results = vec![0; point_count];
+    if point_count == 0 || polygon.is_empty() || rings.is_empty() {
+        return results;
+    }
+    let poly = build_polygon(polygon, rings);
+    let point = |i: usize| (points[i * 2] as f64, points[i * 2 + 1] as f64);
+    for_each_location_by_rows(&poly, point_count, point, points_are_row_major, |i, location| {
+        results[i] = location.to_u32(boundary_is_inside)
+    });
+    results
 }
 
-// 空间网格单元：存储落在该网格内的边的索引
-#[derive(Clone)]
-struct GridCell {
-    edge_indices: Vec<usize>,  // 该网格单元包含的边的索引列表
+// WebAssembly导出函数：与point_in_polygon_scanline相同，但每个点的结果只占一个字节（Uint8Array）
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_scanline_u8(
+    points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> Vec<u8> {
+    let point_count = points.len() / 2;
+    let options = PipOptions::with_boundary(boundary_is_inside);
+    let point = |i: usize| (points[i * 2] as f64, points[i * 2 + 1] as f64);
+    let mut results = vec![0u8; point_count];
+    for_each_location_with(point_count, point, polygon, rings, &options, |i, location| {
+        results[i] = location.to_u32(boundary_is_inside) as u8
+    });
+    results
 }
 
-// WebAssembly导出函数：批量判断点是否在多边形内部
-#[wasm_bindgen]
-pub fn point_in_polygon_scanline(
+// WebAssembly导出函数：位打包的结果，第i个点的结果是第i/32个字的第i%32位（从最低位开始），
+// 最后一个字中多出的位为0；js端用 (words[i >>> 5] >>> (i & 31)) & 1 取出第i个点的结果
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_scanline_bits(
     points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
     polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
     rings: &[u32],            // 多边形环的分割索引
     boundary_is_inside: bool, // 边界点是否视为内部
 ) -> Vec<u32> {
-    // 处理空输入的边界情况
     let point_count = points.len() / 2;
+    let options = PipOptions::with_boundary(boundary_is_inside);
+    let point = |i: usize| (points[i * 2] as f64, points[i * 2 + 1] as f64);
+    let mut words = vec![0u32; point_count.div_ceil(32)];
+    for_each_location_with(point_count, point, polygon, rings, &options, |i, location| {
+        words[i / 32] |= location.to_u32(boundary_is_inside) << (i % 32)
+    });
+    words
+}
+
+// WebAssembly导出函数：只返回判定为内部的点的下标（递增），结果长度等于内部点数
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_indices(
+    points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> Result<Vec<u32>, ExportError> {
+    let point_count = points.len() / 2;
+    // 最大的下标在u32范围内时所有下标都可以直接转换
+    index_u32(point_count.saturating_sub(1)).map_err(export_error)?;
+    let options = PipOptions::with_boundary(boundary_is_inside);
+    let point = |i: usize| (points[i * 2] as f64, points[i * 2 + 1] as f64);
+    let mut indices = Vec::new();
+    for_each_location_with(point_count, point, polygon, rings, &options, |i, location| {
+        if location.to_u32(boundary_is_inside) == 1 {
+            indices.push(i as u32);
+        }
+    });
+    Ok(indices)
+}
+
+// WebAssembly导出函数：按原顺序返回判定为内部的点的坐标 [x1,y1,x2,y2...]，结果长度为内部点数的2倍
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_filter(
+    points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> Vec<f32> {
+    let point_count = points.len() / 2;
+    let options = PipOptions::with_boundary(boundary_is_inside);
+    let point = |i: usize| (points[i * 2] as f64, points[i * 2 + 1] as f64);
+    let mut filtered = Vec::new();
+    for_each_location_with(point_count, point, polygon, rings, &options, |i, location| {
+        if location.to_u32(boundary_is_inside) == 1 {
+            filtered.extend_from_slice(&points[i * 2..i * 2 + 2]);
+        }
+    });
+    filtered
+}
+
+// WebAssembly导出函数：判定为内部的点数
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn count_points_in_polygon(
+    points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> usize {
+    let options = PipOptions::with_boundary(boundary_is_inside);
+    let point = |i: usize| (points[i * 2] as f64, points[i * 2 + 1] as f64);
+    let mut count = 0;
+    for_each_location_with(points.len() / 2, point, polygon, rings, &options, |_, location| {
+        count += location.to_u32(boundary_is_inside) as usize
+    });
+    count
+}
+
+// WebAssembly导出函数：是否至少有一个点在内部，遇到第一个内部点即返回；没有点时为false
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn any_point_in_polygon(
+    points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> bool {
+    find_point(points, polygon, rings, boundary_is_inside, true)
+}
+
+// WebAssembly导出函数：是否所有点都在内部，遇到第一个外部点即返回；没有点时为true
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn all_points_in_polygon(
+    points: &[f32],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> bool {
+    !find_point(points, polygon, rings, boundary_is_inside, false)
+}
+
+// 是否存在判断结果为inside的点，找到后不再判断剩余的点
+fn find_point(points: &[f32], polygon: &[f32], rings: &[u32], boundary_is_inside: bool, inside: bool) -> bool {
+    let mut points = points.chunks_exact(2);
+    // 空多边形时所有点都在外部
+    if polygon.is_empty() || rings.is_empty() {
+        return !inside && points.len() > 0;
+    }
+    let index = ScanlineIndex::new(polygon, rings);
+    points.any(|p| (index.locate(p[0] as f64, p[1] as f64).to_u32(boundary_is_inside) == 1) == inside)
+}
+
+// WebAssembly导出函数：与point_in_polygon_scanline相同，但点和多边形都是f64坐标（Float64Array），
+// 用于UTM等投影坐标这类数值大、需要亚毫米精度的数据，整个过程不经过f32
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_scanline_f64(
+    points: &[f64],           // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f64],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> Vec<u32> {
+    classify_points(points.len() / 2, |i| (points[i * 2], points[i * 2 + 1]), polygon, rings, boundary_is_inside)
+}
+
+// WebAssembly导出函数：三值判断，边界上的点单独标记，便于在界面上区分显示
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_scanline_ternary(
+    points: &[f32],  // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32], // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],   // 多边形环的分割索引
+) -> Vec<u32> {
+    locate_points_with(points.len() / 2, |i| (points[i * 2] as f64, points[i * 2 + 1] as f64), polygon, rings, &PipOptions::default())
+        .into_iter()
+        .map(|location| match location {
+            Location::Outside => LABEL_OUTSIDE,
+            Location::Inside => LABEL_INSIDE,
+            Location::Boundary => LABEL_BOUNDARY,
+        })
+        .collect()
+}
+
+// WebAssembly导出函数：直接判断交错存储的记录中的点，例如LiDAR的 [x, y, z, intensity, ...]
+// 每条记录占stride个值，x位于记录内第offset个值，y紧随其后；末尾不完整的记录被忽略
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_strided(
+    records: &[f32],          // 交错存储的记录
+    stride: usize,            // 每条记录的值个数
+    offset: usize,            // x在记录内的偏移
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> Vec<u32> {
+    // 记录中放不下x和y时没有可判断的点
+    if offset.checked_add(2).is_none_or(|end| end > stride) {
+        return Vec::new();
+    }
+    let point_count = records.len() / stride;
+    classify_points(
+        point_count,
+        |i| (records[i * stride + offset] as f64, records[i * stride + offset + 1] as f64),
+        polygon,
+        rings,
+        boundary_is_inside,
+    )
+}
+
+// 批量判断的主流程，point(i)给出第i个点的坐标
+pub(crate) fn classify_points<C: Copy + Into<f64>, F: Fn(usize) -> (f64, f64) + MaybeSync>(
+    point_count: usize,
+    point: F,
+    polygon: &[C],
+    rings: &[u32],
+    boundary_is_inside: bool,
+) -> Vec<u32> {
+    classify_points_with(point_count, point, polygon, rings, &PipOptions::with_boundary(boundary_is_inside))
+}
+
+// 按选项批量判断
+pub(crate) fn classify_points_with<C: Copy + Into<f64>, F: Fn(usize) -> (f64, f64) + MaybeSync>(
+    point_count: usize,
+    point: F,
+    polygon: &[C],
+    rings: &[u32],
+    options: &PipOptions,
+) -> Vec<u32> {
+    let boundary_is_inside = options.boundary_is_inside;
+    locate_points_with(point_count, point, polygon, rings, options)
+        .into_iter()
+        .map(|location| location.to_u32(boundary_is_inside))
+        .collect()
+}
+
+// 按选项批量求每个点的位置：距离边界不超过容差的点为Boundary
+pub(crate) fn locate_points_with<C: Copy + Into<f64>, F: Fn(usize) -> (f64, f64) + MaybeSync>(
+    point_count: usize,
+    point: F,
+    polygon: &[C],
+    rings: &[u32],
+    options: &PipOptions,
+) -> Vec<Location> {
+    let mut results = vec![Location::Outside; point_count];
+    #[cfg(rayon_backend)]
+    if point_count >= PARALLEL_MIN_POINTS && !polygon.is_empty() && !rings.is_empty() && crate::threads::threads_ready() {
+        let poly = build_polygon_with(polygon, rings, options);
+        let point = |i| {
+            let (a, b) = point(i);
+            options.axes(a, b)
+        };
+        locate_in_parallel(&poly, point, options.cache_capacity(), &mut results);
+        return results;
+    }
+    for_each_location_with(point_count, point, polygon, rings, options, |i, location| results[i] = location);
+    results
+}
+
+// 按选项逐点求位置，第i个点的结果交给emit(i, location)，按i递增的顺序，不分配结果数组
+pub(crate) fn for_each_location_with<C: Copy + Into<f64>, F: Fn(usize) -> (f64, f64), E: FnMut(usize, Location)>(
+    point_count: usize,
+    point: F,
+    polygon: &[C],
+    rings: &[u32],
+    options: &PipOptions,
+    mut emit: E,
+) {
+    // 处理空输入的边界情况
     if point_count == 0 || polygon.is_empty() || rings.is_empty() {
-        return vec![0; point_count];
+        (0..point_count).for_each(|i| emit(i, Location::Outside));
+        return;
     }
     
-    // 构建多边形数据结构和空间索引
-    let poly = build_polygon(polygon, rings);
-    let grid = build_grid(&poly);
-    
-    // 预分配结果数组
-    let mut results = vec![0; point_count];
+    // 构建多边形数据结构和空间索引，点按与多边形相同的轴顺序和y轴方向转换
+    let poly = build_polygon_with(polygon, rings, options);
+    let point = |i| {
+        let (a, b) = point(i);
+        options.axes(a, b)
+    };
+    for_each_location_in(&poly, point_count, point, options.cache_capacity(), emit)
+}
+
+// 对已构建的多边形（或MultiPolygon）批量求每个点的位置
+pub(crate) fn locate_points_in<F: Fn(usize) -> (f64, f64)>(poly: &Polygon, point_count: usize, point: F) -> Vec<Location> {
+    let mut results = vec![Location::Outside; point_count];
+    for_each_location_in(poly, point_count, point, DEFAULT_CACHE_SIZE, |i, location| results[i] = location);
+    results
+}
+
+// 对已构建的多边形逐点求位置，结果交给emit(i, location)
+fn for_each_location_in<F: Fn(usize) -> (f64, f64), E: FnMut(usize, Location)>(poly: &Polygon, point_count: usize, point: F, cache_size: usize, mut emit: E) {
+    // 快速路径：外环和洞都是轴对齐矩形时，直接用坐标比较判断，无需求交
+    if let Some(rects) = axis_aligned_rects(poly) {
+        for i in 0..point_count {
+            let (x, y) = point(i);
+            emit(i, locate_in_rects(poly, &rects, x, y));
+        }
+        return;
+    }
+
+    let index = build_edge_index(poly);
     
     // 创建扫描线交点缓存，用于重用计算结果
     // 键是量化后的y坐标，值是该y坐标下与多边形的交点列表
-    let mut scanline_cache: HashMap<i64, Vec<(f64, usize, usize)>> = HashMap::new();
+    let mut scanline_cache = ScanlineCache::new(cache_size);
     
     // 处理每个点
     for i in 0..point_count {
-        let x = points[i * 2] as f64;     // 当前点的x坐标
-        let y = points[i * 2 + 1] as f64; // 当前点的y坐标
-        
-        // 1. 边界框快速检查 - 如果点在整个多边形的边界框外，肯定在多边形外
-        if !point_in_bounds(x, y, &poly.bounds) {
-            continue; // 点在多边形外部
+        let (x, y) = point(i); // 当前点的坐标
+        emit(i, locate_one(poly, &index, x, y, &mut scanline_cache));
+    }
+}
+
+// 按行扫描：同一行（y完全相同）的点只计算一次交点，整行复用同一交点列表，不查哈希缓存
+// row_major为true时按输入顺序取连续的同y点为一行，否则先把点下标按y排序再分行
+fn for_each_location_by_rows<F: Fn(usize) -> (f64, f64), E: FnMut(usize, Location)>(
+    poly: &Polygon,
+    point_count: usize,
+    point: F,
+    row_major: bool,
+    mut emit: E,
+) {
+    if let Some(rects) = axis_aligned_rects(poly) {
+        for i in 0..point_count {
+            let (x, y) = point(i);
+            emit(i, locate_in_rects(poly, &rects, x, y));
         }
-        
-        // 2. 检查点是否在边上 - 边界情况处理
-        if is_point_on_edge(&poly, &grid, x, y) {
-            results[i] = boundary_is_inside as u32;
-            continue;
+        return;
+    }
+
+    let index = build_edge_index(poly);
+    let mut order: Vec<usize> = (0..point_count).collect();
+    if !row_major {
+        order.sort_by(|&a, &b| point(a).1.total_cmp(&point(b).1));
+    }
+
+    let mut start = 0;
+    while start < order.len() {
+        let y = point(order[start]).1;
+        let key = quantize_y(y);
+        let mut end = start + 1;
+        while end < order.len() && quantize_y(point(order[end]).1) == key {
+            end += 1;
         }
-        
-        // 3. 使用扫描线算法判断点是否在多边形内部
-        let y_key = quantize_y(y);  // 量化y坐标以便缓存查找
-        let inside = is_point_in_polygon(&poly, &grid, x, y, &mut scanline_cache, y_key);
-        results[i] = inside as u32;
+
+        // 整行在边界框外时不计算交点
+        let in_rows = y >= poly.bounds.min_y && y <= poly.bounds.max_y;
+        let mut intersections = if in_rows { compute_intersections(poly, y) } else { Vec::new() };
+        intersections.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        for &i in &order[start..end] {
+            let (x, _) = point(i);
+            let location = if is_point_on_edge(poly, &index, x, y) {
+                Location::Boundary
+            } else if !point_in_bounds(x, y, &poly.bounds) {
+                Location::Outside
+            } else if inside_at(poly, &intersections, x, y) {
+                Location::Inside
+            } else {
+                Location::Outside
+            };
+            emit(i, location);
+        }
+        start = end;
     }
-    
-    results
 }
 
-// 构建多边形数据结构：从输入的平铺数组构建结构化的多边形表示
-fn build_polygon(polygon: &[f32], rings: &[u32]) -> Polygon {
-    let mut edges = Vec::new();        // 存储所有边
-    let mut poly_rings = Vec::new();   // 存储所有环
-    let mut min_x = f64::MAX;          // 整个多边形的最小x坐标
-    let mut min_y = f64::MAX;          // 整个多边形的最小y坐标
-    let mut max_x = f64::MIN;          // 整个多边形的最大x坐标
-    let mut max_y = f64::MIN;          // 整个多边形的最大y坐标
-    
-    let _start_idx = 0;                // 未使用的变量
-    let mut prev_idx = 0;              // 前一个环的结束索引
-    
-    // 处理每个环（外环和洞）
-    for (i, &split) in rings.iter().enumerate() {
-        let mut ring_min_x = f64::MAX;  // 当前环的最小x坐标
-        let mut ring_min_y = f64::MAX;  // 当前环的最小y坐标
-        let mut ring_max_x = f64::MIN;  // 当前环的最大x坐标
-        let mut ring_max_y = f64::MIN;  // 当前环的最大y坐标
-        
-        let start_edge_idx = edges.len();  // 当前环的第一条边索引
-        let start = prev_idx as usize * 2; // 当前环在polygon数组中的起始位置
-        let end = split as usize * 2;      // 当前环在polygon数组中的结束位置
-        
-        // 提取当前环的所有边
-        let mut ring_edges = 0;
-        for j in (start..end).step_by(2) {
-            if j + 3 < end {
-                let x1 = polygon[j] as f64;         // 边的起点x坐标
-                let y1 = polygon[j + 1] as f64;     // 边的起点y坐标
-                let x2 = polygon[j + 2] as f64;     // 边的终点x坐标
-                let y2 = polygon[j + 3] as f64;     // 边的终点y坐标
-                
-                // 忽略退化边（长度接近0的边）
-                if (x1 - x2).abs() < EPSILON && (y1 - y2).abs() < EPSILON {
-                    continue;
-                }
-                
-                edges.push(Edge { x1, y1, x2, y2 });
-                ring_edges += 1;
-                
-                // 更新环的边界框
-                ring_min_x = ring_min_x.min(x1).min(x2);
-                ring_min_y = ring_min_y.min(y1).min(y2);
-                ring_max_x = ring_max_x.max(x1).max(x2);
-                ring_max_y = ring_max_y.max(y1).max(y2);
+// 判断单个点的位置
+fn locate_one(poly: &Polygon, index: &EdgeIndex, x: f64, y: f64, scanline_cache: &mut ScanlineCache) -> Location {
+    // 1. 检查点是否在边上 - 边界情况处理（自带按容差扩展的边界框排除，边界框外容差带内的点同样在边上）
+    if is_point_on_edge(poly, index, x, y) {
+        Location::Boundary
+    } else if !point_in_bounds(x, y, &poly.bounds) {
+        // 2. 边界框快速检查 - 如果点在整个多边形的边界框外，肯定在多边形外
+        Location::Outside
+    } else if is_point_in_polygon(poly, index, x, y, scanline_cache, quantize_y(y)) {
+        // 3. 使用扫描线算法判断点是否在多边形内部（量化y坐标以便缓存查找）
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+// 对已构建的多边形（或MultiPolygon）求每个点的位置及其所属的外环和洞（环索引）：
+// 内部的点给出包含它的外环；落在洞中的点给出该洞和洞所挖去的外环；边界上的点给出边界所在的外环，
+// 在洞的边界上时给出该洞和它的外环；外部的点两者都没有。重叠的部分（如洞中的岛）取点真正所在的部分
+pub(crate) fn attribute_points_in<F: Fn(usize) -> (f64, f64)>(
+    poly: &Polygon,
+    point_count: usize,
+    point: F,
+) -> Vec<(Location, Option<usize>, Option<usize>)> {
+    let index = build_edge_index(poly);
+    let mut cache = ScanlineCache::new(DEFAULT_CACHE_SIZE);
+    let mut touched = Vec::new();
+    let mut winding = vec![0; poly.rings.len()];
+
+    (0..point_count)
+        .map(|i| {
+            let (x, y) = point(i);
+            let location = locate_one(poly, &index, x, y, &mut cache);
+            if location == Location::Boundary {
+                boundary_rings(poly, &index, x, y, &mut touched);
+                let hole = touched.iter().copied().find(|&r| poly.rings[r].is_hole);
+                let outer = touched.iter().copied().find(|&r| !poly.rings[r].is_hole).or_else(|| hole.and_then(|r| poly.rings[r].shell));
+                return (location, outer, hole);
             }
-        }
-        
-        // 连接环的最后一点和第一点，封闭环
-        if end > start + 2 {
-            let x1 = polygon[end - 2] as f64;     // 最后一点的x坐标
-            let y1 = polygon[end - 1] as f64;     // 最后一点的y坐标
-            let x2 = polygon[start] as f64;       // 第一点的x坐标
-            let y2 = polygon[start + 1] as f64;   // 第一点的y坐标
-            
-            // 检查是否是有效边（非退化边）
-            if (x1 - x2).abs() >= EPSILON || (y1 - y2).abs() >= EPSILON {
-                edges.push(Edge { x1, y1, x2, y2 });
-                ring_edges += 1;
+            if !point_in_bounds(x, y, &poly.bounds) {
+                return (location, None, None);
             }
-        }
-        
-        // 创建环的边界框
-        let ring_bounds = Bounds {
-            min_x: ring_min_x, min_y: ring_min_y,
-            max_x: ring_max_x, max_y: ring_max_y,
-        };
-        
-        // 添加环到环列表
-        poly_rings.push(Ring {
-            start_idx: start_edge_idx,
-            edge_count: ring_edges,
-            is_hole: i > 0, // 第一个环是外环，其余是内环（洞）
-            bounds: ring_bounds,
-        });
-        
-        // 更新整个多边形的边界框
-        min_x = min_x.min(ring_min_x);
-        min_y = min_y.min(ring_min_y);
-        max_x = max_x.max(ring_max_x);
-        max_y = max_y.max(ring_max_y);
-        
-        prev_idx = split;
-    }
-    
-    // 处理最后一个环（如果有）
-    let start = prev_idx as usize * 2;
-    let end = polygon.len();
-    
-    if end > start + 2 {
-        let mut ring_min_x = f64::MAX;
-        let mut ring_min_y = f64::MAX;
-        let mut ring_max_x = f64::MIN;
-        let mut ring_max_y = f64::MIN;
-        
-        let start_edge_idx = edges.len();
-        let mut ring_edges = 0;
-        
-        // 提取最后一个环的所有边
-        for j in (start..end).step_by(2) {
-            if j + 3 < end {
-                let x1 = polygon[j] as f64;
-                let y1 = polygon[j + 1] as f64;
-                let x2 = polygon[j + 2] as f64;
-                let y2 = polygon[j + 3] as f64;
-                
-                // 忽略退化边
-                if (x1 - x2).abs() < EPSILON && (y1 - y2).abs() < EPSILON {
-                    continue;
+
+            // 每个环单独按填充规则判断点是否在环内
+            let intersections = cache.get_or_insert_with(quantize_y(y), || {
+                let mut inters = compute_intersections(poly, y);
+                inters.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                inters
+            });
+            winding.iter_mut().for_each(|w| *w = 0);
+            for &(xi, edge_idx, ring_idx) in intersections.iter() {
+                if crosses_left(poly, (xi, edge_idx, ring_idx), x, y) {
+                    winding[ring_idx] += poly.edges[edge_idx].winding();
                 }
-                
-                edges.push(Edge { x1, y1, x2, y2 });
-                ring_edges += 1;
-                
-                // 更新边界框
-                ring_min_x = ring_min_x.min(x1).min(x2);
-                ring_min_y = ring_min_y.min(y1).min(y2);
-                ring_max_x = ring_max_x.max(x1).max(x2);
-                ring_max_y = ring_max_y.max(y1).max(y2);
             }
-        }
-        
-        // 连接最后一个环的最后一点和第一点
-        if end > start + 2 {
-            let x1 = polygon[end - 2] as f64;
-            let y1 = polygon[end - 1] as f64;
-            let x2 = polygon[start] as f64;
-            let y2 = polygon[start + 1] as f64;
-            
-            if (x1 - x2).abs() >= EPSILON || (y1 - y2).abs() >= EPSILON {
-                edges.push(Edge { x1, y1, x2, y2 });
-                ring_edges += 1;
+            let in_ring = |r: usize| fills(poly.fill_rule, winding[r]);
+            let hole_of = |outer: usize| (0..poly.rings.len()).find(|&r| poly.rings[r].is_hole && poly.rings[r].shell == Some(outer) && in_ring(r));
+
+            // 优先取点不在其洞中的外环（内部的点），其次取点落在其洞中的外环
+            let outers = (0..poly.rings.len()).filter(|&r| !poly.rings[r].is_hole && in_ring(r));
+            let mut found = (None, None);
+            for outer in outers {
+                match hole_of(outer) {
+                    None => return (location, Some(outer), None),
+                    Some(hole) if found.0.is_none() => found = (Some(outer), Some(hole)),
+                    Some(_) => {}
+                }
             }
+            (location, found.0, found.1)
+        })
+        .collect()
+}
+
+// 并行判断：点按PARALLEL_CHUNK分块交给Rayon线程，每个线程使用自己的交点缓存
+#[cfg(rayon_backend)]
+fn locate_in_parallel<F: Fn(usize) -> (f64, f64) + Sync>(poly: &Polygon, point: F, cache_size: usize, results: &mut [Location]) {
+    use rayon::prelude::*;
+
+    let rects = axis_aligned_rects(poly);
+    let index = build_edge_index(poly);
+    results.par_chunks_mut(PARALLEL_CHUNK).enumerate().for_each_init(|| ScanlineCache::new(cache_size), |cache, (chunk_idx, chunk)| {
+        let offset = chunk_idx * PARALLEL_CHUNK;
+        for (k, result) in chunk.iter_mut().enumerate() {
+            let (x, y) = point(offset + k);
+            *result = match &rects {
+                Some(rects) => locate_in_rects(poly, rects, x, y),
+                None => locate_one(poly, &index, x, y, cache),
+            };
         }
-        
-        // 创建最后一个环的边界框
-        let ring_bounds = Bounds {
-            min_x: ring_min_x, min_y: ring_min_y,
-            max_x: ring_max_x, max_y: ring_max_y,
-        };
-        
-        // 添加最后一个环
-        poly_rings.push(Ring {
-            start_idx: start_edge_idx,
-            edge_count: ring_edges,
-            is_hole: rings.len() > 0, // 如果之前有环，则这个是洞
-            bounds: ring_bounds,
-        });
-        
-        // 更新整个多边形的边界框
-        min_x = min_x.min(ring_min_x);
-        min_y = min_y.min(ring_min_y);
-        max_x = max_x.max(ring_max_x);
-        max_y = max_y.max(ring_max_y);
-    }
-    
-    // 创建整个多边形的边界框
-    let poly_bounds = Bounds {
-        min_x, min_y, max_x, max_y,
-    };
-    
-    // 返回构建好的多边形
-    Polygon {
-        edges,
-        rings: poly_rings,
-        bounds: poly_bounds,
-    }
+    });
 }
 
-// 构建空间网格索引：将多边形的边分配到网格单元中，用于加速空间查询
-fn build_grid(poly: &Polygon) -> Vec<Vec<GridCell>> {
-    // 创建网格
-    let mut grid = vec![vec![GridCell { edge_indices: Vec::new() }; GRID_SIZE]; GRID_SIZE];
-    
-    // 计算网格单元尺寸
-    let width = poly.bounds.max_x - poly.bounds.min_x;
-    let height = poly.bounds.max_y - poly.bounds.min_y;
-    
-    // 将所有边添加到相应的网格单元中
-    for (edge_idx, edge) in poly.edges.iter().enumerate() {
-        // 确定边横跨的网格单元
-        let cells = get_grid_cells(
-            poly.bounds.min_x, poly.bounds.min_y,
-            width, height,
-            edge.x1, edge.y1, edge.x2, edge.y2
-        );
-        
-        // 将边的索引添加到相应的网格单元中
-        for (gx, gy) in cells {
-            grid[gx][gy].edge_indices.push(edge_idx);
+// WebAssembly导出函数：诊断输出，给出每个点向左的水平射线与每个环的交点计数
+// 输出格式为 [环数, 点0环0, 点0环1, ..., 点1环0, ...]，计数规则与扫描线判定完全相同
+// （半开区间规则，见common模块），
+// 用于排查不同算法在顶点处结果不一致的问题；环的计数为奇数表示点在该环内
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn ring_crossing_counts(
+    points: &[f32],  // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32], // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],   // 多边形环的分割索引
+) -> Vec<u32> {
+    ring_crossing_counts_with_options(points, polygon, rings, &PipOptions::default())
+}
+
+// WebAssembly导出函数：按选项（轴顺序、顶点策略等）给出各环的交点计数，输出格式与ring_crossing_counts相同
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn ring_crossing_counts_with_options(
+    points: &[f32],       // 输入点集，平铺存储 [x1,y1,x2,y2...]
+    polygon: &[f32],      // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],        // 多边形环的分割索引
+    options: &PipOptions, // 判断选项
+) -> Vec<u32> {
+    let poly = build_polygon_with(polygon, rings, options);
+    let ring_count = poly.rings.len();
+    let point_count = points.len() / 2;
+
+    let mut result = Vec::with_capacity(1 + point_count * ring_count);
+    result.push(ring_count as u32);
+    for i in 0..point_count {
+        let (x, y) = options.axes(points[i * 2] as f64, points[i * 2 + 1] as f64);
+        let mut counts = vec![0u32; ring_count];
+        // 不经过缓存，按点的精确y坐标求交
+        for (xi, _edge_idx, ring_idx) in compute_intersections(&poly, y) {
+            if xi < x {
+                counts[ring_idx] += 1;
+            }
         }
+        result.extend(counts);
     }
-    
-    grid
+    result
 }
 
-// 计算线段横跨的网格单元：使用改进的Bresenham算法跟踪线段穿过的所有网格单元
-fn get_grid_cells(
-    min_x: f64, min_y: f64,
-    width: f64, height: f64,
-    x1: f64, y1: f64, x2: f64, y2: f64
-) -> Vec<(usize, usize)> {
-    // 结果列表：存储线段穿过的所有网格单元坐标
-    let mut cells = Vec::new();
-    
-    // 将线段端点坐标转换为网格索引
-    let x1_grid = ((x1 - min_x) / width * (GRID_SIZE as f64)) as usize;
-    let y1_grid = ((y1 - min_y) / height * (GRID_SIZE as f64)) as usize;
-    let x2_grid = ((x2 - min_x) / width * (GRID_SIZE as f64)) as usize;
-    let y2_grid = ((y2 - min_y) / height * (GRID_SIZE as f64)) as usize;
-    
-    // 确保网格索引不超出范围
-    let x1_grid = x1_grid.min(GRID_SIZE - 1);
-    let y1_grid = y1_grid.min(GRID_SIZE - 1);
-    let x2_grid = x2_grid.min(GRID_SIZE - 1);
-    let y2_grid = y2_grid.min(GRID_SIZE - 1);
-    
-    // 如果线段在单个网格单元内，直接返回
-    if x1_grid == x2_grid && y1_grid == y2_grid {
-        cells.push((x1_grid, y1_grid));
-        return cells;
+// 预处理后的多边形索引：保存多边形结构、空间网格和扫描线缓存，供其他模块逐点复用扫描线判定
+pub(crate) struct ScanlineIndex {
+    poly: Polygon,
+    index: EdgeIndex,
+    cache: RefCell<ScanlineCache>,
+}
+
+impl ScanlineIndex {
+    pub(crate) fn new(polygon: &[f32], rings: &[u32]) -> ScanlineIndex {
+        ScanlineIndex::with_options(polygon, rings, &PipOptions::default())
     }
-    
-    // 简化的Bresenham算法：追踪线段穿过的所有网格单元
-    let dx = (x2_grid as isize - x1_grid as isize).abs();
-    let dy = (y2_grid as isize - y1_grid as isize).abs();
-    let sx = if x1_grid < x2_grid { 1 } else { -1 };
-    let sy = if y1_grid < y2_grid { 1 } else { -1 };
-    let mut err = if dx > dy { dx } else { -dy } as isize / 2;
-    
-    let mut x = x1_grid as isize;
-    let mut y = y1_grid as isize;
-    
-    // 追踪线段路径
-    loop {
-        // 如果网格单元在有效范围内，添加到结果列表
-        if x >= 0 && y >= 0 && x < GRID_SIZE as isize && y < GRID_SIZE as isize {
-            cells.push((x as usize, y as usize));
+
+    // 按选项构建（容差、退化环、绕向、填充规则），边界语义由每次查询指定
+    pub(crate) fn with_options<C: Copy + Into<f64>>(polygon: &[C], rings: &[u32], options: &PipOptions) -> ScanlineIndex {
+        let poly = build_polygon_with(polygon, rings, options);
+        let index = build_edge_index(&poly);
+        ScanlineIndex { poly, index, cache: RefCell::new(ScanlineCache::new(options.cache_capacity())) }
+    }
+
+    // 多边形的边界框 (min_x, min_y, max_x, max_y)
+    pub(crate) fn bounds(&self) -> (f64, f64, f64, f64) {
+        let b = &self.poly.bounds;
+        (b.min_x, b.min_y, b.max_x, b.max_y)
+    }
+
+    // 所有环的边 [x1, y1, x2, y2]
+    pub(crate) fn edges(&self) -> impl Iterator<Item = [f64; 4]> + '_ {
+        self.poly.edges.iter().map(|e| [e.x1, e.y1, e.x2, e.y2])
+    }
+
+    // 点到多边形边界（所有环）的最短距离
+    pub(crate) fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        self.poly
+            .edges
+            .iter()
+            .map(|e| point_segment_distance(x, y, e.x1, e.y1, e.x2, e.y2))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    // 点到多边形边界的距离是否不超过radius：只检查边索引中与以点为中心、半宽为radius的正方形相交的单元登记的边
+    pub(crate) fn boundary_within(&self, x: f64, y: f64, radius: f64) -> bool {
+        let square = Bounds { min_x: x - radius, min_y: y - radius, max_x: x + radius, max_y: y + radius };
+        self.index.any_edge_in(&self.poly, &square, |edge_idx| {
+            let e = &self.poly.edges[edge_idx];
+            point_segment_distance(x, y, e.x1, e.y1, e.x2, e.y2) <= radius
+        })
+    }
+
+    // 构建好的多边形结构，供其他索引（如梯形分解）复用
+    pub(crate) fn polygon(&self) -> &Polygon {
+        &self.poly
+    }
+
+    // 点是否在边界上（距离某条边不超过容差）
+    pub(crate) fn on_boundary(&self, x: f64, y: f64) -> bool {
+        !self.poly.rings.is_empty() && is_point_on_edge(&self.poly, &self.index, x, y)
+    }
+
+    // 点所在边界的全部环（环索引递增），点不在边界上时out为空
+    pub(crate) fn boundary_rings(&self, x: f64, y: f64, out: &mut Vec<usize>) {
+        boundary_rings(&self.poly, &self.index, x, y, out);
+    }
+
+    // 判断单个点的位置，流程与point_in_polygon_scanline相同
+    pub(crate) fn locate(&self, x: f64, y: f64) -> Location {
+        if self.poly.rings.is_empty() {
+            return Location::Outside;
         }
-        
-        // 如果到达终点，结束循环
-        if x == x2_grid as isize && y == y2_grid as isize {
-            break;
+        if is_point_on_edge(&self.poly, &self.index, x, y) {
+            return Location::Boundary;
         }
-        
-        // 计算下一个网格单元
-        let e2 = err;
-        if e2 > -dx {
-            err -= dy as isize;
-            x += sx;
+        if !point_in_bounds(x, y, &self.poly.bounds) {
+            return Location::Outside;
         }
-        if e2 < dy {
-            err += dx as isize;
-            y += sy;
+
+        let mut cache = self.cache.borrow_mut();
+        if is_point_in_polygon(&self.poly, &self.index, x, y, &mut cache, quantize_y(y)) {
+            Location::Inside
+        } else {
+            Location::Outside
         }
     }
-    
-    cells
 }
 
-// 检查点是否在边界框内：快速过滤点
-#[inline]
-fn point_in_bounds(x: f64, y: f64, bounds: &Bounds) -> bool {
-    x >= bounds.min_x && x <= bounds.max_x && y >= bounds.min_y && y <= bounds.max_y
+// WebAssembly导出函数：判断规则点阵中的每个点是否在多边形内部
+// 点阵第r行第c列的点坐标为(x0 + c*dx, y0 + r*dy)，结果按行优先排列（索引为 r*cols + c）
+// 同一行的点共享同一条扫描线，每行只计算一次交点，然后按x顺序扫过整行
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[allow(clippy::too_many_arguments)]
+pub fn grid_points_in_polygon(
+    x0: f64,                  // 点阵第一列的x坐标
+    y0: f64,                  // 点阵第一行的y坐标
+    dx: f64,                  // 列间距（可为负）
+    dy: f64,                  // 行间距（可为负）
+    cols: usize,              // 列数
+    rows: usize,              // 行数
+    polygon: &[f32],          // 多边形顶点，平铺存储 [x1,y1,x2,y2...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界点是否视为内部
+) -> Result<Vec<u32>, ExportError> {
+    grid_point_labels(x0, y0, dx, dy, cols, rows, polygon, rings, boundary_is_inside).map_err(export_error)
 }
 
-// 检查点是否在任何边上：用于处理边界点
-fn is_point_on_edge(poly: &Polygon, grid: &Vec<Vec<GridCell>>, x: f64, y: f64) -> bool {
-    // 确定点所在网格单元
-    let width = poly.bounds.max_x - poly.bounds.min_x;
-    let height = poly.bounds.max_y - poly.bounds.min_y;
-    
-    // 计算点所在的网格单元索引
-    let grid_x = ((x - poly.bounds.min_x) / width * (GRID_SIZE as f64)) as usize;
-    let grid_y = ((y - poly.bounds.min_y) / height * (GRID_SIZE as f64)) as usize;
-    
-    // 检查点是否在网格范围内
-    if grid_x >= GRID_SIZE || grid_y >= GRID_SIZE {
-        return false;
+// 点阵判断的实现，点数 cols × rows 超出usize范围时返回错误信息
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn grid_point_labels(
+    x0: f64,
+    y0: f64,
+    dx: f64,
+    dy: f64,
+    cols: usize,
+    rows: usize,
+    polygon: &[f32],
+    rings: &[u32],
+    boundary_is_inside: bool,
+) -> Result<Vec<u32>, String> {
+    let count = cols
+        .checked_mul(rows)
+        .ok_or_else(|| format!("grid_points_in_polygon: {} cols × {} rows overflows the point count", cols, rows))?;
+    let mut results = vec![0; count];
+    if cols == 0 || rows == 0 || polygon.is_empty() || rings.is_empty() {
+        return Ok(results);
     }
-    
-    // 检查该网格单元中的所有边
-    for &edge_idx in &grid[grid_x][grid_y].edge_indices {
-        let edge = &poly.edges[edge_idx];
-        
-        // 快速边界框检查：如果点不在边的边界框内，跳过
-        let min_x = edge.x1.min(edge.x2) - EPSILON;
-        let max_x = edge.x1.max(edge.x2) + EPSILON;
-        let min_y = edge.y1.min(edge.y2) - EPSILON;
-        let max_y = edge.y1.max(edge.y2) + EPSILON;
-        
-        if x < min_x || x > max_x || y < min_y || y > max_y {
+
+    let poly = build_polygon(polygon, rings);
+    let tol = poly.tolerance;
+
+    for r in 0..rows {
+        let y = y0 + r as f64 * dy;
+        // 距离边界框超过容差的行整行都在多边形外部；容差内的行仍可能落在边上
+        if y < poly.bounds.min_y - tol || y > poly.bounds.max_y + tol {
             continue;
         }
-        
-        // 计算点到线段的距离
-        let dx = edge.x2 - edge.x1;
-        let dy = edge.y2 - edge.y1;
-        let len_sq = dx * dx + dy * dy;
-        
-        // 处理退化为点的边
-        if len_sq < EPSILON * EPSILON {
-            if (x - edge.x1).abs() < EPSILON && (y - edge.y1).abs() < EPSILON {
-                return true;
+
+        let row = &mut results[r * cols..(r + 1) * cols];
+        classify_lattice_row(&poly, y, x0, dx, row, boundary_is_inside);
+    }
+
+    Ok(results)
+}
+
+// 扫描点阵中的一行：交点和边界区间都按x排序，随列的推进单调前移
+fn classify_lattice_row(
+    poly: &Polygon,
+    y: f64,
+    x0: f64,
+    dx: f64,
+    row: &mut [u32],
+    boundary_is_inside: bool,
+) {
+    let cols = row.len();
+
+    // 与逐点路径使用相同的交点计算，保证两条路径结果一致
+    let mut intersections = compute_intersections(poly, y);
+    intersections.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    // 该行上落在边上的x区间，按起点排序
+    let mut spans = boundary_spans(poly, y);
+    spans.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    // 每个环左侧交点数的奇偶性，以及当前处于奇数状态的外环/洞数量
+    let mut ring_parity = vec![false; poly.rings.len()];
+    let mut odd_outer = 0usize;
+    let mut odd_holes = 0usize;
+    let mut next_intersection = 0;
+    let mut next_span = 0;
+    let mut active_spans: Vec<(f64, f64)> = Vec::new();
+
+    for step in 0..cols {
+        // 按x递增的顺序访问列，dx为负时倒序遍历
+        let c = if dx >= 0.0 { step } else { cols - 1 - step };
+        let x = x0 + c as f64 * dx;
+
+        if x < poly.bounds.min_x - poly.tolerance || x > poly.bounds.max_x + poly.tolerance {
+            continue;
+        }
+
+        // 推进所有严格位于该点左侧的交点
+        while next_intersection < intersections.len() && intersections[next_intersection].0 < x {
+            let ring_idx = intersections[next_intersection].2;
+            ring_parity[ring_idx] = !ring_parity[ring_idx];
+            let counter = if poly.rings[ring_idx].is_hole { &mut odd_holes } else { &mut odd_outer };
+            if ring_parity[ring_idx] {
+                *counter += 1;
+            } else {
+                *counter -= 1;
+            }
+            next_intersection += 1;
+        }
+
+        // 维护覆盖当前x的边界区间
+        while next_span < spans.len() && spans[next_span].0 <= x {
+            active_spans.push(spans[next_span]);
+            next_span += 1;
+        }
+        active_spans.retain(|span| span.1 >= x);
+
+        row[c] = if !active_spans.is_empty() {
+            boundary_is_inside as u32
+        } else {
+            (odd_outer % 2 == 1 && odd_holes == 0) as u32
+        };
+    }
+}
+
+// 计算水平线y上所有"点在边上"的x区间
+// 与is_point_on_edge的判定一致：区间是三个范围的交集——边的边界框（加容差）、投影参数t在[0,1]内的x范围、
+// 到边所在直线的垂直距离不超过容差的x范围（斜边的半宽按容差换算到水平方向）
+fn boundary_spans(poly: &Polygon, y: f64) -> Vec<(f64, f64)> {
+    let tol = poly.tolerance;
+    let mut spans = Vec::new();
+
+    for edge in &poly.edges {
+        let min_y = edge.y1.min(edge.y2);
+        let max_y = edge.y1.max(edge.y2);
+        if y < min_y - tol || y > max_y + tol {
+            continue;
+        }
+
+        let edge_dx = edge.x2 - edge.x1;
+        let edge_dy = edge.y2 - edge.y1;
+        let len_sq = edge_dx * edge_dx + edge_dy * edge_dy;
+
+        // 退化为点的边
+        if len_sq < tol * tol {
+            if (y - edge.y1).abs() < tol {
+                spans.push((edge.x1 - tol, edge.x1 + tol));
             }
             continue;
         }
-        
-        // 计算点到线段的投影参数t
-        // 当t在[0,1]范围内时，投影点在线段上
-        let t = ((x - edge.x1) * dx + (y - edge.y1) * dy) / len_sq;
-        
-        if t < 0.0 || t > 1.0 {
-            continue; // 投影点不在线段上
+
+        let mut lo = edge.x1.min(edge.x2) - tol;
+        let mut hi = edge.x1.max(edge.x2) + tol;
+
+        // 投影参数 t(x) = ((x - x1)·dx + (y - y1)·dy) / |e|² 在[0,1]内；竖直边的t只取决于y
+        let along = (y - edge.y1) * edge_dy;
+        if edge_dx != 0.0 {
+            let t0 = edge.x1 - along / edge_dx;
+            let t1 = edge.x1 + (len_sq - along) / edge_dx;
+            lo = lo.max(t0.min(t1));
+            hi = hi.min(t0.max(t1));
+        } else if !(0.0..=len_sq).contains(&along) {
+            continue;
         }
-        
-        // 计算投影点坐标和到原点的距离
-        let px = edge.x1 + t * dx;
-        let py = edge.y1 + t * dy;
-        let dist_sq = (x - px) * (x - px) + (y - py) * (y - py);
-        
-        // 如果距离小于阈值，认为点在边上
-        if dist_sq <= EPSILON * EPSILON {
-            return true;
+
+        // 水平边（或接近水平）：边的范围内都在容差带中
+        if edge_dy.abs() >= tol {
+            let x = edge.x1 + (y - edge.y1) / edge_dy * edge_dx;
+            let half_width = tol * len_sq.sqrt() / edge_dy.abs();
+            lo = lo.max(x - half_width);
+            hi = hi.min(x + half_width);
+        }
+
+        if lo <= hi {
+            spans.push((lo, hi));
         }
     }
-    
-    // 未找到点在上面的边
-    false
+
+    spans
+}
+
+// 检测多边形的每个环是否都是轴对齐矩形：4条边且水平/竖直交替，是则返回各环的矩形（即环的边界框）
+fn axis_aligned_rects(poly: &Polygon) -> Option<Vec<Bounds>> {
+    // 矩形路径把所有洞都视为挖去外环，只适用于只有一个组成部分的多边形；
+    // 矩形的识别和边界都按容差判断，精确模式不使用
+    if poly.part_count != 1 || poly.exact {
+        return None;
+    }
+    // 多个外环按奇偶翻转合并，非零规则下重叠的外环需按环绕数合并，交给扫描线
+    if poly.fill_rule != FILL_EVEN_ODD && poly.rings.iter().filter(|ring| !ring.is_hole).count() > 1 {
+        return None;
+    }
+    let tol = poly.tolerance;
+    let mut rects = Vec::with_capacity(poly.rings.len());
+
+    for ring in &poly.rings {
+        if ring.edge_count != 4 {
+            return None;
+        }
+
+        let edges = &poly.edges[ring.start_idx..ring.start_idx + 4];
+        let is_horizontal = |e: &Edge| (e.y1 - e.y2).abs() < tol;
+        let is_vertical = |e: &Edge| (e.x1 - e.x2).abs() < tol;
+        let first_horizontal = is_horizontal(&edges[0]);
+
+        for (k, edge) in edges.iter().enumerate() {
+            // 相邻边必须一横一竖
+            let expect_horizontal = first_horizontal == (k % 2 == 0);
+            let ok = if expect_horizontal { is_horizontal(edge) } else { is_vertical(edge) };
+            if !ok {
+                return None;
+            }
+        }
+
+        rects.push(ring.bounds);
+    }
+
+    Some(rects)
 }
 
-// 量化y坐标以便缓存：将浮点y值转换为整数键
+// 矩形快速路径下的单点判断：与扫描线路径的语义一致（边界优先，其次外环内且不在任何洞内）
 #[inline]
-fn quantize_y(y: f64) -> i64 {
-    // 将y坐标放大并四舍五入，用于HashMap键
-    (y * 1_000_000.0).round() as i64
+fn locate_in_rects(poly: &Polygon, rects: &[Bounds], x: f64, y: f64) -> Location {
+    let tol = poly.tolerance;
+    let mut in_outer = false;
+    let mut in_hole = false;
+
+    for (ring, rect) in poly.rings.iter().zip(rects) {
+        let in_expanded = x >= rect.min_x - tol && x <= rect.max_x + tol
+            && y >= rect.min_y - tol && y <= rect.max_y + tol;
+        if !in_expanded {
+            continue;
+        }
+
+        let in_interior = x > rect.min_x + tol && x < rect.max_x - tol
+            && y > rect.min_y + tol && y < rect.max_y - tol;
+        if !in_interior {
+            return Location::Boundary; // 点在矩形的边上
+        }
+
+        if ring.is_hole {
+            in_hole = true;
+        } else {
+            in_outer = !in_outer;
+        }
+    }
+
+    if in_outer && !in_hole { Location::Inside } else { Location::Outside }
 }
 
 // 判断点是否在多边形内部：使用扫描线算法
 fn is_point_in_polygon(
     poly: &Polygon,
-    _grid: &Vec<Vec<GridCell>>,
+    _index: &EdgeIndex,
     x: f64,
     y: f64,
-    cache: &mut HashMap<i64, Vec<(f64, usize, usize)>>,
+    cache: &mut ScanlineCache,
     y_key: i64
 ) -> bool {
-    // 获取或计算扫描线交点
-    let intersections = if let Some(cached) = cache.get(&y_key) {
-        cached
-    } else {
-        // 缓存未命中，计算新的交点
+    // 获取或计算扫描线交点（缓存未命中时计算并按x坐标排序）
+    let intersections = cache.get_or_insert_with(y_key, || {
         let mut inters = compute_intersections(poly, y);
-        // 按x坐标排序交点
         inters.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-        
-        // 维护缓存大小，防止内存泄漏
-        if cache.len() >= CACHE_SIZE {
-            // 缓存满时，清除一半缓存
-            let keys: Vec<_> = cache.keys().cloned().collect();
-            for key in keys.iter().take(cache.len() / 2) {
-                cache.remove(key);
-            }
-        }
-        
-        // 将新计算的交点添加到缓存
-        cache.insert(y_key, inters);
-        cache.get(&y_key).unwrap()
-    };
-    
+        inters
+    });
+    inside_at(poly, intersections, x, y)
+}
+
+// 交点是否在点的左侧：默认比较浮点求得的交点x坐标，精确模式下按方向判定的符号（点在边的右侧即交点在点的左侧，
+// 不在边上的点不与边共线）
+#[inline]
+fn crosses_left(poly: &Polygon, (xi, edge_idx, _): (f64, usize, usize), x: f64, y: f64) -> bool {
+    if poly.exact {
+        edge_side(&poly.edges[edge_idx], x, y) == Ordering::Less
+    } else {
+        xi < x
+    }
+}
+
+// 点相对于边（按从下到上的方向）的侧别：Less为右侧，Greater为左侧，Equal为共线
+#[inline]
+fn edge_side(edge: &Edge, x: f64, y: f64) -> Ordering {
+    if edge.y1 < edge.y2 {
+        orient2d(edge.x1, edge.y1, edge.x2, edge.y2, x, y)
+    } else {
+        orient2d(edge.x2, edge.y2, edge.x1, edge.y1, x, y)
+    }
+}
+
+// 根据扫描线y上的交点列表判断点(x, y)是否在多边形内部
+fn inside_at(poly: &Polygon, intersections: &[(f64, usize, usize)], x: f64, y: f64) -> bool {
+    // MultiPolygon：洞只挖去同一部分的外环，按部分分别判断
+    if poly.part_count > 1 {
+        return in_any_part(poly, intersections, x, y);
+    }
+
     // 分别处理外环和内环
     let mut in_holes = false;
     
-    // 首先判断点是否在外环内 (奇数个交点表示在内部)
-    let mut crossings_outer = 0;
-    for &(xi, _edge_idx, ring_idx) in intersections.iter() {
-        if xi >= x {
+    // 首先判断点是否在外环内 (按填充规则判断环绕数，奇偶规则下奇数个交点表示在内部)
+    let mut winding_outer = 0;
+    for &(xi, edge_idx, ring_idx) in intersections.iter() {
+        if !crosses_left(poly, (xi, edge_idx, ring_idx), x, y) {
             continue; // 只考虑点左侧的交点
         }
         
         if !poly.rings[ring_idx].is_hole {
-            crossings_outer += 1;
+            winding_outer += poly.edges[edge_idx].winding();
         }
     }
-    let in_outer = crossings_outer % 2 == 1;
+    let in_outer = fills(poly.fill_rule, winding_outer);
     
     // 如果不在外环内，肯定不在多边形内
     if !in_outer {
@@ -506,17 +999,17 @@ fn is_point_in_polygon(
             continue;
         }
         
-        // 计算与该洞的交点数
-        let mut hole_crossings = 0;
-        for &(xi, _edge_idx, r_idx) in intersections.iter() {
-            if xi >= x || r_idx != ring_idx {
+        // 计算该洞的环绕数
+        let mut hole_winding = 0;
+        for &(xi, edge_idx, r_idx) in intersections.iter() {
+            if r_idx != ring_idx || !crosses_left(poly, (xi, edge_idx, r_idx), x, y) {
                 continue;
             }
-            hole_crossings += 1;
+            hole_winding += poly.edges[edge_idx].winding();
         }
         
         // 如果在任何一个洞内，则不在多边形内
-        if hole_crossings % 2 == 1 {
+        if fills(poly.fill_rule, hole_winding) {
             in_holes = true;
             break;
         }
@@ -526,6 +1019,26 @@ fn is_point_in_polygon(
     in_outer && !in_holes
 }
 
+// MultiPolygon的判断：点在某个部分的外环内，且不在该部分的任何洞内
+// （另一部分可能位于这个部分的洞中，例如湖中的岛，因此不能像单个多边形那样统一累计外环和洞的交点）
+fn in_any_part(poly: &Polygon, intersections: &[(f64, usize, usize)], x: f64, y: f64) -> bool {
+    let mut winding = vec![0; poly.rings.len()];
+    for &(xi, edge_idx, ring_idx) in intersections {
+        if crosses_left(poly, (xi, edge_idx, ring_idx), x, y) {
+            winding[ring_idx] += poly.edges[edge_idx].winding();
+        }
+    }
+
+    let mut part_state = vec![(false, false); poly.part_count]; // (在外环内, 在某个洞内)
+    for (ring, &w) in poly.rings.iter().zip(&winding) {
+        if fills(poly.fill_rule, w) {
+            let state = &mut part_state[ring.part];
+            if ring.is_hole { state.1 = true } else { state.0 = true }
+        }
+    }
+    part_state.iter().any(|&(in_outer, in_hole)| in_outer && !in_hole)
+}
+
 // 计算扫描线与多边形的交点：找出y值与多边形边的所有交点
 fn compute_intersections(poly: &Polygon, y: f64) -> Vec<(f64, usize, usize)> {
     // 结果列表：(x坐标, 边索引, 环索引)
@@ -533,58 +1046,15 @@ fn compute_intersections(poly: &Polygon, y: f64) -> Vec<(f64, usize, usize)> {
     
     // 遍历所有环
     for (ring_idx, ring) in poly.rings.iter().enumerate() {
-        // 跳过不与扫描线相交的环
-        if y < ring.bounds.min_y || y > ring.bounds.max_y {
+        // 跳过不与扫描线相交的环，以及只作为边界的退化环
+        if y < ring.bounds.min_y || y > ring.bounds.max_y || ring.boundary_only {
             continue;
         }
         
-        // 遍历环中的所有边
+        // 遍历环中的所有边，按半开区间规则求交（水平边和经过顶点的情况见common模块）
         let end_idx = ring.start_idx + ring.edge_count;
-        for edge_idx in ring.start_idx..end_idx {
-            let edge = &poly.edges[edge_idx];
-            
-            // 检查边是否与扫描线相交
-            // 优化处理接近扫描线的情况
-            if edge.y1 < y - EPSILON && edge.y2 < y - EPSILON {
-                continue; // 边完全在扫描线下方
-            }
-            
-            if edge.y1 > y + EPSILON && edge.y2 > y + EPSILON {
-                continue; // 边完全在扫描线上方
-            }
-            
-            // 改进处理扫描线经过顶点的情况
-            if (edge.y1 - y).abs() < EPSILON {
-                // 扫描线经过边的起点
-                
-                // 找到该顶点的前一条边
-                let prev_edge_idx = if edge_idx > ring.start_idx {
-                    edge_idx - 1
-                } else {
-                    ring.start_idx + ring.edge_count - 1
-                };
-                
-                let prev_edge = &poly.edges[prev_edge_idx];
-                
-                // 如果两条相邻边的一个在上方一个在下方，则计算交点
-                if (prev_edge.y1 > y && edge.y2 < y) || 
-                   (prev_edge.y1 < y && edge.y2 > y) {
-                    intersections.push((edge.x1, edge_idx, ring_idx));
-                }
-            } else if (edge.y2 - y).abs() < EPSILON {
-                // 扫描线经过边的终点，不重复计算，因为它会被下一条边处理
-                continue;
-            } else if (edge.y1 - edge.y2).abs() < EPSILON {
-                // 忽略水平边，它们不会产生有效交点
-                continue;
-            } else {
-                // 标准情况：线段与扫描线相交于非顶点处
-                let t = (y - edge.y1) / (edge.y2 - edge.y1);
-                let x = edge.x1 + t * (edge.x2 - edge.x1);
-                intersections.push((x, edge_idx, ring_idx));
-            }
-        }
+        for_each_crossing(&poly.edges[ring.start_idx..end_idx], y, poly.vertex_policy, |k, x| intersections.push((x, ring.start_idx + k, ring_idx)));
     }
     
     intersections
-} 
\ No newline at end of file
+}
\ No newline at end of file
@@ -1,8 +1,48 @@
 #[cfg(test)]
 mod tests {
-    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+    use crate::points_in_polygon::scanline::{
+        all_points_in_polygon, any_point_in_polygon, classify_into, count_points_in_polygon, grid_point_labels, grid_points_in_polygon, point_in_polygon_scanline, point_in_polygon_scanline_f64, point_in_polygon_scanline_bits, point_in_polygon_scanline_rows, point_in_polygon_scanline_ternary,
+        point_in_polygon_filter, point_in_polygon_indices, point_in_polygon_scanline_u8, point_in_polygon_strided,
+        ring_crossing_counts, ring_crossing_counts_with_options,
+    };
+    use crate::generators::Rng;
     use std::time::Instant;
 
+    #[test]
+    fn test_square_with_hole() {
+        let polygon = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // Outer ring
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole
+        ];
+        let rings = vec![4];
+        let points = vec![4.0, 1.5, -1.0, 1.5, 1.5, 1.5, 0.5, 0.5, 2.5, 0.5, 3.0, 1.5, 1.0, 1.5];
+
+        // 轴对齐矩形走快速路径
+        assert_eq!(
+            point_in_polygon_scanline(&points, &polygon, &rings, true),
+            vec![0, 0, 0, 1, 1, 1, 1]
+        );
+        assert_eq!(
+            point_in_polygon_scanline(&points, &polygon, &rings, false),
+            vec![0, 0, 0, 1, 1, 0, 0]
+        );
+
+        // 同样的区域但外环多一个共线顶点，走通用路径，结果应一致
+        let polygon = vec![
+            0.0, 0.0, 1.5, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // Outer ring
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole
+        ];
+        let rings = vec![5];
+        assert_eq!(
+            point_in_polygon_scanline(&points, &polygon, &rings, true),
+            vec![0, 0, 0, 1, 1, 1, 1]
+        );
+        assert_eq!(
+            point_in_polygon_scanline(&points, &polygon, &rings, false),
+            vec![0, 0, 0, 1, 1, 0, 0]
+        );
+    }
+
     #[test]
     fn test_circle_with_holes_grid_points() {
         // 1.1. 输入
@@ -50,6 +90,7 @@ mod tests {
         }
 
         // c. 多边形路径点的拆分 [外圆顶点数, 外圆+第一个洞顶点数]
+        #[allow(clippy::unnecessary_cast)]
         let rings = vec![segments as u32, segments * 2 as u32];
 
         // d. 边界上点是否考虑为内部
@@ -104,4 +145,347 @@ mod tests {
         // 确保准确率至少为99%（由于圆形是用多边形近似，允许稍大的误差）
         assert!(correct_count as f64 / total_count as f64 > 0.99);
     }
+
+    // 构造圆形带洞多边形（与test_circle_with_holes_grid_points相同）
+    fn circle_with_holes() -> (Vec<f32>, Vec<u32>) {
+        let segments = 64 * 2;
+        let mut polygon = Vec::new();
+        for (cx, r) in [(0.0, 5.0), (-2.0, 1.0), (2.0, 1.0)] {
+            for i in 0..segments {
+                let angle = 2.0 * std::f32::consts::PI * (i as f32) / (segments as f32);
+                polygon.push(cx + r * angle.cos());
+                polygon.push(r * angle.sin());
+            }
+        }
+        (polygon, vec![segments, segments * 2])
+    }
+
+    #[test]
+    fn test_lattice_matches_general_path() {
+        let (polygon, rings) = circle_with_holes();
+
+        // 点阵：-10到10，间隔0.05，按行优先排列；参考点用与点阵相同的f64公式计算
+        let (x0, y0, step) = (-10.0f64, -10.0f64, 0.05f64);
+        let cols = 401;
+        let rows = 401;
+        let mut points = Vec::with_capacity(cols * rows * 2);
+        for r in 0..rows {
+            for c in 0..cols {
+                points.push(x0 + c as f64 * step);
+                points.push(y0 + r as f64 * step);
+            }
+        }
+        let polygon_f64: Vec<f64> = polygon.iter().map(|&v| v as f64).collect();
+
+        let start = Instant::now();
+        let lattice = grid_points_in_polygon(x0, y0, step, step, cols, rows, &polygon, &rings, true).unwrap();
+        let lattice_duration = start.elapsed();
+
+        let start = Instant::now();
+        let general = point_in_polygon_scanline_f64(&points, &polygon_f64, &rings, true);
+        let general_duration = start.elapsed();
+
+        println!(
+            "scanline lattice {:?} vs general {:?} for {} points",
+            lattice_duration, general_duration, cols * rows
+        );
+
+        assert_eq!(lattice, general);
+    }
+
+    #[test]
+    fn test_random_lattices_match_per_point_path() {
+        // 凹多边形（十角星）带两个洞；点阵起点随机贴近顶点坐标，偏移量在容差附近，步长可正可负
+        let polygon = vec![
+            50.0, 0.0, 62.0, 34.0, 98.0, 36.0, 69.0, 58.0, 80.0, 94.0, 50.0, 72.0, 20.0, 94.0, 31.0, 58.0, 2.0, 36.0, 38.0, 34.0, // 外环
+            44.0, 40.0, 56.0, 40.0, 50.0, 52.0, // 三角形洞
+            46.0, 56.0, 54.0, 56.0, 54.0, 64.0, 46.0, 64.0, // 正方形洞
+        ];
+        let rings = vec![10, 13];
+        let polygon_f64: Vec<f64> = polygon.iter().map(|&v| v as f64).collect();
+        let tol = 1e-9 * (96.0f64 * 96.0 + 94.0 * 94.0).sqrt();
+        let offsets = [0.0, 0.5 * tol, -0.5 * tol, 2.0 * tol, -2.0 * tol];
+
+        let mut rng = Rng::new(17);
+        for _ in 0..200 {
+            let pick = |rng: &mut Rng, coords: &[f64]| {
+                let base = coords[(rng.next_u64() % coords.len() as u64) as usize];
+                base + offsets[(rng.next_u64() % offsets.len() as u64) as usize]
+            };
+            let xs: Vec<f64> = polygon_f64.iter().step_by(2).copied().collect();
+            let ys: Vec<f64> = polygon_f64.iter().skip(1).step_by(2).copied().collect();
+            let (x0, y0) = (pick(&mut rng, &xs), pick(&mut rng, &ys));
+            let dx = [1.0, -1.0, 2.0, 0.5, rng.range(-3.0, 3.0)][(rng.next_u64() % 5) as usize];
+            let dy = [1.0, -1.0, 2.0, 0.5, rng.range(-3.0, 3.0)][(rng.next_u64() % 5) as usize];
+            let cols = 1 + (rng.next_u64() % 60) as usize;
+            let rows = 1 + (rng.next_u64() % 60) as usize;
+
+            let mut points = Vec::with_capacity(cols * rows * 2);
+            for r in 0..rows {
+                for c in 0..cols {
+                    points.push(x0 + c as f64 * dx);
+                    points.push(y0 + r as f64 * dy);
+                }
+            }
+            for boundary_is_inside in [true, false] {
+                assert_eq!(
+                    grid_point_labels(x0, y0, dx, dy, cols, rows, &polygon, &rings, boundary_is_inside).unwrap(),
+                    point_in_polygon_scanline_f64(&points, &polygon_f64, &rings, boundary_is_inside),
+                    "x0={} y0={} dx={} dy={} cols={} rows={}",
+                    x0, y0, dx, dy, cols, rows
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lattice_size_overflow() {
+        let (polygon, rings) = circle_with_holes();
+        assert!(grid_point_labels(0.0, 0.0, 1.0, 1.0, usize::MAX, 2, &polygon, &rings, true).is_err());
+        assert_eq!(grid_point_labels(0.0, 0.0, 1.0, 1.0, usize::MAX, 0, &polygon, &rings, true), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_lattice_boundary_and_reversed_steps() {
+        let polygon = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // Outer ring
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole
+        ];
+        let rings = vec![4];
+
+        // 行: y = 0.0, 1.5；列: x = -0.5, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5
+        let inside = grid_points_in_polygon(-0.5, 0.0, 0.5, 1.5, 9, 2, &polygon, &rings, true).unwrap();
+        assert_eq!(inside, vec![0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0]);
+
+        let open = grid_points_in_polygon(-0.5, 0.0, 0.5, 1.5, 9, 2, &polygon, &rings, false).unwrap();
+        assert_eq!(open, vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0]);
+
+        // 负步长：同一点阵镜像遍历
+        let reversed = grid_points_in_polygon(3.5, 1.5, -0.5, -1.5, 9, 2, &polygon, &rings, true).unwrap();
+        let mut expected = inside.clone();
+        expected.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    #[test]
+    fn test_strided_records_match_packed_points() {
+        let (polygon, rings) = circle_with_holes();
+        let points = crate::generators::uniform_points(2000, -12.0, -12.0, 12.0, 12.0, 4);
+
+        // 记录格式 [z, x, y, intensity]
+        let mut records = Vec::new();
+        for (i, p) in points.chunks(2).enumerate() {
+            records.extend_from_slice(&[i as f32, p[0], p[1], -1.0]);
+        }
+        // 末尾不完整的记录被忽略
+        records.extend_from_slice(&[0.0, 0.0]);
+
+        assert_eq!(
+            point_in_polygon_strided(&records, 4, 1, &polygon, &rings, true),
+            point_in_polygon_scanline(&points, &polygon, &rings, true)
+        );
+        assert!(point_in_polygon_strided(&records, 4, 3, &polygon, &rings, true).is_empty());
+        assert!(point_in_polygon_strided(&records, 4, usize::MAX, &polygon, &rings, true).is_empty());
+    }
+
+    #[test]
+    fn test_ring_crossing_counts() {
+        let polygon = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // Outer ring
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole
+        ];
+        let rings = vec![4];
+        let points = vec![
+            0.5, 1.5, // 内部：外环1次
+            1.5, 1.5, // 洞内：外环1次，洞1次
+            2.5, 1.5, // 内部：外环1次，洞2次
+            4.0, 1.5, // 外部：外环2次，洞2次
+            2.5, 1.0, // 与洞的底边同高：水平边不计，洞的两条竖边各计1次
+        ];
+
+        assert_eq!(
+            ring_crossing_counts(&points, &polygon, &rings),
+            vec![2, 1, 0, 1, 1, 1, 2, 2, 2, 1, 2]
+        );
+
+        // 单环多边形
+        let counts = ring_crossing_counts(&[5.0, 0.5], &[0.0, 0.0, 3.0, 0.0, 3.0, 3.0], &[3]);
+        assert_eq!(counts, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_point_level_with_horizontal_edge() {
+        // 左侧带台阶的多边形：台阶是一条水平边(1,1)-(0,1)
+        let polygon = vec![0.0, 0.0, 5.0, 0.0, 5.0, 3.0, 1.0, 3.0, 1.0, 1.0, 0.0, 1.0];
+        let rings = vec![6];
+        // 与台阶等高但不在台阶上的点
+        let points = vec![
+            3.0, 1.0, // 内部
+            6.0, 1.0, // 外部
+            0.5, 1.0, // 在台阶上
+        ];
+
+        assert_eq!(point_in_polygon_scanline(&points, &polygon, &rings, false), vec![1, 0, 0]);
+        assert_eq!(point_in_polygon_scanline(&points, &polygon, &rings, true), vec![1, 0, 1]);
+    }
+
+    #[test]
+    fn test_ternary_marks_boundary() {
+        // 矩形走快速路径，三角形走扫描线路径，边界上的点（包括洞的边和顶点）都标记为2
+        let square = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // 外环
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // 洞
+        ];
+        let triangle = vec![0.0, 0.0, 3.0, 0.0, 0.0, 3.0];
+        let points = vec![
+            0.5, 0.5, // 内部
+            1.5, 1.5, // 洞内（三角形的斜边上）
+            3.0, 1.5, // 外环右边上（三角形外）
+            1.0, 1.0, // 洞的顶点（三角形内）
+            4.0, 4.0, // 外部
+        ];
+        assert_eq!(point_in_polygon_scanline_ternary(&points, &square, &[4]), vec![1, 0, 2, 2, 0]);
+        assert_eq!(point_in_polygon_scanline_ternary(&points, &triangle, &[3]), vec![1, 2, 0, 1, 0]);
+        assert_eq!(point_in_polygon_scanline_ternary(&[], &triangle, &[3]), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_classify_into_buffer() {
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 1.0, 1.0, 3.0, 1.0, 3.0, 3.0, 1.0, 3.0];
+        let rings = vec![4];
+        let points = vec![0.5, 0.5, 2.0, 2.0, 5.0, 5.0, 1.0, 2.0];
+
+        // 多出的元素保持不变
+        let mut out = vec![7; 5];
+        classify_into(&points, &polygon, &rings, false, &mut out).unwrap();
+        assert_eq!(&out[..4], point_in_polygon_scanline(&points, &polygon, &rings, false).as_slice());
+        assert_eq!(out[4], 7);
+
+        assert!(classify_into(&points, &polygon, &rings, true, &mut [0; 3]).is_err());
+        let mut empty_polygon_out = vec![9; 4];
+        classify_into(&points, &[], &rings, true, &mut empty_polygon_out).unwrap();
+        assert_eq!(empty_polygon_out, vec![0; 4]);
+    }
+
+    #[test]
+    fn test_compact_outputs() {
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let rings = vec![4];
+        // 70个点跨越3个字，内外交替
+        let points: Vec<f32> = (0..70).flat_map(|i| if i % 3 == 0 { [2.0, 2.0] } else { [5.0, 2.0] }).collect();
+        let expected = point_in_polygon_scanline(&points, &polygon, &rings, true);
+
+        let bytes = point_in_polygon_scanline_u8(&points, &polygon, &rings, true);
+        assert_eq!(bytes.iter().map(|&b| b as u32).collect::<Vec<_>>(), expected);
+
+        let words = point_in_polygon_scanline_bits(&points, &polygon, &rings, true);
+        assert_eq!(words.len(), 3);
+        let unpacked: Vec<u32> = (0..70).map(|i| (words[i / 32] >> (i % 32)) & 1).collect();
+        assert_eq!(unpacked, expected);
+        // 最后一个字中多出的位为0
+        assert_eq!(words[2] >> 6, 0);
+        assert!(point_in_polygon_scanline_bits(&[], &polygon, &rings, true).is_empty());
+    }
+
+    #[test]
+    fn test_inside_indices() {
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 1.0, 1.0, 3.0, 1.0, 3.0, 3.0, 1.0, 3.0];
+        let rings = vec![4];
+        let points = vec![0.5, 0.5, 2.0, 2.0, 5.0, 5.0, 1.0, 2.0, 3.5, 3.5];
+
+        assert_eq!(point_in_polygon_indices(&points, &polygon, &rings, false).unwrap(), vec![0, 4]);
+        assert_eq!(point_in_polygon_indices(&points, &polygon, &rings, true).unwrap(), vec![0, 3, 4]);
+        assert!(point_in_polygon_indices(&points, &[], &rings, true).unwrap().is_empty());
+
+        // 内部点的坐标按原顺序排列
+        assert_eq!(point_in_polygon_filter(&points, &polygon, &rings, true), vec![0.5, 0.5, 1.0, 2.0, 3.5, 3.5]);
+        assert!(point_in_polygon_filter(&points, &polygon, &[], true).is_empty());
+    }
+
+    #[test]
+    fn test_count_any_all() {
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 1.0, 1.0, 3.0, 1.0, 3.0, 3.0, 1.0, 3.0];
+        let rings = vec![4];
+        let points = vec![0.5, 0.5, 2.0, 2.0, 5.0, 5.0, 1.0, 2.0, 3.5, 3.5];
+        let inside = vec![0.5, 0.5, 1.0, 2.0, 3.5, 3.5];
+
+        assert_eq!(count_points_in_polygon(&points, &polygon, &rings, true), 3);
+        assert_eq!(count_points_in_polygon(&points, &polygon, &rings, false), 2);
+        assert!(any_point_in_polygon(&points, &polygon, &rings, false));
+        assert!(!any_point_in_polygon(&[2.0, 2.0, 5.0, 5.0], &polygon, &rings, true));
+        assert!(!all_points_in_polygon(&points, &polygon, &rings, true));
+        assert!(all_points_in_polygon(&inside, &polygon, &rings, true));
+        // 边界上的点按boundary_is_inside归类
+        assert!(!all_points_in_polygon(&inside, &polygon, &rings, false));
+
+        // 没有点时any为false、all为true；空多边形时所有点都在外部
+        assert!(!any_point_in_polygon(&[], &polygon, &rings, true));
+        assert!(all_points_in_polygon(&[], &polygon, &rings, true));
+        assert!(all_points_in_polygon(&[], &[], &[], true));
+        assert!(!all_points_in_polygon(&inside, &polygon, &[], true));
+        assert!(!any_point_in_polygon(&inside, &[], &rings, true));
+    }
+
+    #[test]
+    fn test_large_batch_matches_per_point_path() {
+        // 点数超过并行判断的门槛；启用parallel特性时分块并行，结果应与逐点判断的路径相同
+        let polygon = crate::generators::star_polygon(0.0, 0.0, 10.0, 4.0, 9);
+        let rings = vec![(polygon.len() / 2) as u32];
+        let points = crate::generators::uniform_points(70_000, -11.0, -11.0, 11.0, 11.0, 5);
+        let batch = point_in_polygon_scanline(&points, &polygon, &rings, true);
+        let per_point = point_in_polygon_scanline_u8(&points, &polygon, &rings, true);
+        assert_eq!(batch, per_point.iter().map(|&b| b as u32).collect::<Vec<_>>());
+
+        let square = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let batch = point_in_polygon_scanline(&points, &square, &[4], false);
+        let per_point = point_in_polygon_scanline_u8(&points, &square, &[4], false);
+        assert_eq!(batch, per_point.iter().map(|&b| b as u32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_row_sweep_matches_scanline() {
+        // 带洞的星形，点为规则栅格（行优先），含落在顶点所在行上的点
+        let mut polygon = crate::generators::star_polygon(0.0, 0.0, 10.0, 4.0, 7);
+        let outer = (polygon.len() / 2) as u32;
+        polygon.extend_from_slice(&[-1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, 1.0]);
+        let rings = vec![outer, outer + 4];
+        let mut points = Vec::new();
+        for r in 0..41 {
+            for c in 0..41 {
+                points.push(-10.0 + c as f32 * 0.5);
+                points.push(-10.0 + r as f32 * 0.5);
+            }
+        }
+        for boundary in [true, false] {
+            let expected = point_in_polygon_scanline(&points, &polygon, &rings, boundary);
+            assert_eq!(point_in_polygon_scanline_rows(&points, &polygon, &rings, boundary, true), expected);
+            assert_eq!(point_in_polygon_scanline_rows(&points, &polygon, &rings, boundary, false), expected);
+        }
+
+        // 行顺序被打乱时不给提示也得到相同结果
+        let scattered = crate::generators::uniform_points(2_000, -11.0, -11.0, 11.0, 11.0, 3);
+        let mut shuffled = Vec::new();
+        for k in 0..1_000 {
+            shuffled.extend_from_slice(&scattered[k * 2..k * 2 + 2]);
+            shuffled.extend_from_slice(&points[(k * 7 % 1681) * 2..(k * 7 % 1681) * 2 + 2]);
+        }
+        let expected = point_in_polygon_scanline(&shuffled, &polygon, &rings, true);
+        assert_eq!(point_in_polygon_scanline_rows(&shuffled, &polygon, &rings, true, false), expected);
+
+        // 空输入
+        assert!(point_in_polygon_scanline_rows(&[], &polygon, &rings, true, true).is_empty());
+        assert_eq!(point_in_polygon_scanline_rows(&[0.0, 0.0], &polygon, &[], true, false), vec![0]);
+    }
+
+    #[test]
+    fn test_ring_crossing_counts_vertex_policy() {
+        // 射线经过凹口顶点(2, 2)：默认规则下该顶点的两条边都在上方，合计两次；顶点视为在上方时合计零次
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 2.0, 2.0, 0.0, 4.0];
+        let points = vec![3.5, 2.0];
+        let mut options = crate::points_in_polygon::options::PipOptions::new();
+        assert_eq!(ring_crossing_counts_with_options(&points, &polygon, &[5], &options), ring_crossing_counts(&points, &polygon, &[5]));
+        assert_eq!(ring_crossing_counts(&points, &polygon, &[5]), vec![1, 3]);
+        options.set_vertex_policy(1);
+        assert_eq!(ring_crossing_counts_with_options(&points, &polygon, &[5], &options), vec![1, 1]);
+    }
 }
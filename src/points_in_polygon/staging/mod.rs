@@ -0,0 +1,116 @@
+// 双缓冲暂存模块：预处理一次多边形，在wasm内部保留A、B两个点云暂存区，
+// 当前帧判断暂存区A的同时，可以把下一帧的点写入暂存区B，两者交替使用（配合worker或多线程后端时尤其有效）
+
+// 输入(js端):
+//     1. new StagingClassifier(polygon, rings, boundary_is_inside)
+//     2. write_points_a(points) / write_points_b(points) 把点云复制进暂存区
+//        或 staging_view(buffer_id, point_count) 取得暂存区的Float32Array视图直接写入，省去一次复制
+//     3. classify(buffer_id) 判断暂存区中的点，buffer_id 0表示A，1表示B
+//        或 classify_view(buffer_id) 结果写入对象内部复用的结果区，返回其Uint32Array视图，省去结果数组的分配和复制
+// 输出(js端):
+//     1. 点云是否在多边形内部 类型Uint32Array 1表示在多边形内部,0表示在多边形外部
+// 说明: staging_view和classify_view返回的视图直接引用wasm内存，在下一次调用本对象的其他方法或wasm内存扩容后失效
+
+use alloc::{format, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+#[cfg(feature = "wasm")]
+use js_sys::{Float32Array, Uint32Array};
+use super::scanline::ScanlineIndex;
+
+pub mod test;
+
+// 双缓冲判断器
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct StagingClassifier {
+    index: ScanlineIndex,
+    boundary_is_inside: bool,
+    buffers: [Vec<f32>; 2],
+    #[cfg_attr(not(feature = "wasm"), allow(dead_code))]
+    results: Vec<u32>, // classify_view的结果区，跨调用复用
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl StagingClassifier {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(polygon: &[f32], rings: &[u32], boundary_is_inside: bool) -> StagingClassifier {
+        StagingClassifier {
+            index: ScanlineIndex::new(polygon, rings),
+            boundary_is_inside,
+            buffers: [Vec::new(), Vec::new()],
+            results: Vec::new(),
+        }
+    }
+
+    // 写入暂存区A
+    pub fn write_points_a(&mut self, points: &[f32]) {
+        self.write(0, points);
+    }
+
+    // 写入暂存区B
+    pub fn write_points_b(&mut self, points: &[f32]) {
+        self.write(1, points);
+    }
+
+    // 把暂存区调整为point_count个点并返回可直接写入的视图
+    #[cfg(feature = "wasm")]
+    pub fn staging_view(&mut self, buffer_id: u32, point_count: usize) -> Result<Float32Array, ExportError> {
+        let buffer = self.buffer_mut(buffer_id)?;
+        buffer.resize(point_count * 2, 0.0);
+        // 视图的有效期由调用方负责，见模块说明
+        Ok(unsafe { Float32Array::view_mut_raw(buffer.as_mut_ptr(), buffer.len()) })
+    }
+
+    // 判断暂存区中的点
+    pub fn classify(&self, buffer_id: u32) -> Result<Vec<u32>, ExportError> {
+        let points = self.buffers.get(buffer_id as usize).ok_or_else(|| invalid_buffer(buffer_id))?;
+        Ok(self.classify_points(points))
+    }
+
+    // 判断暂存区中的点，结果写入内部的结果区并返回其视图
+    #[cfg(feature = "wasm")]
+    pub fn classify_view(&mut self, buffer_id: u32) -> Result<Uint32Array, ExportError> {
+        let results = self.classify_in_place(buffer_id).ok_or_else(|| invalid_buffer(buffer_id))?;
+        // 视图的有效期由调用方负责，见模块说明
+        Ok(unsafe { Uint32Array::view_mut_raw(results.as_mut_ptr(), results.len()) })
+    }
+}
+
+impl StagingClassifier {
+    fn write(&mut self, slot: usize, points: &[f32]) {
+        let buffer = &mut self.buffers[slot];
+        buffer.clear();
+        buffer.extend_from_slice(points);
+    }
+
+    #[cfg(feature = "wasm")]
+    fn buffer_mut(&mut self, buffer_id: u32) -> Result<&mut Vec<f32>, ExportError> {
+        self.buffers.get_mut(buffer_id as usize).ok_or_else(|| invalid_buffer(buffer_id))
+    }
+
+    // 把暂存区中的点的结果写入结果区，结果区只在点数增加时扩容
+    #[cfg_attr(not(feature = "wasm"), allow(dead_code))]
+    pub(crate) fn classify_in_place(&mut self, buffer_id: u32) -> Option<&mut [u32]> {
+        let points = self.buffers.get(buffer_id as usize)?;
+        let point_count = points.len() / 2;
+        self.results.resize(point_count, 0);
+        for (point, result) in points.chunks_exact(2).zip(self.results.iter_mut()) {
+            *result = self.index.locate(point[0] as f64, point[1] as f64).to_u32(self.boundary_is_inside);
+        }
+        Some(&mut self.results[..point_count])
+    }
+
+    fn classify_points(&self, points: &[f32]) -> Vec<u32> {
+        (0..points.len() / 2)
+            .map(|i| {
+                let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+                self.index.locate(x, y).to_u32(self.boundary_is_inside)
+            })
+            .collect()
+    }
+}
+
+fn invalid_buffer(buffer_id: u32) -> ExportError {
+    export_error(format!("StagingClassifier: invalid buffer id {}", buffer_id))
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::{spiral_polygon, uniform_points};
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+    use crate::points_in_polygon::staging::StagingClassifier;
+
+    #[test]
+    fn test_alternating_buffers() {
+        let polygon = spiral_polygon(0.0, 0.0, 10.0, 3.0, 32);
+        let rings = vec![(polygon.len() / 2) as u32];
+        let mut classifier = StagingClassifier::new(&polygon, &rings, true);
+
+        // 模拟逐帧交替：判断当前帧时另一个暂存区已写入下一帧
+        let frames: Vec<Vec<f32>> = (0..4).map(|seed| uniform_points(500, -11.0, -11.0, 11.0, 11.0, seed)).collect();
+        classifier.write_points_a(&frames[0]);
+        for (i, frame) in frames.iter().enumerate() {
+            if let Some(next) = frames.get(i + 1) {
+                if i % 2 == 0 {
+                    classifier.write_points_b(next);
+                } else {
+                    classifier.write_points_a(next);
+                }
+            }
+            let result = classifier.classify((i % 2) as u32).unwrap();
+            assert_eq!(result, point_in_polygon_scanline(frame, &polygon, &rings, true));
+        }
+    }
+
+    #[test]
+    fn test_result_area_reused() {
+        let polygon = spiral_polygon(0.0, 0.0, 10.0, 3.0, 32);
+        let rings = vec![(polygon.len() / 2) as u32];
+        let mut classifier = StagingClassifier::new(&polygon, &rings, true);
+
+        let large = uniform_points(800, -11.0, -11.0, 11.0, 11.0, 1);
+        let small = uniform_points(200, -11.0, -11.0, 11.0, 11.0, 2);
+        classifier.write_points_a(&large);
+        classifier.write_points_b(&small);
+        assert_eq!(classifier.classify_in_place(0).unwrap().to_vec(), point_in_polygon_scanline(&large, &polygon, &rings, true));
+        let capacity = classifier.results.capacity();
+        assert_eq!(classifier.classify_in_place(1).unwrap().to_vec(), point_in_polygon_scanline(&small, &polygon, &rings, true));
+        assert_eq!(classifier.results.capacity(), capacity);
+        assert!(classifier.classify_in_place(2).is_none());
+    }
+}
@@ -0,0 +1,233 @@
+// 流式输出模块：把点云分块判断，每完成一块就立即交给js端，适合耗时数秒的大任务做渐进式渲染
+
+// 输入(js端):
+//     1. 点云 类型Float32Array, 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+//     2. 边界上点是否考虑为内部 boolean
+//     3. 每块的点数 chunk_size
+//     4. 回调函数 (offset, chunk) => void
+//        offset为该块第一个点的索引，chunk为该块结果 类型Uint32Array 1表示在多边形内部,0表示在多边形外部
+//     5. 拉取式会话 new PipSession(points, polygon, rings, boundary_is_inside)
+//        每次调用next_chunk(max_points)判断下一块，可直接包装为js的ReadableStream，由消费速度控制判断进度
+//     6. 时间预算 budget_ms，以及续判令牌 token（上次返回的下一个点的索引，首次为0）
+//        point_in_polygon_budgeted 超出预算时返回已判断的部分结果和令牌，下一帧带着令牌继续；
+//        PipSession.next_within(budget_ms, max_points) 在会话上按时间预算判断，最多max_points个点，不重复构建索引
+// 说明: chunk直接引用wasm内存，只在回调执行期间有效，需要保留时请调用chunk.slice()复制；
+//     回调抛出异常时停止判断并把异常抛回js端；
+//     时间预算每判断CHECK_INTERVAL个点检查一次，每次调用至少判断一批，预算为0时结果与运行速度无关（固定CHECK_INTERVAL个点）
+
+use alloc::vec::Vec;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+#[cfg(feature = "wasm")]
+use js_sys::{Function, Uint32Array};
+use super::scanline::ScanlineIndex;
+
+pub mod test;
+
+// 未指定块大小时的默认值
+#[cfg_attr(not(feature = "wasm"), allow(dead_code))]
+const DEFAULT_CHUNK_SIZE: usize = 65536;
+
+// 按时间预算判断时每批的点数，每批结束后检查一次是否超时
+pub(crate) const CHECK_INTERVAL: usize = 1024;
+
+// WebAssembly导出函数：分块判断并逐块回调
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn point_in_polygon_chunked(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+    chunk_size: usize,        // 每块的点数，为0时使用默认值
+    callback: &Function,      // 每完成一块时调用
+) -> Result<(), JsValue> {
+    classify_in_chunks(points, polygon, rings, boundary_is_inside, chunk_size, |offset, chunk| {
+        // 回调同步执行，期间wasm内存不会被释放或移动
+        let view = unsafe { Uint32Array::view(chunk) };
+        callback.call2(&JsValue::NULL, &JsValue::from(offset as u32), &view).map(|_| ())
+    })
+}
+
+// 按时间预算判断的部分结果
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct BudgetedChunk {
+    results: Vec<u32>,
+    offset: usize,       // 第一个结果对应的点的索引
+    next: Option<usize>, // 下一个未判断的点的索引，全部判断完时为None
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl BudgetedChunk {
+    // 本次判断的结果，第i个元素对应第offset+i个点
+    pub fn results(&self) -> Vec<u32> {
+        self.results.clone()
+    }
+
+    // 第一个结果对应的点的索引
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    // 续判令牌，传给下一次调用；全部判断完时返回undefined
+    pub fn token(&self) -> Option<usize> {
+        self.next
+    }
+
+    // 是否已全部判断完
+    pub fn is_done(&self) -> bool {
+        self.next.is_none()
+    }
+}
+
+// WebAssembly导出函数：从令牌处开始判断，用时超过budget_ms后停止，返回部分结果和续判令牌
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_budgeted(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+    budget_ms: f64,           // 时间预算（毫秒）
+    token: usize,             // 续判令牌，首次为0
+) -> BudgetedChunk {
+    let index = ScanlineIndex::new(polygon, rings);
+    classify_within(&index, points, boundary_is_inside, token, usize::MAX, budget_ms)
+}
+
+// 拉取式判断会话：构造时复制点云，之后按需逐块判断
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct PipSession {
+    points: Vec<f32>,
+    index: ScanlineIndex,
+    boundary_is_inside: bool,
+    offset: usize, // 下一块第一个点的索引
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl PipSession {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(points: &[f32], polygon: &[f32], rings: &[u32], boundary_is_inside: bool) -> PipSession {
+        PipSession {
+            points: points.to_vec(),
+            index: ScanlineIndex::new(polygon, rings),
+            boundary_is_inside,
+            offset: 0,
+        }
+    }
+
+    // 判断接下来最多max_points个点，全部判断完后返回undefined
+    pub fn next_chunk(&mut self, max_points: usize) -> Option<Vec<u32>> {
+        let point_count = self.points.len() / 2;
+        if self.offset >= point_count {
+            return None;
+        }
+
+        let end = self.offset.saturating_add(max_points.max(1)).min(point_count);
+        let chunk = (self.offset..end)
+            .map(|i| {
+                let (x, y) = (self.points[i * 2] as f64, self.points[i * 2 + 1] as f64);
+                self.index.locate(x, y).to_u32(self.boundary_is_inside)
+            })
+            .collect();
+        self.offset = end;
+        Some(chunk)
+    }
+
+    // 按时间预算判断接下来最多max_points个点（为0时不限），返回部分结果和续判令牌
+    pub fn next_within(&mut self, budget_ms: f64, max_points: usize) -> BudgetedChunk {
+        let max_points = if max_points == 0 { usize::MAX } else { max_points };
+        let chunk = classify_within(&self.index, &self.points, self.boundary_is_inside, self.offset, max_points, budget_ms);
+        self.offset = chunk.next.unwrap_or(self.points.len() / 2);
+        chunk
+    }
+
+    // 下一块第一个点的索引
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    // 尚未判断的点数
+    pub fn remaining(&self) -> usize {
+        (self.points.len() / 2).saturating_sub(self.offset)
+    }
+
+    // 是否已全部判断完
+    pub fn is_done(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+// 分块判断，每块结果交给on_chunk；on_chunk返回错误时立即停止
+#[cfg_attr(not(feature = "wasm"), allow(dead_code))]
+pub(crate) fn classify_in_chunks<E, F: FnMut(usize, &[u32]) -> Result<(), E>>(
+    points: &[f32],
+    polygon: &[f32],
+    rings: &[u32],
+    boundary_is_inside: bool,
+    chunk_size: usize,
+    mut on_chunk: F,
+) -> Result<(), E> {
+    let point_count = points.len() / 2;
+    let chunk_size = if chunk_size == 0 { DEFAULT_CHUNK_SIZE } else { chunk_size };
+    let index = ScanlineIndex::new(polygon, rings);
+    let mut chunk = Vec::with_capacity(chunk_size.min(point_count));
+
+    for start in (0..point_count).step_by(chunk_size) {
+        let end = (start + chunk_size).min(point_count);
+        chunk.clear();
+        chunk.extend((start..end).map(|i| {
+            let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+            index.locate(x, y).to_u32(boundary_is_inside)
+        }));
+        on_chunk(start, &chunk)?;
+    }
+    Ok(())
+}
+
+// 从start开始逐批判断，直到判断完max_points个点、全部判断完或用时超过budget_ms
+pub(crate) fn classify_within(
+    index: &ScanlineIndex,
+    points: &[f32],
+    boundary_is_inside: bool,
+    start: usize,
+    max_points: usize,
+    budget_ms: f64,
+) -> BudgetedChunk {
+    let point_count = points.len() / 2;
+    let start = start.min(point_count);
+    let end = start.saturating_add(max_points).min(point_count);
+    let started = now_ms();
+    let mut results = Vec::new();
+    let mut next = start;
+    while next < end {
+        let batch_end = (next + CHECK_INTERVAL).min(end);
+        results.extend((next..batch_end).map(|i| {
+            let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+            index.locate(x, y).to_u32(boundary_is_inside)
+        }));
+        next = batch_end;
+        // NaN预算视为0
+        if budget_ms.is_nan() || now_ms() - started >= budget_ms {
+            break;
+        }
+    }
+    BudgetedChunk { results, offset: start, next: (next < point_count).then_some(next) }
+}
+
+// 当前时间（毫秒），只用于计算经过的时间
+fn now_ms() -> f64 {
+    #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(all(not(target_arch = "wasm32"), feature = "std"))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now().duration_since(UNIX_EPOCH).map_or(0.0, |d| d.as_secs_f64() * 1000.0)
+    }
+    // no_std构建和不带wasm特性的wasm构建没有时钟，时间预算不会耗尽，每次调用判断到max_points为止（预算不为正数时仍只判断一批）
+    #[cfg(not(any(all(target_arch = "wasm32", feature = "wasm"), all(not(target_arch = "wasm32"), feature = "std"))))]
+    {
+        0.0
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::{star_polygon, uniform_points};
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+    use crate::points_in_polygon::streaming::{classify_in_chunks, point_in_polygon_budgeted, PipSession, CHECK_INTERVAL};
+
+    #[test]
+    fn test_chunks_reassemble_full_result() {
+        let polygon = star_polygon(0.0, 0.0, 10.0, 4.0, 7);
+        let rings = vec![(polygon.len() / 2) as u32];
+        let points = uniform_points(1000, -11.0, -11.0, 11.0, 11.0, 9);
+
+        // 块大小不整除点数时最后一块较短
+        let mut offsets = Vec::new();
+        let mut merged = Vec::new();
+        classify_in_chunks::<(), _>(&points, &polygon, &rings, true, 300, |offset, chunk| {
+            offsets.push(offset);
+            merged.extend_from_slice(chunk);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(offsets, vec![0, 300, 600, 900]);
+        assert_eq!(merged, point_in_polygon_scanline(&points, &polygon, &rings, true));
+
+        // 回调返回错误时停止
+        let mut calls = 0;
+        let result = classify_in_chunks(&points, &polygon, &rings, true, 300, |_, _| {
+            calls += 1;
+            if calls == 2 { Err("stop") } else { Ok(()) }
+        });
+        assert_eq!(result, Err("stop"));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_pull_session() {
+        let polygon = star_polygon(0.0, 0.0, 10.0, 4.0, 7);
+        let rings = vec![(polygon.len() / 2) as u32];
+        let points = uniform_points(1000, -11.0, -11.0, 11.0, 11.0, 9);
+        let mut session = PipSession::new(&points, &polygon, &rings, true);
+
+        // 每次拉取的块大小可以不同
+        let mut merged = Vec::new();
+        for max_points in [100, 450, 300, 1000] {
+            assert_eq!(session.offset(), merged.len());
+            merged.extend(session.next_chunk(max_points).unwrap());
+        }
+        assert!(session.is_done());
+        assert_eq!(session.remaining(), 0);
+        assert!(session.next_chunk(10).is_none());
+        assert_eq!(merged, point_in_polygon_scanline(&points, &polygon, &rings, true));
+
+        // 块大小为usize::MAX时一次取完剩余的点
+        let mut session = PipSession::new(&points, &polygon, &rings, true);
+        session.next_chunk(1).unwrap();
+        assert_eq!(session.next_chunk(usize::MAX).unwrap().len(), 999);
+        assert!(session.is_done());
+    }
+
+    #[test]
+    fn test_budgeted_resumes_from_token() {
+        let polygon = star_polygon(0.0, 0.0, 10.0, 4.0, 7);
+        let rings = vec![(polygon.len() / 2) as u32];
+        let points = uniform_points(3000, -11.0, -11.0, 11.0, 11.0, 9);
+        let expected = point_in_polygon_scanline(&points, &polygon, &rings, true);
+
+        // 预算为0时每次固定判断一批
+        let mut merged = Vec::new();
+        let mut token = 0;
+        loop {
+            let chunk = point_in_polygon_budgeted(&points, &polygon, &rings, true, 0.0, token);
+            assert_eq!(chunk.offset(), merged.len());
+            assert!(chunk.results().len() <= CHECK_INTERVAL);
+            merged.extend(chunk.results());
+            match chunk.token() {
+                Some(next) => token = next,
+                None => break,
+            }
+        }
+        assert_eq!(merged, expected);
+
+        // 预算充足时一次判断完
+        let chunk = point_in_polygon_budgeted(&points, &polygon, &rings, true, f64::INFINITY, 0);
+        assert!(chunk.is_done());
+        assert_eq!(chunk.results(), expected);
+        assert!(point_in_polygon_budgeted(&points, &polygon, &rings, true, 1.0, 5000).results().is_empty());
+
+        // 会话上的max_points先于时间预算生效
+        let mut session = PipSession::new(&points, &polygon, &rings, true);
+        let first = session.next_within(f64::INFINITY, 100);
+        assert_eq!((first.results().len(), first.token()), (100, Some(100)));
+        let rest = session.next_within(f64::INFINITY, 0);
+        assert!(rest.is_done() && session.is_done());
+        assert_eq!([first.results(), rest.results()].concat(), expected);
+    }
+}
@@ -0,0 +1,194 @@
+// 梯形分解点定位：对巨大的静态多边形反复查询时，预先把平面按顶点的x坐标切成竖直的条带，
+// 每个条带内跨过它的边互不相交、可按高度排序，相邻两条边之间是一个梯形，梯形内的点位置都相同，构建时逐个算好；
+// 查询时二分找到条带、再二分找到梯形，每个点O(log n)，不再求扫描线交点
+
+// 构建: 每个梯形的结果由从条带底部向上逐条跨过边时更新的各环环绕数得到（按填充规则和外环、洞、组成部分的组合方式，与扫描线判断相同）
+// 自交叠的环使某个条带内的边相交时，该条带标记为交叉，落在其中的点交给扫描线判断；
+// 全部条带登记的边数超过MAX_ENTRIES（边很多且大多跨过很多条带）时放弃构建
+// 边界上的点由调用方先行检测（见prepared模块），这里只区分内部和外部
+
+use alloc::{vec, vec::Vec};
+use super::common::{fills, Polygon};
+
+pub mod test;
+
+// 全部条带登记的边数上限
+const MAX_ENTRIES: usize = 1 << 25;
+
+// 竖直条带：edges按高度从低到高排列，inside[k]为第k条边之下、第k-1条边之上的梯形是否在多边形内部
+struct Slab {
+    edges: Vec<usize>,
+    inside: Vec<bool>,
+    crossed: bool,
+}
+
+pub(crate) struct TrapezoidMap {
+    xs: Vec<f64>,      // 条带的分界x坐标，升序且互不相同，第i个条带为[xs[i], xs[i + 1]]
+    slabs: Vec<Slab>,
+    lines: Vec<(f64, f64, f64)>, // 每条边所在直线 y = y0 + (x - x0) * slope，记为(x0, y0, slope)
+}
+
+impl TrapezoidMap {
+    pub(crate) fn new(poly: &Polygon) -> Option<TrapezoidMap> {
+        // 只作为边界的退化环不参与内外判断；竖直边不跨过任何条带
+        let edges: Vec<usize> = poly
+            .rings
+            .iter()
+            .filter(|ring| !ring.boundary_only)
+            .flat_map(|ring| ring.start_idx..ring.start_idx + ring.edge_count)
+            .filter(|&e| {
+                let edge = &poly.edges[e];
+                edge.x1 != edge.x2 && [edge.x1, edge.y1, edge.x2, edge.y2].iter().all(|v| v.is_finite())
+            })
+            .collect();
+
+        let mut xs: Vec<f64> = edges.iter().flat_map(|&e| [poly.edges[e].x1, poly.edges[e].x2]).collect();
+        xs.sort_by(f64::total_cmp);
+        xs.dedup();
+        let slab_count = xs.len().saturating_sub(1);
+
+        // 每条边登记到它跨过的全部条带
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); slab_count];
+        let mut entries = 0;
+        for &e in &edges {
+            let edge = &poly.edges[e];
+            let lo = xs.partition_point(|&x| x < edge.x1.min(edge.x2));
+            let hi = xs.partition_point(|&x| x < edge.x1.max(edge.x2));
+            entries += hi - lo;
+            if entries > MAX_ENTRIES {
+                return None;
+            }
+            for slab in &mut members[lo..hi] {
+                slab.push(e);
+            }
+        }
+
+        let lines = poly
+            .edges
+            .iter()
+            .map(|e| if e.x1 != e.x2 { (e.x1, e.y1, (e.y2 - e.y1) / (e.x2 - e.x1)) } else { (e.x1, e.y1, 0.0) })
+            .collect();
+        let mut map = TrapezoidMap { xs, slabs: Vec::with_capacity(slab_count), lines };
+        let mut sweep = Sweep::new(poly);
+        for (i, mut edges) in members.into_iter().enumerate() {
+            let (left, right) = (map.xs[i], map.xs[i + 1]);
+            let mid = (left + right) / 2.0;
+            edges.sort_by(|&a, &b| map.y_at(a, mid).total_cmp(&map.y_at(b, mid)));
+
+            // 边在条带内不相交时，两端的高度顺序与中线处一致（共享端点处相等）
+            let slack = poly.tolerance;
+            let crossed = edges.windows(2).any(|w| {
+                map.y_at(w[0], left) > map.y_at(w[1], left) + slack || map.y_at(w[0], right) > map.y_at(w[1], right) + slack
+            });
+
+            let mut inside = Vec::with_capacity(edges.len() + 1);
+            inside.push(sweep.inside());
+            for &e in &edges {
+                sweep.cross(poly, e);
+                inside.push(sweep.inside());
+            }
+            sweep.reset();
+            map.slabs.push(Slab { edges, inside, crossed });
+        }
+        Some(map)
+    }
+
+    // 边所在直线在x处的高度
+    #[inline]
+    fn y_at(&self, edge: usize, x: f64) -> f64 {
+        let (x0, y0, slope) = self.lines[edge];
+        y0 + (x - x0) * slope
+    }
+
+    // 不在边界上的点是否在多边形内部；点落在交叉的条带中时返回None
+    pub(crate) fn classify(&self, x: f64, y: f64) -> Option<bool> {
+        let (Some(&first), Some(&last)) = (self.xs.first(), self.xs.last()) else {
+            return Some(false);
+        };
+        if !(x >= first && x <= last) || self.slabs.is_empty() {
+            return Some(false);
+        }
+        let slab = &self.slabs[(self.xs.partition_point(|&b| b <= x) - 1).min(self.slabs.len() - 1)];
+        if slab.crossed {
+            return None;
+        }
+        let k = slab.edges.partition_point(|&e| self.y_at(e, x) < y);
+        Some(slab.inside[k])
+    }
+
+    // 梯形的个数，用于测试
+    #[cfg(test)]
+    pub(crate) fn trapezoid_count(&self) -> usize {
+        self.slabs.iter().map(|slab| slab.inside.len()).sum()
+    }
+}
+
+// 自下而上跨过边时的状态：各环的环绕数，以及由此得到的各组成部分是否在外环内、在几个洞内
+// 单个多边形的外环累计到同一个计数器（与扫描线判断相同），MultiPolygon的每个环单独计数
+struct Sweep {
+    counter_of_edge: Vec<usize>,
+    counters: Vec<(usize, bool, i32)>, // (组成部分, 是否为洞, 环绕数)
+    part_outer: Vec<usize>,            // 每个部分填充的外环计数器个数
+    part_holes: Vec<usize>,            // 每个部分填充的洞个数
+    good_parts: usize,                 // 在外环内且不在洞内的部分个数
+    fill_rule: u32,
+}
+
+impl Sweep {
+    fn new(poly: &Polygon) -> Sweep {
+        let mut counters = Vec::new();
+        let mut counter_of_edge = vec![0; poly.edges.len()];
+        let mut shared_outer = None;
+        for ring in &poly.rings {
+            let counter = if !ring.is_hole && poly.part_count == 1 {
+                *shared_outer.get_or_insert_with(|| {
+                    counters.push((0, false, 0));
+                    counters.len() - 1
+                })
+            } else {
+                counters.push((ring.part, ring.is_hole, 0));
+                counters.len() - 1
+            };
+            counter_of_edge[ring.start_idx..ring.start_idx + ring.edge_count].fill(counter);
+        }
+        let parts = poly.part_count.max(1);
+        Sweep { counter_of_edge, counters, part_outer: vec![0; parts], part_holes: vec![0; parts], good_parts: 0, fill_rule: poly.fill_rule }
+    }
+
+    fn inside(&self) -> bool {
+        self.good_parts > 0
+    }
+
+    // 向上跨过一条边：从左向右的边下方在环外、上方在环内（逆时针环的底边），环绕数加1，反之减1
+    fn cross(&mut self, poly: &Polygon, edge: usize) {
+        let e = &poly.edges[edge];
+        let c = self.counter_of_edge[edge];
+        let (part, is_hole, winding) = self.counters[c];
+        let delta = if e.x2 > e.x1 { 1 } else { -1 };
+        let (before, after) = (fills(self.fill_rule, winding), fills(self.fill_rule, winding + delta));
+        self.counters[c].2 = winding + delta;
+        if before == after {
+            return;
+        }
+
+        let good = |s: &Sweep| s.part_outer[part] > 0 && s.part_holes[part] == 0;
+        let was_good = good(self);
+        let count = if is_hole { &mut self.part_holes[part] } else { &mut self.part_outer[part] };
+        if after { *count += 1 } else { *count -= 1 }
+        match (was_good, good(self)) {
+            (false, true) => self.good_parts += 1,
+            (true, false) => self.good_parts -= 1,
+            _ => {}
+        }
+    }
+
+    // 回到条带底部（所有环之外）
+    fn reset(&mut self) {
+        for counter in &mut self.counters {
+            counter.2 = 0;
+        }
+        self.part_outer.iter_mut().for_each(|c| *c = 0);
+        self.part_holes.iter_mut().for_each(|c| *c = 0);
+        self.good_parts = 0;
+    }
+}
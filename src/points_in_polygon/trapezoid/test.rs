@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::{random_simple_polygon, spiral_polygon, uniform_points};
+    use crate::points_in_polygon::common::build_polygon;
+    use crate::points_in_polygon::options::PipOptions;
+    use crate::points_in_polygon::prepared::PreparedPolygon;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+    use crate::points_in_polygon::trapezoid::TrapezoidMap;
+
+    #[test]
+    fn test_trapezoids_match_scanline() {
+        // 带洞的随机多边形和螺旋（大量跨过很多条带的长边）
+        let mut holed = random_simple_polygon(0.0, 0.0, 100.0, 400, 5);
+        let hole_start = (holed.len() / 2) as u32;
+        holed.extend_from_slice(&[-5.0, -5.0, -5.0, 5.0, 5.0, 5.0, 5.0, -5.0]);
+        let spiral = spiral_polygon(0.0, 0.0, 100.0, 4.0, 64);
+        let cases = [(holed, vec![hole_start]), (spiral.clone(), vec![(spiral.len() / 2) as u32])];
+
+        let mut points = uniform_points(5000, -110.0, -110.0, 110.0, 110.0, 9);
+        points.extend_from_slice(&[5.0, 0.0, 0.0, 0.0, 1e6, 0.0, f32::NAN, 0.0]);
+        for (polygon, rings) in &cases {
+            let prepared = PreparedPolygon::with_trapezoids(polygon, rings, &PipOptions::new());
+            assert!(prepared.uses_trapezoids());
+            for boundary_is_inside in [true, false] {
+                assert_eq!(prepared.query(&points, boundary_is_inside), point_in_polygon_scanline(&points, polygon, rings, boundary_is_inside));
+            }
+        }
+    }
+
+    #[test]
+    fn test_crossed_slabs_and_parts() {
+        // 自交叠的五角星：交叉的条带交给扫描线，非零规则下中心在内部
+        let star = vec![0.0, 3.0, 1.76, -2.43, -2.85, 0.93, 2.85, 0.93, -1.76, -2.43];
+        let points = vec![0.0, 0.0, 0.0, 2.0, 2.0, -2.0, 2.4, 0.5];
+        let mut options = PipOptions::new();
+        options.set_fill_rule(1);
+        let prepared = PreparedPolygon::with_trapezoids(&star, &[5], &options);
+        assert_eq!(prepared.query(&points, true), PreparedPolygon::with_options(&star, &[5], &options).query(&points, true));
+        assert_eq!(prepared.query(&points, true)[0], 1);
+
+        // 按绕向区分的两个组成部分，其中一个位于另一个的洞中
+        let polygon = vec![
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 外环
+            2.0, 2.0, 2.0, 8.0, 8.0, 8.0, 8.0, 2.0, // 洞（顺时针）
+            4.0, 4.0, 6.0, 4.0, 6.0, 6.0, 4.0, 6.0, // 洞中的岛
+        ];
+        let mut options = PipOptions::new();
+        options.set_hole_detection(1);
+        let prepared = PreparedPolygon::with_trapezoids(&polygon, &[4, 8], &options);
+        assert_eq!(prepared.query(&[1.0, 1.0, 3.0, 3.0, 5.0, 5.0, 11.0, 5.0], true), vec![1, 0, 1, 0]);
+
+        // 每个条带有边数加1个梯形
+        let square = build_polygon(&[0.0f32, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0], &[4]);
+        assert_eq!(TrapezoidMap::new(&square).unwrap().trapezoid_count(), 3);
+    }
+}
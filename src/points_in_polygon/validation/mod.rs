@@ -0,0 +1,108 @@
+// 校验模块：在判断之前检查多边形输入，报告各算法会如何处理其中的异常环
+
+// 输入(js端):
+//     1. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+//     2. 判断选项 PipOptions（决定退化环被丢弃还是只作为边界）
+// 输出(js端):
+//     1. ValidationReport
+//        is_valid() 没有任何警告时为true
+//        warnings() 警告文本列表，例如 "ring 2: all vertices are collinear, dropped"
+//        degenerate_rings() 退化环的索引 类型Uint32Array（环索引与构建多边形时一致，0为外环）
+//        tolerance() 实际使用的距离容差
+//        closed_rings() 末尾重复了第一个顶点的环的索引，该重复顶点已去掉，不影响判断，因此不计为警告
+//        notes() 上述不影响判断的处理的说明文本
+// 说明: 报告与point_in_polygon_with_options使用同一个多边形构建过程，报告的处理方式即实际的判断行为
+
+use alloc::{format, string::String, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use super::common::build_polygon_with;
+use super::options::PipOptions;
+use super::index_u32;
+
+pub mod test;
+
+// 校验报告
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct ValidationReport {
+    warnings: Vec<String>,
+    degenerate_rings: Vec<u32>,
+    closed_rings: Vec<u32>,
+    notes: Vec<String>,
+    tolerance: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.clone()
+    }
+
+    pub fn degenerate_rings(&self) -> Vec<u32> {
+        self.degenerate_rings.clone()
+    }
+
+    pub fn closed_rings(&self) -> Vec<u32> {
+        self.closed_rings.clone()
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        self.notes.clone()
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+}
+
+// WebAssembly导出函数：校验多边形
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn validate_polygon(
+    polygon: &[f32],      // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],        // 多边形环的分割索引
+    options: &PipOptions, // 判断选项
+) -> Result<ValidationReport, ExportError> {
+    validate_polygon_values(polygon, rings, options).map_err(export_error)
+}
+
+// 校验的实现，环索引超出u32范围时返回错误信息
+pub(crate) fn validate_polygon_values(polygon: &[f32], rings: &[u32], options: &PipOptions) -> Result<ValidationReport, String> {
+    let poly = build_polygon_with(polygon, rings, options);
+    let mut report = ValidationReport {
+        warnings: Vec::new(),
+        degenerate_rings: Vec::new(),
+        closed_rings: Vec::new(),
+        notes: Vec::new(),
+        tolerance: poly.tolerance,
+    };
+
+    // 超出顶点数或小于前一个拆分的拆分索引：对应的环为空
+    let vertex_count = polygon.len() / 2;
+    let mut prev = 0;
+    for (i, &split) in rings.iter().enumerate() {
+        if split as usize > vertex_count {
+            report.warnings.push(format!("ring {}: split index {} is beyond the {} vertices, clamped", i, split, vertex_count));
+        } else if split < prev {
+            report.warnings.push(format!("ring {}: split index {} is smaller than the previous split {}, ring is empty", i, split, prev));
+        }
+        prev = split;
+    }
+
+    for (i, ring) in poly.rings.iter().enumerate() {
+        if ring.closing_duplicate {
+            report.notes.push(format!("ring {}: duplicate closing vertex dropped", i));
+            report.closed_rings.push(index_u32(i)?);
+        }
+        if let Some(degeneracy) = ring.degeneracy {
+            let action = if ring.boundary_only { "kept as boundary only" } else { "dropped" };
+            report.warnings.push(format!("ring {}: {}, {}", i, degeneracy.describe(), action));
+            report.degenerate_rings.push(index_u32(i)?);
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::options::PipOptions;
+    use crate::points_in_polygon::validation::validate_polygon_values;
+
+    #[test]
+    fn test_reports_degenerate_rings() {
+        let polygon = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // 外环
+            1.0, 1.0, 2.0, 2.0, 3.0, 3.0, // 共线的洞
+            1.0, 3.0, 1.0, 3.0, 2.0, 3.0, // 只有2个不同顶点的洞
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 2.0, 1.0, // 沿原路折返的洞
+        ];
+        let rings = vec![4, 7, 10];
+
+        let report = validate_polygon_values(&polygon, &rings, &PipOptions::new()).unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(report.degenerate_rings(), vec![1, 2, 3]);
+        assert_eq!(
+            report.warnings(),
+            vec![
+                "ring 1: all vertices are collinear, dropped",
+                "ring 2: fewer than 3 distinct vertices, dropped",
+                "ring 3: zero area (every edge is retraced), dropped",
+            ]
+        );
+
+        let mut options = PipOptions::new();
+        options.set_degenerate_rings(1);
+        assert_eq!(validate_polygon_values(&polygon, &rings, &options).unwrap().warnings()[0], "ring 1: all vertices are collinear, kept as boundary only");
+
+        // 正常的多边形
+        assert!(validate_polygon_values(&polygon[..8], &[4], &PipOptions::new()).unwrap().is_valid());
+    }
+
+    #[test]
+    fn test_reports_effective_tolerance() {
+        // 自动容差按边界框对角线缩放
+        let polygon = vec![0.0, 0.0, 300.0, 0.0, 300.0, 400.0, 0.0, 400.0];
+        let report = validate_polygon_values(&polygon, &[4], &PipOptions::new()).unwrap();
+        assert!((report.tolerance() - 500.0 * 1e-9).abs() < 1e-18);
+
+        let mut options = PipOptions::new();
+        options.set_tolerance(0.5);
+        assert_eq!(validate_polygon_values(&polygon, &[4], &options).unwrap().tolerance(), 0.5);
+    }
+
+    #[test]
+    fn test_reports_duplicate_closing_vertex() {
+        let polygon = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 0.0, 0.0, // 显式闭合的外环
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, // 未闭合的洞
+        ];
+        let report = validate_polygon_values(&polygon, &[5], &PipOptions::new()).unwrap();
+        assert!(report.is_valid());
+        assert_eq!(report.closed_rings(), vec![0]);
+        assert_eq!(report.notes(), vec!["ring 0: duplicate closing vertex dropped"]);
+    }
+
+    #[test]
+    fn test_reports_out_of_range_splits() {
+        let polygon = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 1.0, 1.0, 2.0, 1.0, 2.0, 2.0];
+        let report = validate_polygon_values(&polygon, &[4, 2, u32::MAX], &PipOptions::new()).unwrap();
+        assert_eq!(
+            report.warnings(),
+            vec![
+                "ring 1: split index 2 is smaller than the previous split 4, ring is empty".to_string(),
+                "ring 2: split index 4294967295 is beyond the 7 vertices, clamped".to_string(),
+                "ring 1: fewer than 3 distinct vertices, dropped".to_string(),
+            ]
+        );
+        assert!(validate_polygon_values(&polygon, &[4, 7], &PipOptions::new()).unwrap().is_valid());
+    }
+}
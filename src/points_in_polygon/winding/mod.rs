@@ -0,0 +1,107 @@
+// 环绕数算法模块：按Sunday的环绕数算法判断点是否在多边形内部，作为射线法、扫描线之外的第三个实现
+// 每条边只用方向判定 is_left 的符号决定是否计数，不求交点的x坐标，顶点附近没有插值误差，
+// 可作为另外两个实现的正确性参照
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array 含义与point_in_polygon_rayster相同
+//     3. 边界上点是否考虑为内部 boolean
+//     4. point_in_polygon_winding_with_options(points, polygon, rings, options) 按PipOptions判断
+// 输出(js端):
+//     1. 点云是否在多边形内部 类型Uint32Array 1表示在多边形内部,0表示在多边形外部
+// 说明: 向上穿过点右侧的边计+1，向下穿过的边计-1（穿越采用与其他算法相同的半开区间规则，见common模块），
+//     每个环的环绕数按填充规则（默认奇偶规则）判断点是否在环内，再按外环和洞组合，结果与其他实现一致
+
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use super::common::{build_edge_index, build_polygon_with, crosses_scanline_with, fills, is_point_on_edge, point_in_bounds, Edge, Polygon};
+use super::options::PipOptions;
+
+pub mod test;
+
+// WebAssembly导出函数：环绕数算法判断点是否在多边形内部
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_winding(
+    points: &[f32],           // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],          // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],            // 多边形环的分割索引
+    boundary_is_inside: bool, // 边界上的点是否视为在多边形内部
+) -> Vec<u32> {
+    winding_classify(points, polygon, rings, &PipOptions::with_boundary(boundary_is_inside))
+}
+
+// WebAssembly导出函数：按选项（填充规则、顶点策略等，见options模块）用环绕数算法判断
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn point_in_polygon_winding_with_options(
+    points: &[f32],       // 输入点集，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],      // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],        // 多边形环的分割索引
+    options: &PipOptions, // 判断选项
+) -> Vec<u32> {
+    winding_classify(points, polygon, rings, options)
+}
+
+// 环绕数算法的主流程
+fn winding_classify(points: &[f32], polygon: &[f32], rings: &[u32], options: &PipOptions) -> Vec<u32> {
+    let boundary_is_inside = options.boundary_is_inside;
+    let point_count = points.len() / 2;
+    if point_count == 0 || polygon.is_empty() || rings.is_empty() {
+        return vec![0; point_count];
+    }
+
+    let poly = build_polygon_with(polygon, rings, options);
+    let index = build_edge_index(&poly);
+    let mut winding = vec![0; poly.rings.len()];
+
+    (0..point_count)
+        .map(|i| {
+            let (x, y) = options.axes(points[i * 2] as f64, points[i * 2 + 1] as f64);
+            if is_point_on_edge(&poly, &index, x, y) {
+                boundary_is_inside as u32
+            } else {
+                point_in_rings(&poly, &mut winding, x, y) as u32
+            }
+        })
+        .collect()
+}
+
+// 求点对每个环的环绕数，点在某个部分的外环内且不在该部分的任何洞内即为内部
+fn point_in_rings(poly: &Polygon, winding: &mut [i32], x: f64, y: f64) -> bool {
+    let mut part_state = vec![(false, false); poly.part_count]; // (在外环内, 在某个洞内)
+    for (ring_idx, ring) in poly.rings.iter().enumerate() {
+        winding[ring_idx] = 0;
+        if ring.boundary_only || !point_in_bounds(x, y, &ring.bounds) {
+            continue; // 边界框外的点对该环的环绕数为0
+        }
+        winding[ring_idx] = poly.edges[ring.start_idx..ring.start_idx + ring.edge_count]
+            .iter()
+            .map(|edge| edge_winding(edge, x, y, poly.vertex_policy))
+            .sum();
+        if fills(poly.fill_rule, winding[ring_idx]) {
+            let state = &mut part_state[ring.part];
+            if ring.is_hole { state.1 = true } else { state.0 = true }
+        }
+    }
+    part_state.iter().any(|&(in_outer, in_hole)| in_outer && !in_hole)
+}
+
+// 单条边对环绕数的贡献：向上的边经过点的左侧为+1，向下的边经过点的右侧为-1（即边穿过点右侧的水平射线）
+#[inline]
+fn edge_winding(edge: &Edge, x: f64, y: f64, vertex_policy: u32) -> i32 {
+    if !crosses_scanline_with(edge.y1, edge.y2, y, vertex_policy) {
+        return 0;
+    }
+    let side = is_left(edge, x, y);
+    if edge.y2 > edge.y1 {
+        (side > 0.0) as i32
+    } else {
+        -((side < 0.0) as i32)
+    }
+}
+
+// 点在边所在直线的左侧为正，右侧为负，在直线上为0
+#[inline]
+fn is_left(edge: &Edge, x: f64, y: f64) -> f64 {
+    (edge.x2 - edge.x1) * (y - edge.y1) - (x - edge.x1) * (edge.y2 - edge.y1)
+}
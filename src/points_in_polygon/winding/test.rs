@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::{random_simple_polygon, uniform_points};
+    use crate::points_in_polygon::rayster::point_in_polygon_rayster;
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline;
+    use crate::points_in_polygon::winding::point_in_polygon_winding;
+
+    #[test]
+    fn test_square_with_hole() {
+        let polygon = vec![
+            0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, // 外环
+            1.0, 1.0, 1.0, 3.0, 3.0, 3.0, 3.0, 1.0, // 洞（顺时针，不影响结果）
+        ];
+        let points = vec![
+            0.5, 0.5, // 内部
+            2.0, 2.0, // 洞内
+            4.0, 2.0, // 外环右边上
+            1.0, 2.0, // 洞的左边上
+            5.0, 2.0, // 外部
+        ];
+        assert_eq!(point_in_polygon_winding(&points, &polygon, &[4], true), vec![1, 0, 1, 1, 0]);
+        assert_eq!(point_in_polygon_winding(&points, &polygon, &[4], false), vec![1, 0, 0, 0, 0]);
+        assert_eq!(point_in_polygon_winding(&[], &polygon, &[4], true), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_agrees_with_other_backends() {
+        // 随机多边形挖去一个随机的洞，点在覆盖多边形的范围内均匀分布
+        for seed in 1..6 {
+            let mut polygon = random_simple_polygon(0.0, 0.0, 100.0, 200, seed);
+            polygon.extend(random_simple_polygon(0.0, 0.0, 15.0, 50, seed + 100));
+            let rings = [200];
+            let points = uniform_points(5000, -110.0, -110.0, 110.0, 110.0, seed);
+
+            let expected = point_in_polygon_scanline(&points, &polygon, &rings, true);
+            assert_eq!(point_in_polygon_winding(&points, &polygon, &rings, true), expected, "seed {}", seed);
+            assert_eq!(point_in_polygon_rayster(&points, &polygon, &rings, true), expected, "seed {}", seed);
+        }
+    }
+}
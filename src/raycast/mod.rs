@@ -0,0 +1,149 @@
+// 射线查询模块：从起点沿给定方向发出射线，求与多边形边界（所有环）的第一个交点，用于游戏中的视线、可见性判断
+// 射线按网格遍历（line_to_grid_cells）逐单元前进，只检查经过的单元中登记的边，找到交点即停止
+
+// 输入(js端):
+//     1. 起点 类型Float64Array [x, y]
+//     2. 方向 类型Float64Array [dx, dy] 不需要是单位向量
+//     3. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+//     4. 最大距离 max_dist 超过该距离的交点不报告
+// 输出(js端):
+//     1. 类型Float64Array [距离, x, y, 边索引]，没有交点时为空数组
+//        边索引k表示输入中第k个顶点与同一环中下一个顶点之间的边（全局顶点索引）
+// 说明: 起点在边界上时距离为0；射线沿边行进时交点为射线到达该边的第一个点。起点或方向不合法时抛出带原因的错误
+
+use alloc::{string::String, string::ToString, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::common::{build_grid, build_polygon, line_to_grid_cells, Edge};
+
+pub mod test;
+
+// 射线命中的边界
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct RayHit {
+    pub(crate) distance: f64,
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) edge: usize, // 边起点的全局顶点索引
+}
+
+// WebAssembly导出函数：射线与多边形边界的第一个交点
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn raycast(
+    origin: &[f64],    // 起点 [x, y]
+    direction: &[f64], // 方向 [dx, dy]
+    polygon: &[f32],   // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],     // 多边形环的分割索引
+    max_dist: f64,     // 最大距离
+) -> Result<Vec<f64>, ExportError> {
+    let hit = raycast_hit(origin, direction, polygon, rings, max_dist).map_err(export_error)?;
+    Ok(hit.map_or_else(Vec::new, |hit| vec![hit.distance, hit.x, hit.y, hit.edge as f64]))
+}
+
+// 射线查询的实现
+pub(crate) fn raycast_hit(
+    origin: &[f64],
+    direction: &[f64],
+    polygon: &[f32],
+    rings: &[u32],
+    max_dist: f64,
+) -> Result<Option<RayHit>, String> {
+    let (&[ox, oy], &[dx, dy]) = (origin, direction) else {
+        return Err("raycast: origin and direction must have 2 values each".to_string());
+    };
+    let len = (dx * dx + dy * dy).sqrt();
+    if !(len > 0.0 && len.is_finite() && ox.is_finite() && oy.is_finite()) {
+        return Err("raycast: origin must be finite and direction must be a non-zero finite vector".to_string());
+    }
+    let (dx, dy) = (dx / len, dy / len);
+
+    let poly = build_polygon(polygon, rings);
+    if poly.edges.is_empty() || max_dist.is_nan() || max_dist < 0.0 {
+        return Ok(None);
+    }
+
+    // 把射线裁剪到按容差加宽的多边形边界框内（slab法），之后的距离参数t都是到起点的距离
+    let tol = poly.tolerance;
+    let (mut t0, mut t1) = (0.0f64, max_dist);
+    for (o, d, min, max) in [
+        (ox, dx, poly.bounds.min_x - tol, poly.bounds.max_x + tol),
+        (oy, dy, poly.bounds.min_y - tol, poly.bounds.max_y + tol),
+    ] {
+        if d == 0.0 {
+            if o < min || o > max {
+                return Ok(None);
+            }
+            continue;
+        }
+        let (ta, tb) = ((min - o) / d, (max - o) / d);
+        t0 = t0.max(ta.min(tb));
+        t1 = t1.min(ta.max(tb));
+    }
+    if t0 > t1 {
+        return Ok(None);
+    }
+
+    // 逐单元前进，当前最近的交点不晚于离开当前单元时即为第一个交点
+    let grid = build_grid(&poly);
+    let span = t1 - t0;
+    let mut best: Option<(f64, usize)> = None;
+    let cells = line_to_grid_cells(&poly, ox + t0 * dx, oy + t0 * dy, ox + t1 * dx, oy + t1 * dy);
+    for (gx, gy, exit) in cells {
+        for &edge_idx in &grid[gx][gy].edge_indices {
+            if let Some(t) = ray_edge_distance(ox, oy, dx, dy, &poly.edges[edge_idx], tol) {
+                if t <= max_dist && best.is_none_or(|(best_t, _)| t < best_t) {
+                    best = Some((t, edge_idx));
+                }
+            }
+        }
+        if let Some((t, _)) = best {
+            if t <= t0 + exit * span + tol {
+                break;
+            }
+        }
+    }
+
+    Ok(best.map(|(t, edge_idx)| RayHit {
+        distance: t,
+        x: ox + t * dx,
+        y: oy + t * dy,
+        edge: poly.edges[edge_idx].vertex,
+    }))
+}
+
+// 单位方向的射线到边的距离，不相交时返回None；射线与边共线时取射线到达该边的第一个点
+fn ray_edge_distance(ox: f64, oy: f64, dx: f64, dy: f64, edge: &Edge, tol: f64) -> Option<f64> {
+    let (ex, ey) = (edge.x2 - edge.x1, edge.y2 - edge.y1);
+    let (ax, ay) = (edge.x1 - ox, edge.y1 - oy);
+    let denom = dx * ey - dy * ex;
+    let len = (ex * ex + ey * ey).sqrt();
+
+    if denom.abs() <= f64::EPSILON * len {
+        // 平行：只有共线（起点到边所在直线的距离不超过容差）时相交
+        if (ax * dy - ay * dx).abs() > tol {
+            return None;
+        }
+        let ta = ax * dx + ay * dy;
+        let tb = (edge.x2 - ox) * dx + (edge.y2 - oy) * dy;
+        return if ta.min(tb) <= 0.0 && ta.max(tb) >= 0.0 {
+            Some(0.0) // 起点在边上
+        } else if ta.min(tb) > 0.0 {
+            Some(ta.min(tb))
+        } else {
+            None
+        };
+    }
+
+    // 起点 + t·方向 = 边起点 + s·边向量
+    let t = (ax * ey - ay * ex) / denom;
+    let s = (ax * dy - ay * dx) / denom;
+    let s_tol = tol / len;
+    if t >= -tol && (-s_tol..=1.0 + s_tol).contains(&s) {
+        Some(t.max(0.0))
+    } else {
+        None
+    }
+}
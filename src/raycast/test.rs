@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use crate::raycast::raycast_hit;
+
+    // [0,10]×[0,10] 的正方形，带 [4,6]×[4,6] 的洞
+    const POLYGON: [f32; 16] = [
+        0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 外环
+        4.0, 4.0, 6.0, 4.0, 6.0, 6.0, 4.0, 6.0, // 洞
+    ];
+
+    #[test]
+    fn test_first_hit_on_hole_and_outer_ring() {
+        // 向右的射线先碰到洞的左边（顶点7到顶点4的边）
+        let hit = raycast_hit(&[1.0, 5.0], &[2.0, 0.0], &POLYGON, &[4], 100.0).unwrap().unwrap();
+        assert_eq!((hit.distance, hit.x, hit.y, hit.edge), (3.0, 4.0, 5.0, 7));
+
+        // 向下的射线碰到外环底边（顶点0到顶点1的边）
+        let hit = raycast_hit(&[1.0, 5.0], &[0.0, -1.0], &POLYGON, &[4], 100.0).unwrap().unwrap();
+        assert_eq!((hit.distance, hit.x, hit.y, hit.edge), (5.0, 1.0, 0.0, 0));
+
+        // 斜向射线碰到外环右边（顶点1到顶点2的边）
+        let hit = raycast_hit(&[8.0, 1.0], &[1.0, 1.0], &POLYGON, &[4], 100.0).unwrap().unwrap();
+        assert!((hit.distance - 2.0 * 2f64.sqrt()).abs() < 1e-12);
+        assert_eq!((hit.x, hit.y, hit.edge), (10.0, 3.0, 1));
+    }
+
+    #[test]
+    fn test_max_distance_and_outside_origin() {
+        assert_eq!(raycast_hit(&[1.0, 5.0], &[1.0, 0.0], &POLYGON, &[4], 2.5).unwrap(), None);
+        // 起点在多边形外，射线背离多边形
+        assert_eq!(raycast_hit(&[-5.0, 5.0], &[-1.0, 0.0], &POLYGON, &[4], 100.0).unwrap(), None);
+        // 起点在多边形外，射线朝向多边形
+        let hit = raycast_hit(&[-5.0, 5.0], &[1.0, 0.0], &POLYGON, &[4], 100.0).unwrap().unwrap();
+        assert_eq!((hit.distance, hit.x, hit.edge), (5.0, 0.0, 3));
+    }
+
+    #[test]
+    fn test_origin_on_boundary_and_collinear_ray() {
+        // 起点在边上，距离为0
+        let hit = raycast_hit(&[5.0, 0.0], &[0.0, 1.0], &POLYGON, &[4], 100.0).unwrap().unwrap();
+        assert_eq!((hit.distance, hit.edge), (0.0, 0));
+
+        // 沿洞的底边所在直线从左侧射入，交点为到达洞的左下顶点处
+        let hit = raycast_hit(&[2.0, 4.0], &[1.0, 0.0], &POLYGON, &[4], 100.0).unwrap().unwrap();
+        assert_eq!((hit.distance, hit.x, hit.y), (2.0, 4.0, 4.0));
+    }
+
+    #[test]
+    fn test_matches_brute_force_on_random_polygon() {
+        let polygon = crate::generators::random_simple_polygon(0.0, 0.0, 100.0, 200, 11);
+        let rings = [200];
+        for k in 0..64 {
+            let angle = k as f64 * std::f64::consts::TAU / 64.0;
+            let direction = [angle.cos(), angle.sin()];
+            let hit = raycast_hit(&[0.0, 0.0], &direction, &polygon, &rings, 1000.0).unwrap().unwrap();
+
+            // 逐条边求交的最近距离
+            let nearest = (0..200)
+                .filter_map(|i| {
+                    let j = (i + 1) % 200;
+                    let (ax, ay) = (polygon[i * 2] as f64, polygon[i * 2 + 1] as f64);
+                    let (bx, by) = (polygon[j * 2] as f64, polygon[j * 2 + 1] as f64);
+                    let (ex, ey) = (bx - ax, by - ay);
+                    let denom = direction[0] * ey - direction[1] * ex;
+                    let t = (ax * ey - ay * ex) / denom;
+                    let s = (ax * direction[1] - ay * direction[0]) / denom;
+                    (t >= 0.0 && (0.0..=1.0).contains(&s)).then_some(t)
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert!((hit.distance - nearest).abs() < 1e-9, "{} {}", hit.distance, nearest);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(raycast_hit(&[0.0], &[1.0, 0.0], &POLYGON, &[4], 1.0).is_err());
+        assert!(raycast_hit(&[0.0, 0.0], &[0.0, 0.0], &POLYGON, &[4], 1.0).is_err());
+    }
+}
@@ -0,0 +1,685 @@
+// 区域模块：以解析方式定义的区域图元，以及由图元组合而成的CSG区域树
+// 与用多段线近似的多边形不同，解析图元按精确的几何方程逐点判断，既精确又快速
+// Region把多边形、圆、矩形等图元用并集/交集/差集组合成一棵树，逐点惰性求值并用边界框剪枝，
+// 复杂的选区逻辑因此可以预先构建为一个对象反复使用
+
+// 输入(js端):
+//     1. 图元参数 例如圆的圆心和半径 new Circle(cx, cy, r)
+//        圆环 new Annulus(cx, cy, inner_r, outer_r)
+//        扇形 new Sector(cx, cy, r, start_angle, end_angle) 角度为弧度, 从x轴正方向逆时针由start扫到end
+//     2. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     3. 边界上点是否考虑为内部 boolean
+//     4. 区域树 Region.circle(...).union(Region.polygon(polygon, rings)).difference(Region.rect(...))
+//     5. 选区 const selection = new SelectionRegion()
+//        selection.add_stroke(polygon, rings) 并入一笔（画笔），selection.erase_stroke(polygon, rings) 挖去一笔（橡皮擦）
+//        selection.classify(points, boundary_is_inside)，selection.clear() 清空
+// 输出(js端):
+//     1. 点云是否在区域内部 类型Uint32Array 例子[1, 0, 1, 0, ...] 1表示在区域内部,0表示在区域外部
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::points_in_polygon::Location;
+use crate::points_in_polygon::common::{Bounds, RELATIVE_TOLERANCE};
+use crate::points_in_polygon::scanline::ScanlineIndex;
+use crate::join::RTree;
+use core::cell::OnceCell;
+use core::f64::consts::{FRAC_PI_2, TAU};
+use alloc::rc::Rc;
+use alloc::{boxed::Box, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
+pub mod test;
+
+// 区域图元的公共接口：所有图元都能给出边界框、边界判定的容差、逐点判断位置，并求点到边界的距离
+pub(crate) trait Shape {
+    fn bounds(&self) -> Bounds;
+    fn tolerance(&self) -> f64;
+    fn locate(&self, x: f64, y: f64) -> Location;
+    fn boundary_distance(&self, x: f64, y: f64) -> f64;
+}
+
+// 对点云批量判断，先用边界框过滤
+pub(crate) fn classify_points<S: Shape + ?Sized>(shape: &S, points: &[f32], boundary_is_inside: bool) -> Vec<u32> {
+    let bounds = shape.bounds();
+    let tolerance = shape.tolerance();
+    let point_count = points.len() / 2;
+    let mut results = vec![0; point_count];
+
+    for (i, result) in results.iter_mut().enumerate() {
+        let x = points[i * 2] as f64;
+        let y = points[i * 2 + 1] as f64;
+        if bounds.contains(x, y, tolerance) {
+            *result = shape.locate(x, y).to_u32(boundary_is_inside);
+        }
+    }
+
+    results
+}
+
+// 图元的边界容差：与多边形相同，按边界框对角线长度缩放，同一图形放大或缩小任意倍数判断结果不变
+fn scaled_tolerance<S: Shape>(shape: &S) -> f64 {
+    RELATIVE_TOLERANCE * shape.bounds().diagonal()
+}
+
+// 圆形区域：圆心(cx, cy)，半径r
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy)]
+pub struct Circle {
+    cx: f64,
+    cy: f64,
+    r: f64,
+    tolerance: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Circle {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(cx: f64, cy: f64, r: f64) -> Circle {
+        let mut circle = Circle { cx, cy, r: r.abs(), tolerance: 0.0 };
+        circle.tolerance = scaled_tolerance(&circle);
+        circle
+    }
+
+    // 批量判断点是否在圆内
+    pub fn classify(&self, points: &[f32], boundary_is_inside: bool) -> Vec<u32> {
+        classify_points(self, points, boundary_is_inside)
+    }
+}
+
+impl Shape for Circle {
+    fn bounds(&self) -> Bounds {
+        Bounds {
+            min_x: self.cx - self.r, min_y: self.cy - self.r,
+            max_x: self.cx + self.r, max_y: self.cy + self.r,
+        }
+    }
+
+    fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    fn locate(&self, x: f64, y: f64) -> Location {
+        let dist = ((x - self.cx) * (x - self.cx) + (y - self.cy) * (y - self.cy)).sqrt();
+        if (dist - self.r).abs() <= self.tolerance {
+            Location::Boundary
+        } else if dist < self.r {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        (((x - self.cx) * (x - self.cx) + (y - self.cy) * (y - self.cy)).sqrt() - self.r).abs()
+    }
+}
+
+// 圆环区域：两个同心圆之间的部分
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy)]
+pub struct Annulus {
+    cx: f64,
+    cy: f64,
+    inner_r: f64,
+    outer_r: f64,
+    tolerance: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Annulus {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(cx: f64, cy: f64, inner_r: f64, outer_r: f64) -> Annulus {
+        let (a, b) = (inner_r.abs(), outer_r.abs());
+        let mut annulus = Annulus { cx, cy, inner_r: a.min(b), outer_r: a.max(b), tolerance: 0.0 };
+        annulus.tolerance = scaled_tolerance(&annulus);
+        annulus
+    }
+
+    // 批量判断点是否在圆环内
+    pub fn classify(&self, points: &[f32], boundary_is_inside: bool) -> Vec<u32> {
+        classify_points(self, points, boundary_is_inside)
+    }
+}
+
+impl Shape for Annulus {
+    fn bounds(&self) -> Bounds {
+        Bounds {
+            min_x: self.cx - self.outer_r, min_y: self.cy - self.outer_r,
+            max_x: self.cx + self.outer_r, max_y: self.cy + self.outer_r,
+        }
+    }
+
+    fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    fn locate(&self, x: f64, y: f64) -> Location {
+        let dist = ((x - self.cx) * (x - self.cx) + (y - self.cy) * (y - self.cy)).sqrt();
+        if (dist - self.outer_r).abs() <= self.tolerance || (dist - self.inner_r).abs() <= self.tolerance {
+            Location::Boundary
+        } else if dist > self.inner_r && dist < self.outer_r {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        let dist = ((x - self.cx) * (x - self.cx) + (y - self.cy) * (y - self.cy)).sqrt();
+        (dist - self.outer_r).abs().min((dist - self.inner_r).abs())
+    }
+}
+
+// 扇形区域：圆心(cx, cy)，半径r，从start_angle逆时针扫到end_angle（弧度）
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Copy)]
+pub struct Sector {
+    cx: f64,
+    cy: f64,
+    r: f64,
+    start: f64,  // 起始角，归一化到[0, 2π)
+    sweep: f64,  // 扫过的角度，范围(0, 2π]
+    tolerance: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Sector {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(cx: f64, cy: f64, r: f64, start_angle: f64, end_angle: f64) -> Sector {
+        // 扫角不小于一整圈时视为完整的圆
+        let sweep = if end_angle - start_angle >= TAU {
+            TAU
+        } else {
+            let sweep = (end_angle - start_angle).rem_euclid(TAU);
+            if sweep == 0.0 { TAU } else { sweep }
+        };
+        let mut sector = Sector { cx, cy, r: r.abs(), start: start_angle.rem_euclid(TAU), sweep, tolerance: 0.0 };
+        sector.tolerance = scaled_tolerance(&sector);
+        sector
+    }
+
+    // 批量判断点是否在扇形内
+    pub fn classify(&self, points: &[f32], boundary_is_inside: bool) -> Vec<u32> {
+        classify_points(self, points, boundary_is_inside)
+    }
+}
+
+impl Sector {
+    #[inline]
+    fn is_full_circle(&self) -> bool {
+        self.sweep >= TAU
+    }
+
+    // 角度相对起始角的偏移是否落在扫角范围内
+    #[inline]
+    fn angle_in_sweep(&self, angle: f64) -> bool {
+        (angle - self.start).rem_euclid(TAU) <= self.sweep
+    }
+
+    // 点到从圆心出发、方向角为angle、长度为r的半径线段的距离
+    fn distance_to_radius(&self, x: f64, y: f64, angle: f64) -> f64 {
+        let (dir_x, dir_y) = (angle.cos(), angle.sin());
+        let (px, py) = (x - self.cx, y - self.cy);
+        let t = (px * dir_x + py * dir_y).clamp(0.0, self.r);
+        ((px - t * dir_x) * (px - t * dir_x) + (py - t * dir_y) * (py - t * dir_y)).sqrt()
+    }
+}
+
+impl Shape for Sector {
+    fn bounds(&self) -> Bounds {
+        let mut bounds = Bounds { min_x: self.cx, min_y: self.cy, max_x: self.cx, max_y: self.cy };
+        let mut include = |angle: f64| {
+            let (x, y) = (self.cx + self.r * angle.cos(), self.cy + self.r * angle.sin());
+            bounds.min_x = bounds.min_x.min(x);
+            bounds.min_y = bounds.min_y.min(y);
+            bounds.max_x = bounds.max_x.max(x);
+            bounds.max_y = bounds.max_y.max(y);
+        };
+
+        // 圆弧两端点以及扫角范围内的四个坐标轴方向的极值点
+        include(self.start);
+        include(self.start + self.sweep);
+        for k in 0..4 {
+            let axis = k as f64 * FRAC_PI_2;
+            if self.angle_in_sweep(axis) {
+                include(axis);
+            }
+        }
+
+        bounds
+    }
+
+    fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    fn locate(&self, x: f64, y: f64) -> Location {
+        let dist = ((x - self.cx) * (x - self.cx) + (y - self.cy) * (y - self.cy)).sqrt();
+        if dist > self.r + self.tolerance {
+            return Location::Outside;
+        }
+
+        let angle = (y - self.cy).atan2(x - self.cx);
+        let in_sweep = self.angle_in_sweep(angle);
+
+        // 圆弧上的点
+        if (dist - self.r).abs() <= self.tolerance && in_sweep {
+            return Location::Boundary;
+        }
+
+        // 两条半径边上的点（完整圆没有半径边）
+        if !self.is_full_circle()
+            && (self.distance_to_radius(x, y, self.start) <= self.tolerance
+                || self.distance_to_radius(x, y, self.start + self.sweep) <= self.tolerance)
+        {
+            return Location::Boundary;
+        }
+
+        if in_sweep && dist < self.r {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        let dist = ((x - self.cx) * (x - self.cx) + (y - self.cy) * (y - self.cy)).sqrt();
+        let angle = (y - self.cy).atan2(x - self.cx);
+
+        // 到圆弧的距离：方向在扫角内时为径向距离，否则为到圆弧端点的距离
+        let arc = if self.angle_in_sweep(angle) {
+            (dist - self.r).abs()
+        } else {
+            [self.start, self.start + self.sweep]
+                .iter()
+                .map(|a| {
+                    let (ex, ey) = (self.cx + self.r * a.cos(), self.cy + self.r * a.sin());
+                    ((x - ex) * (x - ex) + (y - ey) * (y - ey)).sqrt()
+                })
+                .fold(f64::INFINITY, f64::min)
+        };
+        if self.is_full_circle() {
+            return arc;
+        }
+        arc.min(self.distance_to_radius(x, y, self.start))
+            .min(self.distance_to_radius(x, y, self.start + self.sweep))
+    }
+}
+
+// 轴对齐矩形区域
+#[derive(Clone, Copy)]
+pub(crate) struct Rect {
+    bounds: Bounds,
+    tolerance: f64,
+}
+
+impl Shape for Rect {
+    fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    fn locate(&self, x: f64, y: f64) -> Location {
+        let (b, t) = (&self.bounds, self.tolerance);
+        if !b.contains(x, y, t) {
+            Location::Outside
+        } else if x > b.min_x + t && x < b.max_x - t && y > b.min_y + t && y < b.max_y - t {
+            Location::Inside
+        } else {
+            Location::Boundary
+        }
+    }
+
+    fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        let b = &self.bounds;
+        let dx = (b.min_x - x).max(x - b.max_x);
+        let dy = (b.min_y - y).max(y - b.max_y);
+        if dx <= 0.0 && dy <= 0.0 {
+            // 内部：到最近一条边的距离
+            -dx.max(dy)
+        } else {
+            (dx.max(0.0) * dx.max(0.0) + dy.max(0.0) * dy.max(0.0)).sqrt()
+        }
+    }
+}
+
+// 多边形区域：复用扫描线算法的预处理索引
+pub(crate) struct PolygonShape {
+    index: ScanlineIndex,
+}
+
+impl Shape for PolygonShape {
+    fn bounds(&self) -> Bounds {
+        let (min_x, min_y, max_x, max_y) = self.index.bounds();
+        Bounds { min_x, min_y, max_x, max_y }
+    }
+
+    fn tolerance(&self) -> f64 {
+        self.index.polygon().tolerance
+    }
+
+    fn locate(&self, x: f64, y: f64) -> Location {
+        self.index.locate(x, y)
+    }
+
+    fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        self.index.boundary_distance(x, y)
+    }
+}
+
+// 区域树节点
+enum Node {
+    Shape(Box<dyn Shape>),
+    Union(Rc<NodeRef>, Rc<NodeRef>),
+    Intersection(Rc<NodeRef>, Rc<NodeRef>),
+    Difference(Rc<NodeRef>, Rc<NodeRef>),
+}
+
+// 带缓存边界框和容差的节点，子树在多个Region之间共享；组合节点的容差取两侧的较大值
+struct NodeRef {
+    bounds: Bounds,
+    tolerance: f64,
+    node: Node,
+}
+
+impl NodeRef {
+    // 惰性求值：先用边界框剪枝，组合节点在结果确定后不再计算另一侧
+    fn locate(&self, x: f64, y: f64) -> Location {
+        if !self.bounds.contains(x, y, self.tolerance) {
+            return Location::Outside;
+        }
+
+        match &self.node {
+            Node::Shape(shape) => shape.locate(x, y),
+            Node::Union(a, b) => match a.locate(x, y) {
+                Location::Inside => Location::Inside,
+                first => match b.locate(x, y) {
+                    Location::Inside => Location::Inside,
+                    Location::Boundary => Location::Boundary,
+                    Location::Outside => first,
+                },
+            },
+            Node::Intersection(a, b) => match a.locate(x, y) {
+                Location::Outside => Location::Outside,
+                first => match b.locate(x, y) {
+                    Location::Outside => Location::Outside,
+                    Location::Inside => first,
+                    Location::Boundary => Location::Boundary,
+                },
+            },
+            Node::Difference(a, b) => match a.locate(x, y) {
+                Location::Outside => Location::Outside,
+                first => match b.locate(x, y) {
+                    Location::Inside => Location::Outside,
+                    Location::Outside => first,
+                    Location::Boundary => Location::Boundary,
+                },
+            },
+        }
+    }
+
+    // 组合区域的边界包含于两侧边界之并，取两侧距离的较小值，得到真实距离的下界
+    fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        match &self.node {
+            Node::Shape(shape) => shape.boundary_distance(x, y),
+            Node::Union(a, b) | Node::Intersection(a, b) | Node::Difference(a, b) => {
+                a.boundary_distance(x, y).min(b.boundary_distance(x, y))
+            }
+        }
+    }
+}
+
+// CSG区域：由图元经并集/交集/差集组合而成
+// 说明：两个图元共享的边在组合后仍按边界处理（例如两个相邻矩形的公共边）
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone)]
+pub struct Region {
+    root: Rc<NodeRef>,
+}
+
+impl Region {
+    fn from_shape<S: Shape + 'static>(shape: S) -> Region {
+        Region { root: Rc::new(NodeRef { bounds: shape.bounds(), tolerance: shape.tolerance(), node: Node::Shape(Box::new(shape)) }) }
+    }
+
+    fn combine(&self, other: &Region, bounds: Bounds, node: Node) -> Region {
+        let tolerance = self.root.tolerance.max(other.root.tolerance);
+        Region { root: Rc::new(NodeRef { bounds, tolerance, node }) }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Region {
+    // 多边形图元，参数与point_in_polygon_scanline相同
+    pub fn polygon(polygon: &[f32], rings: &[u32]) -> Region {
+        Region::from_shape(PolygonShape { index: ScanlineIndex::new(polygon, rings) })
+    }
+
+    // 圆形图元
+    pub fn circle(cx: f64, cy: f64, r: f64) -> Region {
+        Region::from_shape(Circle::new(cx, cy, r))
+    }
+
+    // 轴对齐矩形图元
+    pub fn rect(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Region {
+        let bounds = Bounds {
+            min_x: min_x.min(max_x), min_y: min_y.min(max_y),
+            max_x: min_x.max(max_x), max_y: min_y.max(max_y),
+        };
+        Region::from_shape(Rect { bounds, tolerance: RELATIVE_TOLERANCE * bounds.diagonal() })
+    }
+
+    // 圆环图元
+    pub fn annulus(cx: f64, cy: f64, inner_r: f64, outer_r: f64) -> Region {
+        Region::from_shape(Annulus::new(cx, cy, inner_r, outer_r))
+    }
+
+    // 扇形图元
+    pub fn sector(cx: f64, cy: f64, r: f64, start_angle: f64, end_angle: f64) -> Region {
+        Region::from_shape(Sector::new(cx, cy, r, start_angle, end_angle))
+    }
+
+    // 并集：在任一区域内
+    pub fn union(&self, other: &Region) -> Region {
+        let bounds = self.root.bounds.union(&other.root.bounds);
+        self.combine(other, bounds, Node::Union(self.root.clone(), other.root.clone()))
+    }
+
+    // 交集：同时在两个区域内
+    pub fn intersection(&self, other: &Region) -> Region {
+        let bounds = self.root.bounds.intersection(&other.root.bounds);
+        self.combine(other, bounds, Node::Intersection(self.root.clone(), other.root.clone()))
+    }
+
+    // 差集：在本区域内且不在other内
+    pub fn difference(&self, other: &Region) -> Region {
+        let bounds = self.root.bounds;
+        self.combine(other, bounds, Node::Difference(self.root.clone(), other.root.clone()))
+    }
+
+    // 批量判断点是否在区域内
+    pub fn classify(&self, points: &[f32], boundary_is_inside: bool) -> Vec<u32> {
+        classify_points(self, points, boundary_is_inside)
+    }
+}
+
+impl Shape for Region {
+    fn bounds(&self) -> Bounds {
+        self.root.bounds
+    }
+
+    fn tolerance(&self) -> f64 {
+        self.root.tolerance
+    }
+
+    fn locate(&self, x: f64, y: f64) -> Location {
+        self.root.locate(x, y)
+    }
+
+    fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        self.root.boundary_distance(x, y)
+    }
+}
+
+// 选区的一笔：并入（画笔）或挖去（橡皮擦）的多边形
+#[derive(Clone)]
+struct Stroke {
+    erase: bool,
+    shape: Rc<PolygonShape>,
+}
+
+// 按顺序组合的笔画，保存为平铺的列表而不是逐笔加深的区域树，笔画再多也不会递归过深
+#[derive(Clone)]
+struct Strokes {
+    strokes: Vec<Stroke>,
+    bounds: Bounds,      // 所有画笔笔画的边界框，橡皮擦不扩大选区
+    tolerance: f64,      // 所有笔画容差的较大值
+    tree: OnceCell<Rc<RTree>>, // 按容差扩展后的笔画边界框的R树，首次判断时构建，添加笔画后重建
+}
+
+impl Default for Strokes {
+    fn default() -> Strokes {
+        Strokes { strokes: Vec::new(), bounds: Bounds::empty(), tolerance: 0.0, tree: OnceCell::new() }
+    }
+}
+
+impl Strokes {
+    fn push(&mut self, stroke: Stroke) {
+        if !stroke.erase {
+            self.bounds.extend(&stroke.shape.bounds());
+        }
+        self.tolerance = self.tolerance.max(stroke.shape.tolerance());
+        self.strokes.push(stroke);
+        self.tree = OnceCell::new();
+    }
+
+    // 边界框包含点(x, y)的笔画序号，升序
+    fn candidates(&self, x: f64, y: f64) -> Vec<usize> {
+        let tree = self.tree.get_or_init(|| {
+            let bounds = self
+                .strokes
+                .iter()
+                .map(|stroke| {
+                    let (b, t) = (stroke.shape.bounds(), stroke.shape.tolerance());
+                    Bounds { min_x: b.min_x - t, min_y: b.min_y - t, max_x: b.max_x + t, max_y: b.max_y + t }
+                })
+                .collect();
+            Rc::new(RTree::new(bounds))
+        });
+        let mut out = Vec::new();
+        tree.query(x, y, &mut out);
+        out.sort_unstable();
+        out
+    }
+}
+
+impl Shape for Strokes {
+    fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    // 最后一个以该点为内部的笔画直接决定结果（画笔为内部，橡皮擦为外部），更早的笔画不必计算；
+    // 只有之后还有以该点为边界的笔画时，才按笔画顺序把这些边界依次组合上去
+    fn locate(&self, x: f64, y: f64) -> Location {
+        if !self.bounds.contains(x, y, self.tolerance) {
+            return Location::Outside;
+        }
+
+        // 只有边界框包含该点的笔画可能影响结果，记下其中以该点为边界的笔画
+        let candidates = self.candidates(x, y);
+        let mut result = Location::Outside;
+        let mut boundaries = Vec::new();
+        for &k in candidates.iter().rev() {
+            let stroke = &self.strokes[k];
+            match stroke.shape.locate(x, y) {
+                Location::Inside => {
+                    result = if stroke.erase { Location::Outside } else { Location::Inside };
+                    break;
+                }
+                Location::Boundary => boundaries.push(stroke.erase),
+                Location::Outside => {}
+            }
+        }
+
+        // 与并集/差集节点相同：画笔的边界在已选中的内部不起作用，橡皮擦的边界在未选中的外部不起作用
+        for &erase in boundaries.iter().rev() {
+            result = match (erase, result) {
+                (false, Location::Inside) => Location::Inside,
+                (true, Location::Outside) => Location::Outside,
+                _ => Location::Boundary,
+            };
+        }
+        result
+    }
+
+    // 与组合区域相同，取所有笔画距离的较小值
+    fn boundary_distance(&self, x: f64, y: f64) -> f64 {
+        self.strokes.iter().map(|stroke| stroke.shape.boundary_distance(x, y)).fold(f64::INFINITY, f64::min)
+    }
+}
+
+// 选区：依次并入（画笔）或挖去（橡皮擦）多边形笔画，按笔画顺序逐点求值，
+// 封装了选区界面中常见的并集/差集处理；笔画之后才画的部分不受之前的橡皮擦影响
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone, Default)]
+pub struct SelectionRegion {
+    strokes: Strokes, // 没有任何笔画时所有点都在选区外
+}
+
+impl SelectionRegion {
+    fn push(&mut self, erase: bool, polygon: &[f32], rings: &[u32]) {
+        let shape = PolygonShape { index: ScanlineIndex::new(polygon, rings) };
+        self.strokes.push(Stroke { erase, shape: Rc::new(shape) });
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl SelectionRegion {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new() -> SelectionRegion {
+        SelectionRegion::default()
+    }
+
+    // 并入一笔，参数与point_in_polygon_scanline相同
+    pub fn add_stroke(&mut self, polygon: &[f32], rings: &[u32]) {
+        self.push(false, polygon, rings);
+    }
+
+    // 挖去一笔；选区为空时没有可挖去的部分
+    pub fn erase_stroke(&mut self, polygon: &[f32], rings: &[u32]) {
+        if !self.strokes.strokes.is_empty() {
+            self.push(true, polygon, rings);
+        }
+    }
+
+    // 已记录的笔画数（包括橡皮擦）
+    pub fn stroke_count(&self) -> usize {
+        self.strokes.strokes.len()
+    }
+
+    pub fn clear(&mut self) {
+        *self = SelectionRegion::default();
+    }
+
+    // 当前选区，没有任何笔画时为None；笔画在返回的Region与选区之间共享
+    pub fn region(&self) -> Option<Region> {
+        if self.strokes.strokes.is_empty() {
+            None
+        } else {
+            Some(Region::from_shape(self.strokes.clone()))
+        }
+    }
+
+    // 批量判断点是否在选区内
+    pub fn classify(&self, points: &[f32], boundary_is_inside: bool) -> Vec<u32> {
+        classify_points(&self.strokes, points, boundary_is_inside)
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use crate::region::{Annulus, Circle, Region, Sector, SelectionRegion, Shape};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_circle_exact_classification() {
+        let circle = Circle::new(1.0, -1.0, 2.0);
+        let points = vec![
+            1.0, -1.0, // 圆心
+            2.9, -1.0, // 靠近边界的内部点
+            3.0, -1.0, // 边界
+            1.0, 1.0,  // 边界
+            3.1, -1.0, // 外部
+            2.5, 0.5,  // 边界框内但在圆外
+        ];
+
+        assert_eq!(circle.classify(&points, true), vec![1, 1, 1, 1, 0, 0]);
+        assert_eq!(circle.classify(&points, false), vec![1, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_annulus_classification() {
+        // 内外半径顺序颠倒时自动交换
+        let annulus = Annulus::new(0.0, 0.0, 3.0, 1.0);
+        let points = vec![0.0, 0.0, 0.5, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0, -3.0, 4.0, 0.0];
+
+        assert_eq!(annulus.classify(&points, true), vec![0, 0, 1, 1, 1, 0]);
+        assert_eq!(annulus.classify(&points, false), vec![0, 0, 0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_sector_classification() {
+        // 第二象限到第三象限的半圆：从90°扫到270°
+        let sector = Sector::new(0.0, 0.0, 2.0, PI / 2.0, 3.0 * PI / 2.0);
+        let points = vec![
+            -1.0, 0.5,  // 内部
+            1.0, 0.5,   // 扫角外
+            0.0, 1.0,   // 半径边上
+            -2.0, 0.0,  // 圆弧上
+            0.0, 0.0,   // 圆心
+            -1.9, -1.9, // 半径外
+        ];
+
+        assert_eq!(sector.classify(&points, true), vec![1, 0, 1, 1, 1, 0]);
+        assert_eq!(sector.classify(&points, false), vec![1, 0, 0, 0, 0, 0]);
+
+        // 跨越0°的扇形：从-45°扫到45°
+        let wedge = Sector::new(0.0, 0.0, 1.0, -PI / 4.0, PI / 4.0);
+        assert_eq!(wedge.classify(&[0.5, 0.0, 0.5, 0.4, -0.5, 0.0, 0.5, 0.6], true), vec![1, 1, 0, 0]);
+
+        // 扫角为一整圈时等同于圆
+        let full = Sector::new(0.0, 0.0, 1.0, 0.0, 2.0 * PI);
+        assert_eq!(full.classify(&[0.5, 0.0, 0.0, 0.0, 1.0, 0.0], false), vec![1, 1, 0]);
+    }
+
+    #[test]
+    fn test_region_tree() {
+        // 正方形多边形 ∪ 右侧的圆，再挖掉中间的矩形
+        let square = Region::polygon(&[0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0], &[4]);
+        let region = square
+            .union(&Region::circle(6.0, 2.0, 1.0))
+            .difference(&Region::rect(1.0, 1.0, 2.0, 2.0));
+
+        let points = vec![
+            0.5, 0.5, // 正方形内
+            1.5, 1.5, // 被挖掉的矩形内
+            1.0, 1.5, // 被挖掉的矩形的边上
+            6.0, 2.0, // 圆内
+            5.0, 2.0, // 圆的边上
+            5.0, 0.5, // 两者都不在
+            4.0, 2.0, // 正方形的边上
+        ];
+        assert_eq!(region.classify(&points, true), vec![1, 0, 1, 1, 1, 0, 1]);
+        assert_eq!(region.classify(&points, false), vec![1, 0, 0, 1, 0, 0, 0]);
+
+        // 交集：扇形与圆环的重叠部分
+        let wedge = Region::sector(0.0, 0.0, 3.0, 0.0, PI / 2.0).intersection(&Region::annulus(0.0, 0.0, 1.0, 2.0));
+        assert_eq!(
+            wedge.classify(&[1.0, 1.0, 0.5, 0.5, -1.0, 1.0, 2.5, 0.1], true),
+            vec![1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_boundary_distance() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        // 矩形：内部取到最近边的距离，外部取到矩形的欧氏距离
+        let rect = Region::rect(0.0, 0.0, 10.0, 4.0);
+        assert!(close(rect.boundary_distance(3.0, 1.0), 1.0));
+        assert!(close(rect.boundary_distance(13.0, 8.0), 5.0));
+
+        // 四分之一扇形：半径边、圆弧、圆弧端点
+        let sector = Sector::new(0.0, 0.0, 10.0, 0.0, PI / 2.0);
+        assert!(close(sector.boundary_distance(5.0, 2.0), 2.0));
+        assert!(close(sector.boundary_distance(0.0, 12.0), 2.0));
+        assert!(close(sector.boundary_distance(10.0, -3.0), 3.0));
+        assert!(close(Annulus::new(0.0, 0.0, 2.0, 5.0).boundary_distance(3.0, 0.0), 1.0));
+
+        // 组合区域取两侧距离的较小值
+        let union = Region::circle(0.0, 0.0, 5.0).union(&Region::circle(20.0, 0.0, 5.0));
+        assert!(close(union.boundary_distance(10.0, 0.0), 5.0));
+    }
+
+    #[test]
+    fn test_selection_strokes() {
+        let mut selection = SelectionRegion::new();
+        let points = vec![
+            1.0, 1.0, // 第一笔内
+            5.0, 1.0, // 第二笔内
+            3.0, 1.0, // 被擦去
+            3.0, 3.5, // 擦去之后重新画上
+            9.0, 9.0, // 外部
+        ];
+
+        // 空选区、空选区上的橡皮擦
+        selection.erase_stroke(&[0.0, 0.0, 1.0, 0.0, 1.0, 1.0], &[3]);
+        assert_eq!(selection.classify(&points, true), vec![0; 5]);
+        assert_eq!(selection.stroke_count(), 0);
+
+        // 两笔相连的矩形，擦去中间一条，再在擦去的区域上方画一笔
+        selection.add_stroke(&[0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0], &[4]);
+        selection.add_stroke(&[3.0, 0.0, 6.0, 0.0, 6.0, 4.0, 3.0, 4.0], &[4]);
+        selection.erase_stroke(&[2.5, -1.0, 3.5, -1.0, 3.5, 5.0, 2.5, 5.0], &[4]);
+        selection.add_stroke(&[2.0, 3.0, 4.0, 3.0, 4.0, 4.0, 2.0, 4.0], &[4]);
+        assert_eq!(selection.classify(&points, true), vec![1, 1, 0, 1, 0]);
+        assert_eq!(selection.stroke_count(), 4);
+        assert!(selection.region().is_some());
+
+        selection.clear();
+        assert_eq!(selection.classify(&points, true), vec![0; 5]);
+        assert!(selection.region().is_none());
+    }
+
+    #[test]
+    fn test_tolerance_scales_with_shape() {
+        use crate::points_in_polygon::Location;
+
+        // 极小的图元：圆心、矩形中心不会因为固定容差被判为边界
+        assert!(matches!(Circle::new(0.0, 0.0, 1e-10).locate(0.0, 0.0), Location::Inside));
+        assert!(matches!(Region::rect(0.0, 0.0, 1e-10, 1e-10).locate(5e-11, 5e-11), Location::Inside));
+        assert!(matches!(Sector::new(0.0, 0.0, 1e-10, 0.0, PI).locate(0.0, 5e-11), Location::Inside));
+
+        // 极大的图元：坐标的舍入误差不超过按尺寸缩放的容差，边界附近的点仍判为边界
+        let circle = Circle::new(0.0, 0.0, 1e7);
+        assert!(matches!(circle.locate(1e7 + 1e-3, 0.0), Location::Boundary));
+        assert!(matches!(circle.locate(1e7 + 1.0, 0.0), Location::Outside));
+        let region = Region::circle(0.0, 0.0, 1e7).union(&Region::rect(0.0, 0.0, 2e7, 1.0));
+        assert!(matches!(region.locate(2e7 + 1e-3, 0.5), Location::Boundary));
+    }
+
+    #[test]
+    fn test_many_strokes() {
+        // 10000笔：沿x轴依次画单位正方形，每画两笔擦去前一笔的右半部分
+        let mut selection = SelectionRegion::new();
+        let square = |x0: f32, x1: f32| vec![x0, 0.0, x1, 0.0, x1, 1.0, x0, 1.0];
+        for k in 0..5000 {
+            let x = k as f32;
+            selection.add_stroke(&square(x, x + 1.0), &[4]);
+            selection.erase_stroke(&square(x + 0.5, x + 1.0), &[4]);
+        }
+        assert_eq!(selection.stroke_count(), 10000);
+
+        let mut points = Vec::new();
+        for k in 0..5000 {
+            points.extend_from_slice(&[k as f32 + 0.25, 0.5, k as f32 + 0.75, 0.5, k as f32 + 0.5, 0.5]);
+        }
+        let labels = selection.classify(&points, true);
+        assert!(labels.chunks(3).all(|l| l == [1, 0, 1]));
+        assert_eq!(selection.classify(&points, false)[..3], [1, 0, 0]);
+
+        // 导出的Region与选区共享笔画，二者都可以安全释放
+        let region = selection.region().unwrap();
+        assert_eq!(region.classify(&points, true), labels);
+        drop(selection);
+        assert_eq!(region.classify(&points[..6], true), vec![1, 0, 1]);
+    }
+}
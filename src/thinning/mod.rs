@@ -0,0 +1,64 @@
+// 点云抽稀模块：按最小间距挑选点的子集，保留的任意两点距离都不小于最小间距，
+// 用于在低缩放级别渲染判断结果之前减少重叠的点
+
+// 输入(js端):
+//     1. 点云 类型Float32Array 例子[x1, y1, x2, y2, ...]
+//     2. 最小间距 min_spacing
+// 输出(js端):
+//     1. 保留的点的索引 类型Uint32Array 按索引升序排列
+// 说明: 按输入顺序贪心挑选，排在前面的点优先保留，因此可以先按重要程度排序再抽稀；
+//     点按边长为最小间距的网格分桶，每个点只需检查相邻的3×3个单元。
+//     最小间距不大于0时保留全部点，坐标不是有限数的点不保留
+
+use alloc::{string::String, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use hashbrown::HashMap;
+use crate::points_in_polygon::index_u32;
+
+pub mod test;
+
+// WebAssembly导出函数：按最小间距抽稀点云，返回保留的点的索引
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn thin_points(
+    points: &[f32],   // 输入点集，格式为[x1, y1, x2, y2, ...]
+    min_spacing: f64, // 保留的点之间的最小距离
+) -> Result<Vec<u32>, ExportError> {
+    thin_points_values(points, min_spacing).map_err(export_error)
+}
+
+// 抽稀的实现，索引超出u32范围时返回错误信息
+pub(crate) fn thin_points_values(points: &[f32], min_spacing: f64) -> Result<Vec<u32>, String> {
+    let point_count = points.len() / 2;
+    if min_spacing.is_nan() || min_spacing <= 0.0 {
+        return (0..point_count).map(index_u32).collect();
+    }
+
+    let min_sq = min_spacing * min_spacing;
+    let mut cells: HashMap<(i64, i64), Vec<(f64, f64)>> = HashMap::new();
+    let mut kept = Vec::new();
+
+    for i in 0..point_count {
+        let (x, y) = (points[i * 2] as f64, points[i * 2 + 1] as f64);
+        if !(x.is_finite() && y.is_finite()) {
+            continue;
+        }
+        // 单元边长等于最小间距，距离小于最小间距的点一定在相邻的单元中
+        let (cx, cy) = ((x / min_spacing).floor() as i64, (y / min_spacing).floor() as i64);
+        let crowded = (cx - 1..=cx + 1).any(|gx| {
+            (cy - 1..=cy + 1).any(|gy| {
+                cells.get(&(gx, gy)).is_some_and(|bucket| {
+                    bucket.iter().any(|&(px, py)| (px - x) * (px - x) + (py - y) * (py - y) < min_sq)
+                })
+            })
+        });
+        if !crowded {
+            cells.entry((cx, cy)).or_default().push((x, y));
+            kept.push(index_u32(i)?);
+        }
+    }
+    Ok(kept)
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use crate::generators::uniform_points;
+    use crate::thinning::thin_points_values;
+
+    #[test]
+    fn test_spacing_is_respected() {
+        let points = uniform_points(3000, 0.0, 0.0, 100.0, 100.0, 9);
+        let kept = thin_points_values(&points, 5.0).unwrap();
+        assert!(!kept.is_empty() && kept.len() < 3000);
+
+        // 保留的点两两距离不小于最小间距
+        for (a, &i) in kept.iter().enumerate() {
+            for &j in &kept[a + 1..] {
+                let (i, j) = (i as usize, j as usize);
+                let d = ((points[i * 2] - points[j * 2]) as f64).hypot((points[i * 2 + 1] - points[j * 2 + 1]) as f64);
+                assert!(d >= 5.0);
+            }
+        }
+
+        // 被丢弃的点都有一个距离小于最小间距的保留点（贪心结果是极大的）
+        for i in 0..3000 {
+            if kept.binary_search(&(i as u32)).is_ok() {
+                continue;
+            }
+            assert!(kept.iter().any(|&j| {
+                let j = j as usize;
+                ((points[i * 2] - points[j * 2]) as f64).hypot((points[i * 2 + 1] - points[j * 2 + 1]) as f64) < 5.0
+            }));
+        }
+    }
+
+    #[test]
+    fn test_input_order_priority_and_edge_cases() {
+        let points = vec![0.0, 0.0, 0.5, 0.0, 1.0, 0.0, 3.0, 0.0, f32::NAN, 0.0];
+        assert_eq!(thin_points_values(&points, 1.0).unwrap(), vec![0, 2, 3]);
+        assert_eq!(thin_points_values(&points[2..], 1.0).unwrap(), vec![0, 2]);
+        assert_eq!(thin_points_values(&points, 0.0).unwrap(), vec![0, 1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,48 @@
+// 多线程模块：浏览器中多线程判断的开关，只在以wasm-threads特性构建的wasm中起作用
+
+// 输入(js端):
+//     1. await init_threads(navigator.hardwareConcurrency) 创建Web Worker线程池（基于wasm-bindgen-rayon），
+//        需要页面处于跨源隔离状态（crossOriginIsolated为true，即设置了COOP/COEP响应头），否则SharedArrayBuffer不可用
+//     2. threads_ready() 批量判断当前是否会分给多个线程执行
+// 说明: 以wasm-threads特性构建时，线程池创建完成之前（或页面未跨源隔离、没有调用init_threads时）批量判断按单线程执行，结果相同；
+//     本机以parallel特性构建时总是并行；其他构建中没有init_threads，threads_ready()总是false
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(all(rayon_backend, target_arch = "wasm32"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+pub mod test;
+
+// 线程池是否已创建完成
+#[cfg(all(rayon_backend, target_arch = "wasm32"))]
+static THREADS_READY: AtomicBool = AtomicBool::new(false);
+
+// WebAssembly导出函数：创建线程池，返回的Promise完成后批量判断开始并行执行
+#[cfg(all(rayon_backend, target_arch = "wasm32"))]
+#[wasm_bindgen]
+pub fn init_threads(num_threads: usize) -> js_sys::Promise {
+    let mark_ready = Closure::once(|_: JsValue| THREADS_READY.store(true, Ordering::Release));
+    let promise = wasm_bindgen_rayon::init_thread_pool(num_threads).then(&mark_ready);
+    // 回调只执行一次，交给js端持有
+    mark_ready.forget();
+    promise
+}
+
+// WebAssembly导出函数：批量判断是否会分给多个线程执行
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn threads_ready() -> bool {
+    #[cfg(all(rayon_backend, target_arch = "wasm32"))]
+    {
+        THREADS_READY.load(Ordering::Acquire)
+    }
+    #[cfg(all(rayon_backend, not(target_arch = "wasm32")))]
+    {
+        true
+    }
+    #[cfg(not(rayon_backend))]
+    {
+        false
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests {
+    use crate::threads::threads_ready;
+
+    #[test]
+    fn test_threads_ready_matches_build() {
+        // 本机构建只在启用parallel特性时并行
+        assert_eq!(threads_ready(), cfg!(feature = "parallel"));
+    }
+}
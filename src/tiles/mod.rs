@@ -0,0 +1,181 @@
+// 瓦片覆盖模块：给出与经纬度多边形相交的Web墨卡托瓦片（quadkey / XYZ）及每个瓦片被多边形覆盖的面积比例，
+// 大规模系统可以先按瓦片预筛点：比例为1的瓦片中的点全在内部，不相交的瓦片中的点全在外部，只有部分覆盖的瓦片需要精确判断
+
+// 输入(js端):
+//     1. 多边形路径点 类型Float32Array 例子[lon1, lat1, lon2, lat2, ...]（度）, 多边形路径点的拆分 类型Uint32Array
+//     2. 缩放级别 zoom 0~MAX_ZOOM，第zoom级共有 2^zoom × 2^zoom 个瓦片
+// 输出(js端):
+//     1. TileCoverage
+//        tiles() 相交瓦片的坐标 类型Uint32Array [x1, y1, x2, y2, ...]，按y再按x递增，y向南增大（与XYZ瓦片服务相同）
+//        fractions() 每个瓦片的覆盖比例 类型Float64Array 0~1，按墨卡托投影下的面积计算
+//        quadkeys() 每个瓦片的quadkey 字符串数组
+// 说明: 纬度限制在墨卡托投影的范围±85.05112878度以内，经度超出±180度的部分被截去；覆盖面积为0（只在边界上接触）的瓦片不输出；
+//     洞按环的顺序确定（与point_in_polygon_scanline相同）；涉及的瓦片总数超过MAX_TILES或zoom超出范围时报错；
+//     只支持四叉树瓦片，不支持H3等六边形格网
+
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::common::build_polygon;
+use crate::points_in_polygon::index_u32;
+
+pub mod test;
+
+// 支持的最大缩放级别
+const MAX_ZOOM: u32 = 24;
+// 多边形边界框覆盖的瓦片总数的上限
+const MAX_TILES: u64 = 1 << 22;
+// 墨卡托投影的纬度范围
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+// 与多边形相交的瓦片及其覆盖比例
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct TileCoverage {
+    zoom: u32,
+    tiles: Vec<u32>,
+    fractions: Vec<f64>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl TileCoverage {
+    pub fn tiles(&self) -> Vec<u32> {
+        self.tiles.clone()
+    }
+
+    pub fn fractions(&self) -> Vec<f64> {
+        self.fractions.clone()
+    }
+
+    pub fn quadkeys(&self) -> Vec<String> {
+        self.tiles.chunks_exact(2).map(|tile| quadkey(tile[0], tile[1], self.zoom)).collect()
+    }
+
+    pub fn tile_count(&self) -> usize {
+        self.fractions.len()
+    }
+}
+
+// WebAssembly导出函数：与多边形相交的瓦片及覆盖比例
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn tile_coverage(
+    polygon: &[f32], // 多边形顶点，格式为[lon1, lat1, lon2, lat2, ...]
+    rings: &[u32],   // 多边形环的分割索引
+    zoom: u32,       // 缩放级别
+) -> Result<TileCoverage, ExportError> {
+    tile_coverage_values(polygon, rings, zoom).map_err(export_error)
+}
+
+// 瓦片覆盖的主流程，参数不合法时返回错误信息
+pub(crate) fn tile_coverage_values(polygon: &[f32], rings: &[u32], zoom: u32) -> Result<TileCoverage, String> {
+    if zoom > MAX_ZOOM {
+        return Err(format!("zoom {} is beyond the maximum {}", zoom, MAX_ZOOM));
+    }
+    let mut result = TileCoverage { zoom, tiles: Vec::new(), fractions: Vec::new() };
+    if polygon.is_empty() || rings.is_empty() {
+        return Ok(result);
+    }
+
+    // 投影到以瓦片为单位的墨卡托坐标，第(x, y)个瓦片为[x, x+1]×[y, y+1]
+    let size = (1u64 << zoom) as f64;
+    let projected: Vec<f64> = polygon
+        .chunks_exact(2)
+        .flat_map(|p| {
+            let lat = (p[1] as f64).clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+            let x = (p[0] as f64 + 180.0) / 360.0 * size;
+            let y = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / core::f64::consts::PI) / 2.0 * size;
+            [x, y]
+        })
+        .collect();
+    let poly = build_polygon(&projected, rings);
+    if poly.rings.is_empty() || !poly.bounds.min_x.is_finite() {
+        return Ok(result);
+    }
+
+    let b = &poly.bounds;
+    if b.max_x < 0.0 || b.min_x > size || b.max_y < 0.0 || b.min_y > size {
+        return Ok(result);
+    }
+    let tile_range = |min: f64, max: f64| ((min.max(0.0).floor() as u64).min(size as u64 - 1), (max.min(size).ceil() as u64).max(1) - 1);
+    let (x0, x1) = tile_range(b.min_x, b.max_x);
+    let (y0, y1) = tile_range(b.min_y, b.max_y);
+    let tile_total = (x1 - x0 + 1) * (y1 - y0 + 1);
+    if tile_total > MAX_TILES {
+        return Err(format!("polygon spans {} tiles at zoom {}, exceeding {}", tile_total, zoom, MAX_TILES));
+    }
+
+    // 每个瓦片内被覆盖的面积：外环加、洞减；先把环裁到列条带，再裁到条带中的每个瓦片
+    let (cols, rows) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+    let mut areas = vec![0.0; cols * rows];
+    for ring in poly.rings.iter().filter(|ring| !ring.boundary_only) {
+        let vertices: Vec<(f64, f64)> =
+            poly.edges[ring.start_idx..ring.start_idx + ring.edge_count].iter().map(|e| (e.x1, e.y1)).collect();
+        let sign = if ring.is_hole { -1.0 } else { 1.0 };
+        for c in 0..cols {
+            let left = (x0 as usize + c) as f64;
+            let strip = clip(&clip(&vertices, 0, left, true), 0, left + 1.0, false);
+            if strip.len() < 3 {
+                continue;
+            }
+            for r in 0..rows {
+                let top = (y0 as usize + r) as f64;
+                let piece = clip(&clip(&strip, 1, top, true), 1, top + 1.0, false);
+                areas[r * cols + c] += sign * area(&piece);
+            }
+        }
+    }
+
+    for r in 0..rows {
+        for c in 0..cols {
+            let fraction = areas[r * cols + c].clamp(0.0, 1.0);
+            if fraction > 0.0 {
+                result.tiles.extend([index_u32(x0 as usize + c)?, index_u32(y0 as usize + r)?]);
+                result.fractions.push(fraction);
+            }
+        }
+    }
+    Ok(result)
+}
+
+// 用直线 坐标[axis] = bound 裁剪环，keep_greater为true时保留不小于bound的一侧（Sutherland-Hodgman）
+fn clip(ring: &[(f64, f64)], axis: usize, bound: f64, keep_greater: bool) -> Vec<(f64, f64)> {
+    let value = |p: (f64, f64)| if axis == 0 { p.0 } else { p.1 };
+    let keep = |p: (f64, f64)| if keep_greater { value(p) >= bound } else { value(p) <= bound };
+    let mut clipped = Vec::with_capacity(ring.len() + 4);
+    for k in 0..ring.len() {
+        let (p, q) = (ring[k], ring[(k + 1) % ring.len()]);
+        if keep(p) {
+            clipped.push(p);
+        }
+        if keep(p) != keep(q) {
+            let t = (bound - value(p)) / (value(q) - value(p));
+            clipped.push((p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1)));
+        }
+    }
+    clipped
+}
+
+// 环面积的绝对值
+fn area(ring: &[(f64, f64)]) -> f64 {
+    let twice: f64 = (0..ring.len())
+        .map(|k| {
+            let (p, q) = (ring[k], ring[(k + 1) % ring.len()]);
+            p.0 * q.1 - q.0 * p.1
+        })
+        .sum();
+    twice.abs() / 2.0
+}
+
+// 瓦片(x, y)在第zoom级的quadkey
+fn quadkey(x: u32, y: u32, zoom: u32) -> String {
+    (1..=zoom)
+        .rev()
+        .map(|level| {
+            let mask = 1 << (level - 1);
+            let digit = (x & mask != 0) as u8 + 2 * (y & mask != 0) as u8;
+            (b'0' + digit) as char
+        })
+        .collect()
+}
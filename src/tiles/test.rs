@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use crate::tiles::tile_coverage_values;
+
+    #[test]
+    fn test_tiles_of_western_hemisphere() {
+        // 西半球北部（经度-180~0，纬度0~85）在第1级恰好是瓦片(0, 0)
+        let polygon = vec![-180.0, 0.0, 0.0, 0.0, 0.0, 85.05113, -180.0, 85.05113];
+        let coverage = tile_coverage_values(&polygon, &[4], 1).unwrap();
+        assert_eq!(coverage.tiles(), vec![0, 0]);
+        assert!((coverage.fractions()[0] - 1.0).abs() < 1e-6);
+        assert_eq!(coverage.quadkeys(), vec!["0".to_string()]);
+
+        // 第2级时覆盖4个瓦片
+        let coverage = tile_coverage_values(&polygon, &[4], 2).unwrap();
+        assert_eq!(coverage.tiles(), vec![0, 0, 1, 0, 0, 1, 1, 1]);
+        assert_eq!(coverage.quadkeys(), vec!["00", "01", "02", "03"]);
+        assert!(coverage.fractions().iter().all(|f| (f - 1.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_partial_coverage_with_hole() {
+        // 经度0~90、赤道附近的窄带，挖去东半部分
+        let polygon = vec![0.0, -1.0, 90.0, -1.0, 90.0, 1.0, 0.0, 1.0, 45.0, -1.0, 90.0, -1.0, 90.0, 1.0, 45.0, 1.0];
+        let coverage = tile_coverage_values(&polygon, &[4], 2).unwrap();
+        // 赤道两侧的瓦片(2, 1)和(2, 2)
+        assert_eq!(coverage.tiles(), vec![2, 1, 2, 2]);
+        assert_eq!(coverage.quadkeys(), vec!["12", "30"]);
+        let fractions = coverage.fractions();
+        assert!((fractions[0] - fractions[1]).abs() < 1e-9);
+        assert!(fractions[0] > 0.0 && fractions[0] < 0.05);
+
+        assert!(tile_coverage_values(&polygon, &[4], 30).is_err());
+        assert_eq!(tile_coverage_values(&[], &[], 3).unwrap().tile_count(), 0);
+    }
+}
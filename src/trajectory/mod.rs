@@ -0,0 +1,236 @@
+// 轨迹模块：把相邻轨迹点连成线段，检测轨迹穿越多边形边界的位置，用于从GPS轨迹中提取围栏事件
+
+// 输入(js端):
+//     1. 轨迹点 类型Float32Array 例子[x1, y1, x2, y2, ...] 按时间顺序排列
+//     2. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array 含义与points_in_polygon模块一致
+// 输出(js端):
+//     1. trajectory_crossings: 类型Float64Array 每次穿越占4个值 [线段索引, x, y, 方向]
+//        线段索引k表示第k个点到第k+1个点之间的线段，方向1表示进入，-1表示离开，按轨迹顺序排列
+//     2. trajectory_time_inside: 类型Float64Array [总时长, 进入时间1, 离开时间1, 进入时间2, 离开时间2, ...]
+//        需额外传入与轨迹点一一对应的时间戳 类型Float64Array，穿越时间在线段上按位置线性插值
+//     3. trajectory_visits: 类型Float64Array 每次停留占6个值 [进入x, 进入y, 进入时间, 离开x, 离开y, 离开时间]
+// 说明: 只在轨迹真正从一侧到达另一侧时产生穿越；擦过顶点或边界、沿边界行进后返回原侧都不产生穿越。
+//     沿边界行进后到达另一侧时，穿越位置记在离开边界处
+
+use alloc::{vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::points_in_polygon::common::RELATIVE_TOLERANCE;
+use crate::points_in_polygon::scanline::ScanlineIndex;
+use crate::points_in_polygon::Location;
+
+pub mod test;
+
+// 一次边界穿越
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Crossing {
+    pub(crate) segment: usize, // 所在线段的索引
+    pub(crate) t: f64,         // 在线段上的参数位置 [0, 1]
+    pub(crate) x: f64,         // 穿越点坐标
+    pub(crate) y: f64,
+    pub(crate) entering: bool, // true为进入，false为离开
+}
+
+// WebAssembly导出函数：检测轨迹与多边形边界的穿越
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn trajectory_crossings(
+    path_points: &[f32], // 轨迹点，格式为[x1, y1, x2, y2, ...]
+    polygon: &[f32],     // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],       // 多边形环的分割索引
+) -> Vec<f64> {
+    let index = ScanlineIndex::new(polygon, rings);
+    let (_, crossings) = find_crossings(path_points, &index);
+
+    let mut result = Vec::with_capacity(crossings.len() * 4);
+    for c in crossings {
+        result.extend_from_slice(&[c.segment as f64, c.x, c.y, if c.entering { 1.0 } else { -1.0 }]);
+    }
+    result
+}
+
+// WebAssembly导出函数：计算轨迹在多边形内部停留的总时长和每次停留的时间区间
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn trajectory_time_inside(
+    path_points: &[f32], // 轨迹点，格式为[x1, y1, x2, y2, ...]
+    timestamps: &[f64],  // 每个轨迹点的时间戳
+    polygon: &[f32],     // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],       // 多边形环的分割索引
+) -> Vec<f64> {
+    let visits = track_visits(path_points, timestamps, polygon, rings);
+
+    let total: f64 = visits.iter().map(|v| v.exit[2] - v.enter[2]).sum();
+    let mut result = Vec::with_capacity(1 + visits.len() * 2);
+    result.push(total);
+    for v in visits {
+        result.push(v.enter[2]);
+        result.push(v.exit[2]);
+    }
+    result
+}
+
+// WebAssembly导出函数：按停留顺序返回每次停留的进入点和离开点
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn trajectory_visits(
+    path_points: &[f32], // 轨迹点，格式为[x1, y1, x2, y2, ...]
+    timestamps: &[f64],  // 每个轨迹点的时间戳
+    polygon: &[f32],     // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],       // 多边形环的分割索引
+) -> Vec<f64> {
+    let visits = track_visits(path_points, timestamps, polygon, rings);
+
+    let mut result = Vec::with_capacity(visits.len() * 6);
+    for v in visits {
+        result.extend_from_slice(&v.enter);
+        result.extend_from_slice(&v.exit);
+    }
+    result
+}
+
+// 一次停留：进入点和离开点，均为 [x, y, 时间]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Visit {
+    pub(crate) enter: [f64; 3],
+    pub(crate) exit: [f64; 3],
+}
+
+// 对齐轨迹点与时间戳后求所有停留
+fn track_visits(path_points: &[f32], timestamps: &[f64], polygon: &[f32], rings: &[u32]) -> Vec<Visit> {
+    // 轨迹点与时间戳数量不一致时取较短者
+    let point_count = (path_points.len() / 2).min(timestamps.len());
+    if point_count == 0 {
+        return Vec::new();
+    }
+
+    let index = ScanlineIndex::new(polygon, rings);
+    visits(&path_points[..point_count * 2], timestamps, &index)
+}
+
+// 按轨迹顺序求每次停留；轨迹起点在内部时以第一个轨迹点作为进入点，终点在内部时以最后一个轨迹点作为离开点
+pub(crate) fn visits(path: &[f32], timestamps: &[f64], index: &ScanlineIndex) -> Vec<Visit> {
+    let last = path.len() / 2 - 1;
+    let (starts_inside, crossings) = find_crossings(path, index);
+
+    let mut visits = Vec::new();
+    let mut open = if starts_inside {
+        Some([path[0] as f64, path[1] as f64, timestamps[0]])
+    } else {
+        None
+    };
+    for c in crossings {
+        let point = [c.x, c.y, crossing_time(timestamps, &c)];
+        match (c.entering, open) {
+            (true, None) => open = Some(point),
+            (false, Some(enter)) => {
+                visits.push(Visit { enter, exit: point });
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(enter) = open {
+        let exit = [path[last * 2] as f64, path[last * 2 + 1] as f64, timestamps[last]];
+        visits.push(Visit { enter, exit });
+    }
+    visits
+}
+
+// 按穿越点在线段上的位置插值时间
+fn crossing_time(timestamps: &[f64], c: &Crossing) -> f64 {
+    let (t0, t1) = (timestamps[c.segment], timestamps[c.segment + 1]);
+    t0 + (t1 - t0) * c.t
+}
+
+// 求轨迹的所有穿越，同时返回轨迹起始时是否在内部（起点在边界上时取第一段离开边界后的状态）
+pub(crate) fn find_crossings(path: &[f32], index: &ScanlineIndex) -> (bool, Vec<Crossing>) {
+    let point_count = path.len() / 2;
+    let edges: Vec<[f64; 4]> = index.edges().collect();
+    // 与多边形的边界判定使用同一容差，长度不超过该值的线段视为静止
+    let tolerance = index.polygon().tolerance;
+    let mut crossings = Vec::new();
+    // 最近一段不在边界上的子线段的状态
+    let mut state: Option<bool> = None;
+    let mut initial = None;
+
+    for segment in 0..point_count.saturating_sub(1) {
+        let (x0, y0) = (path[segment * 2] as f64, path[segment * 2 + 1] as f64);
+        let (x1, y1) = (path[segment * 2 + 2] as f64, path[segment * 2 + 3] as f64);
+        let (dx, dy) = (x1 - x0, y1 - y0);
+        if dx.hypot(dy) <= tolerance {
+            continue;
+        }
+
+        // 线段与各边的交点把线段切成若干子线段，每个子线段整体位于同一侧
+        let breaks = segment_breaks(&edges, x0, y0, x1, y1, tolerance);
+        for pair in breaks.windows(2) {
+            let (t0, t1) = (pair[0], pair[1]);
+            let mid = (t0 + t1) / 2.0;
+            let inside = match index.locate(x0 + dx * mid, y0 + dy * mid) {
+                Location::Inside => true,
+                Location::Outside => false,
+                Location::Boundary => continue,
+            };
+
+            if initial.is_none() {
+                initial = Some(inside);
+            }
+            if state.is_some_and(|previous| previous != inside) {
+                crossings.push(Crossing {
+                    segment,
+                    t: t0,
+                    x: x0 + dx * t0,
+                    y: y0 + dy * t0,
+                    entering: inside,
+                });
+            }
+            state = Some(inside);
+        }
+    }
+
+    (initial.unwrap_or(false), crossings)
+}
+
+// 线段与所有边的交点参数，包含0和1，升序且去重；tolerance为多边形的距离容差，
+// 边所在直线到线段起点的距离、交点超出边端点的距离以及相邻交点的间距都按它判断
+pub(crate) fn segment_breaks(edges: &[[f64; 4]], x0: f64, y0: f64, x1: f64, y1: f64, tolerance: f64) -> Vec<f64> {
+    let (dx, dy) = (x1 - x0, y1 - y0);
+    let len2 = dx * dx + dy * dy;
+    let len = len2.sqrt();
+    let (min_x, max_x) = (x0.min(x1) - tolerance, x0.max(x1) + tolerance);
+    let (min_y, max_y) = (y0.min(y1) - tolerance, y0.max(y1) + tolerance);
+
+    let mut breaks = vec![0.0, 1.0];
+    for &[ax, ay, bx, by] in edges {
+        if ax.max(bx) < min_x || ax.min(bx) > max_x || ay.max(by) < min_y || ay.min(by) > max_y {
+            continue;
+        }
+
+        let (ex, ey) = (bx - ax, by - ay);
+        let denom = dx * ey - dy * ex;
+        let (wx, wy) = (ax - x0, ay - y0);
+
+        let edge_len = ex.hypot(ey);
+        if denom.abs() <= RELATIVE_TOLERANCE * len * edge_len {
+            // 平行：共线时把重叠部分的端点作为断点
+            if (wx * dy - wy * dx).abs() <= tolerance * len {
+                for (px, py) in [(ax, ay), (bx, by)] {
+                    breaks.push(((px - x0) * dx + (py - y0) * dy) / len2);
+                }
+            }
+            continue;
+        }
+
+        let t = (wx * ey - wy * ex) / denom;
+        let u = (wx * dy - wy * dx) / denom;
+        let slack = tolerance / edge_len;
+        if (-slack..=1.0 + slack).contains(&u) {
+            breaks.push(t);
+        }
+    }
+
+    let mut breaks: Vec<f64> = breaks.into_iter().filter(|t| (0.0..=1.0).contains(t)).collect();
+    breaks.sort_by(|a, b| a.total_cmp(b));
+    breaks.dedup_by(|a, b| (*a - *b).abs() * len <= tolerance);
+    breaks
+}
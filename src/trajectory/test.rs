@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use crate::trajectory::{trajectory_crossings, trajectory_time_inside, trajectory_visits};
+
+    fn square_with_hole() -> (Vec<f32>, Vec<u32>) {
+        let polygon = vec![
+            0.0, 0.0, 3.0, 0.0, 3.0, 3.0, 0.0, 3.0, // Outer ring
+            1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0, // Hole
+        ];
+        (polygon, vec![4])
+    }
+
+    #[test]
+    fn test_crossings_through_polygon_and_hole() {
+        let (polygon, rings) = square_with_hole();
+
+        // 穿过洞的水平轨迹，分两段
+        let path = vec![-1.0, 1.5, 1.5, 1.5, 4.0, 1.5];
+        assert_eq!(
+            trajectory_crossings(&path, &polygon, &rings),
+            vec![
+                0.0, 0.0, 1.5, 1.0, // 进入外环
+                0.0, 1.0, 1.5, -1.0, // 进入洞
+                1.0, 2.0, 1.5, 1.0, // 离开洞
+                1.0, 3.0, 1.5, -1.0, // 离开外环
+            ]
+        );
+
+        // 经过顶点的对角线轨迹：顶点处的穿越只记一次
+        let path = vec![-1.0, 4.0, 4.0, -1.0];
+        let crossings = trajectory_crossings(&path, &polygon, &rings);
+        let points: Vec<(f64, f64, f64)> = crossings.chunks(4).map(|c| (c[1], c[2], c[3])).collect();
+        assert_eq!(points, vec![(0.0, 3.0, 1.0), (1.0, 2.0, -1.0), (2.0, 1.0, 1.0), (3.0, 0.0, -1.0)]);
+    }
+
+    #[test]
+    fn test_touching_and_boundary_travel() {
+        let (polygon, rings) = square_with_hole();
+
+        // 只擦过顶点、沿底边行进后回到外部：都不是穿越
+        let path = vec![-1.0, -1.0, 0.0, 0.0, -1.0, 1.0];
+        assert!(trajectory_crossings(&path, &polygon, &rings).is_empty());
+        let path = vec![-1.0, 0.0, 2.0, 0.0, 4.0, -1.0];
+        assert!(trajectory_crossings(&path, &polygon, &rings).is_empty());
+
+        // 沿底边行进后转入内部：在离开边界处记为进入
+        let path = vec![-1.0, 0.0, 0.5, 0.0, 0.5, 0.5];
+        assert_eq!(trajectory_crossings(&path, &polygon, &rings), vec![1.0, 0.5, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_time_inside_with_interpolated_crossings() {
+        let (polygon, rings) = square_with_hole();
+
+        // 从外部出发，穿过洞后停在内部
+        let path = vec![-1.0, 1.5, 1.5, 1.5, 2.5, 1.5];
+        let timestamps = vec![0.0, 10.0, 20.0];
+        // 进入外环: t=4，进入洞: t=8，离开洞: t=15，终点仍在内部: t=20
+        assert_eq!(
+            trajectory_time_inside(&path, &timestamps, &polygon, &rings),
+            vec![9.0, 4.0, 8.0, 15.0, 20.0]
+        );
+
+        // 起点在内部
+        let path = vec![0.5, 0.5, 0.5, 4.5];
+        assert_eq!(
+            trajectory_time_inside(&path, &[100.0, 140.0], &polygon, &rings),
+            vec![25.0, 100.0, 125.0]
+        );
+
+        // 完全在外部
+        let path = vec![-1.0, -1.0, 5.0, -1.0];
+        assert_eq!(trajectory_time_inside(&path, &[0.0, 1.0], &polygon, &rings), vec![0.0]);
+    }
+
+    #[test]
+    fn test_visit_entry_and_exit_points() {
+        let (polygon, rings) = square_with_hole();
+
+        // 与停留时长测试相同的轨迹
+        let path = vec![-1.0, 1.5, 1.5, 1.5, 2.5, 1.5];
+        assert_eq!(
+            trajectory_visits(&path, &[0.0, 10.0, 20.0], &polygon, &rings),
+            vec![
+                0.0, 1.5, 4.0, 1.0, 1.5, 8.0, // 第一次停留：外环进入，洞边离开
+                2.0, 1.5, 15.0, 2.5, 1.5, 20.0, // 第二次停留：离开洞，终点仍在内部
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tiny_polygon_crossings() {
+        // 尺寸远小于1e-9的多边形：线段长度、交点间距都按多边形的容差判断，不会被当作静止或重合
+        let polygon = vec![0.0, 0.0, 1e-10, 0.0, 1e-10, 1e-10, 0.0, 1e-10];
+        let path = vec![-1e-10, 5e-11, 2e-10, 5e-11];
+        let crossings = trajectory_crossings(&path, &polygon, &[4]);
+        let directions: Vec<f64> = crossings.chunks(4).map(|c| c[3]).collect();
+        assert_eq!(directions, vec![1.0, -1.0]);
+    }
+}
@@ -0,0 +1,83 @@
+// 变换模块：对多边形做绕锚点的缩放、旋转，再平移，一次调用得到变换后的副本，
+// 用于交互式移动、缩放、旋转选区时整个过程留在wasm中，结果可直接传给判断函数或PreparedPolygon
+
+// 输入(js端):
+//     1. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array 含义与points_in_polygon模块一致
+//     2. 锚点 anchor 类型Float64Array [x, y]，缩放和旋转都以该点为中心
+//     3. 缩放 scale 类型Float64Array [s] 等比缩放，或 [sx, sy] 分别缩放两个方向
+//     4. 旋转角 rotation 弧度，逆时针（y轴向上）为正
+//     5. 平移 translation 类型Float64Array [dx, dy]
+// 输出(js端):
+//     1. 变换后的路径点 类型Float32Array，拆分索引不变，仍使用输入的rings
+// 说明: 每个顶点依次按 锚点 + 旋转(缩放(顶点 - 锚点)) + 平移 计算；
+//     两个方向的缩放异号（镜像）会使环的绕向反转，此时把每个环的顶点倒序，保持各环原有的绕向，
+//     按绕向区分外环和洞（PipOptions.set_hole_detection）的结果因此不受影响；anchor、scale、translation长度不对时报错
+
+use alloc::{format, string::String, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::common::ring_ranges;
+
+pub mod test;
+
+// WebAssembly导出函数：变换多边形
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn transform_polygon(
+    polygon: &[f32],      // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],        // 多边形环的分割索引
+    anchor: &[f64],       // 锚点 [x, y]
+    scale: &[f64],        // 缩放 [s] 或 [sx, sy]
+    rotation: f64,        // 旋转角（弧度）
+    translation: &[f64],  // 平移 [dx, dy]
+) -> Result<Vec<f32>, ExportError> {
+    transform_values(polygon, rings, anchor, scale, rotation, translation).map_err(export_error)
+}
+
+// 变换的主流程，参数不合法时返回错误信息
+pub(crate) fn transform_values(
+    polygon: &[f32],
+    rings: &[u32],
+    anchor: &[f64],
+    scale: &[f64],
+    rotation: f64,
+    translation: &[f64],
+) -> Result<Vec<f32>, String> {
+    let (ax, ay) = match anchor {
+        [x, y] => (*x, *y),
+        _ => return Err(format!("anchor must have 2 values, got {}", anchor.len())),
+    };
+    let (sx, sy) = match scale {
+        [s] => (*s, *s),
+        [sx, sy] => (*sx, *sy),
+        _ => return Err(format!("scale must have 1 or 2 values, got {}", scale.len())),
+    };
+    let (dx, dy) = match translation {
+        [x, y] => (*x, *y),
+        _ => return Err(format!("translation must have 2 values, got {}", translation.len())),
+    };
+
+    let (sin, cos) = rotation.sin_cos();
+    let mut result = Vec::with_capacity(polygon.len() & !1);
+    for vertex in polygon.chunks_exact(2) {
+        let x = (vertex[0] as f64 - ax) * sx;
+        let y = (vertex[1] as f64 - ay) * sy;
+        result.push((ax + x * cos - y * sin + dx) as f32);
+        result.push((ay + x * sin + y * cos + dy) as f32);
+    }
+
+    // 镜像时倒序每个环的顶点，恢复原有的绕向
+    if sx * sy < 0.0 {
+        for (start, end) in ring_ranges(result.len() / 2, rings) {
+            let ring = &mut result[start * 2..end * 2];
+            ring.reverse();
+            for vertex in ring.chunks_exact_mut(2) {
+                vertex.swap(0, 1);
+            }
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use crate::transform::transform_values;
+    use crate::points_in_polygon::options::{point_in_polygon_with_options, PipOptions};
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_scale_rotate_translate_about_anchor() {
+        let square = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0];
+
+        // 以中心(1, 1)为锚点放大2倍、逆时针旋转90度，再向右平移10
+        let moved = transform_values(&square, &[4], &[1.0, 1.0], &[2.0], std::f64::consts::FRAC_PI_2, &[10.0, 0.0]).unwrap();
+        assert_close(&moved, &[13.0, -1.0, 13.0, 3.0, 9.0, 3.0, 9.0, -1.0]);
+
+        // 单位变换原样返回，两个方向分别缩放
+        assert_close(&transform_values(&square, &[4], &[5.0, 5.0], &[1.0], 0.0, &[0.0, 0.0]).unwrap(), &square);
+        let stretched = transform_values(&square, &[4], &[0.0, 0.0], &[3.0, 0.5], 0.0, &[0.0, 0.0]).unwrap();
+        assert_close(&stretched, &[0.0, 0.0, 6.0, 0.0, 6.0, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_mirror_keeps_ring_winding() {
+        // 洞（顺时针）排在外环（逆时针）之前，按绕向区分
+        let polygon = vec![
+            2.0, 2.0, 2.0, 8.0, 8.0, 8.0, 8.0, 2.0, // 洞，顺时针
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0, // 外环，逆时针
+        ];
+        let rings = [4];
+        let mirrored = transform_values(&polygon, &rings, &[0.0, 0.0], &[-1.0, 1.0], 0.0, &[0.0, 0.0]).unwrap();
+
+        // 每个环的顶点集合是原来的镜像，顺序倒转
+        assert_close(&mirrored[..8], &[-8.0, 2.0, -8.0, 8.0, -2.0, 8.0, -2.0, 2.0]);
+
+        let mut options = PipOptions::new();
+        options.set_hole_detection(1);
+        let points = vec![-1.0, 1.0, -5.0, 5.0, 1.0, 1.0];
+        assert_eq!(point_in_polygon_with_options(&points, &mirrored, &rings, &options), vec![1, 0, 0]);
+    }
+
+    #[test]
+    fn test_rejects_malformed_parameters() {
+        let square = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0];
+        assert!(transform_values(&square, &[], &[0.0], &[1.0], 0.0, &[0.0, 0.0]).is_err());
+        assert!(transform_values(&square, &[], &[0.0, 0.0], &[1.0, 2.0, 3.0], 0.0, &[0.0, 0.0]).is_err());
+        assert!(transform_values(&square, &[], &[0.0, 0.0], &[1.0], 0.0, &[]).is_err());
+    }
+}
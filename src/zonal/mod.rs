@@ -0,0 +1,86 @@
+// 分区统计模块：统计栅格中中心落在多边形内的单元的个数、总和、均值、最小值和最大值，
+// 单元中心的判断直接使用规则点阵判断（每行一条扫描线），无需在JS端生成单元中心的点云
+
+// 输入(js端):
+//     1. 栅格值 类型Float32Array 长度为 width × height，按行优先排列，第0行为最上方（y最大）的一行，与图像和GeoTIFF一致
+//     2. 栅格宽度 width、高度 height（单元数）
+//     3. 栅格范围 类型Float64Array [min_x, min_y, max_x, max_y]
+//     4. 多边形路径点 类型Float32Array, 多边形路径点的拆分 类型Uint32Array
+// 输出(js端):
+//     1. 类型Float64Array [count, sum, mean, min, max]
+//        值为NaN的单元视为无数据，不参与统计；没有参与统计的单元时mean、min、max为NaN
+// 说明: 单元中心落在边界上时视为在多边形内部。栅格值的长度或范围不合法时抛出带原因的错误
+
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+use crate::export::{export_error, ExportError};
+use crate::points_in_polygon::scanline::grid_point_labels;
+
+pub mod test;
+
+// WebAssembly导出函数：栅格在多边形内的分区统计
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn zonal_stats(
+    grid: &[f32],    // 栅格值，行优先，第0行在最上方
+    width: usize,    // 栅格列数
+    height: usize,   // 栅格行数
+    bounds: &[f64],  // 栅格范围 [min_x, min_y, max_x, max_y]
+    polygon: &[f32], // 多边形顶点，格式为[x1, y1, x2, y2, ...]
+    rings: &[u32],   // 多边形环的分割索引
+) -> Result<Vec<f64>, ExportError> {
+    zonal_stats_values(grid, width, height, bounds, polygon, rings).map_err(export_error)
+}
+
+// 分区统计的实现，返回 [count, sum, mean, min, max]
+pub(crate) fn zonal_stats_values(
+    grid: &[f32],
+    width: usize,
+    height: usize,
+    bounds: &[f64],
+    polygon: &[f32],
+    rings: &[u32],
+) -> Result<Vec<f64>, String> {
+    let cells = width
+        .checked_mul(height)
+        .ok_or_else(|| format!("zonal_stats: width {} × height {} overflows the cell count", width, height))?;
+    if grid.len() != cells {
+        return Err(format!("zonal_stats: grid has {} values, width × height is {}", grid.len(), cells));
+    }
+    let &[min_x, min_y, max_x, max_y] = bounds else {
+        return Err(format!("zonal_stats: bounds must have 4 values, got {}", bounds.len()));
+    };
+    if !(min_x < max_x && min_y < max_y) {
+        return Err("zonal_stats: bounds must satisfy min_x < max_x and min_y < max_y".to_string());
+    }
+
+    let (mut count, mut sum) = (0usize, 0.0f64);
+    let (mut min, mut max) = (f64::NAN, f64::NAN);
+    if width > 0 && height > 0 {
+        // 单元中心组成的点阵，从最上方一行开始向下
+        let cell_w = (max_x - min_x) / width as f64;
+        let cell_h = (max_y - min_y) / height as f64;
+        let inside = grid_point_labels(
+            min_x + 0.5 * cell_w,
+            max_y - 0.5 * cell_h,
+            cell_w,
+            -cell_h,
+            width,
+            height,
+            polygon,
+            rings,
+            true,
+        )?;
+
+        for (&value, _) in grid.iter().zip(&inside).filter(|&(value, &flag)| flag == 1 && !value.is_nan()) {
+            let value = value as f64;
+            count += 1;
+            sum += value;
+            min = if count == 1 { value } else { min.min(value) };
+            max = if count == 1 { value } else { max.max(value) };
+        }
+    }
+
+    let mean = if count == 0 { f64::NAN } else { sum / count as f64 };
+    Ok(vec![count as f64, sum, mean, min, max])
+}
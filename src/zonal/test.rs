@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use crate::points_in_polygon::scanline::point_in_polygon_scanline_f64;
+    use crate::zonal::zonal_stats_values;
+
+    #[test]
+    fn test_stats_over_triangle() {
+        // 4×4栅格覆盖 [0,4]×[0,4]，第0行在最上方，值为行号*10+列号
+        let grid: Vec<f32> = (0..16).map(|i| ((i / 4) * 10 + i % 4) as f32).collect();
+        let bounds = [0.0, 0.0, 4.0, 4.0];
+        // 左下角的直角三角形：中心 (0.5,0.5) (1.5,0.5) (2.5,0.5) (0.5,1.5) (1.5,1.5) (0.5,2.5) 在内部
+        let triangle = vec![0.0, 0.0, 3.5, 0.0, 0.0, 3.5];
+        // 对应的栅格行为 3, 3, 3, 2, 2, 1
+        let values = [30.0, 31.0, 32.0, 20.0, 21.0, 10.0];
+        let sum: f64 = values.iter().sum();
+
+        let stats = zonal_stats_values(&grid, 4, 4, &bounds, &triangle, &[3]).unwrap();
+        assert_eq!(stats, vec![6.0, sum, sum / 6.0, 10.0, 32.0]);
+    }
+
+    #[test]
+    fn test_nodata_and_empty() {
+        let mut grid = vec![1.0f32; 4];
+        grid[0] = f32::NAN;
+        let square = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0];
+        assert_eq!(zonal_stats_values(&grid, 2, 2, &[0.0, 0.0, 2.0, 2.0], &square, &[4]).unwrap()[..2], [3.0, 3.0]);
+
+        // 多边形不覆盖任何单元中心
+        let stats = zonal_stats_values(&grid, 2, 2, &[10.0, 10.0, 12.0, 12.0], &square, &[4]).unwrap();
+        assert_eq!(stats[..2], [0.0, 0.0]);
+        assert!(stats[2..].iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let square = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0];
+        assert!(zonal_stats_values(&[1.0; 3], 2, 2, &[0.0, 0.0, 2.0, 2.0], &square, &[4]).is_err());
+        assert!(zonal_stats_values(&[1.0; 4], 2, 2, &[0.0, 0.0, 2.0], &square, &[4]).is_err());
+        assert!(zonal_stats_values(&[1.0; 4], 2, 2, &[2.0, 0.0, 0.0, 2.0], &square, &[4]).is_err());
+        assert!(zonal_stats_values(&[1.0; 4], usize::MAX, 2, &[0.0, 0.0, 2.0, 2.0], &square, &[4]).is_err());
+    }
+
+    #[test]
+    fn test_matches_per_point_classification() {
+        // 凹多边形带洞，单元中心落在整数坐标上，许多中心恰好在顶点和边上
+        let polygon = vec![
+            50.0, 0.0, 62.0, 34.0, 98.0, 36.0, 69.0, 58.0, 80.0, 94.0, 50.0, 72.0, 20.0, 94.0, 31.0, 58.0, 2.0, 36.0, 38.0, 34.0, // 外环
+            46.0, 56.0, 54.0, 56.0, 54.0, 64.0, 46.0, 64.0, // 洞
+        ];
+        let rings = [10];
+        let (width, height) = (101, 101);
+        let grid: Vec<f32> = (0..width * height).map(|i| (i * 7 % 13) as f32).collect();
+        let polygon_f64: Vec<f64> = polygon.iter().map(|&v| v as f64).collect();
+
+        // 栅格整体平移半个容差，单元中心落在距边界框和顶点不到容差的位置
+        for shift in [0.0, 6e-8, -6e-8] {
+            let bounds = [-0.5 + shift, -0.5 + shift, 100.5 + shift, 100.5 + shift];
+
+            // 逐点判断单元中心，中心坐标与分区统计的点阵使用相同的公式
+            let (cell_w, cell_h) = ((bounds[2] - bounds[0]) / width as f64, (bounds[3] - bounds[1]) / height as f64);
+            let mut centers = Vec::with_capacity(width * height * 2);
+            for r in 0..height {
+                for c in 0..width {
+                    centers.push(bounds[0] + 0.5 * cell_w + c as f64 * cell_w);
+                    centers.push(bounds[3] - 0.5 * cell_h + r as f64 * -cell_h);
+                }
+            }
+            let labels = point_in_polygon_scanline_f64(&centers, &polygon_f64, &rings, true);
+            let values: Vec<f64> = grid.iter().zip(&labels).filter(|&(_, &flag)| flag == 1).map(|(&v, _)| v as f64).collect();
+            let sum: f64 = values.iter().sum();
+
+            let stats = zonal_stats_values(&grid, width, height, &bounds, &polygon, &rings).unwrap();
+            assert_eq!(stats[0], values.len() as f64);
+            assert_eq!(stats[1], sum);
+            assert_eq!(stats[3], values.iter().cloned().fold(f64::INFINITY, f64::min));
+            assert_eq!(stats[4], values.iter().cloned().fold(f64::NEG_INFINITY, f64::max));
+        }
+    }
+}